@@ -3,6 +3,7 @@ import { mkdir, mkdtemp, readFile, rm, writeFile } from "node:fs/promises"
 import { tmpdir } from "node:os"
 import { join } from "node:path"
 import { describe, it } from "node:test"
+import { createRosterWorkflowHandlers } from "@repo-edu/application"
 import type {
   WorkflowClient,
   WorkflowHandlerMap,
@@ -305,6 +306,66 @@ describe("CLI workflow-backed behaviors", () => {
     })
   })
 
+  it("validate reports required files missing from a template clone", async () => {
+    await withTempCliDataDirectory(async (rootDirectory) => {
+      const course = makeProfile()
+      await seedCliDataDirectory(rootDirectory, {
+        course,
+        settings: makeSettings(course.id),
+      })
+      const templatePath = join(rootDirectory, "template")
+      await mkdir(join(templatePath, ".git"), { recursive: true })
+      await writeFile(join(templatePath, "README.md"), "# Project 1\n", "utf8")
+
+      const result = await runCli(
+        [
+          "validate",
+          "--assignment",
+          "Project 1",
+          "--template-path",
+          templatePath,
+          "--required-files",
+          "README.md,tests/test_main.py",
+        ],
+        { storageRoot: rootDirectory },
+      )
+      assert.equal(result.exitCode, 1)
+      assert.match(
+        result.stdout,
+        /- missing_required_file \[.+\]: tests\/test_main\.py$/m,
+      )
+      assert.doesNotMatch(result.stdout, /missing_required_file .*README\.md/)
+    })
+  })
+
+  it("validate rejects template paths that are not Git repositories", async () => {
+    await withTempCliDataDirectory(async (rootDirectory) => {
+      const course = makeProfile()
+      await seedCliDataDirectory(rootDirectory, {
+        course,
+        settings: makeSettings(course.id),
+      })
+      const templatePath = join(rootDirectory, "template")
+      await mkdir(templatePath, { recursive: true })
+      await writeFile(join(templatePath, "README.md"), "# Project 1\n", "utf8")
+
+      const result = await runCli(
+        [
+          "validate",
+          "--assignment",
+          "Project 1",
+          "--template-path",
+          templatePath,
+          "--required-files",
+          "README.md",
+        ],
+        { storageRoot: rootDirectory },
+      )
+      assert.equal(result.exitCode, 1)
+      assert.match(result.stderr, /not a Git repository/)
+    })
+  })
+
   it("git verify forwards the configured user-agent into the workflow input", async () => {
     const settings: PersistedAppSettings = {
       ...makeSettings(null),
@@ -342,6 +403,681 @@ describe("CLI workflow-backed behaviors", () => {
     })
   })
 
+  it("roster export --format members round-trips through roster import", async () => {
+    const course = makeProfile()
+    course.roster.students[0].email = "ada@example.edu"
+    course.roster.students.push({
+      ...course.roster.students[0],
+      id: "m_0002",
+      name: "Grace Hopper, RADM",
+      email: "grace@example.edu",
+      studentNumber: "1002",
+      gitUsername: "grace",
+      gitUsernameStatus: "valid",
+    })
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const exported = await runCli(["roster", "export", "--format", "members"], {
+      workflowClient,
+    })
+    const verifiedOnly = await runCli(
+      ["roster", "export", "--format", "members", "--include-git-usernames"],
+      { workflowClient },
+    )
+
+    assert.equal(exported.exitCode, 0)
+    assert.equal(
+      normalize(verifiedOnly.stdout),
+      [
+        "name,email,student_number,git_username,status,enrollment_type",
+        '"Grace Hopper, RADM",grace@example.edu,1002,grace,active,student',
+      ].join("\n"),
+    )
+
+    const rosterHandlers = createRosterWorkflowHandlers({
+      lms: { fetchRoster: async () => [] },
+      userFile: {
+        readText: async (reference) => ({
+          displayName: reference.displayName,
+          mediaType: "text/csv",
+          text: exported.stdout,
+          byteLength: exported.stdout.length,
+        }),
+        writeText: async () => {
+          throw new Error("not used")
+        },
+      },
+    })
+    const emptyCourse = makeProfile()
+    emptyCourse.roster.students = []
+    emptyCourse.roster.groups = []
+    emptyCourse.roster.groupSets = []
+    emptyCourse.roster.assignments = []
+    const imported = await rosterHandlers["roster.importFromFile"]({
+      course: emptyCourse,
+      file: {
+        kind: "user-file-ref",
+        referenceId: "members",
+        displayName: "members.csv",
+        mediaType: "text/csv",
+        byteLength: null,
+      },
+    })
+
+    const identity = (member: PersistedCourse["roster"]["students"][number]) => [
+      member.name,
+      member.email,
+      member.studentNumber,
+      member.gitUsername,
+      member.enrollmentType,
+    ]
+    assert.deepStrictEqual(
+      imported.roster.students.map(identity),
+      course.roster.students.map(identity),
+    )
+  })
+
+  it("roster export rejects unknown formats", async () => {
+    const result = await runCli(["roster", "export", "--format", "xlsx"], {
+      workflowClient: createWorkflowClient({} as WorkflowHandlerMap),
+    })
+
+    assert.equal(result.exitCode, 1)
+    assert.match(result.stderr, /Invalid --format value/)
+  })
+
+  it("git list-repos prints one repository per line with a total", async () => {
+    const settings: PersistedAppSettings = {
+      ...makeSettings(null),
+      gitConnections: [
+        {
+          id: "main-git",
+          provider: "gitlab",
+          baseUrl: "https://gitlab.com",
+          token: "token-1",
+        },
+      ],
+      activeGitConnectionId: "main-git",
+    }
+
+    let listInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "repo.listNamespace": async (input) => {
+        listInput = input
+        return {
+          repositories: [
+            { name: "lab-1", identifier: "lab-1", archived: false },
+            { name: "lab-1", identifier: "team-a/lab-1", archived: true },
+          ],
+        }
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["git", "list-repos", "--namespace", "course-org", "--filter", "lab-*"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(listInput, {
+      credentials: splitAppSettings(settings).credentials,
+      namespace: "course-org",
+      filter: "lab-*",
+      includeArchived: undefined,
+    })
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "lab-1",
+        "team-a/lab-1\t(archived)",
+        "Repository list complete: namespace=course-org total=2",
+      ].join("\n"),
+    )
+  })
+
+  it("repo archive runs the archive workflow for the selected assignment", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+
+    let archiveInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "repo.archive": async (input) => {
+        archiveInput = input
+        return {
+          repositoriesPlanned: 2,
+          repositoriesChanged: 2,
+          repositoriesFailed: 0,
+          completedAt: "2026-03-04T10:00:00.000Z",
+        }
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["repo", "archive", "--assignment", "Project 1"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(archiveInput, {
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      assignmentId: "a1",
+      action: "archive",
+    })
+    assert.equal(
+      normalize(result.stdout),
+      "Repository archive complete: planned=2 changed=2 failed=0 completedAt=2026-03-04T10:00:00.000Z",
+    )
+  })
+
+  it("repo list-prs prints pull requests with the requested state", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+
+    let listInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "repo.listPullRequests": async (input) => {
+        listInput = input
+        return {
+          repositoriesPlanned: 2,
+          repositoriesFailed: 0,
+          pullRequests: [
+            {
+              repositoryName: "team-a-project-1",
+              number: 3,
+              title: "Ready for review",
+              author: "alice",
+              headBranch: "feature",
+              baseBranch: "main",
+              createdAt: "2026-03-04T10:00:00Z",
+              state: "open",
+              url: "https://github.com/org/team-a-project-1/pull/3",
+            },
+          ],
+          completedAt: "2026-03-04T10:00:00.000Z",
+        }
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["repo", "list-prs", "--assignment", "Project 1", "--state", "all"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(listInput, {
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      assignmentId: "a1",
+      state: "all",
+    })
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "team-a-project-1\t#3\topen\talice\tfeature -> main\t2026-03-04T10:00:00Z\tReady for review",
+        "Pull requests listed: repositories=2 pullRequests=1 failed=0",
+      ].join("\n"),
+    )
+  })
+
+  it("repo sync-teams syncs membership and can remove stale members", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+
+    let syncInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "repo.syncTeams": async (input) => {
+        syncInput = input
+        return {
+          teamsPlanned: 3,
+          membersAdded: 2,
+          membersRemoved: 1,
+          teamsUnchanged: 1,
+          teamsMissing: 0,
+          teamsFailed: 0,
+          completedAt: "2026-03-04T10:00:00.000Z",
+        }
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["repo", "sync-teams", "--assignment", "Project 1", "--remove-stale"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(syncInput, {
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      assignmentId: "a1",
+      removeStaleMembers: true,
+    })
+    assert.equal(
+      normalize(result.stdout),
+      "Team sync complete: teams=3 added=2 removed=1 unchanged=1 missing=0 failed=0 completedAt=2026-03-04T10:00:00.000Z",
+    )
+  })
+
+  it("repo stats prints commit counts for a branch since a timestamp", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+
+    let statsInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "repo.commitStats": async (input) => {
+        statsInput = input
+        return {
+          repositoriesPlanned: 2,
+          repositoriesMissing: 1,
+          repositoriesFailed: 0,
+          repositories: [
+            { repositoryName: "team-a-project-1", commitCount: 14 },
+            { repositoryName: "team-b-project-1", commitCount: null },
+          ],
+          totalCommits: 14,
+          completedAt: "2026-09-15T10:00:00.000Z",
+        }
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      [
+        "repo",
+        "stats",
+        "--assignment",
+        "Project 1",
+        "--branch",
+        "main",
+        "--since",
+        "2026-09-01T00:00:00Z",
+      ],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(statsInput, {
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      assignmentId: "a1",
+      branch: "main",
+      since: "2026-09-01T00:00:00.000Z",
+    })
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "team-a-project-1\t14",
+        "team-b-project-1\t(missing)",
+        "Commit stats: repositories=2 commits=14 missing=1 failed=0",
+      ].join("\n"),
+    )
+  })
+
+  it("lms list-group-sets writes group sets as JSON", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+
+    let fetchInput: unknown = null
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "groupSet.fetchAvailableFromLms": async (input) => {
+        fetchInput = input
+        return [
+          { id: "set-1", name: "Lab Teams", groupCount: 4, memberCount: 12 },
+          { id: "set-2", name: "Projects", groupCount: 2, memberCount: 5 },
+        ]
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["lms", "list-group-sets", "--with-counts", "--format", "json"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.deepStrictEqual(fetchInput, {
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      withMemberCounts: true,
+    })
+    assert.deepStrictEqual(JSON.parse(result.stdout), [
+      { id: "set-1", name: "Lab Teams", groupCount: 4, memberCount: 12 },
+      { id: "set-2", name: "Projects", groupCount: 2, memberCount: 5 },
+    ])
+  })
+
+  it("lms list-group-sets prints an aligned table by default", async () => {
+    const course = makeProfile()
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "groupSet.fetchAvailableFromLms": async () => [
+        { id: "set-1", name: "Lab Teams", groupCount: 4 },
+        { id: "set-22", name: "Projects, spring", groupCount: 12 },
+      ],
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const table = await runCli(["lms", "list-group-sets"], { workflowClient })
+    assert.equal(
+      normalize(table.stdout),
+      [
+        "ID      Name              Groups",
+        "set-1   Lab Teams         4",
+        "set-22  Projects, spring  12",
+      ].join("\n"),
+    )
+
+    const csv = await runCli(["lms", "list-group-sets", "--format", "csv"], {
+      workflowClient,
+    })
+    assert.equal(
+      normalize(csv.stdout),
+      [
+        "id,name,group_count",
+        "set-1,Lab Teams,4",
+        'set-22,"Projects, spring",12',
+      ].join("\n"),
+    )
+  })
+
+  it("lms list-group-sets rejects unknown output formats", async () => {
+    const result = await runCli(["lms", "list-group-sets", "--format", "xml"], {
+      workflowClient: createWorkflowClient({} as WorkflowHandlerMap),
+    })
+    assert.equal(result.exitCode, 1)
+    assert.match(result.stderr, /Invalid --format value/)
+  })
+
+  it("roster compact saves the compacted roster", async () => {
+    const course = makeProfile()
+    course.roster.groups.push({
+      id: "g_orphan",
+      name: "orphan",
+      memberIds: [],
+      origin: "local",
+      lmsGroupId: null,
+    })
+    const settings = makeSettings(course.id)
+
+    const saved: PersistedCourse[] = []
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+      "course.save": async (input) => {
+        saved.push(input)
+        return input
+      },
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const dryRun = await runCli(["roster", "compact", "--dry-run"], {
+      workflowClient,
+    })
+    assert.equal(dryRun.exitCode, 0)
+    assert.equal(saved.length, 0)
+    assert.match(dryRun.stdout, /Roster compaction planned: groups=1 /)
+
+    const result = await runCli(["roster", "compact"], { workflowClient })
+    assert.equal(result.exitCode, 0)
+    assert.equal(saved.length, 1)
+    assert.equal(
+      saved[0]?.roster.groups.some((group) => group.id === "g_orphan"),
+      false,
+    )
+    assert.match(result.stdout, /Roster compaction complete: groups=1 /)
+  })
+
+  it("roster export prints teams CSV with quoted names", async () => {
+    const course = makeProfile()
+    course.roster.students[0].gitUsername = "ada"
+    course.roster.groups[1].name = 'Beta, "B"'
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(["roster", "export", "--format", "teams"], {
+      workflowClient,
+    })
+
+    assert.equal(result.exitCode, 0)
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "team_name,member_git_username,assignment",
+        "Alpha,,Project 1",
+        '"Beta, ""B""",ada,Project 1',
+      ].join("\n"),
+    )
+  })
+
+  it("roster export --stream prints the same CSV as the buffered export", async () => {
+    const course = makeProfile()
+    course.roster.students[0].gitUsername = "ada"
+    course.roster.groups[1].name = 'Beta, "B"'
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const buffered = await runCli(["roster", "export", "--format", "teams"], {
+      workflowClient,
+    })
+    const streamed = await runCli(
+      ["roster", "export", "--format", "teams", "--stream"],
+      { workflowClient },
+    )
+
+    assert.equal(streamed.exitCode, 0)
+    assert.equal(streamed.stdout, buffered.stdout)
+
+    const members = await runCli(["roster", "export", "--stream"], {
+      workflowClient,
+    })
+    assert.equal(members.exitCode, 1)
+    assert.match(members.stderr, /--stream requires --format teams/)
+  })
+
+  it("roster diff compares the roster with another course", async () => {
+    const earlier = { ...makeProfile(), id: "seed-course-copy" }
+    const course = makeProfile()
+    const ada = course.roster.students[0]
+    course.roster.students = [
+      { ...ada, name: "Ada King" },
+      { ...ada, id: "m_0002", name: "Grace Hopper", email: "grace@x.edu" },
+    ]
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async ({ courseId }) =>
+        courseId === earlier.id ? earlier : course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const markdown = await runCli(
+      ["roster", "diff", "--against", "seed-course-copy"],
+      { workflowClient },
+    )
+    assert.equal(markdown.exitCode, 0)
+    assert.equal(
+      normalize(markdown.stdout),
+      [
+        "## Added (1)",
+        "",
+        "- Grace Hopper <grace@x.edu>",
+        "",
+        "## Name changes (1)",
+        "",
+        "- Ada Lovelace → Ada King",
+      ].join("\n"),
+    )
+
+    const csv = await runCli(
+      ["roster", "diff", "--against", "seed-course-copy", "--format", "csv"],
+      { workflowClient },
+    )
+    assert.equal(csv.exitCode, 0)
+    assert.equal(
+      normalize(csv.stdout),
+      [
+        "change,id,name,email,previous_name,previous_email",
+        "added,m_0002,Grace Hopper,grace@x.edu,,",
+        "name_changed,m_0001,Ada King,,Ada Lovelace,",
+      ].join("\n"),
+    )
+
+    const unchanged = await runCli(
+      ["roster", "diff", "--against", course.id],
+      { workflowClient },
+    )
+    assert.equal(normalize(unchanged.stdout), "No roster changes.")
+  })
+
+  it("roster stats counts unique active students and enrollment types", async () => {
+    const course = makeProfile()
+    const ada = { ...course.roster.students[0], email: "ada@example.com" }
+    course.roster.students = [
+      ada,
+      { ...ada },
+      { ...ada, id: "m_0002", name: "Grace Hopper", email: "ADA@example.com" },
+      { ...ada, id: "m_0004", name: "Edsger Dijkstra", status: "dropped" },
+    ]
+    course.roster.staff = [
+      { ...ada, id: "m_0003", name: "Alan Turing", enrollmentType: "ta" },
+    ]
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(["roster", "stats"], { workflowClient })
+
+    assert.equal(result.exitCode, 0)
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "Roster stats: activeStudents=2 inactiveStudents=1 duplicateEmails=yes",
+        "- student=4",
+        "- teacher=0",
+        "- ta=1",
+        "- designer=0",
+        "- observer=0",
+        "- other=0",
+      ].join("\n"),
+    )
+  })
+
+  it("roster student-summary lists the student's assignment groups", async () => {
+    const course = makeProfile()
+    course.roster.students[0].email = "ada@example.com"
+    course.roster.students[0].studentNumber = "s-1001"
+    course.roster.students[0].lmsUserId = "canvas-42"
+    const settings = makeSettings(course.id)
+    const handlers: Partial<WorkflowHandlerMap> = {
+      "settings.loadApp": async () => ({
+        ...splitAppSettings(settings),
+        recovery: [],
+      }),
+      "course.load": async () => course,
+    }
+    const workflowClient = createWorkflowClient(handlers as WorkflowHandlerMap)
+
+    const result = await runCli(
+      ["roster", "student-summary", "--student", "ADA@example.com"],
+      { workflowClient },
+    )
+    assert.equal(result.exitCode, 0)
+    assert.equal(
+      normalize(result.stdout),
+      [
+        "Ada Lovelace <ada@example.com> id=m_0001 status=active",
+        "- Project 1\tgroup=Beta",
+      ].join("\n"),
+    )
+
+    const byNumber = await runCli(
+      ["roster", "student-summary", "--student", "s-1001"],
+      { workflowClient },
+    )
+    assert.equal(byNumber.exitCode, 0)
+    assert.equal(normalize(byNumber.stdout), normalize(result.stdout))
+
+    const byLmsUserId = await runCli(
+      ["roster", "student-summary", "--student", "canvas-42"],
+      { workflowClient },
+    )
+    assert.equal(byLmsUserId.exitCode, 0)
+    assert.equal(normalize(byLmsUserId.stdout), normalize(result.stdout))
+
+    const missing = await runCli(
+      ["roster", "student-summary", "--student", "nobody@example.com"],
+      { workflowClient },
+    )
+    assert.equal(missing.exitCode, 1)
+    assert.match(missing.stderr, /No roster member with email/)
+  })
+
   it("repo create fails when selected course has no git connection", async () => {
     await withTempCliDataDirectory(async (rootDirectory) => {
       const course = makeProfile()
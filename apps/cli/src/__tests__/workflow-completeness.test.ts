@@ -21,7 +21,7 @@ const workflowToCommandMatrix: Record<
   "course.load": { kind: "composite", commands: ["course load"] },
   "course.save": {
     kind: "composite",
-    commands: ["repo create", "repo update"],
+    commands: ["repo create", "repo update", "roster compact"],
   },
   "settings.loadApp": {
     kind: "composite",
@@ -30,6 +30,10 @@ const workflowToCommandMatrix: Record<
   "settings.savePreferences": { kind: "composite", commands: ["course load"] },
   "connection.verifyLmsDraft": { kind: "direct", commands: ["lms verify"] },
   "connection.verifyGitDraft": { kind: "direct", commands: ["git verify"] },
+  "groupSet.fetchAvailableFromLms": {
+    kind: "direct",
+    commands: ["lms list-group-sets"],
+  },
   "validation.roster": {
     kind: "composite",
     commands: ["validate"],
@@ -41,8 +45,18 @@ const workflowToCommandMatrix: Record<
   "repo.create": { kind: "direct", commands: ["repo create"] },
   "repo.clone": { kind: "direct", commands: ["repo clone"] },
   "repo.update": { kind: "direct", commands: ["repo update"] },
-  "repo.listNamespace": { kind: "composite", commands: ["repo discover"] },
+  "repo.listNamespace": {
+    kind: "composite",
+    commands: ["repo discover", "git list-repos"],
+  },
   "repo.bulkClone": { kind: "composite", commands: ["repo discover"] },
+  "repo.archive": {
+    kind: "direct",
+    commands: ["repo archive", "repo unarchive"],
+  },
+  "repo.listPullRequests": { kind: "direct", commands: ["repo list-prs"] },
+  "repo.syncTeams": { kind: "direct", commands: ["repo sync-teams"] },
+  "repo.commitStats": { kind: "direct", commands: ["repo stats"] },
 }
 
 describe("CLI workflow-to-command completeness", () => {
@@ -86,7 +100,7 @@ describe("CLI workflow-to-command completeness", () => {
 
     const droppedPaths = [
       ["course", "delete"],
-      ["roster"],
+      ["roster", "show"],
       ["lms", "list-courses"],
       ["lms", "import-students"],
       ["lms", "import-groups"],
@@ -143,6 +143,33 @@ describe("cli workflow runtime", () => {
       assert.equal(outputEvents[0].channel, "info")
       assert.equal(outputEvents[1].channel, "warn")
     })
+
+    it("writes warnings to stderr with a warning prefix by default", async () => {
+      const { client, probe } = createTestCliClient({
+        "course.load": async (
+          _input: unknown,
+          options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+        ) => {
+          options?.onOutput?.({ channel: "info", message: "Loading course." })
+          options?.onOutput?.({
+            channel: "warn",
+            code: "lms_members_missing_email",
+            message: "2 LMS members have no email address.",
+          })
+          return {} as never
+        },
+      })
+
+      await client.run(
+        "course.load",
+        { courseId: "c1" },
+        { signal: new AbortController().signal },
+      )
+
+      const warning = "⚠ 2 LMS members have no email address.\n"
+      assert.deepStrictEqual(probe.stdoutWrites, ["Loading course.\n"])
+      assert.equal(probe.stderrWrites.includes(warning), true)
+    })
   })
 
   describe("cancellation via abort signal", () => {
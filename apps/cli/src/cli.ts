@@ -5,6 +5,7 @@ import { registerCourseCommands } from "./commands/course.js"
 import { registerGitCommands } from "./commands/git.js"
 import { registerLmsCommands } from "./commands/lms.js"
 import { registerRepoCommands } from "./commands/repo.js"
+import { registerRosterCommands } from "./commands/roster.js"
 import { registerUpdateCommand } from "./commands/update.js"
 import { registerValidateCommand } from "./commands/validate.js"
 import { createCliWorkflowClient } from "./workflow-runtime.js"
@@ -32,6 +33,7 @@ export function createProgram(options?: CreateProgramOptions): Command {
   registerLmsCommands(program, createWorkflowClient)
   registerGitCommands(program, createWorkflowClient)
   registerRepoCommands(program, createWorkflowClient)
+  registerRosterCommands(program, createWorkflowClient)
   registerUpdateCommand(program, pkg.version)
   registerValidateCommand(program, createWorkflowClient)
 
@@ -33,6 +33,10 @@ export function toErrorMessage(error: unknown): string {
   return String(error)
 }
 
+export function toCsvCell(value: string): string {
+  return /[",\n\r]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value
+}
+
 function formatSettingsRecoveryWarning(entry: SettingsRecoveryEntry): string {
   if (entry.unit === "unsupported-composite") {
     return `Settings recovery: unsupported app settings were moved to ${entry.backupPath}.`
@@ -8,6 +8,12 @@ import {
 } from "../command-utils.js"
 import { createCliWorkflowClient } from "../workflow-runtime.js"
 
+type GitListReposOptions = {
+  namespace?: string
+  filter?: string
+  includeArchived?: boolean
+}
+
 export function registerGitCommands(
   parent: Command,
   createWorkflow: () => WorkflowClient = createCliWorkflowClient,
@@ -41,4 +47,40 @@ export function registerGitCommands(
         emitCommandError(toErrorMessage(error))
       }
     })
+
+  git
+    .command("list-repos")
+    .description("List repositories in a namespace without cloning them")
+    .requiredOption("--namespace <name>", "Namespace (org or user) to list")
+    .option("--filter <pattern>", "Glob pattern to filter repo names")
+    .option("--include-archived", "Include archived repositories")
+    .action(async function (this: Command, options: GitListReposOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const settings = await loadAppSettings(workflowClient)
+        if (!options.namespace) {
+          throw new Error("--namespace is required")
+        }
+
+        const result = await workflowClient.run("repo.listNamespace", {
+          credentials: settings.credentials,
+          namespace: options.namespace,
+          filter: options.filter,
+          includeArchived: options.includeArchived,
+        })
+
+        // One identifier per line keeps the output pipeable; the identifier
+        // carries the GitLab subgroup path when there is one.
+        for (const entry of result.repositories) {
+          const archivedAnnotation = entry.archived ? "\t(archived)" : ""
+          process.stdout.write(`${entry.identifier}${archivedAnnotation}\n`)
+        }
+        process.stdout.write(
+          `Repository list complete: namespace=${options.namespace} total=${result.repositories.length}\n`,
+        )
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
 }
@@ -1,13 +1,63 @@
-import type { WorkflowClient } from "@repo-edu/application-contract"
+import type {
+  GroupSetLmsSummary,
+  WorkflowClient,
+} from "@repo-edu/application-contract"
 import type { Command } from "commander"
 import {
   emitCommandError,
   loadSelectedCourse,
   requireLmsConnection,
+  toCsvCell,
   toErrorMessage,
 } from "../command-utils.js"
 import { createCliWorkflowClient } from "../workflow-runtime.js"
 
+type LmsListGroupSetsOptions = {
+  withCounts?: boolean
+  format: string
+}
+
+function formatGroupSetTable(
+  groupSets: readonly GroupSetLmsSummary[],
+  withCounts: boolean,
+): string[] {
+  const header = ["ID", "Name", "Groups", ...(withCounts ? ["Members"] : [])]
+  const rows = groupSets.map((groupSet) => [
+    groupSet.id,
+    groupSet.name,
+    String(groupSet.groupCount),
+    ...(withCounts ? [String(groupSet.memberCount ?? 0)] : []),
+  ])
+  const widths = header.map((title, column) =>
+    Math.max(title.length, ...rows.map((row) => row[column].length)),
+  )
+  return [header, ...rows].map((row) =>
+    row
+      .map((cell, column) => cell.padEnd(widths[column]))
+      .join("  ")
+      .trimEnd(),
+  )
+}
+
+function formatGroupSetCsv(
+  groupSets: readonly GroupSetLmsSummary[],
+  withCounts: boolean,
+): string[] {
+  const header = [
+    "id",
+    "name",
+    "group_count",
+    ...(withCounts ? ["member_count"] : []),
+  ]
+  const rows = groupSets.map((groupSet) => [
+    toCsvCell(groupSet.id),
+    toCsvCell(groupSet.name),
+    String(groupSet.groupCount),
+    ...(withCounts ? [String(groupSet.memberCount ?? 0)] : []),
+  ])
+  return [header, ...rows].map((row) => row.join(","))
+}
+
 export function registerLmsCommands(
   parent: Command,
   createWorkflow: () => WorkflowClient = createCliWorkflowClient,
@@ -31,11 +81,18 @@ export function registerLmsCommands(
           baseUrl: connection.baseUrl,
           token: connection.token,
           userAgent: connection.userAgent,
+          tokenExpiresAt: connection.tokenExpiresAt,
         })
 
         process.stdout.write(
           `LMS connection '${connection.name}' verified=${result.verified} checkedAt=${result.checkedAt}\n`,
         )
+        if (result.user) {
+          const { name, email } = result.user
+          process.stdout.write(
+            `Authenticated as ${name ?? "(unnamed user)"}${email ? ` <${email}>` : ""}\n`,
+          )
+        }
         if (!result.verified) {
           process.exitCode = 1
         }
@@ -43,4 +100,50 @@ export function registerLmsCommands(
         emitCommandError(toErrorMessage(error))
       }
     })
+
+  lms
+    .command("list-group-sets")
+    .description("List LMS group sets for the selected course's LMS course")
+    .option("--with-counts", "Fetch each group set to count its members")
+    .option("--format <format>", "Output format: table, json or csv", "table")
+    .action(async function (this: Command, options: LmsListGroupSetsOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        if (
+          options.format !== "table" &&
+          options.format !== "json" &&
+          options.format !== "csv"
+        ) {
+          throw new Error(
+            "Invalid --format value. Expected one of: table, json, csv.",
+          )
+        }
+        const { course, settings } = await loadSelectedCourse(
+          this,
+          workflowClient,
+        )
+        const withCounts = options.withCounts === true
+        const groupSets = await workflowClient.run(
+          "groupSet.fetchAvailableFromLms",
+          {
+            course,
+            credentials: settings.credentials,
+            withMemberCounts: withCounts,
+          },
+        )
+
+        if (options.format === "json") {
+          process.stdout.write(`${JSON.stringify(groupSets, null, 2)}\n`)
+          return
+        }
+        const lines =
+          options.format === "csv"
+            ? formatGroupSetCsv(groupSets, withCounts)
+            : formatGroupSetTable(groupSets, withCounts)
+        process.stdout.write(`${lines.join("\n")}\n`)
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
 }
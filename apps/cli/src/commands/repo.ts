@@ -22,6 +22,8 @@ type RepoCreateOptions = {
   assignment?: string
   all?: boolean
   dryRun?: boolean
+  failOnExisting?: boolean
+  mirrorTemplate?: boolean
   templatePath?: string
 }
 
@@ -37,6 +39,30 @@ type RepoUpdateOptions = {
   templatePath?: string
 }
 
+type RepoArchiveOptions = {
+  assignment?: string
+  all?: boolean
+}
+
+type RepoListPrsOptions = {
+  assignment?: string
+  all?: boolean
+  state: "open" | "closed" | "all"
+}
+
+type RepoSyncTeamsOptions = {
+  assignment?: string
+  all?: boolean
+  removeStale?: boolean
+}
+
+type RepoStatsOptions = {
+  assignment?: string
+  all?: boolean
+  branch?: string
+  since?: string
+}
+
 type RepoDiscoverOptions = {
   namespace?: string
   filter?: string
@@ -256,6 +282,14 @@ export function registerRepoCommands(
     .option("--assignment <name>", "Assignment name or id")
     .option("--all", "Run across all assignments")
     .option("--dry-run", "Show what would be created")
+    .option(
+      "--fail-on-existing",
+      "Abort before creating anything if a planned repository already exists",
+    )
+    .option(
+      "--mirror-template",
+      "Push every template branch and tag instead of only the default branch",
+    )
     .option(
       "--template-path <dir>",
       "Local template directory (must be a Git repository)",
@@ -317,6 +351,8 @@ export function registerRepoCommands(
           credentials: settings.credentials,
           assignmentId: assignment?.id ?? null,
           template,
+          ...(options.failOnExisting ? { failOnExisting: true } : {}),
+          ...(options.mirrorTemplate ? { mirrorTemplate: true } : {}),
         })
 
         let nextCourse = applyTemplateCommitShas(
@@ -491,6 +527,179 @@ export function registerRepoCommands(
       }
     })
 
+  for (const action of ["archive", "unarchive"] as const) {
+    repo
+      .command(action)
+      .description(
+        action === "archive"
+          ? "Archive assignment repositories (read-only, not deleted)"
+          : "Unarchive assignment repositories",
+      )
+      .option("--assignment <name>", "Assignment name or id")
+      .option("--all", "Run across all assignments")
+      .action(async function (this: Command, options: RepoArchiveOptions) {
+        const workflowClient = createWorkflow()
+
+        try {
+          const { course, settings } = await loadSelectedCourse(
+            this,
+            workflowClient,
+          )
+          const assignment = resolveAssignmentSelection(
+            course,
+            options.assignment,
+            options.all,
+          )
+
+          const result = await workflowClient.run("repo.archive", {
+            course,
+            credentials: settings.credentials,
+            assignmentId: assignment?.id ?? null,
+            action,
+          })
+
+          process.stdout.write(
+            `Repository ${action} complete: planned=${result.repositoriesPlanned} changed=${result.repositoriesChanged} failed=${result.repositoriesFailed} completedAt=${result.completedAt}\n`,
+          )
+        } catch (error) {
+          emitCommandError(toErrorMessage(error))
+        }
+      })
+  }
+
+  repo
+    .command("list-prs")
+    .description("List pull requests across assignment repositories")
+    .option("--assignment <name>", "Assignment name or id")
+    .option("--all", "Run across all assignments")
+    .option("--state <state>", "Pull request state: open, closed, all", "open")
+    .action(async function (this: Command, options: RepoListPrsOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course, settings } = await loadSelectedCourse(
+          this,
+          workflowClient,
+        )
+        const assignment = resolveAssignmentSelection(
+          course,
+          options.assignment,
+          options.all,
+        )
+        if (
+          options.state !== "open" &&
+          options.state !== "closed" &&
+          options.state !== "all"
+        ) {
+          throw new Error(
+            "Invalid --state value. Expected one of: open, closed, all.",
+          )
+        }
+
+        const result = await workflowClient.run("repo.listPullRequests", {
+          course,
+          credentials: settings.credentials,
+          assignmentId: assignment?.id ?? null,
+          state: options.state,
+        })
+
+        for (const pullRequest of result.pullRequests) {
+          process.stdout.write(
+            `${pullRequest.repositoryName}\t#${pullRequest.number}\t${pullRequest.state}\t${pullRequest.author ?? "(unknown)"}\t${pullRequest.headBranch} -> ${pullRequest.baseBranch}\t${pullRequest.createdAt}\t${pullRequest.title}\n`,
+          )
+        }
+        process.stdout.write(
+          `Pull requests listed: repositories=${result.repositoriesPlanned} pullRequests=${result.pullRequests.length} failed=${result.repositoriesFailed}\n`,
+        )
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  repo
+    .command("sync-teams")
+    .description("Sync provider team membership with the roster groups")
+    .option("--assignment <name>", "Assignment name or id")
+    .option("--all", "Run across all assignments")
+    .option("--remove-stale", "Remove team members no longer in the group")
+    .action(async function (this: Command, options: RepoSyncTeamsOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course, settings } = await loadSelectedCourse(
+          this,
+          workflowClient,
+        )
+        const assignment = resolveAssignmentSelection(
+          course,
+          options.assignment,
+          options.all,
+        )
+
+        const result = await workflowClient.run("repo.syncTeams", {
+          course,
+          credentials: settings.credentials,
+          assignmentId: assignment?.id ?? null,
+          removeStaleMembers: options.removeStale === true,
+        })
+
+        process.stdout.write(
+          `Team sync complete: teams=${result.teamsPlanned} added=${result.membersAdded} removed=${result.membersRemoved} unchanged=${result.teamsUnchanged} missing=${result.teamsMissing} failed=${result.teamsFailed} completedAt=${result.completedAt}\n`,
+        )
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  repo
+    .command("stats")
+    .description("Count commits in each assignment repository")
+    .option("--assignment <name>", "Assignment name or id")
+    .option("--all", "Run across all assignments")
+    .option("--branch <name>", "Branch to count (defaults to default branch)")
+    .option("--since <timestamp>", "Only count commits since an ISO timestamp")
+    .action(async function (this: Command, options: RepoStatsOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course, settings } = await loadSelectedCourse(
+          this,
+          workflowClient,
+        )
+        const assignment = resolveAssignmentSelection(
+          course,
+          options.assignment,
+          options.all,
+        )
+        const since =
+          options.since === undefined ? null : Date.parse(options.since)
+        if (since !== null && Number.isNaN(since)) {
+          throw new Error(
+            "Invalid --since value. Expected an ISO timestamp such as 2026-09-01.",
+          )
+        }
+
+        const result = await workflowClient.run("repo.commitStats", {
+          course,
+          credentials: settings.credentials,
+          assignmentId: assignment?.id ?? null,
+          branch: options.branch ?? null,
+          since: since === null ? null : new Date(since).toISOString(),
+        })
+
+        for (const repository of result.repositories) {
+          process.stdout.write(
+            `${repository.repositoryName}\t${repository.commitCount ?? "(missing)"}\n`,
+          )
+        }
+        process.stdout.write(
+          `Commit stats: repositories=${result.repositoriesPlanned} commits=${result.totalCommits} missing=${result.repositoriesMissing} failed=${result.repositoriesFailed}\n`,
+        )
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
   repo
     .command("discover")
     .description(
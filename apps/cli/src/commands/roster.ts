@@ -0,0 +1,276 @@
+import type { WorkflowClient } from "@repo-edu/application-contract"
+import { groupsForStudent } from "@repo-edu/domain/group-selection"
+import { compactRoster } from "@repo-edu/domain/group-set"
+import {
+  collectAssignmentTeams,
+  exportTeamsCsvRows,
+  iterateTeamsCsvRows,
+  teamsCsvHeaders,
+} from "@repo-edu/domain/group-set-import-export"
+import {
+  allRosterMembers,
+  diffRosters,
+  findMemberByEmail,
+  findMemberByLmsUserId,
+  findMemberByStudentNumber,
+  formatRosterDiffMarkdown,
+  hasDuplicateEmails,
+  inactiveStudents,
+  membersByEnrollmentType,
+  rosterDiffCsvHeaders,
+  rosterDiffCsvRows,
+  rosterMemberCsvHeaders,
+  rosterMemberCsvRow,
+  uniqueActiveStudentCount,
+} from "@repo-edu/domain/roster"
+import {
+  enrollmentTypeKinds,
+  type PersistedCourse,
+  type TeamsCsvRow,
+} from "@repo-edu/domain/types"
+import type { Command } from "commander"
+import {
+  emitCommandError,
+  loadSelectedCourse,
+  toCsvCell,
+  toErrorMessage,
+} from "../command-utils.js"
+import { createCliWorkflowClient } from "../workflow-runtime.js"
+
+type RosterCompactOptions = {
+  dryRun?: boolean
+}
+
+type RosterExportOptions = {
+  format: string
+  includeGitUsernames?: boolean
+  stream?: boolean
+}
+
+function formatMemberCsv(
+  course: PersistedCourse,
+  includeGitUsernamesOnly: boolean,
+): string {
+  const members = allRosterMembers(course.roster).filter(
+    (member) =>
+      !includeGitUsernamesOnly || member.gitUsernameStatus !== "unknown",
+  )
+  return [
+    rosterMemberCsvHeaders.join(","),
+    ...members.map((member) => {
+      const row = rosterMemberCsvRow(member)
+      return rosterMemberCsvHeaders
+        .map((header) => toCsvCell(row[header]))
+        .join(",")
+    }),
+  ].join("\n")
+}
+
+function formatTeamsCsvLine(row: TeamsCsvRow): string {
+  return teamsCsvHeaders.map((header) => toCsvCell(row[header])).join(",")
+}
+
+function formatTeamsCsv(course: PersistedCourse): string {
+  const rows = exportTeamsCsvRows(collectAssignmentTeams(course.roster))
+  return [teamsCsvHeaders.join(","), ...rows.map(formatTeamsCsvLine)].join(
+    "\n",
+  )
+}
+
+type RosterDiffOptions = {
+  against: string
+  format: string
+}
+
+type RosterStudentSummaryOptions = {
+  student: string
+}
+
+export function registerRosterCommands(
+  parent: Command,
+  createWorkflow: () => WorkflowClient = createCliWorkflowClient,
+): void {
+  const roster = parent.command("roster").description("Roster operations")
+
+  roster
+    .command("compact")
+    .description(
+      "Remove empty orphan groups, dangling group references and empty group sets",
+    )
+    .option("--dry-run", "Report what would be removed without saving")
+    .action(async function (this: Command, options: RosterCompactOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course } = await loadSelectedCourse(this, workflowClient)
+        const { roster: compacted, report } = compactRoster(course.roster)
+
+        const changed = compacted !== course.roster
+        if (changed && options.dryRun !== true) {
+          await workflowClient.run("course.save", {
+            ...course,
+            roster: compacted,
+          })
+        }
+
+        process.stdout.write(
+          `Roster compaction ${options.dryRun === true ? "planned" : "complete"}: groups=${report.groupsRemoved} danglingGroupIds=${report.danglingGroupIdsRemoved} groupSets=${report.groupSetsRemoved}\n`,
+        )
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  roster
+    .command("export")
+    .description("Print roster data as CSV")
+    .option(
+      "--format <format>",
+      "Export format: members (the desktop member export columns) or teams (team_name, member_git_username, assignment)",
+      "members",
+    )
+    .option(
+      "--include-git-usernames",
+      "Skip members whose Git username status is unknown (members format)",
+    )
+    .option(
+      "--stream",
+      "Write rows as they are produced instead of buffering (teams format)",
+    )
+    .action(async function (this: Command, options: RosterExportOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        if (options.format !== "members" && options.format !== "teams") {
+          throw new Error(
+            "Invalid --format value. Expected one of: members, teams.",
+          )
+        }
+        if (options.stream === true && options.format !== "teams") {
+          throw new Error("--stream requires --format teams.")
+        }
+        const { course } = await loadSelectedCourse(this, workflowClient)
+        if (options.stream === true) {
+          process.stdout.write(`${teamsCsvHeaders.join(",")}\n`)
+          for (const row of iterateTeamsCsvRows(
+            collectAssignmentTeams(course.roster),
+          )) {
+            process.stdout.write(`${formatTeamsCsvLine(row)}\n`)
+          }
+          return
+        }
+
+        const csv =
+          options.format === "teams"
+            ? formatTeamsCsv(course)
+            : formatMemberCsv(course, options.includeGitUsernames === true)
+        process.stdout.write(`${csv}\n`)
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  roster
+    .command("diff")
+    .description(
+      "Compare the roster with another course's roster, such as a kept copy",
+    )
+    .requiredOption(
+      "--against <course-id>",
+      "Course holding the earlier roster",
+    )
+    .option("--format <format>", "Output format: markdown or csv", "markdown")
+    .action(async function (this: Command, options: RosterDiffOptions) {
+      const workflowClient = createWorkflow()
+
+      try {
+        if (options.format !== "markdown" && options.format !== "csv") {
+          throw new Error(
+            "Invalid --format value. Expected one of: markdown, csv.",
+          )
+        }
+        const { course } = await loadSelectedCourse(this, workflowClient)
+        const earlier = await workflowClient.run("course.load", {
+          courseId: options.against,
+        })
+        const diff = diffRosters(earlier.roster, course.roster)
+
+        if (options.format === "csv") {
+          const lines = [
+            rosterDiffCsvHeaders.join(","),
+            ...rosterDiffCsvRows(diff).map((row) =>
+              row.map(toCsvCell).join(","),
+            ),
+          ]
+          process.stdout.write(`${lines.join("\n")}\n`)
+          return
+        }
+        process.stdout.write(formatRosterDiffMarkdown(diff))
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  roster
+    .command("stats")
+    .description("Print student status and enrollment type counts")
+    .action(async function (this: Command) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course } = await loadSelectedCourse(this, workflowClient)
+        process.stdout.write(
+          `Roster stats: activeStudents=${uniqueActiveStudentCount(course.roster)} inactiveStudents=${inactiveStudents(course.roster).length} duplicateEmails=${hasDuplicateEmails(course.roster) ? "yes" : "no"}\n`,
+        )
+        for (const enrollmentType of enrollmentTypeKinds) {
+          const count = membersByEnrollmentType(
+            course.roster,
+            enrollmentType,
+          ).length
+          process.stdout.write(`- ${enrollmentType}=${count}\n`)
+        }
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+
+  roster
+    .command("student-summary")
+    .description("List the assignment groups one roster member belongs to")
+    .requiredOption(
+      "--student <email|number|lms-id>",
+      "Email, student number or LMS user id of the student or staff member",
+    )
+    .action(async function (
+      this: Command,
+      options: RosterStudentSummaryOptions,
+    ) {
+      const workflowClient = createWorkflow()
+
+      try {
+        const { course } = await loadSelectedCourse(this, workflowClient)
+        const member =
+          findMemberByEmail(course.roster, options.student) ??
+          findMemberByStudentNumber(course.roster, options.student) ??
+          findMemberByLmsUserId(course.roster, options.student)
+        if (member === undefined) {
+          throw new Error(
+            `No roster member with email, student number or LMS user id '${options.student}' in course '${course.id}'.`,
+          )
+        }
+
+        const memberships = groupsForStudent(course.roster, member.id)
+        process.stdout.write(
+          `${member.name} <${member.email}> id=${member.id} status=${member.status}\n`,
+        )
+        if (memberships.length === 0) {
+          process.stdout.write("- No assignment groups.\n")
+        }
+        for (const { assignment, group } of memberships) {
+          process.stdout.write(`- ${assignment.name}\tgroup=${group.name}\n`)
+        }
+      } catch (error) {
+        emitCommandError(toErrorMessage(error))
+      }
+    })
+}
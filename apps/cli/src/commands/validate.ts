@@ -16,7 +16,22 @@ export function registerValidateCommand(
     .command("validate")
     .description("Validate assignment readiness")
     .requiredOption("--assignment <name>", "Assignment name or id")
-    .action(async function (this: Command, options: { assignment: string }) {
+    .option(
+      "--required-files <files>",
+      "Comma-separated files the template must contain",
+    )
+    .option(
+      "--template-path <dir>",
+      "Local template clone to check required files against",
+    )
+    .action(async function (
+      this: Command,
+      options: {
+        assignment: string
+        requiredFiles?: string
+        templatePath?: string
+      },
+    ) {
       const workflowClient = createWorkflow()
 
       try {
@@ -41,6 +56,12 @@ export function registerValidateCommand(
           {
             course,
             assignmentId: assignment.id,
+            ...(options.templatePath !== undefined
+              ? { templatePath: options.templatePath }
+              : {}),
+            ...(options.requiredFiles !== undefined
+              ? { requiredFiles: options.requiredFiles.split(",") }
+              : {}),
           },
         )
 
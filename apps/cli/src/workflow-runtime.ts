@@ -1,6 +1,7 @@
 import {
   createConnectionWorkflowHandlers,
   createCourseWorkflowHandlers,
+  createGroupSetWorkflowHandlers,
   createRepositoryWorkflowHandlers,
   createSettingsWorkflowHandlers,
   createValidationWorkflowHandlers,
@@ -16,6 +17,7 @@ import {
   createNodeGitCommandPort,
   createNodeHttpPort,
 } from "@repo-edu/host-node"
+import type { UserFilePort } from "@repo-edu/host-runtime-contract"
 import { createGitProviderDispatch } from "@repo-edu/integrations-git"
 import { createLmsProviderDispatch } from "@repo-edu/integrations-lms"
 import {
@@ -23,6 +25,17 @@ import {
   createCliCourseStore,
 } from "./state-store.js"
 
+// Group-set file import and export stay GUI-only, so the CLI never reaches
+// the user-file port.
+const unavailableUserFilePort: UserFilePort = {
+  async readText(): Promise<never> {
+    throw new Error("User file access is not available in the CLI.")
+  },
+  async writeText(): Promise<never> {
+    throw new Error("User file access is not available in the CLI.")
+  },
+}
+
 export type CliWorkflowRuntimeOptions = {
   storageRoot?: string
 }
@@ -35,10 +48,15 @@ export function createCliWorkflowHandlers(
   const http = createNodeHttpPort()
   const lms = createLmsProviderDispatch(http)
   const git = createGitProviderDispatch(http)
+  const fileSystem = createNodeFileSystemPort()
 
   const courseHandlers = createCourseWorkflowHandlers(courseStore)
   const connectionHandlers = createConnectionWorkflowHandlers({ lms, git })
   const settingsHandlers = createSettingsWorkflowHandlers(appSettingsStore)
+  const groupSetHandlers = createGroupSetWorkflowHandlers({
+    lms,
+    userFile: unavailableUserFilePort,
+  })
 
   return {
     "course.list": courseHandlers["course.list"],
@@ -50,11 +68,13 @@ export function createCliWorkflowHandlers(
       connectionHandlers["connection.verifyLmsDraft"],
     "connection.verifyGitDraft":
       connectionHandlers["connection.verifyGitDraft"],
-    ...createValidationWorkflowHandlers(),
+    "groupSet.fetchAvailableFromLms":
+      groupSetHandlers["groupSet.fetchAvailableFromLms"],
+    ...createValidationWorkflowHandlers({ fileSystem }),
     ...createRepositoryWorkflowHandlers({
       git,
       gitCommand: createNodeGitCommandPort(),
-      fileSystem: createNodeFileSystemPort(),
+      fileSystem,
     }),
   }
 }
@@ -75,10 +95,12 @@ export function createCliWorkflowClientFromBase(
   }
 
   function writeOutputToRuntime(event: DiagnosticOutput): void {
+    if (event.channel === "warn") {
+      runtimeProcess.stderr.write(`⚠ ${event.message}\n`)
+      return
+    }
     const stream =
-      event.channel === "stderr" || event.channel === "warn"
-        ? runtimeProcess.stderr
-        : runtimeProcess.stdout
+      event.channel === "stderr" ? runtimeProcess.stderr : runtimeProcess.stdout
     stream.write(`${event.message}\n`)
   }
 
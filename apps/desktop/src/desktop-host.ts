@@ -43,6 +43,7 @@ const openDialogFilterByFormat: Record<
   xlsx: { name: "Excel", extensions: ["xlsx"] },
   json: { name: "JSON", extensions: ["json"] },
   txt: { name: "Text", extensions: ["txt"] },
+  yaml: { name: "YAML", extensions: ["yaml", "yml"] },
 }
 
 const saveDialogFilterByFormat: Record<
@@ -53,6 +54,7 @@ const saveDialogFilterByFormat: Record<
   xlsx: { name: "Excel", extensions: ["xlsx"] },
   json: { name: "JSON", extensions: ["json"] },
   txt: { name: "Text", extensions: ["txt"] },
+  yaml: { name: "YAML", extensions: ["yaml", "yml"] },
 }
 
 function inferFormatFromPath(filePath: string): FileFormat | null {
@@ -70,6 +72,9 @@ function inferFormatFromPath(filePath: string): FileFormat | null {
   if (extension === ".txt") {
     return "txt"
   }
+  if (extension === ".yaml" || extension === ".yml") {
+    return "yaml"
+  }
 
   return null
 }
@@ -84,6 +89,8 @@ function mediaTypeForFormat(format: FileFormat | null): string | null {
       return "application/json"
     case "txt":
       return "text/plain"
+    case "yaml":
+      return "application/yaml"
     default:
       return null
   }
@@ -12,6 +12,7 @@ import {
   type PersistedAppCredentials,
 } from "@repo-edu/domain/settings"
 import {
+  checkStorageDirectoryAccess,
   createNodeFileSystemPort,
   createNodeGitCommandPort,
   createNodeHttpPort,
@@ -459,6 +460,25 @@ function buildUpdateMenuItems(): MenuItemConstructorOptions[] {
   return items
 }
 
+// Read-only storage (containers, locked-down lab machines) otherwise only
+// surfaces as a failed save after the user has already made changes.
+async function warnOnStorageAccessIssues(storageRoot: string) {
+  const issues = await checkStorageDirectoryAccess([
+    join(storageRoot, "settings"),
+    join(storageRoot, "courses"),
+  ])
+  if (issues.length === 0) return
+
+  await dialog.showMessageBox({
+    type: "warning",
+    title: `${desktopAppName} storage is not writable`,
+    message: "Settings and courses may not be saved.",
+    detail: issues.map((issue) => issue.message).join("\n"),
+    buttons: ["OK"],
+    defaultId: 0,
+  })
+}
+
 async function showAboutDialog() {
   const version = app.getVersion()
   const runtime = app.isPackaged ? "Packaged build" : "Development build"
@@ -959,6 +979,7 @@ async function createWindow(): Promise<BrowserWindow> {
 if (hasSingleInstanceLock) {
   app.whenReady().then(async () => {
     storageRootPath = resolveStorageRootPath()
+    void warnOnStorageAccessIssues(storageRootPath)
     bindUpdaterMenu()
 
     let shutdownPhase: "idle" | "draining" | "ready" = "idle"
@@ -3,6 +3,7 @@ import {
   type CourseStore,
   createAnalysisWorkflowHandlers,
   createConnectionWorkflowHandlers,
+  createCourseBundleWorkflowHandlers,
   createCourseWorkflowHandlers,
   createExaminationArchive,
   createExaminationArchiveWorkflowHandlers,
@@ -241,6 +242,10 @@ function createDesktopWorkflowRegistry(
 
   return {
     ...createCourseWorkflowHandlers(ports.courseStore),
+    ...createCourseBundleWorkflowHandlers({
+      courseStore: ports.courseStore,
+      userFile: ports.userFile,
+    }),
     ...wrappedSettingsHandlers,
     ...createConnectionWorkflowHandlers({ lms, git }),
     ...createLlmConnectionWorkflowHandlers({
@@ -258,7 +263,7 @@ function createDesktopWorkflowRegistry(
       userFile: ports.userFile,
       git,
     }),
-    ...createValidationWorkflowHandlers(),
+    ...createValidationWorkflowHandlers({ fileSystem: ports.fileSystem }),
     ...createRepositoryWorkflowHandlers({
       git,
       gitCommand: ports.gitCommand,
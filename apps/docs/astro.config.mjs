@@ -68,6 +68,7 @@ export default defineConfig({
                     { label: "LMS Commands", slug: "cli/lms-commands" },
                     { label: "Repository Commands", slug: "cli/repo-commands" },
                     { label: "Git Commands", slug: "cli/git-commands" },
+                    { label: "Roster Commands", slug: "cli/roster-commands" },
                     { label: "Validate Commands", slug: "cli/validate-commands" },
                   ],
                 },
@@ -1,6 +1,7 @@
 import {
   createAnalysisWorkflowHandlers,
   createConnectionWorkflowHandlers,
+  createCourseBundleWorkflowHandlers,
   createCourseWorkflowHandlers,
   createExaminationArchiveWorkflowHandlers,
   createExaminationWorkflowHandlers,
@@ -234,6 +235,12 @@ export function createDocsDemoRuntime() {
     ) {
       return
     },
+    async listTeamMembers() {
+      return []
+    },
+    async removeTeamMembers() {
+      return
+    },
     async getRepositoryDefaultBranchHead(
       _draft: unknown,
       request: { owner: string; repositoryName: string },
@@ -267,6 +274,9 @@ export function createDocsDemoRuntime() {
         created: true,
       }
     },
+    async listPullRequests() {
+      return []
+    },
     async resolveRepositoryCloneUrls(
       _draft: unknown,
       request: { organization: string; repositoryNames: string[] },
@@ -282,6 +292,15 @@ export function createDocsDemoRuntime() {
     async listRepositories() {
       return { repositories: [] }
     },
+    async getCommitCount() {
+      return null
+    },
+    async archiveRepository() {
+      return
+    },
+    async unarchiveRepository() {
+      return
+    },
   }
 
   const gitCommandPort = analysisGitMock.gitCommandPort
@@ -289,6 +308,10 @@ export function createDocsDemoRuntime() {
 
   const workflowHandlers = {
     ...createCourseWorkflowHandlers(courseStore),
+    ...createCourseBundleWorkflowHandlers({
+      courseStore: courseStore,
+      userFile: browserMockHost.userFilePort,
+    }),
     ...createSettingsWorkflowHandlers(appSettingsStore),
     ...createConnectionWorkflowHandlers({
       lms: lmsPorts,
@@ -318,7 +341,7 @@ export function createDocsDemoRuntime() {
         },
       }),
     }),
-    ...createValidationWorkflowHandlers(),
+    ...createValidationWorkflowHandlers({ fileSystem: fileSystemPort }),
     ...createRosterWorkflowHandlers({
       lms: lmsPorts,
       userFile: browserMockHost.userFilePort,
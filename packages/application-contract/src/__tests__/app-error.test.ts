@@ -4,6 +4,7 @@ import {
   createCancelledAppError,
   createTransportAppError,
   isAppError,
+  withAppErrorContext,
 } from "../index.js"
 
 describe("isAppError", () => {
@@ -25,6 +26,7 @@ describe("isAppError", () => {
       "not-found",
       "conflict",
       "provider",
+      "not-supported",
       "persistence",
       "unexpected",
     ] as const
@@ -69,3 +71,49 @@ describe("isAppError", () => {
     assert.equal(isAppError({ message: "no type" }), false)
   })
 })
+
+describe("withAppErrorContext", () => {
+  it("prefixes the message and keeps the other fields", () => {
+    const error = withAppErrorContext(
+      {
+        type: "provider",
+        message: "Canvas returned 500",
+        provider: "canvas",
+        operation: "fetchRoster",
+        retryable: true,
+      },
+      "Fetching the LMS roster",
+    )
+
+    assert.deepStrictEqual(error, {
+      type: "provider",
+      message: "Fetching the LMS roster: Canvas returned 500",
+      provider: "canvas",
+      operation: "fetchRoster",
+      retryable: true,
+    })
+  })
+
+  it("chains contexts with the outermost first", () => {
+    const inner = withAppErrorContext(
+      { type: "unexpected", message: "boom", retryable: false },
+      "Reading group set",
+    )
+    const outer = withAppErrorContext(inner, () => "Syncing 'Lab groups'")
+
+    assert.equal(outer.message, "Syncing 'Lab groups': Reading group set: boom")
+  })
+
+  it("only builds lazy context when it is applied", () => {
+    let calls = 0
+    const cancelled = createCancelledAppError()
+
+    const result = withAppErrorContext(cancelled, () => {
+      calls += 1
+      return "Importing roster"
+    })
+
+    assert.equal(result, cancelled)
+    assert.equal(calls, 0)
+  })
+})
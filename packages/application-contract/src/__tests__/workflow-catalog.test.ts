@@ -28,6 +28,8 @@ describe("application-contract workflow catalog", () => {
       "course.load",
       "course.save",
       "course.delete",
+      "course.exportBundle",
+      "course.importBundle",
       "settings.loadApp",
       "settings.saveCredentials",
       "settings.savePreferences",
@@ -52,6 +54,10 @@ describe("application-contract workflow catalog", () => {
       "repo.update",
       "repo.listNamespace",
       "repo.bulkClone",
+      "repo.archive",
+      "repo.listPullRequests",
+      "repo.syncTeams",
+      "repo.commitStats",
       "userFile.inspectSelection",
       "userFile.exportPreview",
       "analysis.run",
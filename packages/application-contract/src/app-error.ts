@@ -59,6 +59,12 @@ export type AppError =
       operation: string
       retryable: boolean
     }
+  | {
+      type: "not-supported"
+      message: string
+      provider: LmsProviderKind | GitProviderKind
+      operation: string
+    }
   | {
       type: "persistence"
       message: string
@@ -85,6 +91,8 @@ export const appErrorOwnership = {
     "Only packages/application may expose conflict after detecting write or identity collisions.",
   provider:
     "Only packages/application may normalize LMS, Git, or subprocess adapter failures into provider errors.",
+  "not-supported":
+    "Only packages/application may reject operations a provider adapter does not implement.",
   persistence:
     "Only packages/application may normalize settings, course, and user-file boundary failures into persistence errors.",
   unexpected:
@@ -113,6 +121,24 @@ export function createCancelledAppError(
   }
 }
 
+/**
+ * Prefixes the message with what the workflow was doing when the error
+ * occurred, giving `"<context>: <message>"`. Context can be chained and the
+ * outermost prefix comes first. Pass a function to build the context lazily.
+ * Cancellations are returned unchanged.
+ */
+export function withAppErrorContext(
+  error: AppError,
+  context: string | (() => string),
+): AppError {
+  if (error.type === "cancelled") {
+    return error
+  }
+
+  const prefix = typeof context === "function" ? context() : context
+  return { ...error, message: `${prefix}: ${error.message}` }
+}
+
 const appErrorTypes = new Set<string>([
   "transport",
   "cancelled",
@@ -120,6 +146,7 @@ const appErrorTypes = new Set<string>([
   "not-found",
   "conflict",
   "provider",
+  "not-supported",
   "persistence",
   "unexpected",
 ])
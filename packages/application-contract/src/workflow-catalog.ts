@@ -29,6 +29,16 @@ export const workflowCatalog: Record<WorkflowId, WorkflowMetadata> = {
     progress: "none",
     cancellation: "non-cancellable",
   },
+  "course.exportBundle": {
+    delivery: ["desktop", "docs"],
+    progress: "milestone",
+    cancellation: "cooperative",
+  },
+  "course.importBundle": {
+    delivery: ["desktop", "docs"],
+    progress: "milestone",
+    cancellation: "cooperative",
+  },
   "settings.loadApp": {
     delivery: ["desktop", "docs", "cli"],
     progress: "none",
@@ -80,7 +90,7 @@ export const workflowCatalog: Record<WorkflowId, WorkflowMetadata> = {
     cancellation: "cooperative",
   },
   "groupSet.fetchAvailableFromLms": {
-    delivery: ["desktop", "docs"],
+    delivery: ["desktop", "docs", "cli"],
     progress: "milestone",
     cancellation: "best-effort",
   },
@@ -149,6 +159,26 @@ export const workflowCatalog: Record<WorkflowId, WorkflowMetadata> = {
     progress: "milestone",
     cancellation: "best-effort",
   },
+  "repo.archive": {
+    delivery: ["desktop", "docs", "cli"],
+    progress: "milestone",
+    cancellation: "best-effort",
+  },
+  "repo.listPullRequests": {
+    delivery: ["desktop", "docs", "cli"],
+    progress: "milestone",
+    cancellation: "best-effort",
+  },
+  "repo.syncTeams": {
+    delivery: ["desktop", "docs", "cli"],
+    progress: "milestone",
+    cancellation: "best-effort",
+  },
+  "repo.commitStats": {
+    delivery: ["desktop", "docs", "cli"],
+    progress: "milestone",
+    cancellation: "best-effort",
+  },
   "userFile.inspectSelection": {
     delivery: ["desktop", "docs"],
     progress: "milestone",
@@ -12,9 +12,19 @@ export type WorkflowExecutionProfile = {
   cancellation: WorkflowCancellationGuarantee
 }
 
+/** Stable identifiers for non-fatal issues reported on the `warn` channel. */
+export type DiagnosticWarningCode =
+  | "lms_members_missing_email"
+  | "lms_member_email_changed"
+  | "lms_roster_page_failed"
+  | "lms_group_members_unmatched"
+  | "group_set_groups_deleted"
+  | "group_names_truncated"
+
 export type DiagnosticOutput = {
   channel: "info" | "warn" | "stdout" | "stderr"
   message: string
+  code?: DiagnosticWarningCode
 }
 
 export type MilestoneProgress = {
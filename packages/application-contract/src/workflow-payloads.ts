@@ -44,6 +44,10 @@ import type {
   AnalysisRunInput,
   AssignmentValidationInput,
   ConnectionVerificationResult,
+  CourseBundleExportInput,
+  CourseBundleExportResult,
+  CourseBundleImportInput,
+  CourseBundleImportResult,
   DiscoverReposProgress,
   GitUsernameImportInput,
   GroupSetConnectFromLmsInput,
@@ -52,16 +56,25 @@ import type {
   GroupSetImportFromFileInput,
   GroupSetLmsApplyResult,
   GroupSetLmsSummary,
+  GroupSetLmsSyncResult,
   GroupSetPreviewImportFromFileInput,
   GroupSetSyncFromLmsInput,
   ListLmsCoursesDraftInput,
   LmsCourseSummary,
+  RepositoryArchiveInput,
+  RepositoryArchiveResult,
   RepositoryBatchInput,
   RepositoryBulkCloneInput,
   RepositoryCloneResult,
+  RepositoryCommitStatsInput,
+  RepositoryCommitStatsResult,
   RepositoryCreateResult,
   RepositoryListNamespaceInput,
   RepositoryListNamespaceResult,
+  RepositoryListPullRequestsInput,
+  RepositoryListPullRequestsResult,
+  RepositorySyncTeamsInput,
+  RepositorySyncTeamsResult,
   RepositoryUpdateInput,
   RepositoryUpdateResult,
   RosterExportMembersInput,
@@ -121,6 +134,18 @@ export type WorkflowPayloads = {
     output: never
     result: undefined
   }
+  "course.exportBundle": {
+    input: CourseBundleExportInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: CourseBundleExportResult
+  }
+  "course.importBundle": {
+    input: CourseBundleImportInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: CourseBundleImportResult
+  }
   "settings.loadApp": {
     input: undefined
     progress: never
@@ -197,7 +222,7 @@ export type WorkflowPayloads = {
     input: GroupSetSyncFromLmsInput
     progress: MilestoneProgress
     output: DiagnosticOutput
-    result: GroupSetLmsApplyResult
+    result: GroupSetLmsSyncResult
   }
   "groupSet.previewImportFromFile": {
     input: GroupSetPreviewImportFromFileInput
@@ -265,6 +290,30 @@ export type WorkflowPayloads = {
     output: DiagnosticOutput
     result: RepositoryCloneResult
   }
+  "repo.archive": {
+    input: RepositoryArchiveInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: RepositoryArchiveResult
+  }
+  "repo.listPullRequests": {
+    input: RepositoryListPullRequestsInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: RepositoryListPullRequestsResult
+  }
+  "repo.syncTeams": {
+    input: RepositorySyncTeamsInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: RepositorySyncTeamsResult
+  }
+  "repo.commitStats": {
+    input: RepositoryCommitStatsInput
+    progress: MilestoneProgress
+    output: DiagnosticOutput
+    result: RepositoryCommitStatsResult
+  }
   "userFile.inspectSelection": {
     input: UserFileRef
     progress: MilestoneProgress
@@ -12,6 +12,7 @@ import type {
   GroupSet,
   GroupSetImportFormat,
   IdSequences,
+  LmsImportMatchPriority,
   LmsProviderKind,
   PersistedCourse,
   RepositoryTemplate,
@@ -28,10 +29,12 @@ import type {
 import type {
   LmsCourseSummary as LmsContractCourseSummary,
   LmsGroupSetSummary as LmsContractGroupSetSummary,
+  LmsTokenUser,
 } from "@repo-edu/integrations-lms-contract"
 
 export type VerifyLmsDraftInput = ConnectionBase & {
   provider: LmsProviderKind
+  tokenExpiresAt?: string | null
 }
 
 export type ListLmsCoursesDraftInput = ConnectionBase & {
@@ -63,10 +66,62 @@ export type VerifyLlmDraftInput =
 export type ConnectionVerificationResult = {
   verified: boolean
   checkedAt: string
+  /** Token owner reported by the LMS; never set for Git verification. */
+  user?: LmsTokenUser
 }
 
 export type LmsCourseSummary = LmsContractCourseSummary
 
+export const COURSE_BUNDLE_FORMAT = "repo-edu-course-bundle" as const
+export const COURSE_BUNDLE_VERSION = 1 as const
+
+export type CourseBundleManifest = {
+  courseIds: string[]
+  courseCount: number
+  activeCourseIncluded: boolean
+}
+
+export type CourseBundle = {
+  format: typeof COURSE_BUNDLE_FORMAT
+  bundleVersion: typeof COURSE_BUNDLE_VERSION
+  exportedAt: string
+  manifest: CourseBundleManifest
+  courses: PersistedCourse[]
+}
+
+export type CourseBundleExportInput = {
+  target: UserSaveTargetRef
+  /** Recorded in the manifest so the bundle notes the course that was open. */
+  activeCourseId: string | null
+}
+
+export type CourseBundleExportResult = {
+  file: UserSaveTargetRef
+  manifest: CourseBundleManifest
+}
+
+/**
+ * How a bundled course whose id already exists in the course store is
+ * handled: left alone, replaced, imported under a fresh id, or kept with the
+ * bundled roster merged into the stored one.
+ */
+export type CourseBundleConflictResolution =
+  | "skip"
+  | "overwrite"
+  | "rename"
+  | "merge"
+
+export type CourseBundleImportInput = {
+  file: UserFileRef
+  conflict: CourseBundleConflictResolution
+}
+
+export type CourseBundleImportResult = {
+  /** Ids under which courses were saved, including renamed ones. */
+  importedCourseIds: string[]
+  skippedCourseIds: string[]
+}
+
 export type RosterImportFromFileInput = {
   course: PersistedCourse
   file: UserFileRef
@@ -87,12 +142,29 @@ export type RosterImportFromLmsInput = {
   course: PersistedCourse
   credentials: PersistedAppCredentials
   lmsCourseId: string
+  /** Defaults to `lmsUserId`. */
+  matchPriority?: LmsImportMatchPriority
+  /**
+   * Fetches the roster page by page and skips pages the LMS fails to return,
+   * reporting each as a warning. By default the first failure aborts. When
+   * a page is skipped, existing members missing from the import are kept
+   * as they are instead of being marked dropped. Moodle and Brightspace
+   * return the roster in one response and are fetched once either way.
+   */
+  continueOnPageError?: boolean
+  /**
+   * Largest fraction (0 to 1) of fetched students that may lack an email
+   * before the import is rejected. Unset means no limit.
+   */
+  maxMissingEmailRatio?: number
 }
 
 export type GroupSetSyncFromLmsInput = {
   course: PersistedCourse
   credentials: PersistedAppCredentials
   groupSetId: string
+  /** Reports the changes without applying them to the roster. */
+  dryRun?: boolean
 }
 
 export type GroupSetConnectFromLmsInput = {
@@ -104,15 +176,48 @@ export type GroupSetConnectFromLmsInput = {
 export type GroupSetFetchAvailableFromLmsInput = {
   course: PersistedCourse
   credentials: PersistedAppCredentials
+  /** Also fetch each set's groups to count distinct members. */
+  withMemberCounts?: boolean
 }
 
-export type GroupSetLmsSummary = LmsContractGroupSetSummary
+export type GroupSetLmsSummary = LmsContractGroupSetSummary & {
+  /** Present only when requested with `withMemberCounts`. */
+  memberCount?: number
+}
 
 export type GroupSetLmsApplyResult = {
   roster: Roster
   idSequences: IdSequences
 } & GroupSet
 
+export type GroupSetSyncGroupRef = {
+  groupId: string
+  name: string
+}
+
+export type GroupSetSyncGroupUpdate = GroupSetSyncGroupRef & {
+  /** Name before the sync when the LMS renamed the group, otherwise `null`. */
+  previousName: string | null
+  addedMemberIds: string[]
+  removedMemberIds: string[]
+}
+
+export type GroupSetSyncChanges = {
+  addedGroups: GroupSetSyncGroupRef[]
+  removedGroups: GroupSetSyncGroupRef[]
+  updatedGroups: GroupSetSyncGroupUpdate[]
+  unchangedGroupCount: number
+}
+
+/**
+ * On a dry run the roster, ID sequences and group set are returned as they
+ * were before the sync.
+ */
+export type GroupSetLmsSyncResult = GroupSetLmsApplyResult & {
+  dryRun: boolean
+  changes: GroupSetSyncChanges
+}
+
 export type GroupSetPreviewImportFromFileInput = {
   course: PersistedCourse
   file: UserFileRef
@@ -143,6 +248,13 @@ export type GitUsernameImportInput = {
 export type AssignmentValidationInput = {
   course: PersistedCourse
   assignmentId: string
+  /**
+   * Local clone of the assignment template that `requiredFiles` are checked
+   * against. Defaults to the path of a local repository template.
+   */
+  templatePath?: string
+  /** Overrides the assignment's `requiredFiles` for this run. */
+  requiredFiles?: string[]
 }
 
 export type RosterValidationInput = {
@@ -156,6 +268,14 @@ export type RepositoryBatchInput = {
   template: RepositoryTemplate | null
   targetDirectory?: string
   directoryLayout?: "flat" | "by-team" | "by-task"
+  /** When set, `repo.create` aborts before creating anything if a planned name already exists. */
+  failOnExisting?: boolean
+  /**
+   * `repo.create` pushes every template branch and tag with
+   * `git push --mirror` instead of only the default branch. Falls back to the
+   * default-branch push when the template cannot be mirrored.
+   */
+  mirrorTemplate?: boolean
 }
 
 export type RepositoryUpdateInput = {
@@ -165,6 +285,42 @@ export type RepositoryUpdateInput = {
   templateOverride?: RepositoryTemplate | null
 }
 
+export type RepositoryArchiveAction = "archive" | "unarchive"
+
+export type RepositoryArchiveInput = {
+  course: PersistedCourse
+  credentials: PersistedAppCredentials
+  assignmentId: string | null
+  action: RepositoryArchiveAction
+}
+
+export type RepositoryPullRequestStateFilter = "open" | "closed" | "all"
+
+export type RepositoryListPullRequestsInput = {
+  course: PersistedCourse
+  credentials: PersistedAppCredentials
+  assignmentId: string | null
+  state: RepositoryPullRequestStateFilter
+}
+
+export type RepositorySyncTeamsInput = {
+  course: PersistedCourse
+  credentials: PersistedAppCredentials
+  assignmentId: string | null
+  /** Also remove team members who are no longer in the group. */
+  removeStaleMembers: boolean
+}
+
+export type RepositoryCommitStatsInput = {
+  course: PersistedCourse
+  credentials: PersistedAppCredentials
+  assignmentId: string | null
+  /** Counts the default branch when null. */
+  branch: string | null
+  /** ISO timestamp; counts every commit when null. */
+  since: string | null
+}
+
 export type RepositoryListNamespaceInput = {
   credentials: PersistedAppCredentials
   namespace: string
@@ -235,6 +391,60 @@ export type RepositoryUpdateResult = {
   completedAt: string
 }
 
+export type RepositoryArchiveResult = {
+  repositoriesPlanned: number
+  repositoriesChanged: number
+  repositoriesFailed: number
+  completedAt: string
+}
+
+export type RepositoryPullRequest = {
+  repositoryName: string
+  number: number
+  title: string
+  author: string | null
+  headBranch: string
+  baseBranch: string
+  createdAt: string
+  state: "open" | "closed" | "merged"
+  url: string
+}
+
+export type RepositoryListPullRequestsResult = {
+  repositoriesPlanned: number
+  repositoriesFailed: number
+  /** Sorted by repository name, then newest first. */
+  pullRequests: RepositoryPullRequest[]
+  completedAt: string
+}
+
+export type RepositorySyncTeamsResult = {
+  teamsPlanned: number
+  membersAdded: number
+  membersRemoved: number
+  teamsUnchanged: number
+  /** Teams that do not exist on the provider yet; `repo create` makes them. */
+  teamsMissing: number
+  teamsFailed: number
+  completedAt: string
+}
+
+export type RepositoryCommitCount = {
+  repositoryName: string
+  /** Null when the repository or branch does not exist on the provider. */
+  commitCount: number | null
+}
+
+export type RepositoryCommitStatsResult = {
+  repositoriesPlanned: number
+  repositoriesMissing: number
+  repositoriesFailed: number
+  /** Sorted by repository name; failed repositories are omitted. */
+  repositories: RepositoryCommitCount[]
+  totalCommits: number
+  completedAt: string
+}
+
 export type UserFileInspectResult = {
   workflowId: "userFile.inspectSelection"
   displayName: string
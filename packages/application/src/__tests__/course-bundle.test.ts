@@ -0,0 +1,236 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { CourseBundleConflictResolution } from "@repo-edu/application-contract"
+import type { PersistedCourse } from "@repo-edu/domain/types"
+import type {
+  UserFileRef,
+  UserFilePort,
+  UserSaveTargetRef,
+} from "@repo-edu/host-runtime-contract"
+import { createInMemoryCourseStore } from "../core.js"
+import { createCourseBundleWorkflowHandlers } from "../course-bundle-workflows.js"
+import { getCourseScenario } from "./helpers/fixture-scenarios.js"
+
+const target: UserSaveTargetRef = {
+  kind: "user-save-target-ref",
+  referenceId: "bundle-target",
+  displayName: "courses.json",
+  suggestedFormat: "json",
+}
+
+const file: UserFileRef = {
+  kind: "user-file-ref",
+  referenceId: "bundle-file",
+  displayName: "courses.json",
+  mediaType: "application/json",
+  byteLength: null,
+}
+
+function createMemoryUserFile(initialText = ""): {
+  port: UserFilePort
+  read(): string
+} {
+  let text = initialText
+  return {
+    port: {
+      readText: async (reference) => ({
+        displayName: reference.displayName,
+        mediaType: "application/json",
+        text,
+        byteLength: text.length,
+      }),
+      writeText: async (reference, nextText) => {
+        text = nextText
+        return {
+          displayName: reference.displayName,
+          mediaType: "application/json",
+          byteLength: nextText.length,
+          savedAt: "2026-03-04T10:00:00.000Z",
+        }
+      },
+    },
+    read: () => text,
+  }
+}
+
+function makeCourses(): PersistedCourse[] {
+  return [
+    getCourseScenario(undefined, { id: "course-a", displayName: "Course A" }),
+    getCourseScenario(
+      { tier: "small", preset: "shared-teams" },
+      { id: "course-b", displayName: "Course B" },
+    ),
+  ]
+}
+
+async function exportBundle(courses: readonly PersistedCourse[]) {
+  const userFile = createMemoryUserFile()
+  const handlers = createCourseBundleWorkflowHandlers({
+    courseStore: createInMemoryCourseStore(courses),
+    userFile: userFile.port,
+  })
+  const result = await handlers["course.exportBundle"]({
+    target,
+    activeCourseId: "course-b",
+  })
+  return { result, text: userFile.read() }
+}
+
+describe("course bundle workflows", () => {
+  it("exports every course with a manifest", async () => {
+    const { result, text } = await exportBundle(makeCourses())
+
+    assert.deepStrictEqual(result, {
+      file: target,
+      manifest: {
+        courseIds: ["course-a", "course-b"],
+        courseCount: 2,
+        activeCourseIncluded: true,
+      },
+    })
+    const bundle = JSON.parse(text)
+    assert.equal(bundle.format, "repo-edu-course-bundle")
+    assert.deepStrictEqual(bundle.manifest, result.manifest)
+  })
+
+  it("round-trips courses into an empty store", async () => {
+    const courses = makeCourses()
+    const { text } = await exportBundle(courses)
+    const store = createInMemoryCourseStore([])
+    const handlers = createCourseBundleWorkflowHandlers({
+      courseStore: store,
+      userFile: createMemoryUserFile(text).port,
+    })
+
+    const result = await handlers["course.importBundle"]({
+      file,
+      conflict: "skip",
+    })
+
+    assert.deepStrictEqual(result, {
+      importedCourseIds: ["course-a", "course-b"],
+      skippedCourseIds: [],
+    })
+    for (const course of courses) {
+      const imported = await store.loadCourse(course.id)
+      assert.ok(imported)
+      const { revision: _r, updatedAt: _u, ...importedData } = imported
+      const { revision: _or, updatedAt: _ou, ...originalData } = course
+      assert.deepStrictEqual(importedData, originalData)
+    }
+  })
+
+  it("skips, overwrites, or renames courses that already exist", async () => {
+    const { text } = await exportBundle(makeCourses())
+    const existing = getCourseScenario(undefined, {
+      id: "course-a",
+      displayName: "Local Course A",
+      revision: 4,
+    })
+
+    const importWith = async (conflict: CourseBundleConflictResolution) => {
+      const store = createInMemoryCourseStore([existing])
+      const outputs: string[] = []
+      const result = await createCourseBundleWorkflowHandlers({
+        courseStore: store,
+        userFile: createMemoryUserFile(text).port,
+      })["course.importBundle"](
+        { file, conflict },
+        { onOutput: (output) => outputs.push(output.message) },
+      )
+      return { result, store, outputs }
+    }
+
+    const skipped = await importWith("skip")
+    assert.deepStrictEqual(skipped.result, {
+      importedCourseIds: ["course-b"],
+      skippedCourseIds: ["course-a"],
+    })
+    assert.equal(skipped.outputs.length, 1)
+    assert.equal(
+      (await skipped.store.loadCourse("course-a"))?.displayName,
+      "Local Course A",
+    )
+
+    const overwritten = await importWith("overwrite")
+    assert.deepStrictEqual(overwritten.result.skippedCourseIds, [])
+    const replaced = await overwritten.store.loadCourse("course-a")
+    assert.equal(replaced?.displayName, "Course A")
+    assert.equal(replaced?.revision, 5)
+
+    const renamed = await importWith("rename")
+    assert.deepStrictEqual(renamed.result.importedCourseIds, [
+      "course-a-copy",
+      "course-b",
+    ])
+    assert.equal(
+      (await renamed.store.loadCourse("course-a"))?.displayName,
+      "Local Course A",
+    )
+    assert.equal(
+      (await renamed.store.loadCourse("course-a-copy"))?.displayName,
+      "Course A",
+    )
+  })
+
+  it("merges the bundled roster into a course that already exists", async () => {
+    const courses = makeCourses()
+    const { text } = await exportBundle(courses)
+    const bundled = courses[0]
+    const existing = getCourseScenario(undefined, {
+      id: "course-a",
+      displayName: "Local Course A",
+      revision: 4,
+    })
+    existing.roster = {
+      ...existing.roster,
+      students: existing.roster.students.slice(0, 1),
+      groups: [],
+      groupSets: [],
+      assignments: [],
+    }
+    const store = createInMemoryCourseStore([existing])
+
+    const result = await createCourseBundleWorkflowHandlers({
+      courseStore: store,
+      userFile: createMemoryUserFile(text).port,
+    })["course.importBundle"]({ file, conflict: "merge" })
+
+    assert.deepStrictEqual(result, {
+      importedCourseIds: ["course-a", "course-b"],
+      skippedCourseIds: [],
+    })
+    const merged = await store.loadCourse("course-a")
+    assert.equal(merged?.displayName, "Local Course A")
+    assert.equal(merged?.revision, 5)
+    assert.equal(
+      merged?.roster.students.length,
+      bundled.roster.students.length,
+    )
+    assert.ok(
+      merged?.roster.students.some(
+        (student) => student.id === existing.roster.students[0]?.id,
+      ),
+    )
+    assert.deepStrictEqual(
+      merged?.roster.groupSets.map((groupSet) => groupSet.id),
+      bundled.roster.groupSets.map((groupSet) => groupSet.id),
+    )
+  })
+
+  it("rejects files that are not course bundles", async () => {
+    const handlers = createCourseBundleWorkflowHandlers({
+      courseStore: createInMemoryCourseStore([]),
+      userFile: createMemoryUserFile('{"format":"other"}').port,
+    })
+
+    await assert.rejects(
+      handlers["course.importBundle"]({ file, conflict: "skip" }),
+      (error: unknown) =>
+        typeof error === "object" &&
+        error !== null &&
+        "type" in error &&
+        error.type === "validation",
+    )
+  })
+})
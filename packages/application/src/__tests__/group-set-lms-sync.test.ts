@@ -0,0 +1,343 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { PersistedCourse } from "@repo-edu/domain/types"
+import type { LmsFetchedGroupSet } from "@repo-edu/integrations-lms-contract"
+import {
+  applyFetchedGroupSetToCourse,
+  describeGroupSetSyncChanges,
+} from "../group-set-workflows/helpers.js"
+import { getCourseScenario } from "./helpers/fixture-scenarios.js"
+
+type Group = PersistedCourse["roster"]["groups"][number]
+
+function lmsGroup(
+  id: string,
+  name: string,
+  memberIds: string[],
+  lmsGroupId: string | null = id.replace("g_", ""),
+): Group {
+  return {
+    id,
+    name,
+    memberIds,
+    origin: lmsGroupId === null ? "local" : "lms",
+    lmsGroupId,
+  }
+}
+
+function makeCourse(groups: Group[], setGroupIds: string[]): PersistedCourse {
+  const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+  const template = course.roster.students[0]
+  course.roster.students = ["1", "2", "3", "4"].map((suffix) => ({
+    ...template,
+    id: `m_${suffix}`,
+    lmsUserId: `u-${suffix}`,
+  }))
+  course.roster.staff = []
+  course.roster.groups = groups
+  course.roster.groupSets = [
+    {
+      id: "gs_sync",
+      name: "LMS Set",
+      groupIds: setGroupIds,
+      connection: {
+        kind: "canvas",
+        courseId: "course-42",
+        groupSetId: "remote-set",
+        lastUpdated: "2026-03-01T00:00:00.000Z",
+      },
+      nameMode: "named",
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+    },
+  ]
+  return course
+}
+
+function fetched(
+  groups: { id: string; name: string; members: string[] }[],
+): LmsFetchedGroupSet {
+  return {
+    groupSet: { id: "remote-set", name: "LMS Set" },
+    groups: groups.map((group) => ({
+      id: group.id,
+      name: group.name,
+      memberLmsUserIds: group.members,
+    })),
+  }
+}
+
+function syncChanges(
+  course: PersistedCourse,
+  groups: { id: string; name: string; members: string[] }[],
+) {
+  const applied = applyFetchedGroupSetToCourse(
+    course,
+    "gs_sync",
+    fetched(groups),
+  )
+  return applied.changes
+}
+
+describe("LMS group-set sync changes", () => {
+  it("counts groups whose name and members match as unchanged", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1"]), lmsGroup("g_20", "Beta", ["m_2"])],
+      ["g_10", "g_20"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+      { id: "20", name: "Beta", members: ["u-2"] },
+    ])
+
+    assert.deepStrictEqual(changes, {
+      addedGroups: [],
+      removedGroups: [],
+      updatedGroups: [],
+      unchangedGroupCount: 2,
+    })
+  })
+
+  it("reports groups that are new in the LMS as added", () => {
+    const course = makeCourse([lmsGroup("g_10", "Alpha", ["m_1"])], ["g_10"])
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+      { id: "30", name: "Gamma", members: ["u-3"] },
+    ])
+
+    assert.equal(changes.addedGroups.length, 1)
+    assert.equal(changes.addedGroups[0]?.name, "Gamma")
+    assert.equal(changes.unchangedGroupCount, 1)
+  })
+
+  it("reports groups missing from the LMS as removed with their names", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1"]), lmsGroup("g_20", "Beta", ["m_2"])],
+      ["g_10", "g_20"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+    ])
+
+    assert.deepStrictEqual(changes.removedGroups, [
+      { groupId: "g_20", name: "Beta" },
+    ])
+  })
+
+  it("reports members that joined a group", () => {
+    const course = makeCourse([lmsGroup("g_10", "Alpha", ["m_1"])], ["g_10"])
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1", "u-2"] },
+    ])
+
+    assert.deepStrictEqual(changes.updatedGroups, [
+      {
+        groupId: "g_10",
+        name: "Alpha",
+        previousName: null,
+        addedMemberIds: ["m_2"],
+        removedMemberIds: [],
+      },
+    ])
+  })
+
+  it("reports members that left a group", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1", "m_2"])],
+      ["g_10"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+    ])
+
+    assert.deepStrictEqual(changes.updatedGroups[0]?.removedMemberIds, ["m_2"])
+    assert.deepStrictEqual(changes.updatedGroups[0]?.addedMemberIds, [])
+  })
+
+  it("reports joins and departures in the same group together", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1", "m_2"])],
+      ["g_10"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-2", "u-3"] },
+    ])
+
+    assert.equal(changes.updatedGroups.length, 1)
+    assert.deepStrictEqual(changes.updatedGroups[0]?.addedMemberIds, ["m_3"])
+    assert.deepStrictEqual(changes.updatedGroups[0]?.removedMemberIds, ["m_1"])
+  })
+
+  it("reports renamed groups with their previous name", () => {
+    const course = makeCourse([lmsGroup("g_10", "Alpha", ["m_1"])], ["g_10"])
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha Team", members: ["u-1"] },
+    ])
+
+    assert.deepStrictEqual(changes.updatedGroups, [
+      {
+        groupId: "g_10",
+        name: "Alpha Team",
+        previousName: "Alpha",
+        addedMemberIds: [],
+        removedMemberIds: [],
+      },
+    ])
+  })
+
+  it("ignores member order when comparing groups", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1", "m_2"])],
+      ["g_10"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-2", "u-1"] },
+    ])
+
+    assert.equal(changes.updatedGroups.length, 0)
+    assert.equal(changes.unchangedGroupCount, 1)
+  })
+
+  it("does not count unmatched LMS users as added members", () => {
+    const course = makeCourse([lmsGroup("g_10", "Alpha", ["m_1"])], ["g_10"])
+
+    const applied = applyFetchedGroupSetToCourse(
+      course,
+      "gs_sync",
+      fetched([{ id: "10", name: "Alpha", members: ["u-1", "u-missing"] }]),
+    )
+
+    assert.deepStrictEqual(applied.unmatchedLmsUserIds, ["u-missing"])
+    assert.equal(applied.changes.unchangedGroupCount, 1)
+  })
+
+  it("replaces local groups without an LMS id", () => {
+    const course = makeCourse(
+      [lmsGroup("g_local", "Local", ["m_1"], null)],
+      ["g_local"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+    ])
+
+    assert.deepStrictEqual(changes.removedGroups, [
+      { groupId: "g_local", name: "Local" },
+    ])
+    assert.equal(changes.addedGroups[0]?.name, "Alpha")
+  })
+
+  it("treats groups from other sets as new even with a matching LMS id", () => {
+    const course = makeCourse([lmsGroup("g_10", "Alpha", ["m_1"])], [])
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+    ])
+
+    assert.equal(changes.addedGroups.length, 1)
+    assert.notEqual(changes.addedGroups[0]?.groupId, "g_10")
+  })
+
+  it("reports every group as removed when the LMS set is empty", () => {
+    const course = makeCourse(
+      [lmsGroup("g_10", "Alpha", ["m_1"]), lmsGroup("g_20", "Beta", [])],
+      ["g_10", "g_20"],
+    )
+
+    const changes = syncChanges(course, [])
+
+    assert.equal(changes.removedGroups.length, 2)
+    assert.equal(changes.unchangedGroupCount, 0)
+  })
+
+  it("sorts a mixed sync into all categories", () => {
+    const course = makeCourse(
+      [
+        lmsGroup("g_10", "Alpha", ["m_1"]),
+        lmsGroup("g_20", "Beta", ["m_2"]),
+        lmsGroup("g_30", "Gamma", ["m_3"]),
+      ],
+      ["g_10", "g_20", "g_30"],
+    )
+
+    const changes = syncChanges(course, [
+      { id: "10", name: "Alpha", members: ["u-1"] },
+      { id: "20", name: "Beta", members: ["u-2", "u-4"] },
+      { id: "40", name: "Delta", members: [] },
+    ])
+
+    assert.equal(changes.unchangedGroupCount, 1)
+    assert.deepStrictEqual(
+      changes.updatedGroups.map((group) => group.name),
+      ["Beta"],
+    )
+    assert.deepStrictEqual(
+      changes.removedGroups.map((group) => group.name),
+      ["Gamma"],
+    )
+    assert.deepStrictEqual(
+      changes.addedGroups.map((group) => group.name),
+      ["Delta"],
+    )
+  })
+
+  it("describes applied changes one line each", () => {
+    const lines = describeGroupSetSyncChanges(
+      {
+        addedGroups: [{ groupId: "g_1", name: "Delta" }],
+        removedGroups: [{ groupId: "g_2", name: "Gamma" }],
+        updatedGroups: [
+          {
+            groupId: "g_3",
+            name: "Beta Team",
+            previousName: "Beta",
+            addedMemberIds: ["m_4"],
+            removedMemberIds: [],
+          },
+        ],
+        unchangedGroupCount: 1,
+      },
+      false,
+    )
+
+    assert.deepStrictEqual(lines, [
+      "Added group 'Delta'.",
+      "Removed group 'Gamma'.",
+      "Updated group 'Beta Team': renamed from 'Beta', 1 members added.",
+    ])
+  })
+
+  it("describes dry-run changes as planned", () => {
+    const lines = describeGroupSetSyncChanges(
+      {
+        addedGroups: [{ groupId: "g_1", name: "Delta" }],
+        removedGroups: [],
+        updatedGroups: [
+          {
+            groupId: "g_3",
+            name: "Beta",
+            previousName: null,
+            addedMemberIds: [],
+            removedMemberIds: ["m_2", "m_3"],
+          },
+        ],
+        unchangedGroupCount: 0,
+      },
+      true,
+    )
+
+    assert.deepStrictEqual(lines, [
+      "Would add group 'Delta'.",
+      "Would update group 'Beta': 2 members removed.",
+    ])
+  })
+})
@@ -60,6 +60,8 @@ export function createRepoHarness(options?: {
         })),
       assignRepositoriesToTeam:
         options?.git?.assignRepositoriesToTeam ?? (async () => {}),
+      listTeamMembers: options?.git?.listTeamMembers ?? (async () => []),
+      removeTeamMembers: options?.git?.removeTeamMembers ?? (async () => {}),
       getRepositoryDefaultBranchHead:
         options?.git?.getRepositoryDefaultBranchHead ??
         (async () => ({
@@ -75,6 +77,7 @@ export function createRepoHarness(options?: {
           url: "https://example.com/pr/1",
           created: true,
         })),
+      listPullRequests: options?.git?.listPullRequests ?? (async () => []),
       resolveRepositoryCloneUrls:
         options?.git?.resolveRepositoryCloneUrls ??
         (async () => ({
@@ -86,6 +89,10 @@ export function createRepoHarness(options?: {
         (async () => ({
           repositories: [],
         })),
+      getCommitCount: options?.git?.getCommitCount ?? (async () => 0),
+      archiveRepository: options?.git?.archiveRepository ?? (async () => {}),
+      unarchiveRepository:
+        options?.git?.unarchiveRepository ?? (async () => {}),
     },
     gitCommand: {
       cancellation: "best-effort",
@@ -13,7 +13,10 @@ describe("application connection verification workflow helpers", () => {
       lms: {
         verifyConnection: async (draft) => {
           lmsDraft = draft
-          return { verified: true }
+          return {
+            verified: true,
+            user: { name: "Teacher", email: "teacher@example.edu" },
+          }
         },
         listCourses: async (draft) => {
           lmsCourseDraft = draft
@@ -39,6 +42,10 @@ describe("application connection verification workflow helpers", () => {
     })
     assert.equal(lmsResult.verified, true)
     assertValidTimestamp(lmsResult.checkedAt)
+    assert.deepStrictEqual(lmsResult.user, {
+      name: "Teacher",
+      email: "teacher@example.edu",
+    })
     assert.deepStrictEqual(lmsDraft, {
       provider: "canvas",
       baseUrl: "https://canvas.example.edu",
@@ -71,6 +78,7 @@ describe("application connection verification workflow helpers", () => {
     })
     assert.equal(gitResult.verified, false)
     assertValidTimestamp(gitResult.checkedAt)
+    assert.equal("user" in gitResult, false)
     assert.deepStrictEqual(gitDraft, {
       provider: "github",
       baseUrl: "https://github.com",
@@ -126,6 +134,36 @@ describe("application connection verification workflow helpers", () => {
     })
   })
 
+  it("forwards the recorded token expiry to the LMS adapter", async () => {
+    let lmsDraft: unknown = null
+    const handlers = createConnectionWorkflowHandlers({
+      lms: {
+        verifyConnection: async (draft) => {
+          lmsDraft = draft
+          return { verified: true }
+        },
+        listCourses: async () => [],
+      },
+      git: {
+        verifyConnection: async () => ({ verified: true }),
+      },
+    })
+
+    await handlers["connection.verifyLmsDraft"]({
+      provider: "canvas",
+      baseUrl: "https://canvas.example.edu",
+      token: "token-1",
+      tokenExpiresAt: "2026-03-01T12:00:00.000Z",
+    })
+    assert.deepStrictEqual(lmsDraft, {
+      provider: "canvas",
+      baseUrl: "https://canvas.example.edu",
+      token: "token-1",
+      userAgent: undefined,
+      tokenExpiresAt: "2026-03-01T12:00:00.000Z",
+    })
+  })
+
   it("normalizes provider failures and cancellation", async () => {
     const handlers = createConnectionWorkflowHandlers({
       lms: {
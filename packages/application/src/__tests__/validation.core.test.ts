@@ -1,5 +1,9 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
+import type {
+  FileSystemEntryStatus,
+  FileSystemInspectRequest,
+} from "@repo-edu/host-runtime-contract"
 import {
   createValidationAppError,
   runValidateAssignmentForCourse,
@@ -75,6 +79,103 @@ describe("application validation helpers", () => {
     )
   })
 
+  it("reports required files missing from the template clone", async () => {
+    const base = makeCourseWithKnownValidationIssues()
+    const course = {
+      ...base,
+      roster: {
+        ...base.roster,
+        assignments: base.roster.assignments.map((assignment) => ({
+          ...assignment,
+          requiredFiles: ["README.md"],
+        })),
+      },
+    }
+    const inspected: string[][] = []
+    const present = new Set(["/templates/p1/.git", "/templates/p1/README.md"])
+    const handlers = createValidationWorkflowHandlers({
+      fileSystem: {
+        inspect: async (request: FileSystemInspectRequest) => {
+          inspected.push(request.paths)
+          return request.paths.map(
+            (path): FileSystemEntryStatus => ({
+              path,
+              kind: present.has(path) ? "file" : "missing",
+            }),
+          )
+        },
+      },
+    })
+
+    const result = await handlers["validation.assignment"]({
+      course,
+      assignmentId: "a1",
+      templatePath: "/templates/p1",
+      requiredFiles: ["README.md", "src/main.py"],
+    })
+
+    assert.deepStrictEqual(inspected, [
+      [
+        "/templates/p1/.git",
+        "/templates/p1/README.md",
+        "/templates/p1/src/main.py",
+      ],
+    ])
+    assert.deepStrictEqual(
+      result.issues.filter((issue) => issue.kind === "missing_required_file"),
+      [
+        {
+          kind: "missing_required_file",
+          affectedIds: ["a1"],
+          context: "src/main.py",
+        },
+      ],
+    )
+  })
+
+  it("rejects template paths that are not Git repositories", async () => {
+    const handlers = createValidationWorkflowHandlers({
+      fileSystem: {
+        inspect: async (request: FileSystemInspectRequest) =>
+          request.paths.map((path) => ({ path, kind: "missing" as const })),
+      },
+    })
+
+    await assert.rejects(
+      handlers["validation.assignment"]({
+        course: makeCourseWithKnownValidationIssues(),
+        assignmentId: "a1",
+        templatePath: "/templates/not-a-clone",
+        requiredFiles: ["README.md"],
+      }),
+      (error: unknown) =>
+        typeof error === "object" &&
+        error !== null &&
+        "type" in error &&
+        error.type === "validation",
+    )
+  })
+
+  it("skips the template check when no files are required", async () => {
+    let inspectCalls = 0
+    const handlers = createValidationWorkflowHandlers({
+      fileSystem: {
+        inspect: async () => {
+          inspectCalls += 1
+          return []
+        },
+      },
+    })
+
+    await handlers["validation.assignment"]({
+      course: makeCourseWithKnownValidationIssues(),
+      assignmentId: "a1",
+      templatePath: "/templates/p1",
+    })
+
+    assert.equal(inspectCalls, 0)
+  })
+
   it("returns validation error for invalid course snapshots", async () => {
     const handlers = createValidationWorkflowHandlers()
 
@@ -2,7 +2,9 @@ import assert from "node:assert/strict"
 import { describe, it } from "node:test"
 import type { GitUsernameImportInput } from "@repo-edu/application-contract"
 import { splitAppSettings } from "@repo-edu/domain/settings"
+import { parseCsv } from "../adapters/tabular/index.js"
 import { createGitUsernameWorkflowHandlers } from "../git-username-workflows.js"
+import { parseGitUsernameRows } from "../workflow-helpers.js"
 import { getCourseAndSettingsScenario } from "./helpers/fixture-scenarios.js"
 
 describe("application git username workflow helpers", () => {
@@ -174,4 +176,18 @@ describe("application git username workflow helpers", () => {
         error.type === "validation",
     )
   })
+
+  it("reads usernames from the first matching custom column", () => {
+    const parsed = parseCsv(
+      "Email,Student Login,Username\ns1@example.com,s1-login,s1-fallback",
+    )
+
+    assert.deepStrictEqual(
+      parseGitUsernameRows(parsed.rows, ["Student Login", "username"]),
+      [{ email: "s1@example.com", git_username: "s1-login" }],
+    )
+    assert.deepStrictEqual(parseGitUsernameRows(parsed.rows), [
+      { email: "s1@example.com", git_username: "s1-fallback" },
+    ])
+  })
 })
@@ -1,5 +1,6 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
 import { splitAppSettings } from "@repo-edu/domain/settings"
 import type { GroupSetWorkflowPorts } from "../group-set-workflows.js"
 import { createGroupSetWorkflowHandlers } from "../group-set-workflows.js"
@@ -92,6 +93,104 @@ describe("application group-set workflow helpers", () => {
     ])
   })
 
+  it("counts distinct members per group set when requested", async () => {
+    const { course, settings } = createLmsScenario()
+    const fetchedSetIds: string[] = []
+
+    const handlers = createGroupSetHarness({
+      lms: {
+        listGroupSets: async () => [
+          { id: "set-1", name: "Lab Teams", groupCount: 2 },
+          { id: "set-2", name: "Project Teams", groupCount: 0 },
+        ],
+        fetchGroupSet: async (_draft, _courseId, groupSetId) => {
+          fetchedSetIds.push(groupSetId)
+          return {
+            groupSet: { id: groupSetId, name: groupSetId },
+            groups:
+              groupSetId === "set-1"
+                ? [
+                    { id: "g1", name: "A", memberLmsUserIds: ["u1", "u2"] },
+                    { id: "g2", name: "B", memberLmsUserIds: ["u2", "u3"] },
+                  ]
+                : [],
+          }
+        },
+      },
+    })
+
+    const result = await handlers["groupSet.fetchAvailableFromLms"]({
+      course,
+      credentials: splitAppSettings(settings).credentials,
+      withMemberCounts: true,
+    })
+
+    assert.deepStrictEqual(fetchedSetIds, ["set-1", "set-2"])
+    assert.deepStrictEqual(result, [
+      { id: "set-1", name: "Lab Teams", groupCount: 2, memberCount: 3 },
+      { id: "set-2", name: "Project Teams", groupCount: 0, memberCount: 0 },
+    ])
+  })
+
+  it("names the operation in LMS provider failures", async () => {
+    const { course, settings } = createLmsScenario()
+    const handlers = createGroupSetHarness({
+      lms: {
+        listGroupSets: async () => {
+          throw new Error("Canvas returned 500")
+        },
+        fetchGroupSet: async () => {
+          throw new Error("Canvas returned 503")
+        },
+      },
+    })
+    const credentials = splitAppSettings(settings).credentials
+
+    await assert.rejects(
+      handlers["groupSet.fetchAvailableFromLms"]({ course, credentials }),
+      {
+        type: "provider",
+        message: "Listing LMS group sets: Canvas returned 500",
+        operation: "listGroupSets",
+      },
+    )
+    await assert.rejects(
+      handlers["groupSet.connectFromLms"]({
+        course,
+        credentials,
+        remoteGroupSetId: "set-9",
+      }),
+      {
+        type: "provider",
+        message: "Connecting LMS group set 'set-9': Canvas returned 503",
+      },
+    )
+  })
+
+  it("rejects group-set workflows for providers without group sets", async () => {
+    const { course, settings } = createLmsScenario()
+    settings.lmsConnections[0].provider = "brightspace"
+    const handlers = createGroupSetHarness({})
+    const credentials = splitAppSettings(settings).credentials
+
+    await assert.rejects(
+      handlers["groupSet.fetchAvailableFromLms"]({ course, credentials }),
+      {
+        type: "not-supported",
+        provider: "brightspace",
+        operation: "listGroupSets",
+      },
+    )
+    await assert.rejects(
+      handlers["groupSet.connectFromLms"]({
+        course,
+        credentials,
+        remoteGroupSetId: "set-9",
+      }),
+      { type: "not-supported", operation: "fetchGroupSet" },
+    )
+  })
+
   it("rejects LMS group-set discovery for RepoBee courses", async () => {
     const { course, settings } = getCourseAndSettingsScenario({
       tier: "small",
@@ -288,11 +387,15 @@ describe("application group-set workflow helpers", () => {
       },
     })
 
-    const synced = await handlers["groupSet.syncFromLms"]({
-      course,
-      credentials: splitAppSettings(settings).credentials,
-      groupSetId: "gs_0002",
-    })
+    const outputs: DiagnosticOutput[] = []
+    const synced = await handlers["groupSet.syncFromLms"](
+      {
+        course,
+        credentials: splitAppSettings(settings).credentials,
+        groupSetId: "gs_0002",
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
 
     if (synced.nameMode !== "named") {
       return assert.fail("Expected LMS-synced group set to be named.")
@@ -309,6 +412,79 @@ describe("application group-set workflow helpers", () => {
       syncedGroups.some((group) => group.id === "g_0001"),
       true,
     )
+    assert.deepStrictEqual(
+      outputs.map((output) => output.code),
+      ["lms_group_members_unmatched", "group_set_groups_deleted"],
+    )
+    assert.equal(outputs[0]?.message.includes("missing-user"), true)
+  })
+
+  it("previews an LMS group-set sync without changing the roster", async () => {
+    const { course, settings } = createLmsScenario()
+    course.roster.students = [
+      { ...course.roster.students[0], id: "m_0001", lmsUserId: "u-1" },
+    ]
+    course.roster.groups = [
+      {
+        id: "g_0001",
+        name: "Group 10",
+        memberIds: [],
+        origin: "lms",
+        lmsGroupId: "10",
+      },
+    ]
+    course.roster.groupSets = [
+      {
+        id: "gs_0002",
+        name: "Imported LMS Set",
+        groupIds: ["g_0001"],
+        connection: {
+          kind: "canvas",
+          courseId: "course-42",
+          groupSetId: "remote-set-1",
+          lastUpdated: "2026-03-01T00:00:00.000Z",
+        },
+        nameMode: "named",
+        repoNameTemplate: null,
+        columnVisibility: {},
+        columnSizing: {},
+      },
+    ]
+
+    const handlers = createGroupSetHarness({
+      lms: {
+        fetchGroupSet: async () => ({
+          groupSet: { id: "remote-set-1", name: "Imported LMS Set" },
+          groups: [
+            { id: "10", name: "Group 10", memberLmsUserIds: ["u-1"] },
+            { id: "20", name: "Group 20", memberLmsUserIds: [] },
+          ],
+        }),
+      },
+    })
+
+    const labels: string[] = []
+    const preview = await handlers["groupSet.syncFromLms"](
+      {
+        course,
+        credentials: splitAppSettings(settings).credentials,
+        groupSetId: "gs_0002",
+        dryRun: true,
+      },
+      { onProgress: (progress) => labels.push(progress.label) },
+    )
+
+    assert.equal(preview.dryRun, true)
+    assert.deepStrictEqual(preview.roster, course.roster)
+    assert.deepStrictEqual(preview.idSequences, course.idSequences)
+    assert.deepStrictEqual(
+      preview.changes.addedGroups.map((group) => group.name),
+      ["Group 20"],
+    )
+    assert.deepStrictEqual(preview.changes.updatedGroups[0]?.addedMemberIds, [
+      "m_0001",
+    ])
+    assert.ok(labels.includes("Would add group 'Group 20'."))
   })
 
   it("previews group-set import and reimport from csv", async () => {
@@ -391,6 +567,144 @@ describe("application group-set workflow helpers", () => {
     assert.equal(reimportPreview.groups.length > 0, true)
   })
 
+  it("warns when imported CSV group names are truncated", async () => {
+    const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+    const longName = "t".repeat(150)
+    const csvText = ["group_name,email", `${longName},x@example.com`].join("\n")
+    const outputs: DiagnosticOutput[] = []
+
+    const handlers = createGroupSetHarness({
+      lms: {},
+      userFile: {
+        readText: async () => ({
+          displayName: "groups.csv",
+          mediaType: "text/csv",
+          byteLength: csvText.length,
+          text: csvText,
+        }),
+      },
+    })
+
+    await handlers["groupSet.importFromFile"](
+      {
+        course,
+        file: {
+          kind: "user-file-ref",
+          referenceId: "long-names-file",
+          displayName: "groups.csv",
+          mediaType: "text/csv",
+          byteLength: null,
+        },
+        format: "group-set-csv",
+        targetGroupSetId: null,
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    const warnings = outputs.filter((output) => output.channel === "warn")
+    assert.equal(warnings.length, 1)
+    assert.equal(warnings[0]?.code, "group_names_truncated")
+  })
+
+  it("reports every structural CSV problem before matching members", async () => {
+    const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+    const csvText = [
+      "team,email",
+      "Team A,a@example.com",
+      ",b@example.com",
+      "Team B,a@example.com",
+      "Team B,c@example.com",
+      "Team B,c@example.com",
+    ].join("\n")
+
+    const handlers = createGroupSetHarness({
+      lms: {},
+      userFile: {
+        readText: async () => ({
+          displayName: "groups.csv",
+          mediaType: "text/csv",
+          byteLength: csvText.length,
+          text: csvText,
+        }),
+      },
+    })
+
+    await assert.rejects(
+      handlers["groupSet.previewImportFromFile"]({
+        course,
+        file: {
+          kind: "user-file-ref",
+          referenceId: "malformed-file",
+          displayName: "groups.csv",
+          mediaType: "text/csv",
+          byteLength: null,
+        },
+        format: "group-set-csv",
+        targetGroupSetId: null,
+      }),
+      {
+        type: "validation",
+        message: "Group-set import file is malformed.",
+        issues: [
+          { path: "group_name", message: "Line 3: empty group_name" },
+          {
+            path: "rows",
+            message:
+              "Line 4: email 'a@example.com' is in both group 'Team A' and group 'Team B'",
+          },
+          {
+            path: "rows",
+            message:
+              "Line 6: email 'c@example.com' is listed twice in group 'Team B'",
+          },
+        ],
+      },
+    )
+  })
+
+  it("rejects a group-set CSV without an email column", async () => {
+    const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+    const csvText = ["group_name,name", "Team A,Alice"].join("\n")
+    const outputs: DiagnosticOutput[] = []
+
+    const handlers = createGroupSetHarness({
+      lms: {},
+      userFile: {
+        readText: async () => ({
+          displayName: "groups.csv",
+          mediaType: "text/csv",
+          byteLength: csvText.length,
+          text: csvText,
+        }),
+      },
+    })
+
+    await assert.rejects(
+      handlers["groupSet.previewImportFromFile"](
+        {
+          course,
+          file: {
+            kind: "user-file-ref",
+            referenceId: "no-email-file",
+            displayName: "groups.csv",
+            mediaType: "text/csv",
+            byteLength: null,
+          },
+          format: "group-set-csv",
+          targetGroupSetId: null,
+        },
+        { onOutput: (output) => outputs.push(output) },
+      ),
+      {
+        type: "validation",
+        issues: [
+          { path: "columns", message: "Missing required column 'email'" },
+        ],
+      },
+    )
+    assert.deepStrictEqual(outputs, [])
+  })
+
   it("imports RepoBee teams without mutating roster members", async () => {
     const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
     const initialStudentCount = course.roster.students.length
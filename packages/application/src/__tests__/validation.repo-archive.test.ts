@@ -0,0 +1,70 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
+import { planRepositoryOperation } from "@repo-edu/domain/repository-planning"
+import { createRepoHarness } from "./helpers/repo-workflow-harness.js"
+
+describe("application repository archive workflow", () => {
+  it("archives every planned repository in the course organization", async () => {
+    const archived: string[] = []
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        archiveRepository: async (_draft, request) => {
+          archived.push(`${request.owner}/${request.repositoryName}`)
+        },
+      },
+    })
+    const plan = planRepositoryOperation(course, "a1", "clone")
+    assert.ok(plan.ok)
+
+    const result = await handlers["repo.archive"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      action: "archive",
+    })
+
+    const expected = plan.value.groups.map(
+      (group) => `repo-edu/${group.repoName}`,
+    )
+    assert.deepStrictEqual([...archived].sort(), [...expected].sort())
+    assert.equal(result.repositoriesPlanned, expected.length)
+    assert.equal(result.repositoriesChanged, expected.length)
+    assert.equal(result.repositoriesFailed, 0)
+  })
+
+  it("counts provider failures and keeps going", async () => {
+    const outputs: DiagnosticOutput[] = []
+    let calls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        unarchiveRepository: async () => {
+          calls += 1
+          if (calls === 1) {
+            throw new Error("Repository not found")
+          }
+        },
+      },
+    })
+
+    const result = await handlers["repo.archive"](
+      {
+        course,
+        credentials: settings,
+        assignmentId: "a1",
+        action: "unarchive",
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    assert.equal(result.repositoriesFailed, 1)
+    assert.equal(result.repositoriesChanged, result.repositoriesPlanned - 1)
+    assert.ok(
+      outputs.some(
+        (output) =>
+          output.channel === "warn" &&
+          output.message.includes("Repository not found"),
+      ),
+    )
+  })
+})
@@ -0,0 +1,79 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
+import type { CommitCountRequest } from "@repo-edu/integrations-git-contract"
+import { createRepoHarness } from "./helpers/repo-workflow-harness.js"
+
+describe("application repository commit stats workflow", () => {
+  it("counts commits per repository and reports missing ones", async () => {
+    const requests: CommitCountRequest[] = []
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        getCommitCount: async (_draft, request) => {
+          requests.push(request)
+          return requests.length === 1 ? null : 5
+        },
+      },
+    })
+
+    const result = await handlers["repo.commitStats"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      branch: "main",
+      since: "2026-09-01T00:00:00.000Z",
+    })
+
+    assert.ok(result.repositoriesPlanned > 1)
+    assert.equal(result.repositories.length, result.repositoriesPlanned)
+    assert.deepStrictEqual(
+      result.repositories.map((entry) => entry.repositoryName),
+      [...result.repositories.map((entry) => entry.repositoryName)].sort(),
+    )
+    assert.equal(result.repositoriesMissing, 1)
+    assert.equal(result.repositoriesFailed, 0)
+    assert.equal(result.totalCommits, 5 * (result.repositoriesPlanned - 1))
+    assert.ok(
+      requests.every(
+        (request) =>
+          request.branch === "main" &&
+          request.since === "2026-09-01T00:00:00.000Z",
+      ),
+    )
+  })
+
+  it("reports provider failures per repository without aborting", async () => {
+    const outputs: DiagnosticOutput[] = []
+    let calls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        getCommitCount: async () => {
+          calls += 1
+          if (calls === 1) throw new Error("rate limited")
+          return 2
+        },
+      },
+    })
+
+    const result = await handlers["repo.commitStats"](
+      {
+        course,
+        credentials: settings,
+        assignmentId: "a1",
+        branch: null,
+        since: null,
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    assert.equal(result.repositoriesFailed, 1)
+    assert.equal(result.repositories.length, result.repositoriesPlanned - 1)
+    assert.equal(result.totalCommits, 2 * (result.repositoriesPlanned - 1))
+    assert.ok(
+      outputs.some(
+        (output) =>
+          output.channel === "warn" && output.message.includes("rate limited"),
+      ),
+    )
+  })
+})
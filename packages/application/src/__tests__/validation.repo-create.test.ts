@@ -181,6 +181,134 @@ describe("application repository create workflow helpers", () => {
     assert.equal(result.repositoriesFailed, 0)
   })
 
+  it("aborts before creating anything when failOnExisting finds collisions", async () => {
+    let createCalls = 0
+    let listedNamespace = ""
+    let listedIncludesArchived = false
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listRepositories: async (_draft, request) => {
+          listedNamespace = request.namespace
+          listedIncludesArchived = request.includeArchived === true
+          const firstName = planForAssignment(course, "a1").groups[0].repoName
+          return {
+            repositories: [
+              {
+                name: firstName.toUpperCase(),
+                identifier: firstName.toUpperCase(),
+                archived: true,
+              },
+              { name: "unrelated", identifier: "unrelated", archived: false },
+            ],
+          }
+        },
+        createRepositories: async () => {
+          createCalls += 1
+          return { created: [], alreadyExisted: [], failed: [] }
+        },
+      },
+    })
+    const [firstGroup] = planForAssignment(course, "a1").groups
+
+    await assert.rejects(
+      () =>
+        handlers["repo.create"]({
+          course,
+          credentials: settings,
+          assignmentId: "a1",
+          template: null,
+          failOnExisting: true,
+        }),
+      (error: unknown) => {
+        assert.ok(typeof error === "object" && error !== null)
+        assert.ok("type" in error && error.type === "validation")
+        assert.ok("issues" in error)
+        assert.deepStrictEqual(error.issues, [
+          {
+            path: "repositoryNames",
+            message: `Repository '${firstGroup.repoName}' already exists in 'repo-edu'.`,
+          },
+        ])
+        return true
+      },
+    )
+    assert.equal(listedNamespace, "repo-edu")
+    assert.equal(listedIncludesArchived, true)
+    assert.equal(createCalls, 0)
+  })
+
+  it("rejects planned names the Git provider would refuse", async () => {
+    let createCalls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        createRepositories: async () => {
+          createCalls += 1
+          return { created: [], alreadyExisted: [], failed: [] }
+        },
+      },
+    })
+    const invalidCourse = structuredClone(course)
+    const assignment = invalidCourse.roster.assignments.find(
+      (candidate) => candidate.id === "a1",
+    )
+    const groupSet = invalidCourse.roster.groupSets.find(
+      (candidate) => candidate.id === assignment?.groupSetId,
+    )
+    assert.ok(groupSet)
+    groupSet.repoNameTemplate = "{group}"
+    const [firstGroup] = planForAssignment(invalidCourse, "a1").groups
+    const group = invalidCourse.roster.groups.find(
+      (candidate) => candidate.id === firstGroup.groupId,
+    )
+    assert.ok(group)
+    group.name = "Team Git"
+
+    await assert.rejects(
+      () =>
+        handlers["repo.create"]({
+          course: invalidCourse,
+          credentials: settings,
+          assignmentId: "a1",
+          template: null,
+        }),
+      (error: unknown) => {
+        assert.ok(typeof error === "object" && error !== null)
+        assert.ok("type" in error && error.type === "validation")
+        assert.ok("issues" in error)
+        assert.deepStrictEqual(error.issues, [
+          {
+            path: "repositoryNames",
+            message: "Repository 'team.git': name cannot end with '.git'.",
+          },
+        ])
+        return true
+      },
+    )
+    assert.equal(createCalls, 0)
+  })
+
+  it("skips the existence preflight unless failOnExisting is set", async () => {
+    let listCalls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listRepositories: async () => {
+          listCalls += 1
+          return { repositories: [] }
+        },
+      },
+    })
+
+    const result = await handlers["repo.create"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      template: null,
+    })
+
+    assert.equal(listCalls, 0)
+    assert.equal(result.repositoriesFailed, 0)
+  })
+
   it("uses per-assignment template when available", async () => {
     const assignmentTemplate = {
       kind: "remote" as const,
@@ -245,6 +373,61 @@ describe("application repository create workflow helpers", () => {
     assert.equal(receivedVisibility, assignmentTemplate.visibility)
   })
 
+  it("rejects creation when a remote template does not exist", async () => {
+    const { course, settings } = getCourseAndSettingsScenario(
+      { tier: "small", preset: "shared-teams" },
+      ({ course, settings }) => {
+        course.organization = "repo-edu"
+        course.repositoryTemplate = {
+          kind: "remote",
+          owner: "course-templates",
+          name: "missing-template",
+          visibility: "private",
+        }
+        settings.activeSurface = { kind: "course", courseId: course.id }
+        settings.gitConnections = [
+          {
+            id: "main-git",
+            provider: "github",
+            baseUrl: "https://github.com",
+            token: "token-1",
+          },
+        ]
+        settings.activeGitConnectionId = "main-git"
+      },
+    )
+    let createCalls = 0
+
+    const { handlers } = createRepoHarness({
+      git: {
+        getRepositoryDefaultBranchHead: async () => null,
+        createRepositories: async () => {
+          createCalls += 1
+          return { created: [], alreadyExisted: [], failed: [] }
+        },
+      },
+    })
+
+    await assert.rejects(
+      handlers["repo.create"]({
+        course,
+        credentials: splitAppSettings(settings).credentials,
+        assignmentId: "a1",
+        template: null,
+      }),
+      (error: unknown) =>
+        typeof error === "object" &&
+        error !== null &&
+        "type" in error &&
+        error.type === "validation" &&
+        "issues" in error &&
+        JSON.stringify(error.issues).includes(
+          "course-templates/missing-template",
+        ),
+    )
+    assert.equal(createCalls, 0)
+  })
+
   it("pushes local templates through clone URLs returned by creation", async () => {
     const cloneUrls: string[] = []
     let resolutionCalls = 0
@@ -295,6 +478,91 @@ describe("application repository create workflow helpers", () => {
     assert.ok(cloneUrls.every((url) => url.includes("created-token")))
   })
 
+  it("mirrors every template ref when mirrorTemplate is set", async () => {
+    const commands: { args: string[]; cwd?: string }[] = []
+    const { course, settings, handlers } = createRepoHarness({
+      gitCommand: {
+        run: async (request) => {
+          commands.push({ args: request.args, cwd: request.cwd })
+          return {
+            exitCode: 0,
+            signal: null,
+            stdout: request.args.includes("--abbrev-ref") ? "main\n" : "sha\n",
+            stderr: "",
+          }
+        },
+      },
+    })
+
+    const result = await handlers["repo.create"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      template: {
+        kind: "local",
+        path: "/course-template",
+        visibility: "private",
+      },
+      mirrorTemplate: true,
+    })
+
+    assert.deepStrictEqual(commands[0]?.args, [
+      "clone",
+      "--bare",
+      "/course-template",
+      "/tmp/repo-edu-test",
+    ])
+    const pushes = commands.filter((command) => command.args[0] === "push")
+    assert.equal(pushes.length, result.repositoriesCreated)
+    for (const push of pushes) {
+      assert.equal(push.args[1], "--mirror")
+      assert.equal(push.cwd, "/tmp/repo-edu-test")
+    }
+  })
+
+  it("falls back to the default-branch push when the mirror clone fails", async () => {
+    const pushes: string[][] = []
+    const warnings: string[] = []
+    const { course, settings, handlers } = createRepoHarness({
+      gitCommand: {
+        run: async (request) => {
+          if (request.args[0] === "push") {
+            pushes.push(request.args)
+          }
+          return {
+            exitCode: request.args.includes("--bare") ? 128 : 0,
+            signal: null,
+            stdout: request.args.includes("--abbrev-ref") ? "main\n" : "sha\n",
+            stderr: "",
+          }
+        },
+      },
+    })
+
+    await handlers["repo.create"](
+      {
+        course,
+        credentials: settings,
+        assignmentId: "a1",
+        template: {
+          kind: "local",
+          path: "/course-template",
+          visibility: "private",
+        },
+        mirrorTemplate: true,
+      },
+      {
+        onOutput: (output) => {
+          if (output.channel === "warn") warnings.push(output.message)
+        },
+      },
+    )
+
+    assert.ok(warnings.some((message) => message.includes("Failed to mirror")))
+    assert.ok(pushes.length > 0)
+    assert.ok(pushes.every((args) => args[2] === "HEAD:refs/heads/main"))
+  })
+
   it("does not turn caller cancellation during team setup into a warning", async () => {
     for (const failedOperation of ["create", "assign"] as const) {
       const controller = new AbortController()
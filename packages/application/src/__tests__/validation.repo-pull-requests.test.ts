@@ -0,0 +1,96 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
+import { planRepositoryOperation } from "@repo-edu/domain/repository-planning"
+import { createRepoHarness } from "./helpers/repo-workflow-harness.js"
+
+function makePullRequest(number: number, createdAt: string) {
+  return {
+    number,
+    title: `Submission ${number}`,
+    author: "student",
+    headBranch: `feature-${number}`,
+    baseBranch: "main",
+    createdAt,
+    state: "open" as const,
+    url: `https://github.com/repo-edu/pull/${number}`,
+  }
+}
+
+describe("application repository pull request listing workflow", () => {
+  it("lists pull requests for every planned repository", async () => {
+    const requested: string[] = []
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listPullRequests: async (_draft, request) => {
+          requested.push(
+            `${request.owner}/${request.repositoryName}:${request.state}`,
+          )
+          return [
+            makePullRequest(1, "2026-03-01T10:00:00Z"),
+            makePullRequest(2, "2026-03-02T10:00:00Z"),
+          ]
+        },
+      },
+    })
+    const plan = planRepositoryOperation(course, "a1", "clone")
+    assert.ok(plan.ok)
+
+    const result = await handlers["repo.listPullRequests"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      state: "open",
+    })
+
+    const expected = plan.value.groups.map((group) => group.repoName).sort()
+    assert.deepStrictEqual(
+      [...requested].sort(),
+      expected.map((name) => `repo-edu/${name}:open`),
+    )
+    assert.equal(result.repositoriesPlanned, expected.length)
+    assert.equal(result.repositoriesFailed, 0)
+    assert.equal(result.pullRequests.length, expected.length * 2)
+    assert.deepStrictEqual(
+      result.pullRequests.slice(0, 2).map((pullRequest) => ({
+        repositoryName: pullRequest.repositoryName,
+        number: pullRequest.number,
+      })),
+      [
+        { repositoryName: expected[0], number: 2 },
+        { repositoryName: expected[0], number: 1 },
+      ],
+    )
+  })
+
+  it("warns about repositories that fail and keeps listing", async () => {
+    const outputs: DiagnosticOutput[] = []
+    let calls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listPullRequests: async () => {
+          calls += 1
+          if (calls === 1) {
+            throw new Error("Repository not found")
+          }
+          return [makePullRequest(1, "2026-03-01T10:00:00Z")]
+        },
+      },
+    })
+
+    const result = await handlers["repo.listPullRequests"](
+      { course, credentials: settings, assignmentId: "a1", state: "all" },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    assert.equal(result.repositoriesFailed, 1)
+    assert.equal(result.pullRequests.length, result.repositoriesPlanned - 1)
+    assert.ok(
+      outputs.some(
+        (output) =>
+          output.channel === "warn" &&
+          output.message.includes("Repository not found"),
+      ),
+    )
+  })
+})
@@ -0,0 +1,150 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
+import { planRepositoryOperation } from "@repo-edu/domain/repository-planning"
+import type { PersistedCourse } from "@repo-edu/domain/types"
+import { createRepoHarness } from "./helpers/repo-workflow-harness.js"
+
+function expectedMembersByTeam(course: PersistedCourse): Map<string, string[]> {
+  const plan = planRepositoryOperation(course, "a1", "create")
+  assert.ok(plan.ok)
+  const members = new Map<string, string[]>()
+  for (const group of plan.value.groups) {
+    const teamName =
+      group.groupName.trim().length > 0 ? group.groupName : group.groupId
+    members.set(teamName, [
+      ...(members.get(teamName) ?? []),
+      ...group.gitUsernames,
+    ])
+  }
+  return members
+}
+
+describe("application repository team sync workflow", () => {
+  it("adds missing members and leaves complete teams unchanged", async () => {
+    const added: string[] = []
+    let expected = new Map<string, string[]>()
+    let incompleteTeam = ""
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listTeamMembers: async (_draft, request) => {
+          const members = expected.get(request.teamName) ?? []
+          return request.teamName === incompleteTeam
+            ? members.slice(1)
+            : members.map((username) => username.toUpperCase())
+        },
+        createTeam: async (_draft, request) => {
+          added.push(...request.memberUsernames)
+          return {
+            created: false,
+            teamSlug: request.teamName,
+            membersAdded: request.memberUsernames,
+            membersNotFound: [],
+          }
+        },
+      },
+    })
+    expected = expectedMembersByTeam(course)
+    const incomplete = [...expected.entries()].find(
+      ([, members]) => members.length > 0,
+    )
+    assert.ok(incomplete)
+    const [firstTeam, firstMembers] = incomplete
+    incompleteTeam = firstTeam
+
+    const result = await handlers["repo.syncTeams"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      removeStaleMembers: false,
+    })
+
+    assert.deepStrictEqual(added, [firstMembers[0]])
+    assert.equal(result.teamsPlanned, expected.size)
+    assert.equal(result.membersAdded, 1)
+    assert.equal(result.membersRemoved, 0)
+    assert.equal(result.teamsUnchanged, expected.size - 1)
+    assert.equal(result.teamsFailed, 0)
+  })
+
+  it("removes members no longer in the group only when requested", async () => {
+    const removed: string[] = []
+    let expected = new Map<string, string[]>()
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listTeamMembers: async (_draft, request) => [
+          ...(expected.get(request.teamName) ?? []),
+          "former-student",
+        ],
+        removeTeamMembers: async (_draft, request) => {
+          removed.push(`${request.teamName}:${request.memberUsernames}`)
+        },
+      },
+    })
+    expected = expectedMembersByTeam(course)
+
+    const kept = await handlers["repo.syncTeams"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      removeStaleMembers: false,
+    })
+    assert.equal(kept.membersRemoved, 0)
+    assert.equal(kept.teamsUnchanged, expected.size)
+    assert.deepStrictEqual(removed, [])
+
+    const pruned = await handlers["repo.syncTeams"]({
+      course,
+      credentials: settings,
+      assignmentId: "a1",
+      removeStaleMembers: true,
+    })
+    assert.equal(pruned.membersRemoved, expected.size)
+    assert.equal(pruned.teamsUnchanged, 0)
+    assert.deepStrictEqual(
+      [...removed].sort(),
+      [...expected.keys()]
+        .map((teamName) => `${teamName}:former-student`)
+        .sort(),
+    )
+  })
+
+  it("reports teams that do not exist on the provider", async () => {
+    const outputs: DiagnosticOutput[] = []
+    let createCalls = 0
+    const { course, settings, handlers } = createRepoHarness({
+      git: {
+        listTeamMembers: async () => null,
+        createTeam: async (_draft, request) => {
+          createCalls += 1
+          return {
+            created: true,
+            teamSlug: request.teamName,
+            membersAdded: request.memberUsernames,
+            membersNotFound: [],
+          }
+        },
+      },
+    })
+
+    const result = await handlers["repo.syncTeams"](
+      {
+        course,
+        credentials: settings,
+        assignmentId: "a1",
+        removeStaleMembers: true,
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    assert.equal(createCalls, 0)
+    assert.equal(result.teamsMissing, result.teamsPlanned)
+    assert.equal(result.membersAdded, 0)
+    assert.ok(
+      outputs.some(
+        (output) =>
+          output.channel === "warn" && output.message.includes("repo create"),
+      ),
+    )
+  })
+})
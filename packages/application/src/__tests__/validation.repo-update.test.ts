@@ -52,6 +52,8 @@ describe("application repository update workflow helpers", () => {
           membersNotFound: [],
         }),
         assignRepositoriesToTeam: async () => {},
+        listTeamMembers: async () => [],
+        removeTeamMembers: async () => {},
         getRepositoryDefaultBranchHead: async (_draft, request) => {
           if (
             request.owner === "template-org" &&
@@ -92,6 +94,10 @@ describe("application repository update workflow helpers", () => {
         listRepositories: async () => ({
           repositories: [],
         }),
+        getCommitCount: async () => null,
+        listPullRequests: async () => [],
+        archiveRepository: async () => {},
+        unarchiveRepository: async () => {},
       },
       gitCommand: {
         cancellation: "best-effort",
@@ -176,6 +182,8 @@ describe("application repository update workflow helpers", () => {
           membersNotFound: [],
         }),
         assignRepositoriesToTeam: async () => {},
+        listTeamMembers: async () => [],
+        removeTeamMembers: async () => {},
         getRepositoryDefaultBranchHead: async () => ({
           sha: "same-template-sha",
           branchName: "main",
@@ -196,6 +204,10 @@ describe("application repository update workflow helpers", () => {
         listRepositories: async () => ({
           repositories: [],
         }),
+        getCommitCount: async () => null,
+        listPullRequests: async () => [],
+        archiveRepository: async () => {},
+        unarchiveRepository: async () => {},
       },
       gitCommand: {
         cancellation: "best-effort",
@@ -0,0 +1,404 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  DiagnosticOutput,
+  RosterImportFromLmsInput,
+} from "@repo-edu/application-contract"
+import { splitAppSettings } from "@repo-edu/domain/settings"
+import type { RosterMember } from "@repo-edu/domain/types"
+import {
+  lmsAuthErrorName,
+  type LmsClient,
+  type RemoteLmsMember,
+} from "@repo-edu/integrations-lms-contract"
+import { createRosterWorkflowHandlers } from "../roster-workflows.js"
+import { getCourseAndSettingsScenario } from "./helpers/fixture-scenarios.js"
+
+function remoteMember(
+  lmsUserId: string,
+  email: string | null = `${lmsUserId}@example.com`,
+  enrollmentType = "student",
+): RemoteLmsMember {
+  return {
+    id: `remote-${lmsUserId}`,
+    lmsUserId,
+    name: `Member ${lmsUserId}`,
+    email,
+    studentNumber: null,
+    enrollmentType,
+    enrollmentDisplay: null,
+    status: "active",
+    lmsStatus: null,
+    source: "canvas",
+  }
+}
+
+function existingStudent(lmsUserId: string): RosterMember {
+  return {
+    id: `member-${lmsUserId}`,
+    name: `Member ${lmsUserId}`,
+    email: `${lmsUserId}@example.com`,
+    studentNumber: null,
+    gitUsername: null,
+    gitUsernameStatus: "unknown",
+    status: "active",
+    lmsStatus: "active",
+    lmsUserId,
+    enrollmentType: "student",
+    enrollmentDisplay: null,
+    department: null,
+    institution: null,
+    source: "canvas",
+  }
+}
+
+type PageScript = (RemoteLmsMember[] | Error)[]
+
+function createImport(
+  pages: PageScript,
+  fetchRoster: LmsClient["fetchRoster"] = async () => {
+    throw new Error("fetchRoster should not be called")
+  },
+  existingStudents: RosterMember[] = [],
+  provider: "canvas" | "moodle" = "canvas",
+) {
+  const { course, settings } = getCourseAndSettingsScenario(
+    { tier: "small", preset: "shared-teams" },
+    ({ course, settings }) => {
+      course.lmsConnectionId = "main-lms"
+      course.lmsCourseId = "course-42"
+      course.roster.students = existingStudents
+      course.roster.staff = []
+      settings.lmsConnections = [
+        {
+          id: "main-lms",
+          name: "Main LMS",
+          provider,
+          baseUrl: "https://canvas.example.edu",
+          token: "token-1",
+        },
+      ]
+    },
+  )
+  const requestedPages: number[] = []
+  const requestedCursors: (string | null | undefined)[] = []
+  const outputs: DiagnosticOutput[] = []
+  const handlers = createRosterWorkflowHandlers({
+    lms: {
+      fetchRoster,
+      fetchRosterPage: async (_draft, _courseId, request) => {
+        requestedPages.push(request.page)
+        requestedCursors.push(request.cursor)
+        const page = pages[request.page - 1] ?? []
+        if (page instanceof Error) throw page
+        return {
+          items: page,
+          pagination: {
+            page: request.page,
+            perPage: request.perPage,
+            hasNext: request.page < pages.length,
+            totalCount: null,
+            nextCursor:
+              request.page < pages.length ? `cursor-${request.page + 1}` : null,
+          },
+        }
+      },
+    },
+    userFile: {
+      readText: async () => {
+        throw new Error("not used")
+      },
+      writeText: async () => {
+        throw new Error("not used")
+      },
+    },
+  })
+
+  return {
+    requestedPages,
+    requestedCursors,
+    outputs,
+    run: (input: Partial<RosterImportFromLmsInput> = {}) =>
+      handlers["roster.importFromLms"](
+        {
+          course,
+          credentials: splitAppSettings(settings).credentials,
+          lmsCourseId: "course-42",
+          continueOnPageError: true,
+          ...input,
+        },
+        { onOutput: (output) => outputs.push(output) },
+      ),
+  }
+}
+
+function importedLmsUserIds(result: {
+  roster: { students: { lmsUserId: string | null }[] }
+}): (string | null)[] {
+  return result.roster.students.map((student) => student.lmsUserId).sort()
+}
+
+function pageWarnings(outputs: DiagnosticOutput[]): string[] {
+  return outputs
+    .filter((output) => output.code === "lms_roster_page_failed")
+    .map((output) => output.message)
+}
+
+describe("application LMS roster import with page errors", () => {
+  it("skips a failed first page and keeps the pages after it", async () => {
+    const { run, outputs } = createImport([
+      new Error("Canvas returned 500"),
+      [remoteMember("u-2")],
+      [remoteMember("u-3")],
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-2", "u-3"])
+    assert.deepStrictEqual(pageWarnings(outputs), [
+      "Skipped roster page 1: Canvas returned 500",
+    ])
+  })
+
+  it("skips a failed page in the middle of the import", async () => {
+    const { run, requestedPages, outputs } = createImport([
+      [remoteMember("u-1")],
+      new Error("Canvas returned 502"),
+      [remoteMember("u-3")],
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1", "u-3"])
+    assert.deepStrictEqual(requestedPages, [1, 2, 3])
+    assert.deepStrictEqual(pageWarnings(outputs), [
+      "Skipped roster page 2: Canvas returned 502",
+    ])
+  })
+
+  it("skips a failed last page and stops at the empty page after it", async () => {
+    const { run, requestedPages } = createImport([
+      [remoteMember("u-1")],
+      [remoteMember("u-2")],
+      new Error("Canvas returned 500"),
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1", "u-2"])
+    assert.deepStrictEqual(requestedPages, [1, 2, 3, 4])
+  })
+
+  it("follows page cursors and uses page numbers after a skipped page", async () => {
+    const { run, requestedPages, requestedCursors } = createImport([
+      [remoteMember("u-1")],
+      [remoteMember("u-2")],
+      new Error("Canvas returned 502"),
+      [remoteMember("u-4")],
+      [remoteMember("u-5")],
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), [
+      "u-1",
+      "u-2",
+      "u-4",
+      "u-5",
+    ])
+    assert.deepStrictEqual(requestedPages, [1, 2, 3, 4, 5])
+    assert.deepStrictEqual(requestedCursors, [
+      null,
+      "cursor-2",
+      "cursor-3",
+      null,
+      "cursor-5",
+    ])
+  })
+
+  it("reports each skipped page separately", async () => {
+    const { run, outputs } = createImport([
+      [remoteMember("u-1")],
+      new Error("first"),
+      [remoteMember("u-3")],
+      new Error("second"),
+      [remoteMember("u-5")],
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1", "u-3", "u-5"])
+    assert.deepStrictEqual(pageWarnings(outputs), [
+      "Skipped roster page 2: first",
+      "Skipped roster page 4: second",
+    ])
+  })
+
+  it("keeps existing members on a skipped page active", async () => {
+    const { run } = createImport(
+      [[remoteMember("u-1")], new Error("Canvas returned 500")],
+      undefined,
+      [existingStudent("u-1"), existingStudent("u-2")],
+    )
+
+    const result = await run()
+
+    assert.deepStrictEqual(
+      result.roster.students.map((student) => [
+        student.lmsUserId,
+        student.status,
+      ]),
+      [
+        ["u-1", "active"],
+        ["u-2", "active"],
+      ],
+    )
+  })
+
+  it("marks missing members dropped when every page loads", async () => {
+    const { run } = createImport([[remoteMember("u-1")]], undefined, [
+      existingStudent("u-1"),
+      existingStudent("u-2"),
+    ])
+
+    const result = await run()
+
+    assert.equal(
+      result.roster.students.find((student) => student.lmsUserId === "u-2")
+        ?.status,
+      "dropped",
+    )
+  })
+
+  it("stops paging after three failed pages in a row", async () => {
+    const { run, requestedPages, outputs } = createImport([
+      [remoteMember("u-1")],
+      new Error("down"),
+      new Error("down"),
+      new Error("down"),
+      [remoteMember("u-5")],
+    ])
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1"])
+    assert.deepStrictEqual(requestedPages, [1, 2, 3, 4])
+    assert.equal(pageWarnings(outputs).length, 3)
+  })
+
+  it("fails with the first page error when no page loads", async () => {
+    const unavailable = new Error("Canvas is unavailable")
+    const { run, requestedPages } = createImport([
+      unavailable,
+      unavailable,
+      unavailable,
+      unavailable,
+    ])
+
+    await assert.rejects(run(), {
+      type: "provider",
+      message: "Fetching the LMS roster: Canvas is unavailable",
+      operation: "fetchRoster",
+    })
+    assert.deepStrictEqual(requestedPages, [1, 2, 3])
+  })
+
+  it("aborts on LMS credential failures instead of skipping the page", async () => {
+    const { run, requestedPages } = createImport([
+      [remoteMember("u-1")],
+      Object.assign(new Error("Canvas token expired."), {
+        name: lmsAuthErrorName,
+        kind: "expired",
+        provider: "canvas",
+      }),
+      [remoteMember("u-3")],
+    ])
+
+    await assert.rejects(run(), {
+      type: "provider",
+      message: "Fetching the LMS roster: Canvas token expired.",
+      retryable: false,
+    })
+    assert.deepStrictEqual(requestedPages, [1, 2])
+  })
+
+  it("aborts on the first failure without continueOnPageError", async () => {
+    const { run, requestedPages } = createImport([], async () => {
+      throw new Error("Canvas returned 500")
+    })
+
+    await assert.rejects(run({ continueOnPageError: false }), {
+      type: "provider",
+      message: "Fetching the LMS roster: Canvas returned 500",
+    })
+    assert.deepStrictEqual(requestedPages, [])
+  })
+
+  it("fetches a Moodle roster once instead of page by page", async () => {
+    let fetchCount = 0
+    const { run, requestedPages } = createImport(
+      [[remoteMember("u-1")]],
+      async () => {
+        fetchCount += 1
+        return [remoteMember("u-1"), remoteMember("u-2")]
+      },
+      [],
+      "moodle",
+    )
+
+    const result = await run()
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1", "u-2"])
+    assert.equal(fetchCount, 1)
+    assert.deepStrictEqual(requestedPages, [])
+  })
+
+  it("rejects the import when too many students lack an email", async () => {
+    const { run } = createImport([
+      [remoteMember("u-1"), remoteMember("u-2", null)],
+      new Error("Canvas returned 500"),
+      [remoteMember("u-3", " ")],
+    ])
+
+    await assert.rejects(run({ maxMissingEmailRatio: 0.5 }), {
+      type: "validation",
+      message: "Too many LMS students have no email address.",
+      issues: [
+        {
+          path: "maxMissingEmailRatio",
+          message:
+            "2 of 3 students have no email address, above the allowed ratio of 0.5.",
+        },
+      ],
+    })
+  })
+
+  it("accepts a missing-email ratio at the limit and ignores staff", async () => {
+    const { run } = createImport([
+      [
+        remoteMember("u-1"),
+        remoteMember("u-2", null),
+        remoteMember("t-1", null, "teacher"),
+      ],
+    ])
+
+    const result = await run({ maxMissingEmailRatio: 0.5 })
+
+    assert.deepStrictEqual(importedLmsUserIds(result), ["u-1", "u-2"])
+  })
+
+  it("rejects a missing-email ratio outside 0 to 1 before fetching", async () => {
+    const { run, requestedPages } = createImport([[remoteMember("u-1")]])
+
+    await assert.rejects(run({ maxMissingEmailRatio: 1.5 }), {
+      type: "validation",
+      issues: [
+        {
+          path: "maxMissingEmailRatio",
+          message: "Expected a ratio between 0 and 1.",
+        },
+      ],
+    })
+    assert.deepStrictEqual(requestedPages, [])
+  })
+})
@@ -1,5 +1,6 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
+import type { DiagnosticOutput } from "@repo-edu/application-contract"
 import { systemSetsMissing } from "@repo-edu/domain/group-set"
 import { splitAppSettings } from "@repo-edu/domain/settings"
 import { createRosterWorkflowHandlers } from "../roster-workflows.js"
@@ -8,6 +9,10 @@ import {
   getCourseScenario,
 } from "./helpers/fixture-scenarios.js"
 
+const unusedRosterPage = async () => {
+  throw new Error("not used")
+}
+
 describe("application roster workflow helpers", () => {
   it("imports students from CSV file and ensures system group sets", async () => {
     const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
@@ -22,6 +27,7 @@ describe("application roster workflow helpers", () => {
 
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async () => [],
       },
       userFile: {
@@ -74,6 +80,7 @@ describe("application roster workflow helpers", () => {
 
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async () => [],
       },
       userFile: {
@@ -126,6 +133,7 @@ describe("application roster workflow helpers", () => {
     const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async () => [],
       },
       userFile: {
@@ -164,6 +172,93 @@ describe("application roster workflow helpers", () => {
     )
   })
 
+  it("imports students from a RepoBee students.yaml file", async () => {
+    const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+    course.roster.students = []
+    course.roster.staff = []
+    const handlers = createRosterWorkflowHandlers({
+      lms: {
+        fetchRosterPage: unusedRosterPage,
+        fetchRoster: async () => [],
+      },
+      userFile: {
+        readText: async () => ({
+          displayName: "students.yaml",
+          mediaType: "application/yaml",
+          text: [
+            "users:",
+            "  - name: Ada Lovelace",
+            "    email: ada@example.com",
+            "    github: adal",
+          ].join("\n"),
+          byteLength: 0,
+        }),
+        writeText: async () => {
+          throw new Error("not used")
+        },
+      },
+    })
+
+    const imported = await handlers["roster.importFromFile"]({
+      course,
+      file: {
+        kind: "user-file-ref",
+        referenceId: "file-yaml",
+        displayName: "students.yaml",
+        mediaType: "application/yaml",
+        byteLength: null,
+      },
+    })
+
+    assert.deepStrictEqual(
+      imported.roster.students.map((member) => [
+        member.name,
+        member.email,
+        member.gitUsername,
+      ]),
+      [["Ada Lovelace", "ada@example.com", "adal"]],
+    )
+    assert.equal(imported.roster.connection?.kind, "import")
+  })
+
+  it("fails roster import when the YAML file has no students", async () => {
+    const course = getCourseScenario({ tier: "small", preset: "shared-teams" })
+    const handlers = createRosterWorkflowHandlers({
+      lms: {
+        fetchRosterPage: unusedRosterPage,
+        fetchRoster: async () => [],
+      },
+      userFile: {
+        readText: async () => ({
+          displayName: "students.yml",
+          mediaType: null,
+          text: "teams: []",
+          byteLength: 0,
+        }),
+        writeText: async () => {
+          throw new Error("not used")
+        },
+      },
+    })
+
+    await assert.rejects(
+      handlers["roster.importFromFile"]({
+        course,
+        file: {
+          kind: "user-file-ref",
+          referenceId: "file-yml",
+          displayName: "students.yml",
+          mediaType: null,
+          byteLength: null,
+        },
+      }),
+      {
+        type: "validation",
+        message: "Students YAML file is invalid.",
+      },
+    )
+  })
+
   it("imports roster from LMS using the course connection and enforces system sets", async () => {
     const { course, settings } = getCourseAndSettingsScenario(
       { tier: "small", preset: "shared-teams" },
@@ -189,6 +284,7 @@ describe("application roster workflow helpers", () => {
 
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async (draft, courseId, _signal, onProgress) => {
           receivedDraft = draft
           receivedCourseId = courseId
@@ -249,6 +345,157 @@ describe("application roster workflow helpers", () => {
     assert.equal(systemSetsMissing(imported.roster), false)
   })
 
+  it("warns when LMS roster members have no email address", async () => {
+    const { course, settings } = getCourseAndSettingsScenario(
+      { tier: "small", preset: "shared-teams" },
+      ({ course, settings }) => {
+        course.lmsConnectionId = "main-lms"
+        course.lmsCourseId = "course-42"
+        settings.lmsConnections = [
+          {
+            id: "main-lms",
+            name: "Main LMS",
+            provider: "canvas",
+            baseUrl: "https://canvas.example.edu",
+            token: "token-1",
+          },
+        ]
+      },
+    )
+    const remoteMember = (lmsUserId: string, email: string | null) => ({
+      id: `remote-${lmsUserId}`,
+      lmsUserId,
+      name: `Member ${lmsUserId}`,
+      email,
+      studentNumber: null,
+      enrollmentType: "student",
+      enrollmentDisplay: null,
+      status: "active" as const,
+      lmsStatus: null,
+      source: "canvas",
+    })
+    const outputs: DiagnosticOutput[] = []
+
+    const handlers = createRosterWorkflowHandlers({
+      lms: {
+        fetchRosterPage: unusedRosterPage,
+        fetchRoster: async () => [
+          remoteMember("u-1", "ada@example.com"),
+          remoteMember("u-2", null),
+          remoteMember("u-3", " "),
+        ],
+      },
+      userFile: {
+        readText: async () => {
+          throw new Error("not used")
+        },
+        writeText: async () => {
+          throw new Error("not used")
+        },
+      },
+    })
+
+    await handlers["roster.importFromLms"](
+      {
+        course,
+        credentials: splitAppSettings(settings).credentials,
+        lmsCourseId: "course-42",
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    const warnings = outputs.filter((output) => output.channel === "warn")
+    assert.deepStrictEqual(warnings, [
+      {
+        channel: "warn",
+        code: "lms_members_missing_email",
+        message: "2 LMS members have no email address.",
+      },
+    ])
+  })
+
+  it("warns when a student-number match changes a member's email", async () => {
+    const { course, settings } = getCourseAndSettingsScenario(
+      { tier: "small", preset: "shared-teams" },
+      ({ course, settings }) => {
+        course.lmsConnectionId = "main-lms"
+        course.lmsCourseId = "course-42"
+        settings.lmsConnections = [
+          {
+            id: "main-lms",
+            name: "Main LMS",
+            provider: "canvas",
+            baseUrl: "https://canvas.example.edu",
+            token: "token-1",
+          },
+        ]
+        const student = course.roster.students[0]
+        student.lmsUserId = null
+        student.email = "ada@old.example.edu"
+        student.studentNumber = "S-0042"
+      },
+    )
+    const existingId = course.roster.students[0].id
+    const outputs: DiagnosticOutput[] = []
+
+    const handlers = createRosterWorkflowHandlers({
+      lms: {
+        fetchRosterPage: unusedRosterPage,
+        fetchRoster: async () => [
+          {
+            id: "remote-u-42",
+            lmsUserId: "u-42",
+            name: "Ada Lovelace",
+            email: "ada@new.example.edu",
+            studentNumber: "S-0042",
+            enrollmentType: "student",
+            enrollmentDisplay: null,
+            status: "active",
+            lmsStatus: null,
+            source: "canvas",
+          },
+        ],
+      },
+      userFile: {
+        readText: async () => {
+          throw new Error("not used")
+        },
+        writeText: async () => {
+          throw new Error("not used")
+        },
+      },
+    })
+
+    const imported = await handlers["roster.importFromLms"](
+      {
+        course,
+        credentials: splitAppSettings(settings).credentials,
+        lmsCourseId: "course-42",
+      },
+      { onOutput: (output) => outputs.push(output) },
+    )
+
+    const matched = imported.roster.students.filter(
+      (member) => member.studentNumber === "S-0042",
+    )
+    assert.deepStrictEqual(
+      matched.map((member) => [member.id, member.email]),
+      [[existingId, "ada@new.example.edu"]],
+    )
+    assert.equal(imported.summary.membersMatchedBy.studentNumber, 1)
+    assert.deepStrictEqual(
+      outputs.filter((output) => output.code === "lms_member_email_changed"),
+      [
+        {
+          channel: "warn",
+          code: "lms_member_email_changed",
+          message:
+            "Student number S-0042 matched an existing member; email changed from ada@old.example.edu to ada@new.example.edu.",
+        },
+      ],
+    )
+  })
+
   it("rejects LMS roster import for RepoBee courses", async () => {
     const { course, settings } = getCourseAndSettingsScenario({
       tier: "small",
@@ -256,6 +503,7 @@ describe("application roster workflow helpers", () => {
     })
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async () => {
           throw new Error("not used")
         },
@@ -296,6 +544,7 @@ describe("application roster workflow helpers", () => {
 
     const handlers = createRosterWorkflowHandlers({
       lms: {
+        fetchRosterPage: unusedRosterPage,
         fetchRoster: async () => [],
       },
       userFile: {
@@ -0,0 +1,119 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import { parseStudentsYamlText } from "../students-yaml-parser.js"
+
+function parseIssues(text: string) {
+  const result = parseStudentsYamlText(text)
+  assert.equal(result.ok, false)
+  return result.ok ? [] : result.issues
+}
+
+describe("parseStudentsYamlText", () => {
+  it("reads the RepoBee users map and maps github to git_username", () => {
+    const result = parseStudentsYamlText(
+      [
+        "users:",
+        "  - name: Ada Lovelace",
+        "    email: ada@example.com",
+        "    github: adal",
+        "  - name: Grace Hopper",
+        "    email: grace@example.com",
+        "    github: ghopper",
+      ].join("\n"),
+    )
+
+    assert.deepStrictEqual(result, {
+      ok: true,
+      rows: [
+        {
+          name: "Ada Lovelace",
+          email: "ada@example.com",
+          git_username: "adal",
+        },
+        {
+          name: "Grace Hopper",
+          email: "grace@example.com",
+          git_username: "ghopper",
+        },
+      ],
+    })
+  })
+
+  it("reads a flat list of students", () => {
+    const result = parseStudentsYamlText(
+      [
+        "- name: Ada Lovelace",
+        "  Email: ada@example.com",
+        "  student_number: 1001",
+      ].join("\n"),
+    )
+
+    assert.deepStrictEqual(result, {
+      ok: true,
+      rows: [
+        {
+          name: "Ada Lovelace",
+          email: "ada@example.com",
+          student_number: "1001",
+        },
+      ],
+    })
+  })
+
+  it("keeps entries with missing fields for row validation", () => {
+    const result = parseStudentsYamlText(
+      [
+        "- email: ada@example.com",
+        "  github: '  '",
+        "  teams: [a, b]",
+        "- name: Grace Hopper",
+        "  email: null",
+      ].join("\n"),
+    )
+
+    assert.deepStrictEqual(result, {
+      ok: true,
+      rows: [{ email: "ada@example.com" }, { name: "Grace Hopper" }],
+    })
+  })
+
+  it("rejects an empty file", () => {
+    assert.deepStrictEqual(parseIssues("  \n# no students yet\n"), [
+      { path: "$", message: "Students YAML file is empty." },
+    ])
+  })
+
+  it("rejects a users list without students", () => {
+    assert.deepStrictEqual(parseIssues("users: []"), [
+      { path: "users", message: "Students YAML file has no students." },
+    ])
+  })
+
+  it("explains the supported shapes when neither format matches", () => {
+    const expected = [
+      {
+        path: "$",
+        message:
+          "Students YAML must be a list of students or a map with a 'users' list.",
+      },
+    ]
+
+    assert.deepStrictEqual(parseIssues("students:\n  - name: Ada"), expected)
+    assert.deepStrictEqual(parseIssues("users: ada"), expected)
+    assert.deepStrictEqual(parseIssues("just text"), expected)
+  })
+
+  it("reports list entries that are not maps", () => {
+    assert.deepStrictEqual(
+      parseIssues(["users:", "  - name: Ada", "  - grace"].join("\n")),
+      [{ path: "users.1", message: "Student 2 must be a map of fields." }],
+    )
+  })
+
+  it("reports YAML syntax errors", () => {
+    const issues = parseIssues("users:\n  - name: [Ada")
+
+    assert.equal(issues.length, 1)
+    assert.equal(issues[0]?.path, "$")
+  })
+})
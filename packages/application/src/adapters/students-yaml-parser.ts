@@ -0,0 +1,99 @@
+import { parse, YAMLParseError } from "yaml"
+import { normalizeHeader } from "./tabular/normalize.js"
+import type { TabularRow } from "./tabular/types.js"
+
+export type StudentsYamlParseIssue = {
+  path: string
+  message: string
+}
+
+export type StudentsYamlParseResult =
+  | { ok: true; rows: TabularRow[] }
+  | { ok: false; issues: StudentsYamlParseIssue[] }
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === "object" && value !== null && !Array.isArray(value)
+}
+
+function toRow(entry: Record<string, unknown>): TabularRow {
+  const row: TabularRow = {}
+  for (const [key, value] of Object.entries(entry)) {
+    const header = normalizeHeader(key)
+    if (header === "" || header in row) continue
+    if (typeof value !== "string" && typeof value !== "number") continue
+    const text = String(value).trim()
+    if (text !== "") {
+      row[header] = text
+    }
+  }
+  return row
+}
+
+/**
+ * Reads students from YAML, either a RepoBee-style map with a `users` list
+ * (`users: [{ name, email, github }]`) or a bare list of the same entries.
+ * Keys are normalized like CSV headers, so `github` becomes `git_username`.
+ */
+export function parseStudentsYamlText(text: string): StudentsYamlParseResult {
+  let document: unknown
+  try {
+    document = parse(text)
+  } catch (error) {
+    if (!(error instanceof YAMLParseError)) throw error
+    return { ok: false, issues: [{ path: "$", message: error.message }] }
+  }
+
+  if (document === null || document === undefined) {
+    return {
+      ok: false,
+      issues: [{ path: "$", message: "Students YAML file is empty." }],
+    }
+  }
+
+  let entries: unknown[] | null = null
+  let basePath = "$"
+  if (Array.isArray(document)) {
+    entries = document
+  } else if (isRecord(document) && Array.isArray(document.users)) {
+    entries = document.users
+    basePath = "users"
+  }
+  if (entries === null) {
+    return {
+      ok: false,
+      issues: [
+        {
+          path: "$",
+          message:
+            "Students YAML must be a list of students or a map with a 'users' list.",
+        },
+      ],
+    }
+  }
+
+  const rows: TabularRow[] = []
+  const issues: StudentsYamlParseIssue[] = []
+  for (const [index, entry] of entries.entries()) {
+    if (!isRecord(entry)) {
+      issues.push({
+        path: `${basePath}.${index}`,
+        message: `Student ${index + 1} must be a map of fields.`,
+      })
+      continue
+    }
+    rows.push(toRow(entry))
+  }
+
+  if (issues.length > 0) {
+    return { ok: false, issues }
+  }
+  if (rows.length === 0) {
+    return {
+      ok: false,
+      issues: [
+        { path: basePath, message: "Students YAML file has no students." },
+      ],
+    }
+  }
+  return { ok: true, rows }
+}
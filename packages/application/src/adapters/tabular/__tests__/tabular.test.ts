@@ -1,5 +1,10 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
+import {
+  exportTeamsCsvRows,
+  parseTeamsCsvRows,
+  teamsCsvHeaders,
+} from "@repo-edu/domain/group-set-import-export"
 import {
   normalizeHeader,
   parseCsv,
@@ -33,6 +38,22 @@ describe("normalizeHeader", () => {
   it("handles unicode characters", () => {
     assert.equal(normalizeHeader("Prénom"), "pr_nom")
   })
+
+  it("maps provider-specific username headers to git_username", () => {
+    for (const header of [
+      "github_username",
+      "GitHub Username",
+      "GitLab-Login",
+      "git_id",
+      "GITHUB",
+    ]) {
+      assert.equal(normalizeHeader(header), "git_username", header)
+    }
+  })
+
+  it("leaves unrelated headers containing a provider name alone", () => {
+    assert.equal(normalizeHeader("GitHub Team"), "github_team")
+  })
 })
 
 describe("parseCsv", () => {
@@ -78,6 +99,14 @@ describe("parseCsv", () => {
     ])
   })
 
+  it("reads usernames from aliased Git username columns", () => {
+    const result = parseCsv("Email,GitHub Username\nalice@example.com,alice")
+
+    assert.deepStrictEqual(result.headers, ["email", "git_username"])
+    assert.deepStrictEqual(result.rawHeaderNames, ["Email", "GitHub Username"])
+    assert.equal(result.rows[0].git_username, "alice")
+  })
+
   it("omits empty values from rows", () => {
     const text = "Name,Email\nAlice,"
     const result = parseCsv(text)
@@ -137,6 +166,27 @@ describe("CSV roundtrip", () => {
     assert.equal(parsed.rows[0].email, "alice@example.com")
     assert.equal(parsed.rows[1].name, "Bob")
   })
+
+  it("roundtrips teams.csv with quoted and multi-line team names", () => {
+    const teams = [
+      {
+        assignment: 'Lab 1, "intro"',
+        teamName: "Team\nNewline, 🚀",
+        memberGitUsernames: ["alice", "bob"],
+      },
+      { assignment: "Lab 1", teamName: "Empty", memberGitUsernames: [] },
+    ]
+
+    const csv = serializeCsv({
+      headers: [...teamsCsvHeaders],
+      rows: exportTeamsCsvRows(teams),
+    })
+
+    assert.deepStrictEqual(parseTeamsCsvRows(parseCsv(csv).rows), {
+      ok: true,
+      value: teams,
+    })
+  })
 })
 
 describe("parseXlsx", () => {
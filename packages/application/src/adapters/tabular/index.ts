@@ -1,5 +1,9 @@
 export { parseCsv, serializeCsv } from "./csv-adapter.js"
-export { normalizeHeader } from "./normalize.js"
+export {
+  githubUsernameHeaderAliases,
+  gitlabUsernameHeaderAliases,
+  normalizeHeader,
+} from "./normalize.js"
 export type {
   TabularParseResult,
   TabularRow,
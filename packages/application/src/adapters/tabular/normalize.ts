@@ -1,3 +1,29 @@
+/** Normalized headers that institutions use for a GitHub account name. */
+export const githubUsernameHeaderAliases = [
+  "github_username",
+  "github_user",
+  "github_login",
+  "github_handle",
+  "github",
+] as const
+
+/** Normalized headers that institutions use for a GitLab account name. */
+export const gitlabUsernameHeaderAliases = [
+  "gitlab_username",
+  "gitlab_user",
+  "gitlab_login",
+  "gitlab_handle",
+  "gitlab",
+] as const
+
+const gitUsernameHeaderAliases: ReadonlySet<string> = new Set([
+  ...githubUsernameHeaderAliases,
+  ...gitlabUsernameHeaderAliases,
+  "git_id",
+  "git_user",
+  "git_login",
+])
+
 /**
  * Normalize a tabular header value to a canonical form.
  *
@@ -6,11 +32,14 @@
  * - replace non-alphanumeric characters with `_`
  * - collapse consecutive `_`
  * - trim leading/trailing `_`
+ *
+ * Provider-specific Git username headers then collapse to `git_username`.
  */
 export function normalizeHeader(value: string): string {
-  return value
+  const normalized = value
     .toLowerCase()
     .replace(/[^a-z0-9]/g, "_")
     .replace(/_+/g, "_")
     .replace(/^_|_$/g, "")
+  return gitUsernameHeaderAliases.has(normalized) ? "git_username" : normalized
 }
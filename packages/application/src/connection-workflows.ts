@@ -50,6 +50,9 @@ export function createConnectionWorkflowHandlers(
           baseUrl: input.baseUrl,
           token: input.token,
           userAgent: normalizeUserAgent(input.userAgent),
+          ...(input.tokenExpiresAt
+            ? { tokenExpiresAt: input.tokenExpiresAt }
+            : {}),
         }
 
         options?.onOutput?.({
@@ -74,6 +77,7 @@ export function createConnectionWorkflowHandlers(
         return {
           verified: result.verified,
           checkedAt: new Date().toISOString(),
+          ...(result.user ? { user: result.user } : {}),
         }
       } catch (error) {
         throw normalizeProviderError(error, input.provider, "verifyConnection")
@@ -310,6 +310,7 @@ export function runValidateAssignmentForCourse(
   options?: {
     identityMode?: GitIdentityMode
     repoNameTemplate?: string
+    now?: Date
   },
 ): RosterValidationResult {
   if (options?.repoNameTemplate !== undefined) {
@@ -318,6 +319,7 @@ export function runValidateAssignmentForCourse(
       assignmentId,
       options.identityMode ?? "username",
       options.repoNameTemplate,
+      { now: options.now },
     )
   }
 
@@ -325,6 +327,7 @@ export function runValidateAssignmentForCourse(
     course.roster,
     assignmentId,
     options?.identityMode ?? "username",
+    { now: options?.now },
   )
 }
 
@@ -0,0 +1,232 @@
+import {
+  COURSE_BUNDLE_FORMAT,
+  COURSE_BUNDLE_VERSION,
+  type CourseBundle,
+  type CourseBundleExportInput,
+  type CourseBundleExportResult,
+  type CourseBundleImportInput,
+  type CourseBundleImportResult,
+  type DiagnosticOutput,
+  type MilestoneProgress,
+  type WorkflowCallOptions,
+  type WorkflowHandlerMap,
+} from "@repo-edu/application-contract"
+import { mergeRosters } from "@repo-edu/domain/roster-lms-merge"
+import { validatePersistedCourse } from "@repo-edu/domain/schemas"
+import type { IdSequences, PersistedCourse } from "@repo-edu/domain/types"
+import type { UserFilePort } from "@repo-edu/host-runtime-contract"
+import { type CourseStore, createValidationAppError } from "./core.js"
+import { throwIfAborted, validateLoadedCourse } from "./workflow-helpers.js"
+
+type CourseBundleWorkflowId = "course.exportBundle" | "course.importBundle"
+
+export type CourseBundleWorkflowPorts = {
+  courseStore: CourseStore
+  userFile: UserFilePort
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === "object" && value !== null && !Array.isArray(value)
+}
+
+function parseCourseBundle(text: string): PersistedCourse[] {
+  let parsed: unknown
+  try {
+    parsed = JSON.parse(text)
+  } catch (error) {
+    throw createValidationAppError(
+      `Bundle is not valid JSON: ${
+        error instanceof Error ? error.message : String(error)
+      }`,
+      [{ path: "bundle", message: "Invalid JSON." }],
+    )
+  }
+
+  if (
+    !isRecord(parsed) ||
+    parsed.format !== COURSE_BUNDLE_FORMAT ||
+    !Array.isArray(parsed.courses)
+  ) {
+    throw createValidationAppError("File is not a course bundle.", [
+      { path: "bundle.format", message: `Expected ${COURSE_BUNDLE_FORMAT}.` },
+    ])
+  }
+  if (parsed.bundleVersion !== COURSE_BUNDLE_VERSION) {
+    throw createValidationAppError("Unsupported course bundle version.", [
+      {
+        path: "bundle.bundleVersion",
+        message: `Expected version ${COURSE_BUNDLE_VERSION}.`,
+      },
+    ])
+  }
+
+  const courses: PersistedCourse[] = []
+  const issues: { path: string; message: string }[] = []
+  for (const [index, entry] of parsed.courses.entries()) {
+    const validation = validatePersistedCourse(entry)
+    if (validation.ok) {
+      courses.push(validation.value)
+      continue
+    }
+    for (const issue of validation.issues) {
+      issues.push({
+        path: `bundle.courses.${index}.${issue.path}`,
+        message: issue.message,
+      })
+    }
+  }
+  if (issues.length > 0) {
+    throw createValidationAppError("Course bundle validation failed.", issues)
+  }
+  return courses
+}
+
+function pickFreeCourseId(courseId: string, takenIds: Set<string>): string {
+  let candidate = `${courseId}-copy`
+  for (let suffix = 2; takenIds.has(candidate); suffix += 1) {
+    candidate = `${courseId}-copy-${suffix}`
+  }
+  return candidate
+}
+
+// Bundled groups keep their ids when merged, so the stored course must not
+// allocate ids the bundle already used.
+function laterIdSequences(
+  left: IdSequences,
+  right: IdSequences,
+): IdSequences {
+  return {
+    nextGroupSeq: Math.max(left.nextGroupSeq, right.nextGroupSeq),
+    nextGroupSetSeq: Math.max(left.nextGroupSetSeq, right.nextGroupSetSeq),
+    nextMemberSeq: Math.max(left.nextMemberSeq, right.nextMemberSeq),
+    nextAssignmentSeq: Math.max(
+      left.nextAssignmentSeq,
+      right.nextAssignmentSeq,
+    ),
+    nextTeamSeq: Math.max(left.nextTeamSeq, right.nextTeamSeq),
+  }
+}
+
+export function createCourseBundleWorkflowHandlers(
+  ports: CourseBundleWorkflowPorts,
+): Pick<WorkflowHandlerMap<CourseBundleWorkflowId>, CourseBundleWorkflowId> {
+  return {
+    "course.exportBundle": async (
+      input: CourseBundleExportInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<CourseBundleExportResult> => {
+      throwIfAborted(options?.signal)
+      options?.onProgress?.({
+        step: 1,
+        totalSteps: 2,
+        label: "Collecting courses.",
+      })
+      const courses = (await ports.courseStore.listCourses(options?.signal))
+        .map(validateLoadedCourse)
+        .sort((left, right) => left.id.localeCompare(right.id))
+      const courseIds = courses.map((course) => course.id)
+      const bundle: CourseBundle = {
+        format: COURSE_BUNDLE_FORMAT,
+        bundleVersion: COURSE_BUNDLE_VERSION,
+        exportedAt: new Date().toISOString(),
+        manifest: {
+          courseIds,
+          courseCount: courses.length,
+          activeCourseIncluded:
+            input.activeCourseId !== null &&
+            courseIds.includes(input.activeCourseId),
+        },
+        courses,
+      }
+
+      throwIfAborted(options?.signal)
+      options?.onProgress?.({
+        step: 2,
+        totalSteps: 2,
+        label: "Writing bundle.",
+      })
+      await ports.userFile.writeText(
+        input.target,
+        JSON.stringify(bundle, null, 2),
+        options?.signal,
+      )
+
+      return { file: input.target, manifest: bundle.manifest }
+    },
+    "course.importBundle": async (
+      input: CourseBundleImportInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<CourseBundleImportResult> => {
+      throwIfAborted(options?.signal)
+      options?.onProgress?.({
+        step: 1,
+        totalSteps: 2,
+        label: "Reading bundle.",
+      })
+      const file = await ports.userFile.readText(input.file, options?.signal)
+      const courses = parseCourseBundle(file.text)
+
+      throwIfAborted(options?.signal)
+      options?.onProgress?.({
+        step: 2,
+        totalSteps: 2,
+        label: "Importing courses.",
+      })
+      const storedById = new Map(
+        (await ports.courseStore.listCourses(options?.signal)).map(
+          (course) => [course.id, course] as const,
+        ),
+      )
+      const takenIds = new Set([
+        ...storedById.keys(),
+        ...courses.map((course) => course.id),
+      ])
+      const importedCourseIds: string[] = []
+      const skippedCourseIds: string[] = []
+
+      for (const course of courses) {
+        throwIfAborted(options?.signal)
+        const stored = storedById.get(course.id)
+        let next: PersistedCourse
+        if (stored === undefined) {
+          next = { ...course, revision: 0 }
+        } else if (input.conflict === "skip") {
+          skippedCourseIds.push(course.id)
+          options?.onOutput?.({
+            channel: "warn",
+            message: `Skipped course ${course.displayName}; id ${course.id} already exists.`,
+          })
+          continue
+        } else if (input.conflict === "overwrite") {
+          next = { ...course, revision: stored.revision }
+        } else if (input.conflict === "merge") {
+          const merged = mergeRosters(
+            stored.roster,
+            course.roster,
+            laterIdSequences(stored.idSequences, course.idSequences),
+          )
+          for (const conflict of merged.conflicts) {
+            options?.onOutput?.({
+              channel: "warn",
+              message: `Kept the stored member ${conflict.email} in course ${stored.displayName}; the bundled one has LMS user id ${conflict.rightLmsUserId}, not ${conflict.leftLmsUserId}.`,
+            })
+          }
+          next = {
+            ...stored,
+            roster: merged.roster,
+            idSequences: merged.idSequences,
+          }
+        } else {
+          const id = pickFreeCourseId(course.id, takenIds)
+          takenIds.add(id)
+          next = { ...course, id, revision: 0 }
+        }
+
+        await ports.courseStore.saveCourse(next, options?.signal)
+        importedCourseIds.push(next.id)
+      }
+
+      return { importedCourseIds, skippedCourseIds }
+    },
+  }
+}
@@ -142,7 +142,7 @@ export function createGitUsernameWorkflowHandlers(
               continue
             }
             const status = verificationByUsername.get(username)
-            if (status === undefined) {
+            if (status === undefined || status.rateLimited) {
               member.gitUsernameStatus = "unknown"
               continue
             }
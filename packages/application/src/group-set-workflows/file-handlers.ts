@@ -26,6 +26,7 @@ import { parseRepoBeeStudentsText } from "../adapters/repobee-students-parser.js
 import { parseCsv, serializeCsv } from "../adapters/tabular/index.js"
 import { createValidationAppError } from "../core.js"
 import {
+  checkGroupSetImportCsvStructure,
   normalizeUserFileError,
   parseGroupSetImportRows,
   resolveCourseSnapshot,
@@ -153,7 +154,13 @@ export function createFileGroupSetHandlers(
       }
 
       if (input.format === "group-set-csv") {
-        const parsedRows = parseGroupSetImportRows(parseCsv(fileText.text).rows)
+        const table = parseCsv(fileText.text)
+        const structure = checkGroupSetImportCsvStructure(table)
+        options?.onOutput?.({
+          channel: "info",
+          message: `Checked ${structure.totalRows} rows in ${structure.groupCount} groups.`,
+        })
+        const parsedRows = parseGroupSetImportRows(table.rows)
         const preview = previewImportGroupSet(course.roster, parsedRows, {
           targetGroupSetId: input.targetGroupSetId,
           memberKey: "email",
@@ -270,6 +277,13 @@ export function createFileGroupSetHandlers(
         })
 
         const nextCourse = applyImportResultToCourse(course, result.value)
+        for (const warning of result.value.warnings) {
+          options?.onOutput?.({
+            channel: "warn",
+            code: "group_names_truncated",
+            message: `Group name truncated from ${warning.originalLength} characters to '${warning.groupName}'.`,
+          })
+        }
         options?.onProgress?.({
           step: 4,
           totalSteps,
@@ -1,8 +1,10 @@
 import type {
   AppError,
+  GroupSetSyncChanges,
   VerifyLmsDraftInput,
 } from "@repo-edu/application-contract"
 import { allocateGroupId } from "@repo-edu/domain/id-allocator"
+import { allRosterMembers } from "@repo-edu/domain/roster"
 import { ORIGIN_LMS, type PersistedCourse } from "@repo-edu/domain/types"
 import type { LmsFetchedGroupSet } from "@repo-edu/integrations-lms-contract"
 import { createValidationAppError } from "../core.js"
@@ -31,11 +33,14 @@ export function lmsGroupSetRemoteId(
   if (connection?.kind === "moodle") {
     return connection.groupingId
   }
+  if (connection?.kind === "blackboard" || connection?.kind === "brightspace") {
+    return connection.groupSetId
+  }
 
   throw createValidationAppError("Group set is not LMS-connected.", [
     {
       path: "groupSet.connection",
-      message: "The selected group set must be connected to Canvas or Moodle.",
+      message: "The selected group set must be connected to an LMS.",
     },
   ])
 }
@@ -46,19 +51,20 @@ export function createConnectedGroupSet(
   remoteGroupSetId: string,
   localGroupSetId: string,
 ): PersistedCourse["roster"]["groupSets"][number] {
+  const lastUpdated = new Date().toISOString()
   const connection =
-    provider === "canvas"
+    provider === "moodle"
       ? ({
-          kind: "canvas",
+          kind: "moodle",
           courseId,
-          groupSetId: remoteGroupSetId,
-          lastUpdated: new Date().toISOString(),
+          groupingId: remoteGroupSetId,
+          lastUpdated,
         } as const)
       : ({
-          kind: "moodle",
+          kind: provider,
           courseId,
-          groupingId: remoteGroupSetId,
-          lastUpdated: new Date().toISOString(),
+          groupSetId: remoteGroupSetId,
+          lastUpdated,
         } as const)
 
   return {
@@ -78,6 +84,8 @@ export function connectedRemoteId(
 ): string | null {
   if (connection?.kind === "canvas") return connection.groupSetId
   if (connection?.kind === "moodle") return connection.groupingId
+  if (connection?.kind === "blackboard") return connection.groupSetId
+  if (connection?.kind === "brightspace") return connection.groupSetId
   return null
 }
 
@@ -88,6 +96,9 @@ export function applyFetchedGroupSetToCourse(
 ): {
   nextCourse: PersistedCourse
   nextGroupSet: PersistedCourse["roster"]["groupSets"][number]
+  removedGroupIds: string[]
+  unmatchedLmsUserIds: string[]
+  changes: GroupSetSyncChanges
 } {
   const currentGroupSet = course.roster.groupSets.find(
     (candidate) => candidate.id === localGroupSetId,
@@ -119,6 +130,7 @@ export function applyFetchedGroupSetToCourse(
   }
 
   const memberMap = buildLmsMemberMap(course)
+  const unmatchedLmsUserIds = new Set<string>()
   let seq = course.idSequences
   const syncedGroups = fetched.groups.map((group) => {
     const existing = existingByLmsGroupId.get(group.id)
@@ -134,7 +146,11 @@ export function applyFetchedGroupSetToCourse(
     return {
       id: localId,
       name: group.name,
-      memberIds: resolveLmsGroupMembers(memberMap, group.memberLmsUserIds),
+      memberIds: resolveLmsGroupMembers(
+        memberMap,
+        group.memberLmsUserIds,
+        unmatchedLmsUserIds,
+      ),
       origin: ORIGIN_LMS,
       lmsGroupId: group.id,
     }
@@ -179,6 +195,14 @@ export function applyFetchedGroupSetToCourse(
 
   return {
     nextGroupSet,
+    removedGroupIds,
+    unmatchedLmsUserIds: [...unmatchedLmsUserIds],
+    changes: summarizeGroupSetSync(
+      currentGroupSet.groupIds,
+      course.roster.groups,
+      syncedGroups,
+      removedGroupIds,
+    ),
     nextCourse: {
       ...course,
       idSequences: seq,
@@ -192,9 +216,94 @@ export function applyFetchedGroupSetToCourse(
   }
 }
 
+function summarizeGroupSetSync(
+  previousGroupIds: readonly string[],
+  previousGroups: PersistedCourse["roster"]["groups"],
+  syncedGroups: readonly { id: string; name: string; memberIds: string[] }[],
+  removedGroupIds: readonly string[],
+): GroupSetSyncChanges {
+  const previousById = new Map(previousGroups.map((group) => [group.id, group]))
+  const previousIds = new Set(previousGroupIds)
+  const changes: GroupSetSyncChanges = {
+    addedGroups: [],
+    removedGroups: removedGroupIds.map((groupId) => ({
+      groupId,
+      name: previousById.get(groupId)?.name ?? groupId,
+    })),
+    updatedGroups: [],
+    unchangedGroupCount: 0,
+  }
+
+  for (const group of syncedGroups) {
+    const previous = previousIds.has(group.id)
+      ? previousById.get(group.id)
+      : undefined
+    if (previous === undefined) {
+      changes.addedGroups.push({ groupId: group.id, name: group.name })
+      continue
+    }
+    const previousMembers = new Set(previous.memberIds)
+    const nextMembers = new Set(group.memberIds)
+    const addedMemberIds = group.memberIds.filter(
+      (memberId) => !previousMembers.has(memberId),
+    )
+    const removedMemberIds = previous.memberIds.filter(
+      (memberId) => !nextMembers.has(memberId),
+    )
+    const previousName = previous.name === group.name ? null : previous.name
+    if (
+      previousName === null &&
+      addedMemberIds.length === 0 &&
+      removedMemberIds.length === 0
+    ) {
+      changes.unchangedGroupCount += 1
+      continue
+    }
+    changes.updatedGroups.push({
+      groupId: group.id,
+      name: group.name,
+      previousName,
+      addedMemberIds,
+      removedMemberIds,
+    })
+  }
+
+  return changes
+}
+
+/** One human-readable line per change, for progress reporting. */
+export function describeGroupSetSyncChanges(
+  changes: GroupSetSyncChanges,
+  dryRun: boolean,
+): string[] {
+  const lines: string[] = []
+  for (const group of changes.addedGroups) {
+    lines.push(`${dryRun ? "Would add" : "Added"} group '${group.name}'.`)
+  }
+  for (const group of changes.removedGroups) {
+    lines.push(`${dryRun ? "Would remove" : "Removed"} group '${group.name}'.`)
+  }
+  for (const group of changes.updatedGroups) {
+    const details: string[] = []
+    if (group.previousName !== null) {
+      details.push(`renamed from '${group.previousName}'`)
+    }
+    if (group.addedMemberIds.length > 0) {
+      details.push(`${group.addedMemberIds.length} members added`)
+    }
+    if (group.removedMemberIds.length > 0) {
+      details.push(`${group.removedMemberIds.length} members removed`)
+    }
+    lines.push(
+      `${dryRun ? "Would update" : "Updated"} group '${group.name}': ${details.join(", ")}.`,
+    )
+  }
+  return lines
+}
+
 function buildLmsMemberMap(course: PersistedCourse): Map<string, string> {
   const map = new Map<string, string>()
-  for (const member of course.roster.students.concat(course.roster.staff)) {
+  for (const member of allRosterMembers(course.roster)) {
     map.set(member.id, member.id)
     if (member.lmsUserId !== null && member.lmsUserId !== "") {
       map.set(member.lmsUserId, member.id)
@@ -206,12 +315,17 @@ function buildLmsMemberMap(course: PersistedCourse): Map<string, string> {
 function resolveLmsGroupMembers(
   memberMap: ReadonlyMap<string, string>,
   memberLmsUserIds: readonly string[],
+  unmatched: Set<string>,
 ): string[] {
   const resolved: string[] = []
   const seen = new Set<string>()
   for (const memberLmsUserId of memberLmsUserIds) {
     const rosterMemberId = memberMap.get(memberLmsUserId)
-    if (rosterMemberId === undefined || seen.has(rosterMemberId)) {
+    if (rosterMemberId === undefined) {
+      unmatched.add(memberLmsUserId)
+      continue
+    }
+    if (seen.has(rosterMemberId)) {
       continue
     }
     seen.add(rosterMemberId)
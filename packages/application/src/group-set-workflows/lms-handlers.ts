@@ -1,20 +1,25 @@
-import type {
-  AppError,
-  DiagnosticOutput,
-  GroupSetConnectFromLmsInput,
-  GroupSetFetchAvailableFromLmsInput,
-  GroupSetSyncFromLmsInput,
-  MilestoneProgress,
-  VerifyLmsDraftInput,
-  WorkflowCallOptions,
-  WorkflowHandlerMap,
+import {
+  type AppError,
+  type DiagnosticOutput,
+  type GroupSetConnectFromLmsInput,
+  type GroupSetFetchAvailableFromLmsInput,
+  type GroupSetLmsSummary,
+  type GroupSetLmsSyncResult,
+  type GroupSetSyncFromLmsInput,
+  type MilestoneProgress,
+  type VerifyLmsDraftInput,
+  withAppErrorContext,
+  type WorkflowCallOptions,
+  type WorkflowHandlerMap,
 } from "@repo-edu/application-contract"
 import { allocateGroupSetId } from "@repo-edu/domain/id-allocator"
 import type { PersistedCourse } from "@repo-edu/domain/types"
+import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
 import { createValidationAppError } from "../core.js"
 import {
   isSharedAppError,
   normalizeProviderError,
+  requireLmsGroupSetSupport,
   resolveAppCredentialsSnapshot,
   resolveCourseSnapshot,
   resolveLmsDraft,
@@ -24,10 +29,61 @@ import {
   applyFetchedGroupSetToCourse,
   connectedRemoteId,
   createConnectedGroupSet,
+  describeGroupSetSyncChanges,
   lmsGroupSetRemoteId,
 } from "./helpers.js"
 import type { GroupSetWorkflowPorts } from "./ports.js"
 
+function reportGroupSetSyncWarnings(
+  applied: ReturnType<typeof applyFetchedGroupSetToCourse>,
+  onOutput: ((output: DiagnosticOutput) => void) | undefined,
+  dryRun = false,
+): void {
+  if (applied.unmatchedLmsUserIds.length > 0) {
+    onOutput?.({
+      channel: "warn",
+      code: "lms_group_members_unmatched",
+      message: `${applied.unmatchedLmsUserIds.length} LMS group members do not match any roster member: ${applied.unmatchedLmsUserIds.join(", ")}.`,
+    })
+  }
+  if (applied.removedGroupIds.length > 0 && !dryRun) {
+    onOutput?.({
+      channel: "warn",
+      code: "group_set_groups_deleted",
+      message: `${applied.removedGroupIds.length} groups no longer in the LMS were removed from '${applied.nextGroupSet.name}'.`,
+    })
+  }
+}
+
+async function countGroupSetMembers(
+  ports: GroupSetWorkflowPorts,
+  draft: LmsConnectionDraft,
+  lmsCourseId: string,
+  available: readonly GroupSetLmsSummary[],
+  options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+): Promise<GroupSetLmsSummary[]> {
+  const summaries: GroupSetLmsSummary[] = []
+  for (const [index, summary] of available.entries()) {
+    throwIfAborted(options?.signal)
+    options?.onProgress?.({
+      step: 2,
+      totalSteps: 3,
+      label: `Counting members in '${summary.name}' (${index + 1}/${available.length}).`,
+    })
+    const fetched = await ports.lms.fetchGroupSet(
+      draft,
+      lmsCourseId,
+      summary.id,
+      options?.signal,
+    )
+    const memberIds = new Set(
+      fetched.groups.flatMap((group) => group.memberLmsUserIds),
+    )
+    summaries.push({ ...summary, memberCount: memberIds.size })
+  }
+  return summaries
+}
+
 export function createLmsGroupSetHandlers(
   ports: GroupSetWorkflowPorts,
 ): Pick<
@@ -60,6 +116,7 @@ export function createLmsGroupSetHandlers(
         throwIfAborted(options?.signal)
         const draft = resolveLmsDraft(course, settings)
         providerForError = draft.provider
+        requireLmsGroupSetSupport(draft, "listGroupSets")
 
         if (course.lmsCourseId === null) {
           throw {
@@ -79,6 +136,16 @@ export function createLmsGroupSetHandlers(
           course.lmsCourseId,
           options?.signal,
         )
+        const summaries =
+          input.withMemberCounts === true
+            ? await countGroupSetMembers(
+                ports,
+                draft,
+                course.lmsCourseId,
+                available,
+                options,
+              )
+            : available
 
         throwIfAborted(options?.signal)
         options?.onProgress?.({
@@ -86,12 +153,15 @@ export function createLmsGroupSetHandlers(
           totalSteps,
           label: "LMS group-set discovery complete.",
         })
-        return available
+        return summaries
       } catch (error) {
         if (isSharedAppError(error)) {
           throw error
         }
-        throw normalizeProviderError(error, providerForError, "listGroupSets")
+        throw withAppErrorContext(
+          normalizeProviderError(error, providerForError, "listGroupSets"),
+          "Listing LMS group sets",
+        )
       }
     },
     "groupSet.connectFromLms": async (
@@ -113,6 +183,7 @@ export function createLmsGroupSetHandlers(
         throwIfAborted(options?.signal)
         const draft = resolveLmsDraft(course, settings)
         providerForError = draft.provider
+        requireLmsGroupSetSupport(draft, "fetchGroupSet")
 
         if (course.lmsCourseId === null) {
           throw {
@@ -187,11 +258,13 @@ export function createLmsGroupSetHandlers(
           totalSteps,
           label: "Applying LMS group-set patch to roster.",
         })
-        const { nextCourse, nextGroupSet } = applyFetchedGroupSetToCourse(
+        const applied = applyFetchedGroupSetToCourse(
           courseWithConnectedSet,
           localGroupSetId,
           fetched,
         )
+        const { nextCourse, nextGroupSet } = applied
+        reportGroupSetSyncWarnings(applied, options?.onOutput)
 
         throwIfAborted(options?.signal)
         options?.onProgress?.({
@@ -208,13 +281,16 @@ export function createLmsGroupSetHandlers(
         if (isSharedAppError(error)) {
           throw error
         }
-        throw normalizeProviderError(error, providerForError, "fetchGroupSet")
+        throw withAppErrorContext(
+          normalizeProviderError(error, providerForError, "fetchGroupSet"),
+          () => `Connecting LMS group set '${input.remoteGroupSetId}'`,
+        )
       }
     },
     "groupSet.syncFromLms": async (
       input: GroupSetSyncFromLmsInput,
       options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
-    ) => {
+    ): Promise<GroupSetLmsSyncResult> => {
       const totalSteps = 4
       let providerForError: VerifyLmsDraftInput["provider"] = "canvas"
 
@@ -230,6 +306,7 @@ export function createLmsGroupSetHandlers(
         throwIfAborted(options?.signal)
         const draft = resolveLmsDraft(course, settings)
         providerForError = draft.provider
+        requireLmsGroupSetSupport(draft, "fetchGroupSet")
 
         if (course.lmsCourseId === null) {
           throw {
@@ -260,33 +337,63 @@ export function createLmsGroupSetHandlers(
           },
         )
 
+        const dryRun = input.dryRun === true
         options?.onProgress?.({
           step: 3,
           totalSteps,
-          label: "Applying LMS group-set patch to roster.",
+          label: dryRun
+            ? "Comparing LMS group set with roster."
+            : "Applying LMS group-set patch to roster.",
         })
-        const { nextCourse, nextGroupSet } = applyFetchedGroupSetToCourse(
+        const applied = applyFetchedGroupSetToCourse(
           course,
           input.groupSetId,
           fetched,
         )
+        reportGroupSetSyncWarnings(applied, options?.onOutput, dryRun)
+        for (const label of describeGroupSetSyncChanges(
+          applied.changes,
+          dryRun,
+        )) {
+          options?.onProgress?.({ step: 3, totalSteps, label })
+        }
 
         throwIfAborted(options?.signal)
         options?.onProgress?.({
           step: 4,
           totalSteps,
-          label: "LMS group-set sync complete.",
+          label: dryRun
+            ? "LMS group-set sync preview complete."
+            : "LMS group-set sync complete.",
         })
+        if (dryRun) {
+          const currentGroupSet = course.roster.groupSets.find(
+            (groupSet) => groupSet.id === input.groupSetId,
+          )
+          return {
+            ...(currentGroupSet ?? applied.nextGroupSet),
+            roster: course.roster,
+            idSequences: course.idSequences,
+            dryRun,
+            changes: applied.changes,
+          }
+        }
+        const { nextCourse, nextGroupSet } = applied
         return {
           ...nextGroupSet,
           roster: nextCourse.roster,
           idSequences: nextCourse.idSequences,
+          dryRun,
+          changes: applied.changes,
         }
       } catch (error) {
         if (isSharedAppError(error)) {
           throw error
         }
-        throw normalizeProviderError(error, providerForError, "fetchGroupSet")
+        throw withAppErrorContext(
+          normalizeProviderError(error, providerForError, "fetchGroupSet"),
+          "Syncing LMS group set",
+        )
       }
     },
   }
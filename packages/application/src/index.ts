@@ -26,6 +26,8 @@ export {
   packageId,
   SettingsRecoveryLoadError,
 } from "./core.js"
+export type { CourseBundleWorkflowPorts } from "./course-bundle-workflows.js"
+export { createCourseBundleWorkflowHandlers } from "./course-bundle-workflows.js"
 export { createCourseWorkflowHandlers } from "./course-workflows.js"
 export type { ExaminationArchivePort } from "./examination-workflows/archive-port.js"
 export {
@@ -51,4 +53,5 @@ export {
   runInspectUserFileWorkflow,
   runUserFileExportPreviewWorkflow,
 } from "./user-file-workflows.js"
+export type { ValidationWorkflowPorts } from "./validation-workflows.js"
 export { createValidationWorkflowHandlers } from "./validation-workflows.js"
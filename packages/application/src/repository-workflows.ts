@@ -1,9 +1,13 @@
 import type { WorkflowHandlerMap } from "@repo-edu/application-contract"
+import { createRepoArchiveHandler } from "./repository-workflows/archive-handler.js"
 import { createRepoBulkCloneHandler } from "./repository-workflows/bulk-clone-handler.js"
 import { createRepoCloneHandler } from "./repository-workflows/clone-handler.js"
+import { createRepoCommitStatsHandler } from "./repository-workflows/commit-stats-handler.js"
 import { createRepoCreateHandler } from "./repository-workflows/create-handler.js"
 import { createRepoListNamespaceHandler } from "./repository-workflows/list-namespace-handler.js"
 import type { RepositoryWorkflowPorts } from "./repository-workflows/ports.js"
+import { createRepoListPullRequestsHandler } from "./repository-workflows/pull-requests-handler.js"
+import { createRepoSyncTeamsHandler } from "./repository-workflows/sync-teams-handler.js"
 import { createRepoUpdateHandler } from "./repository-workflows/update-handler.js"
 
 export type { RepositoryWorkflowPorts } from "./repository-workflows/ports.js"
@@ -14,6 +18,10 @@ type RepositoryWorkflowIds =
   | "repo.update"
   | "repo.listNamespace"
   | "repo.bulkClone"
+  | "repo.archive"
+  | "repo.listPullRequests"
+  | "repo.syncTeams"
+  | "repo.commitStats"
 
 export function createRepositoryWorkflowHandlers(
   ports: RepositoryWorkflowPorts,
@@ -24,5 +32,9 @@ export function createRepositoryWorkflowHandlers(
     ...createRepoUpdateHandler(ports),
     ...createRepoListNamespaceHandler(ports),
     ...createRepoBulkCloneHandler(ports),
+    ...createRepoArchiveHandler(ports),
+    ...createRepoListPullRequestsHandler(ports),
+    ...createRepoSyncTeamsHandler(ports),
+    ...createRepoCommitStatsHandler(ports),
   }
 }
@@ -0,0 +1,136 @@
+import type {
+  AppError,
+  DiagnosticOutput,
+  MilestoneProgress,
+  RepositoryArchiveInput,
+  RepositoryArchiveResult,
+  VerifyGitDraftInput,
+  WorkflowCallOptions,
+  WorkflowHandlerMap,
+} from "@repo-edu/application-contract"
+import { createValidationAppError } from "../core.js"
+import {
+  isSharedAppError,
+  normalizeProviderError,
+  resolveAppCredentialsSnapshot,
+  resolveCourseSnapshot,
+  resolveGitDraft,
+  throwIfAborted,
+} from "../workflow-helpers.js"
+import { requireGitOrganization } from "./common.js"
+import { mapConcurrent } from "./git-helpers.js"
+import { collectRepositoryGroups, uniqueRepositoryNames } from "./planning.js"
+import type { RepositoryWorkflowPorts } from "./ports.js"
+
+export function createRepoArchiveHandler(
+  ports: RepositoryWorkflowPorts,
+): Pick<WorkflowHandlerMap<"repo.archive">, "repo.archive"> {
+  return {
+    "repo.archive": async (
+      input: RepositoryArchiveInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<RepositoryArchiveResult> => {
+      const totalSteps = 4
+      let providerForError: VerifyGitDraftInput["provider"] = "github"
+      const operation =
+        input.action === "archive" ? "archiveRepository" : "unarchiveRepository"
+
+      try {
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 1,
+          totalSteps,
+          label: "Reading course and app settings snapshots.",
+        })
+        const course = resolveCourseSnapshot(input.course)
+        const settings = resolveAppCredentialsSnapshot(input.credentials)
+        throwIfAborted(options?.signal)
+        const gitDraft = resolveGitDraft(settings)
+        if (gitDraft === null) {
+          throw {
+            type: "not-found",
+            message: "No Git connection is configured in settings.",
+            resource: "connection",
+          } satisfies AppError
+        }
+        providerForError = gitDraft.provider
+        const organization = requireGitOrganization(course, "repo.archive")
+
+        options?.onProgress?.({
+          step: 2,
+          totalSteps,
+          label: "Planning repositories from roster assignments.",
+        })
+        const planned = collectRepositoryGroups(
+          course,
+          input.assignmentId,
+          "clone",
+        )
+        if (!planned.ok) {
+          throw createValidationAppError(
+            "Repository planning failed.",
+            planned.issues,
+          )
+        }
+        const repositoryNames = uniqueRepositoryNames(planned.value)
+
+        options?.onProgress?.({
+          step: 3,
+          totalSteps,
+          label:
+            input.action === "archive"
+              ? "Archiving repositories with provider."
+              : "Unarchiving repositories with provider.",
+        })
+        const outcomes = await mapConcurrent(
+          repositoryNames,
+          async (repositoryName) => {
+            throwIfAborted(options?.signal)
+            try {
+              await ports.git[operation](
+                gitDraft,
+                { owner: organization, repositoryName },
+                options?.signal,
+              )
+              return "changed" as const
+            } catch (error) {
+              if (isSharedAppError(error)) throw error
+              options?.onOutput?.({
+                channel: "warn",
+                message: `${input.action} failed for '${repositoryName}': ${error instanceof Error ? error.message : String(error)}`,
+              })
+              return "failed" as const
+            }
+          },
+          8,
+        )
+        const changed = outcomes.filter(
+          (outcome) => outcome === "changed",
+        ).length
+        const failed = outcomes.length - changed
+        options?.onOutput?.({
+          channel: "info",
+          message: `Repository ${input.action} summary: planned ${repositoryNames.length}, changed ${changed}, failed ${failed}.`,
+        })
+
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 4,
+          totalSteps,
+          label: "Repository archive workflow complete.",
+        })
+        return {
+          repositoriesPlanned: repositoryNames.length,
+          repositoriesChanged: changed,
+          repositoriesFailed: failed,
+          completedAt: new Date().toISOString(),
+        }
+      } catch (error) {
+        if (isSharedAppError(error)) {
+          throw error
+        }
+        throw normalizeProviderError(error, providerForError, operation)
+      }
+    },
+  }
+}
@@ -0,0 +1,150 @@
+import type {
+  AppError,
+  DiagnosticOutput,
+  MilestoneProgress,
+  RepositoryCommitCount,
+  RepositoryCommitStatsInput,
+  RepositoryCommitStatsResult,
+  VerifyGitDraftInput,
+  WorkflowCallOptions,
+  WorkflowHandlerMap,
+} from "@repo-edu/application-contract"
+import { createValidationAppError } from "../core.js"
+import {
+  isSharedAppError,
+  normalizeProviderError,
+  resolveAppCredentialsSnapshot,
+  resolveCourseSnapshot,
+  resolveGitDraft,
+  throwIfAborted,
+} from "../workflow-helpers.js"
+import { requireGitOrganization } from "./common.js"
+import { mapConcurrent } from "./git-helpers.js"
+import { collectRepositoryGroups, uniqueRepositoryNames } from "./planning.js"
+import type { RepositoryWorkflowPorts } from "./ports.js"
+
+export function createRepoCommitStatsHandler(
+  ports: RepositoryWorkflowPorts,
+): Pick<WorkflowHandlerMap<"repo.commitStats">, "repo.commitStats"> {
+  return {
+    "repo.commitStats": async (
+      input: RepositoryCommitStatsInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<RepositoryCommitStatsResult> => {
+      const totalSteps = 4
+      let providerForError: VerifyGitDraftInput["provider"] = "github"
+
+      try {
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 1,
+          totalSteps,
+          label: "Reading course and app settings snapshots.",
+        })
+        const course = resolveCourseSnapshot(input.course)
+        const settings = resolveAppCredentialsSnapshot(input.credentials)
+        throwIfAborted(options?.signal)
+        const gitDraft = resolveGitDraft(settings)
+        if (gitDraft === null) {
+          throw {
+            type: "not-found",
+            message: "No Git connection is configured in settings.",
+            resource: "connection",
+          } satisfies AppError
+        }
+        providerForError = gitDraft.provider
+        const organization = requireGitOrganization(course, "repo.commitStats")
+
+        options?.onProgress?.({
+          step: 2,
+          totalSteps,
+          label: "Planning repositories from roster assignments.",
+        })
+        const planned = collectRepositoryGroups(
+          course,
+          input.assignmentId,
+          "clone",
+        )
+        if (!planned.ok) {
+          throw createValidationAppError(
+            "Repository planning failed.",
+            planned.issues,
+          )
+        }
+        const repositoryNames = uniqueRepositoryNames(planned.value).sort()
+
+        options?.onProgress?.({
+          step: 3,
+          totalSteps,
+          label: "Counting commits with provider.",
+        })
+        const counts = await mapConcurrent(
+          repositoryNames,
+          async (repositoryName): Promise<RepositoryCommitCount | null> => {
+            throwIfAborted(options?.signal)
+            try {
+              const commitCount = await ports.git.getCommitCount(
+                gitDraft,
+                {
+                  owner: organization,
+                  repositoryName,
+                  branch: input.branch,
+                  since: input.since,
+                },
+                options?.signal,
+              )
+              if (commitCount === null) {
+                options?.onOutput?.({
+                  channel: "warn",
+                  message: `Repository '${repositoryName}' or its branch was not found.`,
+                })
+              }
+              return { repositoryName, commitCount }
+            } catch (error) {
+              if (isSharedAppError(error)) throw error
+              options?.onOutput?.({
+                channel: "warn",
+                message: `Counting commits failed for '${repositoryName}': ${error instanceof Error ? error.message : String(error)}`,
+              })
+              return null
+            }
+          },
+          8,
+        )
+        const repositories = counts.filter((count) => count !== null)
+        const failed = counts.length - repositories.length
+        const missing = repositories.filter(
+          (repository) => repository.commitCount === null,
+        ).length
+        const totalCommits = repositories.reduce(
+          (total, repository) => total + (repository.commitCount ?? 0),
+          0,
+        )
+        options?.onOutput?.({
+          channel: "info",
+          message: `Commit stats summary: planned ${repositoryNames.length}, commits ${totalCommits}, missing ${missing}, failed ${failed}.`,
+        })
+
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 4,
+          totalSteps,
+          label: "Commit stats complete.",
+        })
+        return {
+          repositoriesPlanned: repositoryNames.length,
+          repositoriesMissing: missing,
+          repositoriesFailed: failed,
+          repositories,
+          totalCommits,
+          completedAt: new Date().toISOString(),
+        }
+      } catch (error) {
+        if (isSharedAppError(error)) {
+          throw error
+        }
+        throw normalizeProviderError(error, providerForError, "getCommitCount")
+      }
+    },
+  }
+}
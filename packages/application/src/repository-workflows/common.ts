@@ -11,7 +11,14 @@ import { isSharedAppError, toCancelledAppError } from "../workflow-helpers.js"
  */
 export function requireGitOrganization(
   course: PersistedCourse,
-  operation: "repo.create" | "repo.clone" | "repo.update",
+  operation:
+    | "repo.create"
+    | "repo.clone"
+    | "repo.update"
+    | "repo.archive"
+    | "repo.listPullRequests"
+    | "repo.syncTeams"
+    | "repo.commitStats",
 ): string {
   const normalized =
     course.organization === null
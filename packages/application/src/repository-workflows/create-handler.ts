@@ -9,6 +9,7 @@ import type {
   WorkflowCallOptions,
   WorkflowHandlerMap,
 } from "@repo-edu/application-contract"
+import { validateRepositoryName } from "@repo-edu/domain/repository-planning"
 import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
 import { createValidationAppError } from "../core.js"
 import {
@@ -22,7 +23,9 @@ import {
 import { requireGitOrganization } from "./common.js"
 import {
   cloneRemoteTemplateToTmpdir,
+  cloneTemplateMirror,
   mapConcurrent,
+  pushTemplateMirrorToRepo,
   pushTemplateToRepo,
   resolveLocalDefaultBranch,
   resolveLocalTemplateSha,
@@ -31,9 +34,11 @@ import {
   collectRepositoryGroups,
   createRepositoryBatches,
   describeTemplate,
+  findRepositoryNameCollisions,
   planRepositoriesWithTemplates,
   planTeamSetup,
   templateKey,
+  uniqueRepositoryNames,
 } from "./planning.js"
 import type { RepositoryWorkflowPorts } from "./ports.js"
 
@@ -97,6 +102,22 @@ export function createRepoCreateHandler(
           )
         }
 
+        const invalidNames = uniqueRepositoryNames(planned.value).flatMap(
+          (repositoryName) =>
+            validateRepositoryName(repositoryName, gitDraft.provider).map(
+              (problem) => ({
+                path: "repositoryNames",
+                message: `Repository '${repositoryName}': ${problem}.`,
+              }),
+            ),
+        )
+        if (invalidNames.length > 0) {
+          throw createValidationAppError(
+            `Planned repository names are not valid on ${gitDraft.provider}.`,
+            invalidNames,
+          )
+        }
+
         if (planned.value.length === 0) {
           options?.onProgress?.({
             step: totalSteps,
@@ -114,12 +135,65 @@ export function createRepoCreateHandler(
           }
         }
 
+        if (input.failOnExisting === true) {
+          const listing = await ports.git.listRepositories(
+            gitDraft,
+            { namespace: organization, includeArchived: true },
+            options?.signal,
+          )
+          const collisions = findRepositoryNameCollisions(
+            uniqueRepositoryNames(planned.value),
+            listing.repositories.map((repository) => repository.name),
+          )
+          if (collisions.length > 0) {
+            throw createValidationAppError(
+              "Planned repositories already exist in the organization.",
+              collisions.map((repositoryName) => ({
+                path: "repositoryNames",
+                message: `Repository '${repositoryName}' already exists in '${organization}'.`,
+              })),
+            )
+          }
+        }
+
+        const batches = createRepositoryBatches(plannedWithTemplates.value)
+        // Batches split by visibility, so one remote template can repeat.
+        const remoteTemplates = new Map<
+          string,
+          { owner: string; name: string }
+        >()
+        for (const batch of batches) {
+          if (batch.template?.kind === "remote") {
+            const { owner, name } = batch.template
+            remoteTemplates.set(`${owner}/${name}`, { owner, name })
+          }
+        }
+        const missingTemplates: string[] = []
+        for (const [templateName, template] of remoteTemplates) {
+          const head = await ports.git.getRepositoryDefaultBranchHead(
+            gitDraft,
+            { owner: template.owner, repositoryName: template.name },
+            options?.signal,
+          )
+          if (head === null) {
+            missingTemplates.push(templateName)
+          }
+        }
+        if (missingTemplates.length > 0) {
+          throw createValidationAppError(
+            "Template repositories were not found.",
+            missingTemplates.map((templateName) => ({
+              path: "template",
+              message: `Template repository '${templateName}' was not found on ${gitDraft.provider}.`,
+            })),
+          )
+        }
+
         options?.onProgress?.({
           step: 3,
           totalSteps,
           label: "Creating repositories through Git provider client.",
         })
-        const batches = createRepositoryBatches(plannedWithTemplates.value)
         const created: Awaited<
           ReturnType<GitProviderClient["createRepositories"]>
         >["created"] = []
@@ -232,16 +306,11 @@ export function createRepoCreateHandler(
             continue
           }
 
-          let templateLocalPath: string
+          let templateSource: string
 
           if (template.kind === "local") {
-            templateLocalPath = template.path
+            templateSource = template.path
           } else {
-            // Clone remote template to tmpdir.
-            const tmpDir =
-              await ports.fileSystem.createTempDirectory("repo-edu-template-")
-            tmpDirsToCleanup.push(tmpDir)
-
             const templateAuthUrl = (
               await ports.git.resolveRepositoryCloneUrls(
                 gitDraft,
@@ -260,10 +329,48 @@ export function createRepoCreateHandler(
               })
               continue
             }
+            templateSource = templateAuthUrl
+          }
+
+          let mirrorPath: string | null = null
+
+          if (input.mirrorTemplate === true) {
+            const mirrorDir = await ports.fileSystem.createTempDirectory(
+              "repo-edu-template-mirror-",
+            )
+            tmpDirsToCleanup.push(mirrorDir)
+            const cloned = await cloneTemplateMirror(
+              ports.gitCommand,
+              templateSource,
+              mirrorDir,
+              options?.signal,
+            )
+            if (cloned) {
+              mirrorPath = mirrorDir
+            } else {
+              options?.onOutput?.({
+                channel: "warn",
+                message: `Failed to mirror template '${describeTemplate(template)}'; pushing its default branch only.`,
+              })
+            }
+          }
+          const mirrored = mirrorPath !== null
+
+          let templateLocalPath: string
+
+          if (mirrorPath !== null) {
+            templateLocalPath = mirrorPath
+          } else if (template.kind === "local") {
+            templateLocalPath = template.path
+          } else {
+            // Clone remote template to tmpdir.
+            const tmpDir =
+              await ports.fileSystem.createTempDirectory("repo-edu-template-")
+            tmpDirsToCleanup.push(tmpDir)
 
             const cloned = await cloneRemoteTemplateToTmpdir(
               ports.gitCommand,
-              templateAuthUrl,
+              templateSource,
               tmpDir,
               options?.signal,
             )
@@ -292,13 +399,20 @@ export function createRepoCreateHandler(
           const pushResults = await mapConcurrent(
             pushItems,
             async (item) => {
-              const ok = await pushTemplateToRepo(
-                ports.gitCommand,
-                templateLocalPath,
-                item.authUrl,
-                defaultBranch,
-                options?.signal,
-              )
+              const ok = mirrored
+                ? await pushTemplateMirrorToRepo(
+                    ports.gitCommand,
+                    templateLocalPath,
+                    item.authUrl,
+                    options?.signal,
+                  )
+                : await pushTemplateToRepo(
+                    ports.gitCommand,
+                    templateLocalPath,
+                    item.authUrl,
+                    defaultBranch,
+                    options?.signal,
+                  )
               if (!ok) {
                 options?.onOutput?.({
                   channel: "warn",
@@ -93,6 +93,24 @@ export async function pushTemplateToRepo(
   return result.exitCode === 0
 }
 
+/**
+ * Pushes every branch and tag of a bare template clone, replacing whatever
+ * the freshly created repository already holds.
+ */
+export async function pushTemplateMirrorToRepo(
+  gitCommand: GitCommandPort,
+  mirrorPath: string,
+  authUrl: string,
+  signal?: AbortSignal,
+): Promise<boolean> {
+  const result = await gitCommand.run({
+    args: ["push", "--mirror", authUrl],
+    cwd: mirrorPath,
+    signal,
+  })
+  return result.exitCode === 0
+}
+
 export async function resolveLocalTemplateSha(
   gitCommand: GitCommandPort,
   templatePath: string,
@@ -132,6 +150,21 @@ export async function cloneRemoteTemplateToTmpdir(
   return result.exitCode === 0
 }
 
+// A bare clone holds only branches and tags. `clone --mirror` would also copy
+// provider-hidden refs such as GitHub's `refs/pull/*`, which pushes reject.
+export async function cloneTemplateMirror(
+  gitCommand: GitCommandPort,
+  source: string,
+  destPath: string,
+  signal?: AbortSignal,
+): Promise<boolean> {
+  const result = await gitCommand.run({
+    args: ["clone", "--bare", source, destPath],
+    signal,
+  })
+  return result.exitCode === 0
+}
+
 function parseGitDiffNameStatus(
   output: string,
 ): { status: PatchFileStatus; path: string; previousPath: string | null }[] {
@@ -221,3 +221,13 @@ export function uniqueRepositoryNames(
 ): string[] {
   return Array.from(new Set(groups.map((group) => group.repoName)))
 }
+
+// Provider repository names are case-insensitive, so a planned `Team-A`
+// collides with an existing `team-a`.
+export function findRepositoryNameCollisions(
+  plannedNames: readonly string[],
+  existingNames: readonly string[],
+): string[] {
+  const existing = new Set(existingNames.map((name) => name.toLowerCase()))
+  return plannedNames.filter((name) => existing.has(name.toLowerCase()))
+}
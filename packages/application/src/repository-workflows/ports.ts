@@ -10,12 +10,18 @@ export type RepositoryWorkflowPorts = {
     | "createRepositories"
     | "createTeam"
     | "assignRepositoriesToTeam"
+    | "listTeamMembers"
+    | "removeTeamMembers"
     | "getRepositoryDefaultBranchHead"
     | "getTemplateDiff"
     | "createBranch"
     | "createPullRequest"
+    | "listPullRequests"
     | "resolveRepositoryCloneUrls"
     | "listRepositories"
+    | "getCommitCount"
+    | "archiveRepository"
+    | "unarchiveRepository"
   >
   gitCommand: GitCommandPort
   fileSystem: FileSystemPort
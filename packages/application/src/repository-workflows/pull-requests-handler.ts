@@ -0,0 +1,149 @@
+import type {
+  AppError,
+  DiagnosticOutput,
+  MilestoneProgress,
+  RepositoryListPullRequestsInput,
+  RepositoryListPullRequestsResult,
+  RepositoryPullRequest,
+  VerifyGitDraftInput,
+  WorkflowCallOptions,
+  WorkflowHandlerMap,
+} from "@repo-edu/application-contract"
+import { createValidationAppError } from "../core.js"
+import {
+  isSharedAppError,
+  normalizeProviderError,
+  resolveAppCredentialsSnapshot,
+  resolveCourseSnapshot,
+  resolveGitDraft,
+  throwIfAborted,
+} from "../workflow-helpers.js"
+import { requireGitOrganization } from "./common.js"
+import { mapConcurrent } from "./git-helpers.js"
+import { collectRepositoryGroups, uniqueRepositoryNames } from "./planning.js"
+import type { RepositoryWorkflowPorts } from "./ports.js"
+
+export function createRepoListPullRequestsHandler(
+  ports: RepositoryWorkflowPorts,
+): Pick<
+  WorkflowHandlerMap<"repo.listPullRequests">,
+  "repo.listPullRequests"
+> {
+  return {
+    "repo.listPullRequests": async (
+      input: RepositoryListPullRequestsInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<RepositoryListPullRequestsResult> => {
+      const totalSteps = 4
+      let providerForError: VerifyGitDraftInput["provider"] = "github"
+
+      try {
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 1,
+          totalSteps,
+          label: "Reading course and app settings snapshots.",
+        })
+        const course = resolveCourseSnapshot(input.course)
+        const settings = resolveAppCredentialsSnapshot(input.credentials)
+        throwIfAborted(options?.signal)
+        const gitDraft = resolveGitDraft(settings)
+        if (gitDraft === null) {
+          throw {
+            type: "not-found",
+            message: "No Git connection is configured in settings.",
+            resource: "connection",
+          } satisfies AppError
+        }
+        providerForError = gitDraft.provider
+        const organization = requireGitOrganization(
+          course,
+          "repo.listPullRequests",
+        )
+
+        options?.onProgress?.({
+          step: 2,
+          totalSteps,
+          label: "Planning repositories from roster assignments.",
+        })
+        const planned = collectRepositoryGroups(
+          course,
+          input.assignmentId,
+          "clone",
+        )
+        if (!planned.ok) {
+          throw createValidationAppError(
+            "Repository planning failed.",
+            planned.issues,
+          )
+        }
+        const repositoryNames = uniqueRepositoryNames(planned.value).sort()
+
+        options?.onProgress?.({
+          step: 3,
+          totalSteps,
+          label: "Listing pull requests with provider.",
+        })
+        const listings = await mapConcurrent(
+          repositoryNames,
+          async (repositoryName) => {
+            throwIfAborted(options?.signal)
+            try {
+              const pullRequests = await ports.git.listPullRequests(
+                gitDraft,
+                { owner: organization, repositoryName, state: input.state },
+                options?.signal,
+              )
+              return pullRequests
+                .map(
+                  (pullRequest): RepositoryPullRequest => ({
+                    repositoryName,
+                    ...pullRequest,
+                  }),
+                )
+                .sort((left, right) =>
+                  right.createdAt.localeCompare(left.createdAt),
+                )
+            } catch (error) {
+              if (isSharedAppError(error)) throw error
+              options?.onOutput?.({
+                channel: "warn",
+                message: `Listing pull requests failed for '${repositoryName}': ${error instanceof Error ? error.message : String(error)}`,
+              })
+              return null
+            }
+          },
+          8,
+        )
+        const failed = listings.filter((listing) => listing === null).length
+        const pullRequests = listings.flatMap((listing) => listing ?? [])
+        options?.onOutput?.({
+          channel: "info",
+          message: `Pull request listing summary: planned ${repositoryNames.length}, found ${pullRequests.length}, failed ${failed}.`,
+        })
+
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 4,
+          totalSteps,
+          label: "Pull request listing complete.",
+        })
+        return {
+          repositoriesPlanned: repositoryNames.length,
+          repositoriesFailed: failed,
+          pullRequests,
+          completedAt: new Date().toISOString(),
+        }
+      } catch (error) {
+        if (isSharedAppError(error)) {
+          throw error
+        }
+        throw normalizeProviderError(
+          error,
+          providerForError,
+          "listPullRequests",
+        )
+      }
+    },
+  }
+}
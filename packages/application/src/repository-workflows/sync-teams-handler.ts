@@ -0,0 +1,197 @@
+import type {
+  AppError,
+  DiagnosticOutput,
+  MilestoneProgress,
+  RepositorySyncTeamsInput,
+  RepositorySyncTeamsResult,
+  VerifyGitDraftInput,
+  WorkflowCallOptions,
+  WorkflowHandlerMap,
+} from "@repo-edu/application-contract"
+import { createValidationAppError } from "../core.js"
+import {
+  isSharedAppError,
+  normalizeProviderError,
+  resolveAppCredentialsSnapshot,
+  resolveCourseSnapshot,
+  resolveGitDraft,
+  throwIfAborted,
+} from "../workflow-helpers.js"
+import { requireGitOrganization } from "./common.js"
+import { collectRepositoryGroups, planTeamSetup } from "./planning.js"
+import type { RepositoryWorkflowPorts } from "./ports.js"
+
+// Provider usernames are case-insensitive on every supported provider.
+function membershipKey(username: string): string {
+  return username.trim().toLowerCase()
+}
+
+export function createRepoSyncTeamsHandler(
+  ports: RepositoryWorkflowPorts,
+): Pick<WorkflowHandlerMap<"repo.syncTeams">, "repo.syncTeams"> {
+  return {
+    "repo.syncTeams": async (
+      input: RepositorySyncTeamsInput,
+      options?: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput>,
+    ): Promise<RepositorySyncTeamsResult> => {
+      const totalSteps = 4
+      let providerForError: VerifyGitDraftInput["provider"] = "github"
+
+      try {
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 1,
+          totalSteps,
+          label: "Reading course and app settings snapshots.",
+        })
+        const course = resolveCourseSnapshot(input.course)
+        const settings = resolveAppCredentialsSnapshot(input.credentials)
+        throwIfAborted(options?.signal)
+        const gitDraft = resolveGitDraft(settings)
+        if (gitDraft === null) {
+          throw {
+            type: "not-found",
+            message: "No Git connection is configured in settings.",
+            resource: "connection",
+          } satisfies AppError
+        }
+        providerForError = gitDraft.provider
+        const organization = requireGitOrganization(course, "repo.syncTeams")
+
+        options?.onProgress?.({
+          step: 2,
+          totalSteps,
+          label: "Planning teams from roster assignments.",
+        })
+        const planned = collectRepositoryGroups(
+          course,
+          input.assignmentId,
+          "create",
+        )
+        if (!planned.ok) {
+          throw createValidationAppError(
+            "Repository planning failed.",
+            planned.issues,
+          )
+        }
+        const teams = planTeamSetup(planned.value)
+
+        options?.onProgress?.({
+          step: 3,
+          totalSteps,
+          label: "Comparing team membership with provider.",
+        })
+        let membersAdded = 0
+        let membersRemoved = 0
+        let teamsUnchanged = 0
+        let teamsMissing = 0
+        let teamsFailed = 0
+        for (const team of teams) {
+          throwIfAborted(options?.signal)
+          try {
+            const current = await ports.git.listTeamMembers(
+              gitDraft,
+              { organization, teamName: team.teamName },
+              options?.signal,
+            )
+            if (current === null) {
+              teamsMissing += 1
+              options?.onOutput?.({
+                channel: "warn",
+                message: `Team '${team.teamName}' does not exist; run repo create first.`,
+              })
+              continue
+            }
+
+            const currentKeys = new Set(current.map(membershipKey))
+            const expectedKeys = new Set(team.gitUsernames.map(membershipKey))
+            const missing = team.gitUsernames.filter(
+              (username) => !currentKeys.has(membershipKey(username)),
+            )
+            const stale = input.removeStaleMembers
+              ? current.filter(
+                  (username) => !expectedKeys.has(membershipKey(username)),
+                )
+              : []
+
+            let teamAdded = 0
+            if (missing.length > 0) {
+              const result = await ports.git.createTeam(
+                gitDraft,
+                {
+                  organization,
+                  teamName: team.teamName,
+                  memberUsernames: missing,
+                  permission: "push",
+                },
+                options?.signal,
+              )
+              teamAdded = result.membersAdded.length
+              if (result.membersNotFound.length > 0) {
+                options?.onOutput?.({
+                  channel: "warn",
+                  message: `Team '${team.teamName}' missing members: ${result.membersNotFound.join(", ")}.`,
+                })
+              }
+            }
+            if (stale.length > 0) {
+              await ports.git.removeTeamMembers(
+                gitDraft,
+                {
+                  organization,
+                  teamName: team.teamName,
+                  memberUsernames: stale,
+                },
+                options?.signal,
+              )
+            }
+
+            membersAdded += teamAdded
+            membersRemoved += stale.length
+            if (teamAdded === 0 && stale.length === 0) {
+              teamsUnchanged += 1
+              continue
+            }
+            options?.onOutput?.({
+              channel: "info",
+              message: `Team '${team.teamName}' synced: ${teamAdded} added, ${stale.length} removed.`,
+            })
+          } catch (error) {
+            if (isSharedAppError(error)) throw error
+            throwIfAborted(options?.signal)
+            teamsFailed += 1
+            options?.onOutput?.({
+              channel: "warn",
+              message: `Failed to sync team '${team.teamName}': ${error instanceof Error ? error.message : String(error)}`,
+            })
+          }
+        }
+        options?.onOutput?.({
+          channel: "info",
+          message: `Team sync summary: planned ${teams.length}, added ${membersAdded}, removed ${membersRemoved}, unchanged ${teamsUnchanged}, missing ${teamsMissing}, failed ${teamsFailed}.`,
+        })
+
+        throwIfAborted(options?.signal)
+        options?.onProgress?.({
+          step: 4,
+          totalSteps,
+          label: "Team sync workflow complete.",
+        })
+        return {
+          teamsPlanned: teams.length,
+          membersAdded,
+          membersRemoved,
+          teamsUnchanged,
+          teamsMissing,
+          teamsFailed,
+          completedAt: new Date().toISOString(),
+        }
+      } catch (error) {
+        if (isSharedAppError(error)) {
+          throw error
+        }
+        throw normalizeProviderError(error, providerForError, "syncTeams")
+      }
+    },
+  }
+}
@@ -1,25 +1,40 @@
-import type {
-  DiagnosticOutput,
-  MilestoneProgress,
-  RosterExportMembersInput,
-  RosterImportFromFileInput,
-  RosterImportFromLmsInput,
-  VerifyLmsDraftInput,
-  WorkflowCallOptions,
-  WorkflowHandlerMap,
+import {
+  type DiagnosticOutput,
+  type MilestoneProgress,
+  type RosterExportMembersInput,
+  type RosterImportFromFileInput,
+  type RosterImportFromLmsInput,
+  type VerifyLmsDraftInput,
+  withAppErrorContext,
+  type WorkflowCallOptions,
+  type WorkflowHandlerMap,
 } from "@repo-edu/application-contract"
 import { ensureSystemGroupSets } from "@repo-edu/domain/group-set"
-import { normalizeRoster } from "@repo-edu/domain/roster"
+import {
+  allRosterMembers,
+  normalizeRoster,
+  rosterMemberCsvHeaders,
+  rosterMemberCsvRow,
+} from "@repo-edu/domain/roster"
 import { mergeRosterFromLmsWithConflicts } from "@repo-edu/domain/roster-lms-merge"
+import type { LmsProviderKind } from "@repo-edu/domain/types"
 import type { UserFilePort } from "@repo-edu/host-runtime-contract"
 import type {
   LmsClient,
+  LmsConnectionDraft,
+  LmsPage,
   RemoteLmsMember,
 } from "@repo-edu/integrations-lms-contract"
-import { parseCsv, serializeCsv } from "./adapters/tabular/index.js"
+import { parseStudentsYamlText } from "./adapters/students-yaml-parser.js"
+import {
+  parseCsv,
+  serializeCsv,
+  type TabularRow,
+} from "./adapters/tabular/index.js"
 import { createValidationAppError } from "./core.js"
 import {
   inferFileFormat,
+  isLmsAuthError,
   isSharedAppError,
   normalizeProviderError,
   parseStudentRows,
@@ -31,22 +46,101 @@ import {
 } from "./workflow-helpers.js"
 
 export type RosterWorkflowPorts = {
-  lms: Pick<LmsClient, "fetchRoster">
+  lms: Pick<LmsClient, "fetchRoster" | "fetchRosterPage">
   userFile: UserFilePort
 }
 
-const memberExportHeaders = [
-  "name",
-  "email",
-  "student_number",
-  "git_username",
-  "status",
-  "enrollment_type",
-] as const
+const rosterPageSize = 100
+// Moodle and Brightspace return the whole roster in one response; paging
+// them would download it again for every page.
+const pagedRosterLmsProviders: ReadonlySet<LmsProviderKind> = new Set([
+  "canvas",
+  "blackboard",
+])
+// Stop paging after this many failures in a row; an LMS that keeps failing
+// is down rather than missing a page.
+const maxConsecutiveRosterPageFailures = 3
+
+function isMissingEmail(member: RemoteLmsMember): boolean {
+  return member.email === null || member.email.trim() === ""
+}
+
+async function fetchRosterSkippingFailedPages(
+  lms: RosterWorkflowPorts["lms"],
+  draft: LmsConnectionDraft,
+  courseId: string,
+  options: WorkflowCallOptions<MilestoneProgress, DiagnosticOutput> | undefined,
+  onPageLoaded: (message: string) => void,
+): Promise<{ members: RemoteLmsMember[]; complete: boolean }> {
+  if (!pagedRosterLmsProviders.has(draft.provider)) {
+    // A single request has no page to skip, so its failure aborts as usual.
+    const members = await lms.fetchRoster(
+      draft,
+      courseId,
+      options?.signal,
+      onPageLoaded,
+    )
+    return { members, complete: true }
+  }
+
+  const members: RemoteLmsMember[] = []
+  let loadedPages = 0
+  let complete = true
+  let consecutiveFailures = 0
+  let firstError: unknown = null
+  // A skipped page has no cursor to follow, so paging falls back to the
+  // page number until the next page loads.
+  let cursor: string | null = null
+
+  for (let page = 1; ; page += 1) {
+    throwIfAborted(options?.signal)
+    let result: LmsPage<RemoteLmsMember>
+    try {
+      result = await lms.fetchRosterPage(
+        draft,
+        courseId,
+        { page, perPage: rosterPageSize, cursor },
+        options?.signal,
+      )
+    } catch (error) {
+      // Credential failures and cancellation fail every page alike.
+      if (
+        isSharedAppError(error) ||
+        isLmsAuthError(error) ||
+        options?.signal?.aborted
+      ) {
+        throw error
+      }
+      firstError ??= error
+      consecutiveFailures += 1
+      cursor = null
+      options?.onOutput?.({
+        channel: "warn",
+        code: "lms_roster_page_failed",
+        message: `Skipped roster page ${page}: ${error instanceof Error ? error.message : String(error)}`,
+      })
+      complete = false
+      if (consecutiveFailures >= maxConsecutiveRosterPageFailures) break
+      continue
+    }
+
+    consecutiveFailures = 0
+    loadedPages += 1
+    cursor = result.pagination.nextCursor
+    members.push(...result.items)
+    onPageLoaded(`Fetched roster page ${page} (${members.length} members).`)
+    if (!result.pagination.hasNext) break
+  }
+
+  if (loadedPages === 0) {
+    throw firstError
+  }
+  return { members, complete }
+}
 
 function rosterFromRemoteLmsMembers(
   courseId: string,
-  provider: "canvas" | "moodle",
+  provider: LmsProviderKind,
   members: readonly RemoteLmsMember[],
 ) {
   const studentInputs = members
@@ -89,6 +183,17 @@ function rosterFromRemoteLmsMembers(
   }
 }
 
+function parseStudentsYamlRows(text: string): TabularRow[] {
+  const parsed = parseStudentsYamlText(text)
+  if (!parsed.ok) {
+    throw createValidationAppError(
+      "Students YAML file is invalid.",
+      parsed.issues,
+    )
+  }
+  return parsed.rows
+}
+
 export function createRosterWorkflowHandlers(
   ports: RosterWorkflowPorts,
 ): Pick<
@@ -116,14 +221,14 @@ export function createRosterWorkflowHandlers(
       )
 
       const format = inferFileFormat(input.file)
-      if (format !== "csv") {
+      if (format !== "csv" && format !== "yaml") {
         throw createValidationAppError(
           "Roster import file format is unsupported.",
           [
             {
               path: "file.format",
               message:
-                "Only CSV roster import is supported by the current text-based file port.",
+                "Only CSV and YAML roster import is supported by the current text-based file port.",
             },
           ],
         )
@@ -132,10 +237,13 @@ export function createRosterWorkflowHandlers(
       options?.onProgress?.({
         step: 2,
         totalSteps,
-        label: "Parsing student rows from CSV.",
+        label: `Parsing student rows from ${format === "csv" ? "CSV" : "YAML"}.`,
       })
-      const parsed = parseCsv(fileText.text)
-      const rows = parseStudentRows(parsed.rows)
+      const rows = parseStudentRows(
+        format === "csv"
+          ? parseCsv(fileText.text).rows
+          : parseStudentsYamlRows(fileText.text),
+      )
       const result = upsertRosterFromStudentRows(
         course.roster,
         rows,
@@ -176,6 +284,18 @@ export function createRosterWorkflowHandlers(
         })
         const course = resolveCourseSnapshot(input.course)
         const settings = resolveAppCredentialsSnapshot(input.credentials)
+        const maxMissingEmailRatio = input.maxMissingEmailRatio
+        if (
+          maxMissingEmailRatio !== undefined &&
+          !(maxMissingEmailRatio >= 0 && maxMissingEmailRatio <= 1)
+        ) {
+          throw createValidationAppError("LMS roster import is invalid.", [
+            {
+              path: "maxMissingEmailRatio",
+              message: "Expected a ratio between 0 and 1.",
+            },
+          ])
+        }
         throwIfAborted(options?.signal)
 
         const draft = resolveLmsDraft(course, settings)
@@ -190,18 +310,52 @@ export function createRosterWorkflowHandlers(
           channel: "info",
           message: `Fetching roster from ${draft.provider} course ${input.lmsCourseId}.`,
         })
-        const fetchedMembers = await ports.lms.fetchRoster(
-          draft,
-          input.lmsCourseId,
-          options?.signal,
-          (message) => {
-            options?.onProgress?.({
-              step: 2,
-              totalSteps,
-              label: message,
-            })
-          },
-        )
+        const reportFetchProgress = (message: string) => {
+          options?.onProgress?.({
+            step: 2,
+            totalSteps,
+            label: message,
+          })
+        }
+        const fetched = input.continueOnPageError
+          ? await fetchRosterSkippingFailedPages(
+              ports.lms,
+              draft,
+              input.lmsCourseId,
+              options,
+              reportFetchProgress,
+            )
+          : {
+              members: await ports.lms.fetchRoster(
+                draft,
+                input.lmsCourseId,
+                options?.signal,
+                reportFetchProgress,
+              ),
+              complete: true,
+            }
+        const fetchedMembers = fetched.members
+
+        if (maxMissingEmailRatio !== undefined) {
+          const students = fetchedMembers.filter(
+            (member) => member.enrollmentType === "student",
+          )
+          const missing = students.filter(isMissingEmail).length
+          if (
+            students.length > 0 &&
+            missing / students.length > maxMissingEmailRatio
+          ) {
+            throw createValidationAppError(
+              "Too many LMS students have no email address.",
+              [
+                {
+                  path: "maxMissingEmailRatio",
+                  message: `${missing} of ${students.length} students have no email address, above the allowed ratio of ${maxMissingEmailRatio}.`,
+                },
+              ],
+            )
+          }
+        }
 
         options?.onProgress?.({
           step: 3,
@@ -217,10 +371,31 @@ export function createRosterWorkflowHandlers(
           course.roster,
           fetchedRoster,
           course.idSequences,
+          {
+            matchPriority: input.matchPriority,
+            // Members on a skipped page are absent, not dropped.
+            markMissingAsDropped: fetched.complete,
+          },
         )
         const ensured = ensureSystemGroupSets(result.roster, result.idSequences)
         result.idSequences = ensured.idSequences
 
+        const missingEmailCount = fetchedMembers.filter(isMissingEmail).length
+        if (missingEmailCount > 0) {
+          options?.onOutput?.({
+            channel: "warn",
+            code: "lms_members_missing_email",
+            message: `${missingEmailCount} LMS members have no email address.`,
+          })
+        }
+        for (const change of result.emailChanges) {
+          options?.onOutput?.({
+            channel: "warn",
+            code: "lms_member_email_changed",
+            message: `Student number ${change.studentNumber} matched an existing member; email changed from ${change.previousEmail} to ${change.email}.`,
+          })
+        }
+
         throwIfAborted(options?.signal)
         options?.onProgress?.({
           step: 4,
@@ -232,7 +407,10 @@ export function createRosterWorkflowHandlers(
         if (isSharedAppError(error)) {
           throw error
         }
-        throw normalizeProviderError(error, providerForError, "fetchRoster")
+        throw withAppErrorContext(
+          normalizeProviderError(error, providerForError, "fetchRoster"),
+          "Fetching the LMS roster",
+        )
       }
     },
     "roster.exportMembers": async (
@@ -264,17 +442,10 @@ export function createRosterWorkflowHandlers(
         totalSteps,
         label: "Serializing roster export payload.",
       })
-      const allMembers = [...course.roster.students, ...course.roster.staff]
-      const exportRows = allMembers.map((member) => ({
-        name: member.name,
-        email: member.email,
-        student_number: member.studentNumber ?? "",
-        git_username: member.gitUsername ?? "",
-        status: member.status,
-        enrollment_type: member.enrollmentType,
-      }))
+      const allMembers = allRosterMembers(course.roster)
+      const exportRows = allMembers.map(rosterMemberCsvRow)
       const text = serializeCsv({
-        headers: [...memberExportHeaders],
+        headers: [...rosterMemberCsvHeaders],
         rows: exportRows,
       })
       await ports.userFile.writeText(input.target, text, options?.signal)
@@ -4,13 +4,93 @@ import type {
   WorkflowCallOptions,
   WorkflowHandlerMap,
 } from "@repo-edu/application-contract"
+import type {
+  Assignment,
+  PersistedCourse,
+  RosterValidationIssue,
+} from "@repo-edu/domain/types"
+import {
+  normalizeRequiredFiles,
+  validateRequiredTemplateFiles,
+} from "@repo-edu/domain/validation"
+import type { FileSystemPort } from "@repo-edu/host-runtime-contract"
 import {
+  createValidationAppError,
   runValidateAssignmentForCourse,
   runValidateRosterForCourse,
 } from "./core.js"
+import { joinPath } from "./path-utils.js"
 import { resolveCourseSnapshot, throwIfAborted } from "./workflow-helpers.js"
 
-export function createValidationWorkflowHandlers(): Pick<
+export type ValidationWorkflowPorts = {
+  /** Needed only to check `requiredFiles` against a template clone. */
+  fileSystem?: Pick<FileSystemPort, "inspect">
+}
+
+function resolveTemplatePath(
+  assignment: Assignment,
+  input: AssignmentValidationInput,
+): string | null {
+  if (input.templatePath !== undefined) {
+    return input.templatePath
+  }
+  return assignment.repositoryTemplate?.kind === "local"
+    ? assignment.repositoryTemplate.path
+    : null
+}
+
+async function validateTemplateRequiredFiles(
+  ports: ValidationWorkflowPorts,
+  course: PersistedCourse,
+  input: AssignmentValidationInput,
+  signal?: AbortSignal,
+): Promise<RosterValidationIssue[]> {
+  const assignment = course.roster.assignments.find(
+    (candidate) => candidate.id === input.assignmentId,
+  )
+  if (assignment === undefined) {
+    return []
+  }
+  const requiredFiles = normalizeRequiredFiles(
+    input.requiredFiles ?? assignment.requiredFiles ?? [],
+  )
+  const templatePath = resolveTemplatePath(assignment, input)
+  if (requiredFiles.length === 0 || templatePath === null) {
+    return []
+  }
+  if (ports.fileSystem === undefined) {
+    throw createValidationAppError(
+      "Required template files cannot be checked on this host.",
+      [{ path: "templatePath", message: "File system access is unavailable." }],
+    )
+  }
+
+  // `.git` is a directory in a clone and a file in a linked worktree.
+  const [gitEntry, ...fileEntries] = await ports.fileSystem.inspect({
+    paths: [
+      joinPath(templatePath, ".git"),
+      ...requiredFiles.map((file) => joinPath(templatePath, file)),
+    ],
+    signal,
+  })
+  if (gitEntry === undefined || gitEntry.kind === "missing") {
+    throw createValidationAppError("Template path is not a Git repository.", [
+      {
+        path: "templatePath",
+        message: `'${templatePath}' is not a Git repository.`,
+      },
+    ])
+  }
+
+  const presentFiles = new Set(
+    requiredFiles.filter((_, index) => fileEntries[index]?.kind === "file"),
+  )
+  return validateRequiredTemplateFiles(assignment, requiredFiles, presentFiles)
+}
+
+export function createValidationWorkflowHandlers(
+  ports: ValidationWorkflowPorts = {},
+): Pick<
   WorkflowHandlerMap<"validation.roster" | "validation.assignment">,
   "validation.roster" | "validation.assignment"
 > {
@@ -29,7 +109,18 @@ export function createValidationWorkflowHandlers(): Pick<
     ) => {
       throwIfAborted(options?.signal)
       const course = resolveCourseSnapshot(input.course)
-      return runValidateAssignmentForCourse(course, input.assignmentId)
+      const result = runValidateAssignmentForCourse(
+        course,
+        input.assignmentId,
+        { now: new Date() },
+      )
+      const fileIssues = await validateTemplateRequiredFiles(
+        ports,
+        course,
+        input,
+        options?.signal,
+      )
+      return { issues: [...result.issues, ...fileIssues] }
     },
   }
 }
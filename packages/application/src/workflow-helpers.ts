@@ -10,8 +10,12 @@ import {
   isAppError,
 } from "@repo-edu/application-contract"
 import { normalizeUserAgent } from "@repo-edu/domain/connection"
+import {
+  validateGroupSetImportCsv,
+} from "@repo-edu/domain/group-set-import-export"
 import { allocateMemberId } from "@repo-edu/domain/id-allocator"
 import {
+  allRosterMembers,
   normalizeEmail,
   normalizeMissingEmailStatus,
   normalizeOptionalString,
@@ -36,6 +40,7 @@ import {
   courseSupportsLms,
   type EnrollmentType,
   enrollmentTypeKinds,
+  type GroupSetImportCsvReport,
   type GroupSetImportFormat,
   type GroupSetImportRow,
   type IdSequences,
@@ -50,8 +55,17 @@ import type {
   GitConnectionDraft,
   GitProviderClient,
 } from "@repo-edu/integrations-git-contract"
-import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
-import type { TabularRow } from "./adapters/tabular/types.js"
+import {
+  groupSetLmsProviders,
+  lmsAuthErrorName,
+  type LmsAuthFailure,
+  type LmsConnectionDraft,
+} from "@repo-edu/integrations-lms-contract"
+import { normalizeHeader } from "./adapters/tabular/normalize.js"
+import type {
+  TabularParseResult,
+  TabularRow,
+} from "./adapters/tabular/types.js"
 import type {
   AppSettingsStore,
   CourseStore,
@@ -72,6 +86,10 @@ export function isSharedAppError(value: unknown): value is AppError {
   return isAppError(value)
 }
 
+export function isLmsAuthError(error: unknown): error is LmsAuthFailure {
+  return error instanceof Error && error.name === lmsAuthErrorName
+}
+
 export function throwIfAborted(signal?: AbortSignal) {
   if (signal?.aborted) {
     throw toCancelledAppError()
@@ -216,6 +234,23 @@ export function resolveLmsDraft(
     baseUrl: connection.baseUrl,
     token: connection.token,
     userAgent: normalizeUserAgent(connection.userAgent),
+    ...(connection.tokenExpiresAt
+      ? { tokenExpiresAt: connection.tokenExpiresAt }
+      : {}),
+  }
+}
+
+export function requireLmsGroupSetSupport(
+  draft: LmsConnectionDraft,
+  operation: string,
+): void {
+  if (!groupSetLmsProviders.includes(draft.provider)) {
+    throw {
+      type: "not-supported",
+      message: `LMS provider '${draft.provider}' does not support group sets.`,
+      provider: draft.provider,
+      operation,
+    } satisfies AppError
   }
 }
 
@@ -256,7 +291,8 @@ export function normalizeProviderError(
     message: error instanceof Error ? error.message : String(error),
     provider,
     operation,
-    retryable: true,
+    // Credential failures need user action; retrying cannot succeed.
+    retryable: !isLmsAuthError(error),
   }
 }
 
@@ -299,7 +335,9 @@ export function isRetryablePersistenceWriteKind(
   return kind === "busy" || kind === "locked" || kind === "transient"
 }
 
-export function inferFileFormat(file: UserFileRef): "csv" | "xlsx" | null {
+export function inferFileFormat(
+  file: UserFileRef,
+): "csv" | "xlsx" | "yaml" | null {
   const loweredName = file.displayName.toLowerCase()
   if (loweredName.endsWith(".csv") || file.mediaType === "text/csv") {
     return "csv"
@@ -311,6 +349,13 @@ export function inferFileFormat(file: UserFileRef): "csv" | "xlsx" | null {
   ) {
     return "xlsx"
   }
+  if (
+    loweredName.endsWith(".yaml") ||
+    loweredName.endsWith(".yml") ||
+    file.mediaType === "application/yaml"
+  ) {
+    return "yaml"
+  }
   return null
 }
 
@@ -402,16 +447,28 @@ export function parseStudentRows(
   return normalizedRows
 }
 
+const defaultGitUsernameColumns = ["git_username", "username"]
+
+/**
+ * Reads the username from the first of `columns` present in a row. Columns
+ * are header names in any spelling; they are normalized like file headers.
+ */
 export function parseGitUsernameRows(
   rows: readonly TabularRow[],
+  columns: readonly string[] = defaultGitUsernameColumns,
 ): GitUsernameImportRow[] {
   const normalizedRows: GitUsernameImportRow[] = []
   const issues: AppValidationIssue[] = []
+  const usernameColumns = columns.map(normalizeHeader)
 
   for (const [index, row] of rows.entries()) {
+    const usernameColumn = usernameColumns.find(
+      (column) => row[column] !== undefined,
+    )
     const candidate = {
       email: row.email ?? row.student_email ?? "",
-      git_username: row.git_username ?? row.username ?? "",
+      git_username:
+        usernameColumn === undefined ? "" : (row[usernameColumn] ?? ""),
     }
     const parsed = gitUsernameImportRowSchema.safeParse(candidate)
     if (parsed.success) {
@@ -447,6 +504,58 @@ function toGroupSetImportRow(row: TabularRow): GroupSetImportRow {
   }
 }
 
+const groupSetImportColumnAliases: Record<string, keyof GroupSetImportRow> = {
+  group_name: "group_name",
+  group: "group_name",
+  team: "group_name",
+  name: "name",
+  member_name: "name",
+  student_name: "name",
+  email: "email",
+  student_email: "email",
+  git_username: "git_username",
+  username: "git_username",
+}
+
+/**
+ * Structural preview phase for group-set CSV imports. Reports every missing
+ * column, empty group name and repeated membership at once, before any row
+ * is matched against the roster.
+ */
+export function checkGroupSetImportCsvStructure(
+  table: Pick<TabularParseResult, "headers" | "rows">,
+): GroupSetImportCsvReport {
+  const columns = table.headers.flatMap((header) => {
+    const column = groupSetImportColumnAliases[header]
+    return column === undefined ? [] : [column]
+  })
+  const report = validateGroupSetImportCsv(
+    columns,
+    table.rows.map(toGroupSetImportRow),
+    "email",
+  )
+
+  const issues: AppValidationIssue[] = [
+    ...report.missingColumns.map((column) => ({
+      path: "columns",
+      message: `Missing required column '${column}'`,
+    })),
+    ...report.emptyGroupNameLines.map((line) => ({
+      path: "group_name",
+      message: `Line ${line}: empty group_name`,
+    })),
+    ...report.duplicateMemberships.map((message) => ({
+      path: "rows",
+      message,
+    })),
+  ]
+  if (issues.length > 0) {
+    throw createValidationAppError("Group-set import file is malformed.", issues)
+  }
+
+  return report
+}
+
 export function parseGroupSetImportRows(
   rows: readonly TabularRow[],
 ): GroupSetImportRow[] {
@@ -638,7 +747,7 @@ export function upsertRosterFromStudentRows(
   sequences: IdSequences,
 ): { roster: Roster; idSequences: IdSequences } {
   const membersById = new Map(
-    roster.students.concat(roster.staff).map((member) => [member.id, member]),
+    allRosterMembers(roster).map((member) => [member.id, member]),
   )
   let emailIndex = buildRosterEmailIndex([...membersById.values()])
   let studentNumberIndex = buildRosterStudentNumberIndex([
@@ -0,0 +1,42 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import { mapBounded, sleepWithSignal } from "../index.js"
+
+describe("mapBounded", () => {
+  it("keeps input order and never exceeds the concurrency limit", async () => {
+    let inFlight = 0
+    let peak = 0
+    const results = await mapBounded([30, 10, 20, 0, 5], 2, async (delay) => {
+      inFlight += 1
+      peak = Math.max(peak, inFlight)
+      await new Promise((resolve) => setTimeout(resolve, delay))
+      inFlight -= 1
+      return delay * 2
+    })
+
+    assert.deepStrictEqual(results, [60, 20, 40, 0, 10])
+    assert.equal(peak, 2)
+  })
+
+  it("returns an empty array without calling fn", async () => {
+    const results = await mapBounded([], 4, async () => {
+      throw new Error("unexpected call")
+    })
+    assert.deepStrictEqual(results, [])
+  })
+})
+
+describe("sleepWithSignal", () => {
+  it("rejects with an AbortError when the signal aborts mid-sleep", async () => {
+    const controller = new AbortController()
+    const pending = sleepWithSignal(60_000, controller.signal)
+    controller.abort(new Error("caller stopped"))
+    await assert.rejects(pending, { name: "AbortError" })
+  })
+
+  it("rejects immediately when the signal is already aborted", async () => {
+    await assert.rejects(sleepWithSignal(60_000, AbortSignal.abort()), {
+      name: "AbortError",
+    })
+  })
+})
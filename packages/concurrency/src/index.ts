@@ -0,0 +1,58 @@
+export const packageId = "@repo-edu/concurrency"
+
+/**
+ * Maps `items` through `fn` with at most `maxConcurrency` calls in flight.
+ * Results keep input order; the first rejection rejects the whole call.
+ */
+export async function mapBounded<T, R>(
+  items: readonly T[],
+  maxConcurrency: number,
+  fn: (item: T) => Promise<R>,
+): Promise<R[]> {
+  const results: R[] = new Array(items.length)
+  let nextIndex = 0
+
+  async function worker() {
+    while (nextIndex < items.length) {
+      const index = nextIndex++
+      results[index] = await fn(items[index])
+    }
+  }
+
+  const workers = Array.from(
+    { length: Math.min(maxConcurrency, items.length) },
+    () => worker(),
+  )
+  await Promise.all(workers)
+  return results
+}
+
+function toAbortError(): DOMException {
+  return new DOMException("The operation was aborted.", "AbortError")
+}
+
+/**
+ * Resolves after `delayMs`, or rejects with an `AbortError` as soon as
+ * `signal` aborts.
+ */
+export function sleepWithSignal(
+  delayMs: number,
+  signal?: AbortSignal,
+): Promise<void> {
+  return new Promise((resolve, reject) => {
+    if (signal?.aborted) {
+      reject(toAbortError())
+      return
+    }
+
+    const onAbort = () => {
+      clearTimeout(timeout)
+      reject(toAbortError())
+    }
+    const timeout = setTimeout(() => {
+      signal?.removeEventListener("abort", onAbort)
+      resolve()
+    }, delayMs)
+    signal?.addEventListener("abort", onAbort, { once: true })
+  })
+}
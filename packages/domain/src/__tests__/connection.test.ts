@@ -2,6 +2,7 @@ import assert from "node:assert/strict"
 import { describe, it } from "node:test"
 import {
   DEFAULT_USER_AGENT,
+  isTokenExpired,
   normalizeUserAgent,
   resolveUserAgent,
 } from "../connection.js"
@@ -45,3 +46,21 @@ describe("resolveUserAgent", () => {
     )
   })
 })
+
+describe("isTokenExpired", () => {
+  const expiresAt = "2026-03-01T12:00:00.000Z"
+  const expiresAtMs = Date.parse(expiresAt)
+
+  it("treats the expiry instant as expired", () => {
+    assert.equal(isTokenExpired(expiresAt, expiresAtMs - 1), false)
+    assert.equal(isTokenExpired(expiresAt, expiresAtMs), true)
+    assert.equal(isTokenExpired(expiresAt, expiresAtMs + 1), true)
+  })
+
+  it("returns false for missing and unparseable expiry values", () => {
+    assert.equal(isTokenExpired(null, expiresAtMs), false)
+    assert.equal(isTokenExpired(undefined, expiresAtMs), false)
+    assert.equal(isTokenExpired("", expiresAtMs), false)
+    assert.equal(isTokenExpired("not-a-date", expiresAtMs), false)
+  })
+})
@@ -1,14 +1,22 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
 import {
+  collectAssignmentTeams,
+  defaultGroupNameMaxLength,
   exportGroupSetRows,
+  exportTeamsCsvRows,
   importGroupSet,
+  iterateTeamsCsvRows,
+  normalizeGroupName,
+  parseTeamsCsvRows,
   previewImportGroupSet,
   previewReimportGroupSet,
   previewReplaceGroupSetFromRepoBee,
   reimportGroupSet,
+  validateGroupSetImportCsv,
 } from "../group-set-import-export.js"
 import {
+  type Assignment,
   type Group,
   type GroupSet,
   initialIdSequences,
@@ -104,6 +112,24 @@ describe("group-set import preview", () => {
     )
   })
 
+  it("rejects a member listed in two groups", () => {
+    const result = previewImportGroupSet(makeRoster(), [
+      { group_name: "Alpha", email: "alice@example.com" },
+      { group_name: "Beta", email: "bob@example.com" },
+      { group_name: "Beta", email: "ALICE@example.com" },
+    ])
+
+    assert.equal(result.ok, false)
+    if (result.ok) return
+    assert.deepStrictEqual(result.issues, [
+      {
+        path: "rows.2",
+        message:
+          "Line 4: email 'alice@example.com' is in both group 'Alpha' and group 'Beta'",
+      },
+    ])
+  })
+
   it("supports git username member resolution", () => {
     const roster = makeRoster()
     const result = previewImportGroupSet(
@@ -235,6 +261,40 @@ describe("group-set import and reimport", () => {
     })
   })
 
+  it("rejects overlapping groups on import and reimport", () => {
+    const rows = [
+      { group_name: "Alpha", email: "carol@example.com" },
+      { group_name: "Gamma", email: "carol@example.com" },
+    ]
+    const imported = importGroupSet(
+      makeRoster(),
+      { sourceFilename: "groups.csv" },
+      rows,
+      initialIdSequences(),
+    )
+    assert.equal(imported.ok, false)
+
+    const groupSet: GroupSet = {
+      id: "gs-existing",
+      name: "Imported",
+      nameMode: "named",
+      groupIds: [],
+      connection: null,
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+    }
+    const reimported = reimportGroupSet(
+      makeRoster({ groupSets: [groupSet] }),
+      groupSet.id,
+      { sourceFilename: "groups.csv" },
+      rows,
+    )
+    assert.equal(reimported.ok, false)
+    if (reimported.ok) return
+    assert.match(reimported.issues[0]?.message ?? "", /is in both group/)
+  })
+
   it("reimport wrappers use additive import semantics", () => {
     const groups: Group[] = [
       {
@@ -351,6 +411,127 @@ describe("group-set import and reimport", () => {
   })
 })
 
+describe("group-set CSV validation", () => {
+  const columns = ["group_name", "name", "email"]
+
+  it("reports group and row counts for a well-formed file", () => {
+    const report = validateGroupSetImportCsv(columns, [
+      { group_name: "Team A", email: "a@example.com" },
+      { group_name: "team a ", email: "b@example.com" },
+      { group_name: "Team B" },
+    ])
+
+    assert.deepStrictEqual(report, {
+      totalRows: 3,
+      groupCount: 2,
+      missingColumns: [],
+      emptyGroupNameLines: [],
+      duplicateMemberships: [],
+    })
+  })
+
+  it("lists required columns absent from the header", () => {
+    const report = validateGroupSetImportCsv(["name"], [])
+
+    assert.deepStrictEqual(report.missingColumns, ["group_name", "email"])
+    assert.equal(report.totalRows, 0)
+  })
+
+  it("checks the git_username column for username-keyed imports", () => {
+    const report = validateGroupSetImportCsv(columns, [], "gitUsername")
+
+    assert.deepStrictEqual(report.missingColumns, ["git_username"])
+  })
+
+  it("collects every line with an empty group name", () => {
+    const report = validateGroupSetImportCsv(columns, [
+      { group_name: "", email: "a@example.com" },
+      { group_name: "Team A", email: "b@example.com" },
+      { group_name: "   ", email: "c@example.com" },
+    ])
+
+    assert.deepStrictEqual(report.emptyGroupNameLines, [2, 4])
+    assert.equal(report.groupCount, 1)
+  })
+
+  it("collects repeated and conflicting memberships", () => {
+    const report = validateGroupSetImportCsv(columns, [
+      { group_name: "Alpha", email: "same@example.com" },
+      { group_name: "Alpha", email: "SAME@example.com" },
+      { group_name: "Beta", email: "same@example.com" },
+      { group_name: "Beta", email: "other@example.com" },
+    ])
+
+    assert.deepStrictEqual(report.duplicateMemberships, [
+      "Line 3: email 'same@example.com' is listed twice in group 'Alpha'",
+      "Line 4: email 'same@example.com' is in both group 'Alpha' and group 'Beta'",
+    ])
+  })
+})
+
+describe("group name normalization", () => {
+  it("strips null bytes and other control characters", () => {
+    assert.deepStrictEqual(normalizeGroupName("Team\u0000 A\u0007\r\n"), {
+      name: "Team A",
+      truncated: false,
+    })
+  })
+
+  it("truncates 300-character names and reports a warning on import", () => {
+    const longName = "g".repeat(300)
+    assert.equal(
+      normalizeGroupName(longName).name.length,
+      defaultGroupNameMaxLength,
+    )
+
+    const result = importGroupSet(
+      makeRoster(),
+      {
+        sourceFilename: "groups.csv",
+        sourcePath: null,
+        lastUpdated: "2026-03-04T10:00:00.000Z",
+      },
+      [{ group_name: longName, email: "alice@example.com" }],
+      initialIdSequences(),
+    )
+
+    assert.equal(result.ok, true)
+    if (!result.ok) return
+    const truncatedName = "g".repeat(defaultGroupNameMaxLength)
+    assert.equal(result.value.groupsUpserted[0]?.name, truncatedName)
+    assert.deepStrictEqual(result.value.warnings, [
+      { kind: "truncated", groupName: truncatedName, originalLength: 300 },
+    ])
+  })
+
+  it("rejects distinct names that collide after truncation", () => {
+    const prefix = "g".repeat(defaultGroupNameMaxLength)
+    const result = previewImportGroupSet(makeRoster(), [
+      { group_name: `${prefix}-alpha`, email: "alice@example.com" },
+      { group_name: `${prefix}-beta`, email: "bob@example.com" },
+    ])
+
+    assert.equal(result.ok, false)
+    if (result.ok) return
+    assert.equal(result.issues[0]?.path, "rows.1.group_name")
+    assert.match(
+      result.issues[0]?.message ?? "",
+      /^Line 3: group_name is identical to another group after truncation/,
+    )
+  })
+
+  it("rejects names with only whitespace or control characters", () => {
+    for (const groupName of [" \t ", "\u0000\u0000"]) {
+      const result = previewImportGroupSet(makeRoster(), [
+        { group_name: groupName, email: "alice@example.com" },
+      ])
+      assert.equal(result.ok, false)
+      if (result.ok) return
+      assert.equal(result.issues[0]?.message, "Line 2: empty group_name")
+    }
+  })
+})
+
 describe("group-set export", () => {
   it("exports rows including empty groups and missing member placeholders", () => {
     const groupSet: GroupSet = {
@@ -407,3 +588,280 @@ describe("group-set export", () => {
     ])
   })
 })
+
+function makeNamedGroupSet(id: string, groupIds: string[]): GroupSet {
+  return {
+    id,
+    name: id,
+    nameMode: "named",
+    groupIds,
+    connection: null,
+    repoNameTemplate: null,
+    columnVisibility: {},
+    columnSizing: {},
+  }
+}
+
+function makeGroup(id: string, name: string, memberIds: string[]): Group {
+  return { id, name, memberIds, origin: ORIGIN_LOCAL, lmsGroupId: null }
+}
+
+function makeAssignment(
+  id: string,
+  name: string,
+  groupSetId: string,
+): Assignment {
+  return { id, name, groupSetId, repositories: {} }
+}
+
+function makeTeamsRoster(): Roster {
+  return makeRoster({
+    groups: [
+      makeGroup("g1", "Team A", ["m_0001", "m_0002"]),
+      makeGroup("g2", 'Ünïcode, "quoted" team', ["m_0003"]),
+      makeGroup("g3", "Empty", []),
+    ],
+    groupSets: [
+      makeNamedGroupSet("gs1", ["g1", "g2", "g3"]),
+      {
+        id: "gs2",
+        name: "Pairs",
+        nameMode: "unnamed",
+        teams: [{ id: "t1", gitUsernames: ["alice", "carol"] }],
+        connection: null,
+        repoNameTemplate: null,
+        columnVisibility: {},
+        columnSizing: {},
+      },
+    ],
+    assignments: [
+      makeAssignment("a1", "Lab 1", "gs1"),
+      makeAssignment("a2", "Lab 2", "gs2"),
+      makeAssignment("a3", 'Project, part "A"', "gs1"),
+    ],
+  })
+}
+
+describe("teams CSV", () => {
+  it("collects teams for every assignment in roster order", () => {
+    const teams = collectAssignmentTeams(makeTeamsRoster())
+
+    assert.deepStrictEqual(
+      teams.map((team) => [team.assignment, team.teamName]),
+      [
+        ["Lab 1", "Team A"],
+        ["Lab 1", 'Ünïcode, "quoted" team'],
+        ["Lab 1", "Empty"],
+        ["Lab 2", "alice-carol"],
+        ['Project, part "A"', "Team A"],
+        ['Project, part "A"', 'Ünïcode, "quoted" team'],
+        ['Project, part "A"', "Empty"],
+      ],
+    )
+    assert.deepStrictEqual(teams[0]?.memberGitUsernames, ["alice", "bob"])
+    assert.deepStrictEqual(teams[3]?.memberGitUsernames, ["alice", "carol"])
+  })
+
+  it("leaves out members without a Git username", () => {
+    const roster = makeRoster({
+      groups: [makeGroup("g1", "Team A", ["m_0001", "m_1001"])],
+      groupSets: [makeNamedGroupSet("gs1", ["g1"])],
+      assignments: [makeAssignment("a1", "Lab 1", "gs1")],
+    })
+
+    assert.deepStrictEqual(collectAssignmentTeams(roster), [
+      {
+        assignment: "Lab 1",
+        teamName: "Team A",
+        memberGitUsernames: ["alice"],
+      },
+    ])
+  })
+
+  it("skips assignments whose group set is missing", () => {
+    const roster = makeRoster({
+      assignments: [makeAssignment("a1", "Lab 1", "gs-missing")],
+    })
+
+    assert.deepStrictEqual(collectAssignmentTeams(roster), [])
+  })
+
+  it("writes one row per member and one row for an empty team", () => {
+    const rows = exportTeamsCsvRows([
+      {
+        assignment: "Lab 1",
+        teamName: "Team A",
+        memberGitUsernames: ["alice", "bob"],
+      },
+      { assignment: "Lab 1", teamName: "Empty", memberGitUsernames: [] },
+    ])
+
+    assert.deepStrictEqual(rows, [
+      {
+        team_name: "Team A",
+        member_git_username: "alice",
+        assignment: "Lab 1",
+      },
+      { team_name: "Team A", member_git_username: "bob", assignment: "Lab 1" },
+      { team_name: "Empty", member_git_username: "", assignment: "Lab 1" },
+    ])
+  })
+
+  it("streams the same rows as the batch export", () => {
+    const teams = collectAssignmentTeams(makeTeamsRoster())
+    const expected = exportTeamsCsvRows(teams)
+
+    const streamed: unknown[] = []
+    for (const row of iterateTeamsCsvRows(teams)) {
+      streamed.push(row)
+    }
+
+    assert.ok(expected.length > 0)
+    assert.deepStrictEqual(streamed, expected)
+  })
+
+  it("round-trips names, members and assignment bindings", () => {
+    const teams = collectAssignmentTeams(makeTeamsRoster())
+
+    const parsed = parseTeamsCsvRows(exportTeamsCsvRows(teams))
+
+    assert.deepStrictEqual(parsed, { ok: true, value: teams })
+  })
+
+  it("round-trips names with commas, quotes, unicode and emoji", () => {
+    const teams = [
+      {
+        assignment: "Résumé · Week 3",
+        teamName: 'The "A" Team, 2nd edition 🚀',
+        memberGitUsernames: ["zoë", "o-brien"],
+      },
+    ]
+
+    assert.deepStrictEqual(parseTeamsCsvRows(exportTeamsCsvRows(teams)), {
+      ok: true,
+      value: teams,
+    })
+  })
+
+  it("keeps a team name bound to different assignments as separate teams", () => {
+    const parsed = parseTeamsCsvRows([
+      {
+        team_name: "Team A",
+        member_git_username: "alice",
+        assignment: "Lab 1",
+      },
+      { team_name: "Team A", member_git_username: "bob", assignment: "Lab 2" },
+    ])
+
+    assert.deepStrictEqual(parsed, {
+      ok: true,
+      value: [
+        {
+          assignment: "Lab 1",
+          teamName: "Team A",
+          memberGitUsernames: ["alice"],
+        },
+        {
+          assignment: "Lab 2",
+          teamName: "Team A",
+          memberGitUsernames: ["bob"],
+        },
+      ],
+    })
+  })
+
+  it("merges non-adjacent rows of a team and drops repeated members", () => {
+    const parsed = parseTeamsCsvRows([
+      {
+        team_name: "Team A",
+        member_git_username: "alice",
+        assignment: "Lab 1",
+      },
+      {
+        team_name: "Team B",
+        member_git_username: "carol",
+        assignment: "Lab 1",
+      },
+      { team_name: "Team A", member_git_username: "bob", assignment: "Lab 1" },
+      {
+        team_name: "Team A",
+        member_git_username: "alice",
+        assignment: "Lab 1",
+      },
+    ])
+
+    assert.equal(parsed.ok, true)
+    if (!parsed.ok) return
+    assert.deepStrictEqual(
+      parsed.value.map((team) => [team.teamName, team.memberGitUsernames]),
+      [
+        ["Team A", ["alice", "bob"]],
+        ["Team B", ["carol"]],
+      ],
+    )
+  })
+
+  it("keeps a team listed only with an empty member row", () => {
+    const parsed = parseTeamsCsvRows([
+      { team_name: "Empty", assignment: "Lab 1" },
+    ])
+
+    assert.deepStrictEqual(parsed, {
+      ok: true,
+      value: [
+        { assignment: "Lab 1", teamName: "Empty", memberGitUsernames: [] },
+      ],
+    })
+  })
+
+  it("trims surrounding whitespace from every column", () => {
+    const parsed = parseTeamsCsvRows([
+      {
+        team_name: "  Team A ",
+        member_git_username: " alice ",
+        assignment: " Lab 1",
+      },
+    ])
+
+    assert.deepStrictEqual(parsed, {
+      ok: true,
+      value: [
+        {
+          assignment: "Lab 1",
+          teamName: "Team A",
+          memberGitUsernames: ["alice"],
+        },
+      ],
+    })
+  })
+
+  it("reports rows missing a team name or assignment by line", () => {
+    const parsed = parseTeamsCsvRows([
+      {
+        team_name: "Team A",
+        member_git_username: "alice",
+        assignment: "Lab 1",
+      },
+      { team_name: " ", member_git_username: "bob", assignment: "Lab 1" },
+      { team_name: "Team C", member_git_username: "carol" },
+    ])
+
+    assert.deepStrictEqual(parsed, {
+      ok: false,
+      issues: [
+        {
+          path: "rows.1.team_name",
+          message: "Line 3: team_name is required",
+        },
+        {
+          path: "rows.2.assignment",
+          message: "Line 4: assignment is required",
+        },
+      ],
+    })
+  })
+
+  it("returns no teams for an empty file", () => {
+    assert.deepStrictEqual(parseTeamsCsvRows([]), { ok: true, value: [] })
+  })
+})
@@ -1,13 +1,18 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
 import {
+  compileGroupSelection,
   resolveAssignmentGroups,
   resolveGroupSetGroups,
 } from "../group-selection.js"
 import {
   activeMemberIds,
+  compactRoster,
   ensureSystemGroupSets,
   findSystemSet,
+  isLmsGroupSetSyncStale,
+  mergeGroups,
+  splitGroup,
   systemSetsMissing,
 } from "../group-set.js"
 import { generateGroupName, generateUniqueGroupName } from "../roster.js"
@@ -16,6 +21,7 @@ import {
   type Group,
   type GroupSet,
   initialIdSequences,
+  ORIGIN_LMS,
   ORIGIN_LOCAL,
   ORIGIN_SYSTEM,
   type Roster,
@@ -147,6 +153,60 @@ describe("group resolution", () => {
   })
 })
 
+describe("group selection modes", () => {
+  it("matches every name in all mode", () => {
+    const result = compileGroupSelection({
+      mode: { kind: "all" },
+      matchCase: true,
+      useRegex: false,
+    })
+    assert.ok(result.ok)
+
+    assert.equal(result.value(""), true)
+    assert.equal(result.value("Lab, Section 1"), true)
+  })
+
+  it("keeps commas literal in multi-pattern mode", () => {
+    const result = compileGroupSelection({
+      mode: { kind: "multi-pattern", patterns: ["Lab, Section *", "Exam"] },
+      matchCase: true,
+      useRegex: false,
+    })
+    assert.ok(result.ok)
+
+    assert.equal(result.value("Lab, Section 1"), true)
+    assert.equal(result.value("Exam"), true)
+    assert.equal(result.value("Lab"), false)
+    assert.equal(result.value(" Section 1"), false)
+  })
+
+  it("applies case and regex options to every pattern", () => {
+    const result = compileGroupSelection({
+      mode: { kind: "multi-pattern", patterns: ["^lab-\\d+$", "^exam"] },
+      matchCase: false,
+      useRegex: true,
+    })
+    assert.ok(result.ok)
+
+    assert.equal(result.value("Lab-12"), true)
+    assert.equal(result.value("EXAM retake"), true)
+    assert.equal(result.value("Lab-A"), false)
+  })
+
+  it("reports the malformed pattern by index", () => {
+    const result = compileGroupSelection({
+      mode: { kind: "multi-pattern", patterns: ["Lab*", "[abc"] },
+      matchCase: true,
+      useRegex: false,
+    })
+
+    assert.deepEqual(result, {
+      ok: false,
+      issues: [{ path: "patterns.1", message: "unclosed '[' bracket" }],
+    })
+  })
+})
+
 describe("system group sets", () => {
   it("creates and maintains the system sets", () => {
     const alice = makeMember("s1", "Alice Smith")
@@ -259,3 +319,547 @@ describe("system group sets", () => {
     )
   })
 })
+
+describe("LMS group set sync freshness", () => {
+  const now = new Date("2026-05-02T12:00:00Z")
+
+  function makeGroupSet(connection: GroupSet["connection"]): GroupSet {
+    return {
+      id: "gs1",
+      name: "Project Teams",
+      nameMode: "named",
+      groupIds: [],
+      connection,
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+    }
+  }
+
+  function syncedHoursAgo(hours: number): GroupSet {
+    return makeGroupSet({
+      kind: "canvas",
+      courseId: "c1",
+      groupSetId: "cat-1",
+      lastUpdated: new Date(now.getTime() - hours * 3_600_000).toISOString(),
+    })
+  }
+
+  it("is stale when the last sync is older than the threshold", () => {
+    assert.equal(isLmsGroupSetSyncStale(syncedHoursAgo(25), now, 24), true)
+    assert.equal(isLmsGroupSetSyncStale(syncedHoursAgo(25), now), true)
+  })
+
+  it("is fresh within the threshold", () => {
+    assert.equal(isLmsGroupSetSyncStale(syncedHoursAgo(23), now, 24), false)
+    assert.equal(isLmsGroupSetSyncStale(syncedHoursAgo(25), now, 48), false)
+  })
+
+  it("never treats non-LMS group sets as stale", () => {
+    const imported = makeGroupSet({
+      kind: "import",
+      sourceFilename: "teams.csv",
+      sourcePath: null,
+      lastUpdated: "2020-01-01T00:00:00Z",
+    })
+
+    assert.equal(isLmsGroupSetSyncStale(imported, now), false)
+    assert.equal(isLmsGroupSetSyncStale(makeGroupSet(null), now), false)
+  })
+})
+
+describe("roster compaction", () => {
+  function group(id: string, memberIds: string[] = []): Group {
+    return {
+      id,
+      name: id,
+      memberIds,
+      origin: ORIGIN_LOCAL,
+      lmsGroupId: null,
+    }
+  }
+
+  function namedSet(
+    id: string,
+    groupIds: string[],
+    connection: GroupSet["connection"] = null,
+  ): GroupSet {
+    return {
+      id,
+      name: id,
+      groupIds,
+      connection,
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+      nameMode: "named",
+    }
+  }
+
+  function assignment(
+    groupSetId: string,
+    repositories: Record<string, string> = {},
+  ): Assignment {
+    return {
+      id: `a-${groupSetId}`,
+      name: groupSetId,
+      groupSetId,
+      repositories,
+    }
+  }
+
+  it("removes empty groups that no group set lists", () => {
+    const roster = makeRoster({
+      groups: [group("g-kept", ["s1"]), group("g-listed"), group("g-orphan")],
+      groupSets: [namedSet("gs", ["g-kept", "g-listed"])],
+    })
+
+    const result = compactRoster(roster)
+
+    assert.deepStrictEqual(
+      result.roster.groups.map((entry) => entry.id),
+      ["g-kept", "g-listed"],
+    )
+    assert.deepStrictEqual(result.report, {
+      groupsRemoved: 1,
+      danglingGroupIdsRemoved: 0,
+      groupSetsRemoved: 0,
+    })
+  })
+
+  it("drops group-set references to missing groups", () => {
+    const roster = makeRoster({
+      groups: [group("g-1", ["s1"])],
+      groupSets: [namedSet("gs", ["g-1", "g-gone"])],
+    })
+
+    const result = compactRoster(roster)
+
+    const [groupSet] = result.roster.groupSets
+    assert.ok(groupSet?.nameMode === "named")
+    assert.deepStrictEqual(groupSet.groupIds, ["g-1"])
+    assert.deepStrictEqual(result.report, {
+      groupsRemoved: 0,
+      danglingGroupIdsRemoved: 1,
+      groupSetsRemoved: 0,
+    })
+  })
+
+  it("removes empty group sets except system and assigned sets", () => {
+    const roster = makeRoster({
+      groupSets: [
+        namedSet("gs-empty", []),
+        namedSet("gs-assigned", []),
+        namedSet("gs-system", [], {
+          kind: "system",
+          systemType: SYSTEM_TYPE_STAFF,
+        }),
+      ],
+      assignments: [assignment("gs-assigned")],
+    })
+
+    const result = compactRoster(roster)
+
+    assert.deepStrictEqual(
+      result.roster.groupSets.map((entry) => entry.id),
+      ["gs-assigned", "gs-system"],
+    )
+    assert.deepStrictEqual(result.report, {
+      groupsRemoved: 0,
+      danglingGroupIdsRemoved: 0,
+      groupSetsRemoved: 1,
+    })
+  })
+
+  it("keeps empty groups that an assignment recorded a repository for", () => {
+    const roster = makeRoster({
+      groups: [group("g-repo")],
+      groupSets: [namedSet("gs", ["g-other"])],
+      assignments: [assignment("gs", { "g-repo": "team-repo" })],
+    })
+
+    const result = compactRoster(roster)
+
+    assert.deepStrictEqual(
+      result.roster.groups.map((entry) => entry.id),
+      ["g-repo"],
+    )
+    assert.equal(result.report.groupsRemoved, 0)
+  })
+
+  it("returns the same roster when there is nothing to compact", () => {
+    const roster = makeRoster({
+      groups: [group("g-1", ["s1"])],
+      groupSets: [namedSet("gs", ["g-1"])],
+    })
+
+    const result = compactRoster(roster)
+
+    assert.equal(result.roster, roster)
+  })
+})
+
+describe("group splitting and merging", () => {
+  function group(
+    id: string,
+    memberIds: string[],
+    overrides: Partial<Group> = {},
+  ): Group {
+    return {
+      id,
+      name: id,
+      memberIds,
+      origin: ORIGIN_LOCAL,
+      lmsGroupId: null,
+      ...overrides,
+    }
+  }
+
+  function namedSet(
+    id: string,
+    groupIds: string[],
+    connection: GroupSet["connection"] = null,
+  ): GroupSet {
+    return {
+      id,
+      name: id,
+      groupIds,
+      connection,
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+      nameMode: "named",
+    }
+  }
+
+  function teamRoster(overrides: Partial<Roster> = {}): Roster {
+    return makeRoster({
+      students: [
+        makeMember("s1", "Ada Lovelace"),
+        makeMember("s2", "Alan Turing"),
+        makeMember("s3", "Grace Hopper"),
+        makeMember("s4", "Edsger Dijkstra"),
+        makeMember("s5", "Barbara Liskov"),
+      ],
+      groups: [
+        group("g-1", ["s1", "s2", "s3", "s4", "s5"], { name: "team-1" }),
+        group("g-2", [], { name: "team-2" }),
+      ],
+      groupSets: [namedSet("gs", ["g-1", "g-2"])],
+      ...overrides,
+    })
+  }
+
+  function expectOk<T>(
+    result: { ok: true; value: T } | { ok: false; issues: unknown },
+  ): T {
+    assert.equal(result.ok, true, JSON.stringify(result))
+    if (!result.ok) throw new Error("Expected success")
+    return result.value
+  }
+
+  function issuePaths(result: { ok: boolean; issues?: { path: string }[] }) {
+    assert.equal(result.ok, false)
+    return (result.issues ?? []).map((issue) => issue.path)
+  }
+
+  it("keeps the first members and moves the rest to a new group", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", 2, "team-3"),
+    )
+
+    assert.deepStrictEqual(result.original.memberIds, ["s1", "s2"])
+    assert.equal(result.original.id, "g-1")
+    assert.equal(result.original.name, "team-1")
+    assert.deepStrictEqual(result.created, {
+      id: "g_0001",
+      name: "team-3",
+      memberIds: ["s3", "s4", "s5"],
+      origin: "local",
+      lmsGroupId: null,
+    })
+  })
+
+  it("lists the new group right after the original in the set", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", 3, "team-3"),
+    )
+
+    const [groupSet] = result.roster.groupSets
+    assert.ok(groupSet?.nameMode === "named")
+    assert.deepStrictEqual(groupSet.groupIds, ["g-1", "g_0001", "g-2"])
+    assert.deepStrictEqual(
+      result.roster.groups.map((entry) => entry.id),
+      ["g-1", "g-2", "g_0001"],
+    )
+  })
+
+  it("advances the group id sequence", () => {
+    const sequences = initialIdSequences()
+    const result = expectOk(
+      splitGroup(teamRoster(), sequences, "gs", "g-1", 2, "team-3"),
+    )
+
+    assert.equal(result.idSequences.nextGroupSeq, sequences.nextGroupSeq + 1)
+  })
+
+  it("names the new group from its members when no name is given", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", 3),
+    )
+
+    assert.equal(result.created.name, "dijkstra-liskov")
+  })
+
+  it("moves every member to the new group when splitting at 0", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", 0, "team-3"),
+    )
+
+    assert.deepStrictEqual(result.original.memberIds, [])
+    assert.deepStrictEqual(result.created.memberIds, [
+      "s1",
+      "s2",
+      "s3",
+      "s4",
+      "s5",
+    ])
+  })
+
+  it("creates an empty group when splitting at the member count", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", 5),
+    )
+
+    assert.equal(result.original.memberIds.length, 5)
+    assert.deepStrictEqual(result.created.memberIds, [])
+    assert.equal(result.created.name, "empty-group")
+  })
+
+  it("splits an empty group into two empty groups", () => {
+    const result = expectOk(
+      splitGroup(teamRoster(), initialIdSequences(), "gs", "g-2", 0, "team-3"),
+    )
+
+    assert.deepStrictEqual(result.original.memberIds, [])
+    assert.deepStrictEqual(result.created.memberIds, [])
+  })
+
+  it("rejects split positions outside the member range", () => {
+    for (const splitAt of [-1, 6, 1.5, Number.NaN]) {
+      assert.deepStrictEqual(
+        issuePaths(
+          splitGroup(teamRoster(), initialIdSequences(), "gs", "g-1", splitAt),
+        ),
+        ["splitAt"],
+      )
+    }
+  })
+
+  it("rejects new names that are blank or already used in the set", () => {
+    for (const newName of ["  ", "team-2"]) {
+      assert.deepStrictEqual(
+        issuePaths(
+          splitGroup(
+            teamRoster(),
+            initialIdSequences(),
+            "gs",
+            "g-1",
+            2,
+            newName,
+          ),
+        ),
+        ["newName"],
+      )
+    }
+  })
+
+  it("refuses to split groups outside the set or synced from an LMS", () => {
+    const roster = teamRoster({
+      groups: [
+        group("g-1", ["s1", "s2"], { origin: ORIGIN_LMS, lmsGroupId: "101" }),
+        group("g-2", []),
+        group("g-elsewhere", ["s3"]),
+      ],
+    })
+
+    assert.deepStrictEqual(
+      issuePaths(splitGroup(roster, initialIdSequences(), "gs", "g-1", 1)),
+      ["groupId"],
+    )
+    assert.deepStrictEqual(
+      issuePaths(
+        splitGroup(roster, initialIdSequences(), "gs", "g-elsewhere", 1),
+      ),
+      ["groupId"],
+    )
+  })
+
+  it("refuses to edit missing, system and unnamed group sets", () => {
+    const roster = teamRoster({
+      groupSets: [
+        namedSet("gs", ["g-1", "g-2"], {
+          kind: "system",
+          systemType: SYSTEM_TYPE_INDIVIDUAL_STUDENTS,
+        }),
+        {
+          id: "gs-unnamed",
+          name: "Usernames",
+          nameMode: "unnamed",
+          teams: [],
+          connection: null,
+          repoNameTemplate: null,
+          columnVisibility: {},
+          columnSizing: {},
+        },
+      ],
+    })
+
+    for (const groupSetId of ["gs", "gs-unnamed", "gs-missing"]) {
+      assert.deepStrictEqual(
+        issuePaths(
+          splitGroup(roster, initialIdSequences(), groupSetId, "g-1", 1),
+        ),
+        ["groupSetId"],
+      )
+      assert.deepStrictEqual(
+        issuePaths(mergeGroups(roster, groupSetId, "g-1", "g-2", "merged")),
+        ["groupSetId"],
+      )
+    }
+  })
+
+  it("merges group B's members into group A under the new name", () => {
+    const roster = teamRoster({
+      groups: [
+        group("g-1", ["s1", "s2"], { name: "team-1" }),
+        group("g-2", ["s3"], { name: "team-2" }),
+        group("g-3", ["s4"], { name: "team-3" }),
+      ],
+      groupSets: [namedSet("gs", ["g-1", "g-2", "g-3"])],
+    })
+
+    const result = expectOk(mergeGroups(roster, "gs", "g-1", "g-2", "team-12"))
+
+    assert.deepStrictEqual(result.merged, {
+      id: "g-1",
+      name: "team-12",
+      memberIds: ["s1", "s2", "s3"],
+      origin: "local",
+      lmsGroupId: null,
+    })
+    assert.equal(result.removedGroupId, "g-2")
+    const [groupSet] = result.roster.groupSets
+    assert.ok(groupSet?.nameMode === "named")
+    assert.deepStrictEqual(groupSet.groupIds, ["g-1", "g-3"])
+    assert.deepStrictEqual(
+      result.roster.groups.map((entry) => entry.id),
+      ["g-1", "g-3"],
+    )
+  })
+
+  it("lists shared members once when merging", () => {
+    const roster = teamRoster({
+      groups: [group("g-1", ["s1", "s2"]), group("g-2", ["s2", "s3"])],
+    })
+
+    const result = expectOk(mergeGroups(roster, "gs", "g-1", "g-2", "merged"))
+
+    assert.deepStrictEqual(result.merged.memberIds, ["s1", "s2", "s3"])
+  })
+
+  it("merges empty groups", () => {
+    const roster = teamRoster({
+      groups: [group("g-1", []), group("g-2", [])],
+    })
+
+    const result = expectOk(mergeGroups(roster, "gs", "g-1", "g-2", "merged"))
+
+    assert.deepStrictEqual(result.merged.memberIds, [])
+    assert.deepStrictEqual(
+      result.roster.groups.map((entry) => entry.id),
+      ["g-1"],
+    )
+  })
+
+  it("keeps group B while another set or repository still uses it", () => {
+    const listedElsewhere = teamRoster({
+      groupSets: [namedSet("gs", ["g-1", "g-2"]), namedSet("gs-2", ["g-2"])],
+    })
+    const withRepository = teamRoster({
+      assignments: [
+        {
+          id: "a-1",
+          name: "Lab 1",
+          groupSetId: "gs",
+          repositories: { "g-2": "lab-1-team-2" },
+        },
+      ],
+    })
+
+    for (const roster of [listedElsewhere, withRepository]) {
+      const result = expectOk(
+        mergeGroups(roster, "gs", "g-1", "g-2", "merged"),
+      )
+      assert.ok(result.roster.groups.some((entry) => entry.id === "g-2"))
+    }
+  })
+
+  it("rejects merging a group with itself", () => {
+    assert.deepStrictEqual(
+      issuePaths(mergeGroups(teamRoster(), "gs", "g-1", "g-1", "merged")),
+      ["groupBId"],
+    )
+  })
+
+  it("rejects merged names that are blank or used by another group", () => {
+    const roster = teamRoster({
+      groups: [
+        group("g-1", ["s1"], { name: "team-1" }),
+        group("g-2", ["s2"], { name: "team-2" }),
+        group("g-3", ["s3"], { name: "team-3" }),
+      ],
+      groupSets: [namedSet("gs", ["g-1", "g-2", "g-3"])],
+    })
+
+    assert.deepStrictEqual(
+      issuePaths(mergeGroups(roster, "gs", "g-1", "g-2", "")),
+      ["mergedName"],
+    )
+    assert.deepStrictEqual(
+      issuePaths(mergeGroups(roster, "gs", "g-1", "g-2", "team-3")),
+      ["mergedName"],
+    )
+    // Either source group's name may be reused for the merged group.
+    expectOk(mergeGroups(roster, "gs", "g-1", "g-2", "team-2"))
+  })
+
+  it("rejects merging groups that are not local groups in the set", () => {
+    const roster = teamRoster({
+      groups: [
+        group("g-1", ["s1"]),
+        group("g-2", ["s2"], { origin: ORIGIN_LMS, lmsGroupId: "202" }),
+        group("g-outside", ["s3"]),
+      ],
+    })
+
+    assert.deepStrictEqual(
+      issuePaths(mergeGroups(roster, "gs", "g-1", "g-2", "merged")),
+      ["groupBId"],
+    )
+    assert.deepStrictEqual(
+      issuePaths(mergeGroups(roster, "gs", "g-outside", "g-1", "merged")),
+      ["groupAId"],
+    )
+  })
+
+  it("leaves the input roster untouched", () => {
+    const roster = teamRoster()
+    const snapshot = structuredClone(roster)
+
+    expectOk(splitGroup(roster, initialIdSequences(), "gs", "g-1", 2))
+    expectOk(mergeGroups(roster, "gs", "g-1", "g-2", "merged"))
+
+    assert.deepStrictEqual(roster, snapshot)
+  })
+})
@@ -3,7 +3,10 @@ import { describe, it } from "node:test"
 import {
   compileAnalysisFilterPatterns,
   compileGroupNamePattern,
+  compileGroupNameRegex,
+  compileGroupNamePatterns,
   compileRepoNamePattern,
+  type GroupNameMatchMode,
 } from "../pattern-matching.js"
 
 function compiledAnalysis(patterns: readonly string[]) {
@@ -13,13 +16,26 @@ function compiledAnalysis(patterns: readonly string[]) {
   return result.value
 }
 
-function compiledGroup(pattern: string) {
-  const result = compileGroupNamePattern(pattern)
+function compiledGroup(
+  pattern: string,
+  mode: GroupNameMatchMode = "case-sensitive",
+) {
+  const result = compileGroupNamePattern(pattern, mode)
   assert.equal(result.ok, true)
   if (!result.ok) throw new Error("Expected group pattern to compile")
   return result.value
 }
 
+function compiledGroups(
+  patterns: readonly string[],
+  mode: GroupNameMatchMode = "case-sensitive",
+) {
+  const result = compileGroupNamePatterns(patterns, mode)
+  assert.equal(result.ok, true)
+  if (!result.ok) throw new Error("Expected group patterns to compile")
+  return result.value
+}
+
 describe("repo-name pattern matching", () => {
   it("treats undefined and empty patterns as reusable match-all predicates", () => {
     const undefinedPattern = compileRepoNamePattern(undefined)
@@ -154,3 +170,135 @@ describe("group-name pattern matching", () => {
     }
   })
 })
+
+describe("case-insensitive group-name matching", () => {
+  it("keeps the default mode case-sensitive", () => {
+    assert.equal(compiledGroup("team*")("Team A"), false)
+    assert.equal(compiledGroup("team*", "case-sensitive")("Team A"), false)
+    assert.equal(compiledGroup("Team*")("Team A"), true)
+  })
+
+  it("matches lowercase patterns against capitalized LMS group names", () => {
+    const predicate = compiledGroup("team*", "case-insensitive")
+
+    assert.equal(predicate("Team A"), true)
+    assert.equal(predicate("TEAM B"), true)
+    assert.equal(predicate("team c"), true)
+    assert.equal(predicate("Staff"), false)
+  })
+
+  it("ignores case in literal pattern characters", () => {
+    const predicate = compiledGroup("Lab-Group-1", "case-insensitive")
+
+    assert.equal(predicate("lab-group-1"), true)
+    assert.equal(predicate("LAB-GROUP-1"), true)
+    assert.equal(predicate("lab-group-2"), false)
+  })
+
+  it("ignores case inside character classes and ranges", () => {
+    assert.equal(
+      compiledGroup("team-[a-c]", "case-insensitive")("Team-B"),
+      true,
+    )
+    assert.equal(
+      compiledGroup("team-[ABC]", "case-insensitive")("team-c"),
+      true,
+    )
+    assert.equal(
+      compiledGroup("team-[!a-c]", "case-insensitive")("team-B"),
+      false,
+    )
+  })
+
+  it("ignores case for non-ASCII letters", () => {
+    const predicate = compiledGroup("équipe*", "case-insensitive")
+
+    assert.equal(predicate("Équipe 1"), true)
+    assert.equal(compiledGroup("équipe*")("Équipe 1"), false)
+  })
+
+  it("still validates malformed patterns", () => {
+    assert.deepEqual(compileGroupNamePattern("[abc", "case-insensitive"), {
+      ok: false,
+      issues: [{ path: "pattern", message: "unclosed '[' bracket" }],
+    })
+  })
+
+  it("applies the mode to every pattern in a list", () => {
+    const predicate = compiledGroups(["lab*", "project*"], "case-insensitive")
+
+    assert.equal(predicate("Lab-1"), true)
+    assert.equal(predicate("PROJECT-A"), true)
+    assert.equal(predicate("Staff"), false)
+  })
+})
+
+describe("multi-pattern group-name matching", () => {
+  it("matches a name when any pattern matches", () => {
+    const predicate = compiledGroups(["Lab*", "Project*"])
+
+    assert.equal(predicate("Lab-1"), true)
+    assert.equal(predicate("Project-A"), true)
+    assert.equal(predicate("Staff"), false)
+    assert.equal(predicate("lab-1"), false)
+  })
+
+  it("compiles an empty list to match-none", () => {
+    const predicate = compiledGroups([])
+
+    assert.equal(predicate(""), false)
+    assert.equal(predicate("Lab-1"), false)
+  })
+
+  it("matches every name when all patterns are wildcards", () => {
+    const predicate = compiledGroups(["*", "*"])
+
+    assert.equal(predicate(""), true)
+    assert.equal(predicate("Lab-1"), true)
+    assert.equal(predicate("team/one"), true)
+  })
+
+  it("reports the index of the first malformed pattern", () => {
+    const result = compileGroupNamePatterns(["Lab*", "[abc", "**"])
+
+    assert.deepEqual(result, {
+      ok: false,
+      issues: [{ path: "patterns.1", message: "unclosed '[' bracket" }],
+    })
+  })
+})
+
+describe("group-name regex matching", () => {
+  it("matches names anywhere unless anchored", () => {
+    const result = compileGroupNameRegex("^Lab-\\d+$")
+    assert.ok(result.ok)
+
+    assert.equal(result.value("Lab-12"), true)
+    assert.equal(result.value("Lab-A"), false)
+    assert.equal(result.value("Old Lab-12"), false)
+
+    const unanchored = compileGroupNameRegex("Lab")
+    assert.ok(unanchored.ok)
+    assert.equal(unanchored.value("Old Lab-12"), true)
+  })
+
+  it("honours the match mode", () => {
+    const insensitive = compileGroupNameRegex("^team", "case-insensitive")
+    const sensitive = compileGroupNameRegex("^team")
+    assert.ok(insensitive.ok)
+    assert.ok(sensitive.ok)
+
+    assert.equal(insensitive.value("Team A"), true)
+    assert.equal(sensitive.value("Team A"), false)
+  })
+
+  it("reports invalid expressions as pattern issues", () => {
+    const result = compileGroupNameRegex("Lab-(")
+
+    assert.equal(result.ok, false)
+    if (result.ok) return
+    assert.equal(result.issues.length, 1)
+    assert.equal(result.issues[0]?.path, "pattern")
+    assert.match(result.issues[0]?.message ?? "", /Invalid regular expression/)
+  })
+})
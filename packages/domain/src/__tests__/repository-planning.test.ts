@@ -6,6 +6,7 @@ import {
   planRepositoryOperation,
   preflightRepositoryOperation,
   skippedGroupsFromRepoCollisions,
+  validateRepositoryName,
 } from "../repository-planning.js"
 import { computeMembersSurnamesSlug } from "../roster.js"
 import {
@@ -570,3 +571,51 @@ describe("computeRepoName with members", () => {
     assert.equal(result, "hw.1-alice-bob")
   })
 })
+
+describe("validateRepositoryName", () => {
+  it("accepts names up to the provider length limit", () => {
+    assert.deepStrictEqual(validateRepositoryName("a".repeat(100), "github"), [])
+    assert.deepStrictEqual(validateRepositoryName("a".repeat(255), "gitlab"), [])
+    assert.deepStrictEqual(validateRepositoryName("team_1.v-2", "gitea"), [])
+  })
+
+  it("rejects names over the provider length limit", () => {
+    assert.deepStrictEqual(validateRepositoryName("a".repeat(101), "github"), [
+      "name is longer than 100 characters",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("a".repeat(101), "gitea"), [
+      "name is longer than 100 characters",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("a".repeat(256), "gitlab"), [
+      "name is longer than 255 characters",
+    ])
+  })
+
+  it("rejects empty names and reserved prefixes and suffixes", () => {
+    assert.deepStrictEqual(validateRepositoryName("", "github"), [
+      "name is empty",
+    ])
+    assert.deepStrictEqual(validateRepositoryName(".hidden", "github"), [
+      "name cannot start with '.' or '-'",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("-team", "gitea"), [
+      "name cannot start with '.' or '-'",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("team.GIT", "github"), [
+      "name cannot end with '.git'",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("feed.atom", "gitlab"), [
+      "name cannot end with '.atom'",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("feed.atom", "github"), [])
+  })
+
+  it("rejects characters outside the ASCII name alphabet", () => {
+    assert.deepStrictEqual(validateRepositoryName("équipe", "github"), [
+      "name may only contain ASCII letters, digits, '.', '-', '_'",
+    ])
+    assert.deepStrictEqual(validateRepositoryName("team a", "gitlab"), [
+      "name may only contain ASCII letters, digits, '.', '-', '_'",
+    ])
+  })
+})
@@ -1,11 +1,42 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
-import { normalizeRoster, normalizeRosterMember } from "../roster.js"
+import {
+  assignmentsForStudent,
+  groupsForStudent,
+} from "../group-selection.js"
+import {
+  activeStudents,
+  allRosterMembers,
+  diffRosters,
+  findMemberByEmail,
+  findMemberByLmsUserId,
+  findMemberByStudentNumber,
+  formatRosterDiffMarkdown,
+  hasDuplicateEmails,
+  inactiveStudents,
+  isRosterDiffEmpty,
+  membersByEnrollmentType,
+  normalizeRoster,
+  normalizeRosterMember,
+  removeRosterMember,
+  removeRosterMembers,
+  rosterDiffCsvHeaders,
+  rosterDiffCsvRows,
+  uniqueActiveStudentCount,
+} from "../roster.js"
 import {
   mergeRosterFromLms,
   mergeRosterFromLmsWithConflicts,
+  mergeRosters,
 } from "../roster-lms-merge.js"
-import type { Roster, RosterMember } from "../types.js"
+import type {
+  Assignment,
+  Group,
+  GroupSet,
+  Roster,
+  RosterMember,
+} from "../types.js"
+import { initialIdSequences } from "../types.js"
 
 describe("normalizeRosterMember", () => {
   it("uses the first non-empty name candidate and normalizes optional fields", () => {
@@ -294,6 +325,33 @@ describe("mergeRosterFromLms", () => {
     assert.equal(result.students[0].name, "Gone Student")
   })
 
+  it("keeps unmatched LMS-sourced members when the import is partial", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({
+          id: "99",
+          lmsUserId: "99",
+          name: "Unseen Student",
+          lmsStatus: "active",
+        }),
+      ],
+    })
+    const incoming = makeRoster({
+      connection: { kind: "canvas", courseId: "c1", lastUpdated: "2026-03-11" },
+    })
+
+    const result = mergeRosterFromLmsWithConflicts(
+      existing,
+      incoming,
+      initialIdSequences(),
+      { markMissingAsDropped: false },
+    )
+
+    assert.equal(result.roster.students[0].status, "active")
+    assert.equal(result.roster.students[0].lmsStatus, "active")
+    assert.equal(result.summary.membersUnchanged, 1)
+  })
+
   it("unmatched local member (lmsUserId null) left unchanged", () => {
     const existing = makeRoster({
       students: [
@@ -597,6 +655,204 @@ describe("mergeRosterFromLms", () => {
     assert.equal(result.roster.students[0].gitUsername, "legacy")
   })
 
+  it("takes the new email of a student-number match and reports it", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({
+          id: "local-3",
+          lmsUserId: null,
+          email: "ada@old.example.edu",
+          studentNumber: "s-1001",
+        }),
+        makeMember({
+          id: "local-4",
+          lmsUserId: "u-4",
+          email: "grace@example.edu",
+          studentNumber: "s-1002",
+        }),
+      ],
+    })
+    const incoming = makeRoster({
+      students: [
+        makeMember({
+          id: "lms-3",
+          lmsUserId: "u-3",
+          email: "ada@new.example.edu",
+          studentNumber: "s-1001",
+          source: "canvas",
+        }),
+        makeMember({
+          id: "lms-4",
+          lmsUserId: "u-4",
+          email: "grace@example.edu",
+          studentNumber: "s-1002",
+          source: "canvas",
+        }),
+      ],
+    })
+
+    const result = mergeRosterFromLmsWithConflicts(existing, incoming)
+
+    assert.equal(result.roster.students.length, 2)
+    assert.equal(
+      result.roster.students.find((member) => member.id === "local-3")?.email,
+      "ada@new.example.edu",
+    )
+    assert.equal(result.summary.membersAdded, 0)
+    assert.deepStrictEqual(result.summary.membersMatchedBy, {
+      lmsUserId: 1,
+      email: 0,
+      studentNumber: 1,
+    })
+    assert.deepStrictEqual(result.emailChanges, [
+      {
+        memberId: "local-3",
+        studentNumber: "s-1001",
+        previousEmail: "ada@old.example.edu",
+        email: "ada@new.example.edu",
+      },
+    ])
+  })
+
+  it("prefers student number over email with studentNumber priority", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({
+          id: "by-email",
+          email: "ada@example.com",
+          studentNumber: "s-2000",
+        }),
+        makeMember({
+          id: "by-number",
+          email: "ada.old@example.com",
+          studentNumber: "s-1001",
+        }),
+      ],
+    })
+    const incoming = makeRoster({
+      students: [
+        makeMember({
+          id: "lms-1",
+          lmsUserId: "u-1",
+          email: "ada@example.com",
+          studentNumber: "s-1001",
+          source: "canvas",
+        }),
+      ],
+    })
+
+    const byNumber = mergeRosterFromLmsWithConflicts(
+      existing,
+      incoming,
+      initialIdSequences(),
+      { matchPriority: "studentNumber" },
+    )
+    const byDefault = mergeRosterFromLmsWithConflicts(existing, incoming)
+
+    const matchedId = (result: typeof byNumber) =>
+      result.roster.students.find((member) => member.lmsUserId === "u-1")?.id
+    assert.equal(matchedId(byNumber), "by-number")
+    assert.equal(matchedId(byDefault), "by-email")
+  })
+
+  it("falls back to email when the student number is unknown", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({
+          id: "local-1",
+          email: "ada@example.com",
+          studentNumber: "s-1001",
+        }),
+      ],
+    })
+    const incoming = makeRoster({
+      students: [
+        makeMember({
+          id: "lms-1",
+          lmsUserId: "u-1",
+          email: "ada@example.com",
+          studentNumber: "s-9999",
+          source: "canvas",
+        }),
+      ],
+    })
+
+    const result = mergeRosterFromLmsWithConflicts(
+      existing,
+      incoming,
+      initialIdSequences(),
+      { matchPriority: "studentNumber" },
+    )
+
+    assert.equal(result.roster.students.length, 1)
+    assert.equal(result.roster.students[0].id, "local-1")
+    assert.equal(result.roster.students[0].studentNumber, "s-9999")
+  })
+
+  it("adds members matching neither student number nor email", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({
+          id: "local-1",
+          email: "ada@example.com",
+          studentNumber: "s-1001",
+        }),
+      ],
+    })
+    const incoming = makeRoster({
+      students: [
+        makeMember({
+          id: "lms-2",
+          lmsUserId: "u-2",
+          email: "grace@example.com",
+          studentNumber: "s-2002",
+          source: "canvas",
+        }),
+      ],
+    })
+
+    const result = mergeRosterFromLmsWithConflicts(
+      existing,
+      incoming,
+      initialIdSequences(),
+      { matchPriority: "studentNumber" },
+    )
+
+    assert.equal(result.summary.membersAdded, 1)
+    assert.equal(result.roster.students.length, 2)
+  })
+
+  it("reports conflicts when multiple members share a student number", () => {
+    const existing = makeRoster({
+      students: [
+        makeMember({ id: "s1", studentNumber: "s-1001" }),
+        makeMember({ id: "s2", studentNumber: "s-1001" }),
+      ],
+    })
+    const incoming = makeRoster({
+      students: [
+        makeMember({
+          id: "lms-1",
+          lmsUserId: "u-1",
+          email: "ada@example.com",
+          studentNumber: "s-1001",
+          source: "canvas",
+        }),
+      ],
+    })
+
+    const result = mergeRosterFromLmsWithConflicts(
+      existing,
+      incoming,
+      initialIdSequences(),
+      { matchPriority: "studentNumber" },
+    )
+
+    assert.deepStrictEqual(result.conflicts, [
+      { matchKey: "studentNumber", value: "s-1001", matchedIds: ["s1", "s2"] },
+    ])
+  })
+
   it("reports conflicts when multiple existing members match same email", () => {
     const existing = makeRoster({
       students: [
@@ -687,3 +943,976 @@ describe("mergeRosterFromLms", () => {
     )
   })
 })
+
+// ---------------------------------------------------------------------------
+// mergeRosters
+// ---------------------------------------------------------------------------
+
+function makeGroup(id: string, memberIds: string[]): Group {
+  return { id, name: id, memberIds, origin: "local", lmsGroupId: null }
+}
+
+function makeNamedGroupSet(id: string, groupIds: string[]): GroupSet {
+  return {
+    id,
+    name: id,
+    connection: null,
+    repoNameTemplate: null,
+    columnVisibility: {},
+    columnSizing: {},
+    nameMode: "named",
+    groupIds,
+  }
+}
+
+describe("mergeRosters", () => {
+  it("returns the left roster unchanged when the right roster is empty", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", email: "a@example.com" })],
+    })
+
+    const result = mergeRosters(left, makeRoster())
+
+    assert.deepStrictEqual(result.roster, left)
+    assert.deepStrictEqual(result.conflicts, [])
+  })
+
+  it("appends right-only members with newly allocated ids", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", name: "Ada", email: "a@example.com" })],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", name: "Grace", email: "g@example.com" }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(
+      result.roster.students.map((member) => [member.id, member.name]),
+      [
+        ["a", "Ada"],
+        ["m_0001", "Grace"],
+      ],
+    )
+    assert.equal(result.idSequences.nextMemberSeq, 2)
+  })
+
+  it("continues from the provided id sequences", () => {
+    const right = makeRoster({
+      students: [makeMember({ id: "x", email: "x@example.com" })],
+    })
+
+    const result = mergeRosters(makeRoster(), right, {
+      ...initialIdSequences(),
+      nextMemberSeq: 40,
+    })
+
+    assert.equal(result.roster.students[0]?.id, "m_0040")
+    assert.equal(result.idSequences.nextMemberSeq, 41)
+  })
+
+  it("matches members by lmsUserId and keeps the left-hand id", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", lmsUserId: "42", name: "Old" })],
+    })
+    const right = makeRoster({
+      students: [makeMember({ id: "x", lmsUserId: "42", name: "New" })],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.equal(result.roster.students.length, 1)
+    assert.equal(result.roster.students[0]?.id, "a")
+    assert.equal(result.roster.students[0]?.name, "New")
+  })
+
+  it("matches members by email case-insensitively", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", email: "Ada@Example.com" })],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "ada@example.com", lmsUserId: "42" }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.equal(result.roster.students.length, 1)
+    assert.equal(result.roster.students[0]?.lmsUserId, "42")
+    assert.deepStrictEqual(result.conflicts, [])
+  })
+
+  it("preserves local fields on matched members", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({
+          id: "a",
+          email: "a@example.com",
+          gitUsername: "ada",
+          gitUsernameStatus: "valid",
+          department: "CS",
+          institution: "Uni",
+        }),
+      ],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "a@example.com", source: "canvas" }),
+      ],
+    })
+
+    const [merged] = mergeRosters(left, right).roster.students
+
+    assert.equal(merged?.gitUsername, "ada")
+    assert.equal(merged?.gitUsernameStatus, "valid")
+    assert.equal(merged?.department, "CS")
+    assert.equal(merged?.institution, "Uni")
+    assert.equal(merged?.source, "canvas")
+  })
+
+  it("keeps the left email and student number when the right omits them", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({
+          id: "a",
+          lmsUserId: "42",
+          email: "a@example.com",
+          studentNumber: "S-1",
+        }),
+      ],
+    })
+    const right = makeRoster({
+      students: [makeMember({ id: "x", lmsUserId: "42" })],
+    })
+
+    const [merged] = mergeRosters(left, right).roster.students
+
+    assert.equal(merged?.email, "a@example.com")
+    assert.equal(merged?.studentNumber, "S-1")
+  })
+
+  it("keeps a manual drop on a matched member", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({
+          id: "a",
+          email: "a@example.com",
+          status: "dropped",
+          lmsStatus: "active",
+        }),
+      ],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "a@example.com", lmsStatus: "active" }),
+      ],
+    })
+
+    const [merged] = mergeRosters(left, right).roster.students
+
+    assert.equal(merged?.status, "dropped")
+  })
+
+  it("reports an email match with different lmsUserIds as a conflict", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({ id: "a", email: "a@example.com", lmsUserId: "1" }),
+      ],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "A@example.com", lmsUserId: "2" }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.conflicts, [
+      {
+        email: "a@example.com",
+        leftMemberId: "a",
+        leftLmsUserId: "1",
+        rightMemberId: "x",
+        rightLmsUserId: "2",
+      },
+    ])
+    assert.deepStrictEqual(result.roster.students, left.students)
+  })
+
+  it("does not report a conflict when only one side has an lmsUserId", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", email: "a@example.com" })],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "a@example.com", lmsUserId: "2" }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.conflicts, [])
+    assert.equal(result.roster.students[0]?.lmsUserId, "2")
+  })
+
+  it("prefers an lmsUserId match over a different email match", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({ id: "a", email: "a@example.com", lmsUserId: "1" }),
+        makeMember({ id: "b", email: "b@example.com", lmsUserId: "2" }),
+      ],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "b@example.com", lmsUserId: "1" }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.conflicts, [])
+    assert.equal(
+      result.roster.students.find((member) => member.id === "a")?.email,
+      "b@example.com",
+    )
+  })
+
+  it("moves matched members between students and staff by enrollment type", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", email: "a@example.com" })],
+    })
+    const right = makeRoster({
+      staff: [
+        makeMember({
+          id: "x",
+          email: "a@example.com",
+          enrollmentType: "ta",
+        }),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.roster.students, [])
+    assert.deepStrictEqual(
+      result.roster.staff.map((member) => member.id),
+      ["a"],
+    )
+  })
+
+  it("unions group sets and keeps the left-hand version on id collisions", () => {
+    const left = makeRoster({
+      groupSets: [makeNamedGroupSet("gs-1", ["g-left"])],
+    })
+    const right = makeRoster({
+      groupSets: [
+        makeNamedGroupSet("gs-1", ["g-right"]),
+        makeNamedGroupSet("gs-2", []),
+      ],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.roster.groupSets, [
+      makeNamedGroupSet("gs-1", ["g-left"]),
+      makeNamedGroupSet("gs-2", []),
+    ])
+  })
+
+  it("rewrites right-hand group memberships to merged member ids", () => {
+    const left = makeRoster({
+      students: [makeMember({ id: "a", email: "a@example.com" })],
+      groups: [makeGroup("g-1", ["a"])],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "a@example.com" }),
+        makeMember({ id: "y", email: "new@example.com" }),
+      ],
+      groups: [makeGroup("g-1", ["x"]), makeGroup("g-2", ["x", "y"])],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.roster.groups, [
+      makeGroup("g-1", ["a"]),
+      makeGroup("g-2", ["a", "m_0001"]),
+    ])
+  })
+
+  it("drops conflicted members from right-hand group memberships", () => {
+    const left = makeRoster({
+      students: [
+        makeMember({ id: "a", email: "a@example.com", lmsUserId: "1" }),
+      ],
+    })
+    const right = makeRoster({
+      students: [
+        makeMember({ id: "x", email: "a@example.com", lmsUserId: "2" }),
+      ],
+      groups: [makeGroup("g-2", ["x"])],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.roster.groups, [makeGroup("g-2", [])])
+  })
+
+  it("unions assignments and keeps the left connection", () => {
+    const connection = {
+      kind: "canvas" as const,
+      courseId: "c1",
+      lastUpdated: "2026-03-11",
+    }
+    const assignment = (id: string, groupSetId: string) => ({
+      id,
+      name: id,
+      groupSetId,
+      repositories: {},
+    })
+    const left = makeRoster({
+      connection,
+      assignments: [assignment("a1", "gs-1")],
+    })
+    const right = makeRoster({
+      connection: null,
+      assignments: [assignment("a1", "gs-other"), assignment("a2", "gs-2")],
+    })
+
+    const result = mergeRosters(left, right)
+
+    assert.deepStrictEqual(result.roster.connection, connection)
+    assert.deepStrictEqual(result.roster.assignments, [
+      assignment("a1", "gs-1"),
+      assignment("a2", "gs-2"),
+    ])
+  })
+})
+
+describe("roster queries", () => {
+  it("returns empty lists for an empty roster", () => {
+    const roster = makeRoster()
+
+    assert.deepStrictEqual(allRosterMembers(roster), [])
+    assert.deepStrictEqual(activeStudents(roster), [])
+    assert.deepStrictEqual(inactiveStudents(roster), [])
+  })
+
+  it("never reports staff as active or inactive students", () => {
+    const roster = makeRoster({
+      staff: [
+        makeMember({ id: "t1", enrollmentType: "teacher" }),
+        makeMember({ id: "t2", enrollmentType: "ta", status: "dropped" }),
+      ],
+    })
+
+    assert.deepStrictEqual(
+      allRosterMembers(roster).map((member) => member.id),
+      ["t1", "t2"],
+    )
+    assert.deepStrictEqual(activeStudents(roster), [])
+    assert.deepStrictEqual(inactiveStudents(roster), [])
+  })
+
+  it("partitions students by status and lists students before staff", () => {
+    const roster = makeRoster({
+      students: [
+        makeMember({ id: "s1" }),
+        makeMember({ id: "s2", status: "dropped" }),
+        makeMember({ id: "s3", status: "incomplete" }),
+      ],
+      staff: [makeMember({ id: "t1", enrollmentType: "teacher" })],
+    })
+
+    assert.deepStrictEqual(
+      activeStudents(roster).map((member) => member.id),
+      ["s1"],
+    )
+    assert.deepStrictEqual(
+      inactiveStudents(roster).map((member) => member.id),
+      ["s2", "s3"],
+    )
+    assert.deepStrictEqual(
+      allRosterMembers(roster).map((member) => member.id),
+      ["s1", "s2", "s3", "t1"],
+    )
+  })
+
+  it("finds students and staff by email ignoring case and whitespace", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1", email: "Ada@Example.com" })],
+      staff: [makeMember({ id: "t1", email: "grace@example.com" })],
+    })
+
+    assert.equal(findMemberByEmail(roster, " ada@example.COM ")?.id, "s1")
+    assert.equal(findMemberByEmail(roster, "GRACE@example.com")?.id, "t1")
+    assert.equal(findMemberByEmail(roster, "nobody@example.com"), undefined)
+
+    const withoutEmail = makeRoster({ students: [makeMember({ id: "s2" })] })
+    assert.equal(findMemberByEmail(withoutEmail, ""), undefined)
+  })
+
+  it("finds members by LMS user id", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1", lmsUserId: "42" })],
+      staff: [makeMember({ id: "t1", lmsUserId: "7" })],
+    })
+
+    assert.equal(findMemberByLmsUserId(roster, "42")?.id, "s1")
+    assert.equal(findMemberByLmsUserId(roster, " 7 ")?.id, "t1")
+    assert.equal(findMemberByLmsUserId(roster, "99"), undefined)
+  })
+
+  it("finds members by student number", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1", studentNumber: "s-1001" })],
+      staff: [makeMember({ id: "t1", studentNumber: "t-7" })],
+    })
+
+    assert.equal(findMemberByStudentNumber(roster, "s-1001")?.id, "s1")
+    assert.equal(findMemberByStudentNumber(roster, " t-7 ")?.id, "t1")
+    assert.equal(findMemberByStudentNumber(roster, "s-9999"), undefined)
+    assert.equal(findMemberByStudentNumber(roster, ""), undefined)
+  })
+
+  it("sees student numbers edited in place", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1", studentNumber: "s-1001" })],
+    })
+    assert.equal(findMemberByStudentNumber(roster, "s-1001")?.id, "s1")
+
+    roster.students[0].studentNumber = "s-2002"
+    roster.students.push(makeMember({ id: "s2", studentNumber: "s-1001" }))
+
+    assert.equal(findMemberByStudentNumber(roster, "s-2002")?.id, "s1")
+    assert.equal(findMemberByStudentNumber(roster, "s-1001")?.id, "s2")
+  })
+})
+
+describe("roster enrollment counts", () => {
+  // A head TA who is also enrolled in the course as a student.
+  function headTa(overrides: Partial<RosterMember> = {}): RosterMember[] {
+    return [
+      makeMember({ id: "m-ta", email: "ta@example.com", ...overrides }),
+      makeMember({
+        id: "m-ta",
+        email: "ta@example.com",
+        enrollmentType: "ta",
+        ...overrides,
+      }),
+    ]
+  }
+
+  it("counts students listed once per section a single time", () => {
+    const roster = makeRoster({
+      students: [
+        makeMember({ id: "s1", enrollmentDisplay: "Section A" }),
+        makeMember({ id: "s1", enrollmentDisplay: "Section B" }),
+        makeMember({ id: "s2" }),
+      ],
+    })
+
+    assert.equal(uniqueActiveStudentCount(roster), 2)
+  })
+
+  it("leaves inactive students and staff out of the unique count", () => {
+    const [student, staff] = headTa()
+    const roster = makeRoster({
+      students: [
+        student,
+        makeMember({ id: "s2", status: "dropped" }),
+        makeMember({ id: "s3", status: "incomplete" }),
+      ],
+      staff: [staff, makeMember({ id: "t1", enrollmentType: "teacher" })],
+    })
+
+    assert.equal(uniqueActiveStudentCount(roster), 1)
+    assert.equal(uniqueActiveStudentCount(makeRoster()), 0)
+  })
+
+  it("lists students and staff with the requested enrollment type", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1" }), makeMember({ id: "s2" })],
+      staff: [
+        makeMember({ id: "t1", enrollmentType: "teacher" }),
+        makeMember({ id: "t2", enrollmentType: "ta" }),
+      ],
+    })
+
+    assert.deepStrictEqual(
+      membersByEnrollmentType(roster, "student").map((member) => member.id),
+      ["s1", "s2"],
+    )
+    assert.deepStrictEqual(
+      membersByEnrollmentType(roster, "teacher").map((member) => member.id),
+      ["t1"],
+    )
+    assert.deepStrictEqual(membersByEnrollmentType(roster, "observer"), [])
+  })
+
+  it("lists a TA enrolled as a student under both enrollment types", () => {
+    const [student, staff] = headTa()
+    const roster = makeRoster({ students: [student], staff: [staff] })
+
+    assert.deepStrictEqual(
+      membersByEnrollmentType(roster, "student").map((member) => member.id),
+      ["m-ta"],
+    )
+    assert.deepStrictEqual(
+      membersByEnrollmentType(roster, "ta").map((member) => member.id),
+      ["m-ta"],
+    )
+  })
+
+  it("detects active members sharing an email ignoring case", () => {
+    const roster = makeRoster({
+      students: [makeMember({ id: "s1", email: "Ada@Example.com" })],
+      staff: [makeMember({ id: "t1", email: " ada@example.com " })],
+    })
+
+    assert.equal(hasDuplicateEmails(roster), true)
+  })
+
+  it("does not treat a TA enrolled as a student as a duplicate email", () => {
+    const [student, staff] = headTa()
+    const roster = makeRoster({
+      students: [student, makeMember({ id: "s2", email: "s2@example.com" })],
+      staff: [staff],
+    })
+
+    assert.equal(hasDuplicateEmails(roster), false)
+  })
+
+  it("ignores missing emails when looking for duplicates", () => {
+    const roster = makeRoster({
+      students: [
+        makeMember({ id: "s1" }),
+        makeMember({ id: "s2", email: "  " }),
+      ],
+    })
+
+    assert.equal(hasDuplicateEmails(roster), false)
+  })
+
+  it("ignores inactive members when looking for duplicate emails", () => {
+    const roster = makeRoster({
+      students: [
+        makeMember({ id: "s1", email: "ada@example.com" }),
+        makeMember({ id: "s2", email: "ada@example.com", status: "dropped" }),
+      ],
+    })
+
+    assert.equal(hasDuplicateEmails(roster), false)
+  })
+})
+
+describe("student assignment queries", () => {
+  function makeAssignment(id: string, groupSetId: string): Assignment {
+    return { id, name: id, groupSetId, repositories: {} }
+  }
+
+  function makeCoverageRoster(): Roster {
+    return makeRoster({
+      students: [
+        makeMember({ id: "s1", gitUsername: "ada" }),
+        makeMember({ id: "s2", gitUsername: "grace" }),
+      ],
+      groups: [
+        makeGroup("g1", ["s1", "s2"]),
+        makeGroup("g2", ["s2"]),
+        makeGroup("g3", ["s1"]),
+      ],
+      groupSets: [
+        makeNamedGroupSet("gs1", ["g1"]),
+        makeNamedGroupSet("gs2", ["g2"]),
+        makeNamedGroupSet("gs3", ["g3"]),
+      ],
+      assignments: [
+        makeAssignment("a1", "gs1"),
+        makeAssignment("a2", "gs2"),
+        makeAssignment("a3", "gs3"),
+      ],
+    })
+  }
+
+  it("lists the assignments a student has a group in", () => {
+    const roster = makeCoverageRoster()
+
+    assert.deepStrictEqual(
+      assignmentsForStudent(roster, "s1").map((assignment) => assignment.id),
+      ["a1", "a3"],
+    )
+    assert.deepStrictEqual(
+      assignmentsForStudent(roster, "s2").map((assignment) => assignment.id),
+      ["a1", "a2"],
+    )
+  })
+
+  it("pairs each assignment with the student's group", () => {
+    const pairs = groupsForStudent(makeCoverageRoster(), "s1")
+
+    assert.deepStrictEqual(
+      pairs.map(({ assignment, group }) => [assignment.id, group.id]),
+      [
+        ["a1", "g1"],
+        ["a3", "g3"],
+      ],
+    )
+  })
+
+  it("returns nothing for unknown members", () => {
+    const roster = makeCoverageRoster()
+
+    assert.deepStrictEqual(groupsForStudent(roster, "x"), [])
+    assert.deepStrictEqual(assignmentsForStudent(roster, "x"), [])
+  })
+
+  it("matches unnamed teams by Git username", () => {
+    const roster = makeCoverageRoster()
+    roster.groupSets.push({
+      id: "gs4",
+      name: "gs4",
+      connection: null,
+      repoNameTemplate: null,
+      columnVisibility: {},
+      columnSizing: {},
+      nameMode: "unnamed",
+      teams: [
+        { id: "t1", gitUsernames: ["grace", "linus"] },
+        { id: "t2", gitUsernames: ["ada"] },
+      ],
+    })
+    roster.assignments.push(makeAssignment("a4", "gs4"))
+
+    assert.deepStrictEqual(
+      groupsForStudent(roster, "s2").map(({ assignment, group }) => [
+        assignment.id,
+        group.name,
+      ]),
+      [
+        ["a1", "g1"],
+        ["a2", "g2"],
+        ["a4", "grace-linus"],
+      ],
+    )
+  })
+})
+
+describe("diffRosters", () => {
+  const ada = makeMember({
+    id: "m1",
+    name: "Ada Lovelace",
+    email: "ada@example.com",
+    lmsUserId: "101",
+  })
+  const grace = makeMember({
+    id: "m2",
+    name: "Grace Hopper",
+    email: "grace@example.com",
+    lmsUserId: "102",
+  })
+
+  it("reports no changes for identical rosters", () => {
+    const roster = makeRoster({ students: [ada, grace] })
+
+    const diff = diffRosters(roster, structuredClone(roster))
+
+    assert.ok(isRosterDiffEmpty(diff))
+    assert.equal(formatRosterDiffMarkdown(diff), "No roster changes.\n")
+    assert.deepStrictEqual(rosterDiffCsvRows(diff), [])
+  })
+
+  it("lists new enrollments as added", () => {
+    const diff = diffRosters(
+      makeRoster({ students: [ada] }),
+      makeRoster({ students: [ada, grace] }),
+    )
+
+    assert.deepStrictEqual(diff.added, [grace])
+    assert.deepStrictEqual(diff.removed, [])
+  })
+
+  it("lists dropped students as removed", () => {
+    const diff = diffRosters(
+      makeRoster({ students: [ada, grace] }),
+      makeRoster({ students: [grace] }),
+    )
+
+    assert.deepStrictEqual(diff.added, [])
+    assert.deepStrictEqual(diff.removed, [ada])
+  })
+
+  it("pairs members by id and reports name changes", () => {
+    const renamed = { ...ada, name: "Ada King" }
+
+    const diff = diffRosters(
+      makeRoster({ students: [ada] }),
+      makeRoster({ students: [renamed] }),
+    )
+
+    assert.deepStrictEqual(diff.nameChanged, [{ before: ada, after: renamed }])
+    assert.deepStrictEqual(diff.emailChanged, [])
+  })
+
+  it("reports email changes but ignores case and whitespace", () => {
+    const moved = { ...ada, email: "ada@uni.example.com" }
+    const recased = { ...grace, email: " Grace@Example.com" }
+
+    const diff = diffRosters(
+      makeRoster({ students: [ada, grace] }),
+      makeRoster({ students: [moved, recased] }),
+    )
+
+    assert.deepStrictEqual(diff.emailChanged, [{ before: ada, after: moved }])
+  })
+
+  it("falls back to the LMS user id when ids differ", () => {
+    const reimported = { ...ada, id: "m9", name: "Ada King" }
+
+    const diff = diffRosters(
+      makeRoster({ students: [ada] }),
+      makeRoster({ students: [reimported] }),
+    )
+
+    assert.deepStrictEqual(diff.added, [])
+    assert.deepStrictEqual(diff.removed, [])
+    assert.deepStrictEqual(diff.nameChanged, [
+      { before: ada, after: reimported },
+    ])
+  })
+
+  it("does not pair members without an LMS user id", () => {
+    const local = makeMember({ id: "m1", name: "Local" })
+    const other = makeMember({ id: "m2", name: "Other" })
+
+    const diff = diffRosters(
+      makeRoster({ students: [local] }),
+      makeRoster({ students: [other] }),
+    )
+
+    assert.deepStrictEqual(diff.added, [other])
+    assert.deepStrictEqual(diff.removed, [local])
+  })
+
+  it("includes staff members", () => {
+    const teacher = makeMember({
+      id: "t1",
+      name: "Alan Turing",
+      enrollmentType: "teacher",
+    })
+
+    const diff = diffRosters(
+      makeRoster({ students: [ada] }),
+      makeRoster({ students: [ada], staff: [teacher] }),
+    )
+
+    assert.deepStrictEqual(diff.added, [teacher])
+  })
+
+  it("renders Markdown sections for non-empty changes only", () => {
+    const diff = diffRosters(
+      makeRoster({ students: [ada, grace] }),
+      makeRoster({
+        students: [
+          { ...ada, email: "" },
+          makeMember({ id: "m3", name: "Linus" }),
+        ],
+      }),
+    )
+
+    assert.equal(
+      formatRosterDiffMarkdown(diff),
+      [
+        "## Added (1)",
+        "",
+        "- Linus",
+        "",
+        "## Removed (1)",
+        "",
+        "- Grace Hopper <grace@example.com>",
+        "",
+        "## Email changes (1)",
+        "",
+        "- Ada Lovelace: ada@example.com → (none)",
+        "",
+      ].join("\n"),
+    )
+  })
+
+  it("writes one CSV row per change", () => {
+    const diff = diffRosters(
+      makeRoster({ students: [ada] }),
+      makeRoster({
+        students: [{ ...ada, name: "Ada King", email: "ada@king.example" }],
+      }),
+    )
+
+    assert.equal(rosterDiffCsvHeaders.length, 6)
+    assert.deepStrictEqual(rosterDiffCsvRows(diff), [
+      [
+        "name_changed",
+        "m1",
+        "Ada King",
+        "ada@king.example",
+        "Ada Lovelace",
+        "ada@example.com",
+      ],
+      [
+        "email_changed",
+        "m1",
+        "Ada King",
+        "ada@king.example",
+        "Ada Lovelace",
+        "ada@example.com",
+      ],
+    ])
+  })
+})
+
+describe("removeRosterMembers", () => {
+  function makeRemovalRoster(): Roster {
+    return makeRoster({
+      students: [
+        makeMember({ id: "s1" }),
+        makeMember({ id: "s2" }),
+        makeMember({ id: "s3" }),
+      ],
+      staff: [makeMember({ id: "t1", enrollmentType: "teacher" })],
+      groups: [
+        makeGroup("g1", ["s1", "s2"]),
+        makeGroup("g2", ["s2", "s3"]),
+        makeGroup("g3", ["s3", "t1"]),
+      ],
+      groupSets: [
+        makeNamedGroupSet("gs1", ["g1", "g2"]),
+        makeNamedGroupSet("gs2", ["g3"]),
+      ],
+    })
+  }
+
+  it("deactivates a member and keeps their group memberships", () => {
+    const roster = makeRemovalRoster()
+    const { roster: next, removal } = removeRosterMember(
+      roster,
+      "s2",
+      "deactivate",
+    )
+
+    assert.deepStrictEqual(removal, {
+      memberId: "s2",
+      found: true,
+      affectedGroupIds: ["g1", "g2"],
+    })
+    assert.equal(next.students.find((m) => m.id === "s2")?.status, "dropped")
+    assert.deepStrictEqual(next.groups, roster.groups)
+  })
+
+  it("purges a member from the roster and from every group", () => {
+    const roster = makeRemovalRoster()
+    const { roster: next, removal } = removeRosterMember(roster, "s3", "purge")
+
+    assert.deepStrictEqual(removal.affectedGroupIds, ["g2", "g3"])
+    assert.deepStrictEqual(next.students.map((m) => m.id), ["s1", "s2"])
+    assert.deepStrictEqual(
+      next.groups.map((group) => group.memberIds),
+      [["s1", "s2"], ["s2"], ["t1"]],
+    )
+    assert.deepStrictEqual(next.groupSets, roster.groupSets)
+  })
+
+  it("purges staff members the same way as students", () => {
+    const { roster: next, removal } = removeRosterMember(
+      makeRemovalRoster(),
+      "t1",
+      "purge",
+    )
+
+    assert.deepStrictEqual(removal.affectedGroupIds, ["g3"])
+    assert.deepStrictEqual(next.staff, [])
+    assert.deepStrictEqual(next.groups[2]?.memberIds, ["s3"])
+  })
+
+  it("reports unknown ids as not found and leaves the roster untouched", () => {
+    const roster = makeRemovalRoster()
+    const { roster: next, removal } = removeRosterMember(roster, "x", "purge")
+
+    assert.deepStrictEqual(removal, {
+      memberId: "x",
+      found: false,
+      affectedGroupIds: [],
+    })
+    assert.equal(next, roster)
+  })
+
+  it("reports an empty affected list for members outside any group", () => {
+    const roster = makeRoster({ students: [makeMember({ id: "s1" })] })
+    const { removal } = removeRosterMember(roster, "s1", "deactivate")
+
+    assert.equal(removal.found, true)
+    assert.deepStrictEqual(removal.affectedGroupIds, [])
+  })
+
+  it("does not mutate the input roster", () => {
+    const roster = makeRemovalRoster()
+    const snapshot = structuredClone(roster)
+
+    removeRosterMembers(roster, ["s1", "s2"], "purge")
+    removeRosterMembers(roster, ["s1", "s2"], "deactivate")
+
+    assert.deepStrictEqual(roster, snapshot)
+  })
+
+  it("removes a batch and reports one removal per id in request order", () => {
+    const { roster: next, removals } = removeRosterMembers(
+      makeRemovalRoster(),
+      ["s3", "x", "s1"],
+      "purge",
+    )
+
+    assert.deepStrictEqual(removals, [
+      { memberId: "s3", found: true, affectedGroupIds: ["g2", "g3"] },
+      { memberId: "x", found: false, affectedGroupIds: [] },
+      { memberId: "s1", found: true, affectedGroupIds: ["g1"] },
+    ])
+    assert.deepStrictEqual(next.students.map((m) => m.id), ["s2"])
+    assert.deepStrictEqual(
+      next.groups.map((group) => group.memberIds),
+      [["s2"], ["s2"], ["t1"]],
+    )
+  })
+
+  it("reports duplicate ids in a batch once", () => {
+    const { removals } = removeRosterMembers(
+      makeRemovalRoster(),
+      ["s1", "s1"],
+      "deactivate",
+    )
+
+    assert.deepStrictEqual(removals.map((removal) => removal.memberId), ["s1"])
+  })
+
+  it("deactivates a batch without touching other members", () => {
+    const { roster: next } = removeRosterMembers(
+      makeRemovalRoster(),
+      ["s1", "t1"],
+      "deactivate",
+    )
+
+    assert.deepStrictEqual(
+      next.students.map((m) => m.status),
+      ["dropped", "active", "active"],
+    )
+    assert.equal(next.staff[0]?.status, "dropped")
+  })
+
+  it("returns the same roster for an empty batch", () => {
+    const roster = makeRemovalRoster()
+    const result = removeRosterMembers(roster, [], "purge")
+
+    assert.equal(result.roster, roster)
+    assert.deepStrictEqual(result.removals, [])
+  })
+})
@@ -463,4 +463,52 @@ describe("validatePersistedCourse", () => {
       )
     }
   })
+  it("validates assignment deadlines as ISO 8601 timestamps", () => {
+    const withDeadline = (deadline: string) => ({
+      ...validProfile,
+      roster: {
+        ...validProfile.roster,
+        groupSets: [
+          {
+            id: "gs_0001",
+            name: "Teams",
+            nameMode: "named",
+            groupIds: [],
+            connection: null,
+            repoNameTemplate: null,
+            columnVisibility: {},
+            columnSizing: {},
+          },
+        ],
+        assignments: [
+          {
+            id: "a_0001",
+            name: "Project 1",
+            groupSetId: "gs_0001",
+            deadline,
+            repositories: {},
+          },
+        ],
+      },
+    })
+
+    const accepted = validatePersistedCourse(
+      withDeadline("2026-05-01T23:59:00+02:00"),
+    )
+    assert.equal(accepted.ok, true)
+    if (accepted.ok) {
+      assert.equal(
+        accepted.value.roster.assignments[0]?.deadline,
+        "2026-05-01T23:59:00+02:00",
+      )
+    }
+
+    const rejected = validatePersistedCourse(withDeadline("next friday"))
+    assert.equal(rejected.ok, false)
+    if (!rejected.ok) {
+      assert.ok(
+        rejected.issues.some((issue) => issue.path.includes("deadline")),
+      )
+    }
+  })
 })
@@ -113,6 +113,48 @@ describe("validatePersistedAppSettings", () => {
     }
   })
 
+  it("defaults the group selection to all groups", () => {
+    const { groupSelection: _groupSelection, ...legacy } = defaultAppSettings
+    void _groupSelection
+    const result = validatePersistedAppSettings(legacy)
+    assert.equal(result.ok, true)
+    if (result.ok) {
+      assert.deepStrictEqual(result.value.groupSelection, {
+        mode: { kind: "all" },
+        matchCase: true,
+        useRegex: false,
+      })
+    }
+  })
+
+  it("roundtrips multi-pattern group selections with commas", () => {
+    const groupSelection = {
+      mode: { kind: "multi-pattern", patterns: ["Lab, Section *", "Exam"] },
+      matchCase: false,
+      useRegex: false,
+    }
+    const result = validatePersistedAppSettings({
+      ...defaultAppSettings,
+      groupSelection,
+    })
+    assert.equal(result.ok, true)
+    if (result.ok) {
+      assert.deepStrictEqual(result.value.groupSelection, groupSelection)
+    }
+  })
+
+  it("rejects a multi-pattern group selection without patterns", () => {
+    const result = validatePersistedAppSettings({
+      ...defaultAppSettings,
+      groupSelection: {
+        mode: { kind: "multi-pattern", patterns: [] },
+        matchCase: true,
+        useRegex: false,
+      },
+    })
+    assert.equal(result.ok, false)
+  })
+
   it("rejects non-object input", () => {
     const result = validatePersistedAppSettings("not an object")
     assert.equal(result.ok, false)
@@ -14,8 +14,11 @@ import { initialIdSequences } from "../types.js"
 import {
   blockingIssues,
   hasBlockingIssues,
+  isBlockingValidationKind,
+  normalizeRequiredFiles,
   validateAssignment,
   validateAssignmentWithTemplate,
+  validateRequiredTemplateFiles,
   validateRoster,
   warningIssues,
 } from "../validation.js"
@@ -348,4 +351,218 @@ describe("validateAssignment", () => {
       false,
     )
   })
+  describe("deadlines", () => {
+    const now = new Date("2026-05-02T12:00:00Z")
+
+    function withDeadline(deadline: string | null | undefined): {
+      roster: Roster
+      assignment: Assignment
+    } {
+      const { roster, assignment } = makeAssignmentFixture()
+      const dated = { ...assignment, deadline }
+      return { roster: { ...roster, assignments: [dated] }, assignment: dated }
+    }
+
+    function deadlineIssues(result: { issues: { kind: string }[] }) {
+      return result.issues.filter(
+        (issue) => issue.kind === "assignment_deadline_passed",
+      )
+    }
+
+    it("warns when the deadline has passed", () => {
+      const { roster, assignment } = withDeadline("2026-05-01T23:59:00Z")
+
+      const result = validateAssignment(roster, assignment.id, "email", { now })
+
+      assert.deepStrictEqual(deadlineIssues(result), [
+        {
+          kind: "assignment_deadline_passed",
+          affectedIds: ["a1"],
+          context: "2026-05-01T23:59:00Z",
+        },
+      ])
+      assert.equal(
+        blockingIssues(result).some(
+          (issue) => issue.kind === "assignment_deadline_passed",
+        ),
+        false,
+      )
+    })
+
+    it("does not warn before the deadline", () => {
+      const { roster, assignment } = withDeadline("2026-05-03T00:00:00Z")
+
+      const result = validateAssignment(roster, assignment.id, "email", { now })
+
+      assert.deepStrictEqual(deadlineIssues(result), [])
+    })
+
+    it("compares deadlines with offsets as instants", () => {
+      const { roster, assignment } = withDeadline("2026-05-02T13:30:00+02:00")
+
+      const result = validateAssignment(roster, assignment.id, "email", { now })
+
+      assert.equal(deadlineIssues(result).length, 1)
+    })
+
+    it("skips the check without a deadline or reference time", () => {
+      const undated = withDeadline(null)
+      const dated = withDeadline("2026-05-01T23:59:00Z")
+
+      assert.deepStrictEqual(
+        deadlineIssues(
+          validateAssignment(undated.roster, undated.assignment.id, "email", {
+            now,
+          }),
+        ),
+        [],
+      )
+      assert.deepStrictEqual(
+        deadlineIssues(
+          validateAssignment(dated.roster, dated.assignment.id, "email"),
+        ),
+        [],
+      )
+    })
+
+    it("warns for assignments on unnamed group sets", () => {
+      const assignment: Assignment = {
+        id: "a1",
+        name: "Project 1",
+        groupSetId: "gs-unnamed",
+        deadline: "2026-05-01T23:59:00Z",
+        repositories: {},
+      }
+      const roster = makeRoster({
+        groupSets: [
+          {
+            id: "gs-unnamed",
+            name: "RepoBee Teams",
+            nameMode: "unnamed",
+            teams: [{ id: "ut_0001", gitUsernames: ["alice"] }],
+            connection: null,
+            repoNameTemplate: null,
+            columnVisibility: {},
+            columnSizing: {},
+          },
+        ],
+        assignments: [assignment],
+      })
+
+      const result = validateAssignmentWithTemplate(
+        roster,
+        assignment.id,
+        "username",
+        "{members}",
+        { now },
+      )
+
+      assert.equal(deadlineIssues(result).length, 1)
+    })
+  })
+  it("warns when the assignment's LMS group set sync is stale", () => {
+    const { roster, assignment } = makeAssignmentFixture()
+    const now = new Date("2026-05-02T12:00:00Z")
+    const staleRoster: Roster = {
+      ...roster,
+      groupSets: roster.groupSets.map((groupSet) => ({
+        ...groupSet,
+        connection: {
+          kind: "moodle",
+          courseId: "c1",
+          groupingId: "7",
+          lastUpdated: "2026-05-01T11:00:00Z",
+        },
+      })),
+    }
+
+    const stale = validateAssignment(staleRoster, assignment.id, "email", {
+      now,
+    })
+    const relaxed = validateAssignment(staleRoster, assignment.id, "email", {
+      now,
+      lmsSyncMaxAgeHours: 48,
+    })
+
+    assert.deepStrictEqual(
+      stale.issues.filter((issue) => issue.kind === "stale_lms_group_set"),
+      [
+        {
+          kind: "stale_lms_group_set",
+          affectedIds: ["gs1"],
+          context: "Projects",
+        },
+      ],
+    )
+    assert.equal(
+      relaxed.issues.some((issue) => issue.kind === "stale_lms_group_set"),
+      false,
+    )
+    assert.equal(
+      blockingIssues(stale).some(
+        (issue) => issue.kind === "stale_lms_group_set",
+      ),
+      false,
+    )
+  })
+})
+
+describe("required template files", () => {
+  const assignment: Assignment = {
+    id: "a1",
+    name: "Project 1",
+    groupSetId: "gs1",
+    repositories: {},
+    requiredFiles: ["README.md", "tests/test_main.py"],
+  }
+
+  it("normalizes separators, leading dots and duplicates", () => {
+    assert.deepStrictEqual(
+      normalizeRequiredFiles([
+        " README.md ",
+        "./tests\\test_main.py",
+        "/README.md",
+        "",
+        "tests/test_main.py",
+      ]),
+      ["README.md", "tests/test_main.py"],
+    )
+  })
+
+  it("reports each required file missing from the template", () => {
+    const issues = validateRequiredTemplateFiles(
+      assignment,
+      ["README.md", "tests/test_main.py", "Makefile"],
+      new Set(["README.md"]),
+    )
+
+    assert.deepStrictEqual(issues, [
+      {
+        kind: "missing_required_file",
+        affectedIds: ["a1"],
+        context: "tests/test_main.py",
+      },
+      {
+        kind: "missing_required_file",
+        affectedIds: ["a1"],
+        context: "Makefile",
+      },
+    ])
+    assert.equal(isBlockingValidationKind("missing_required_file"), true)
+  })
+
+  it("reports nothing when all required files are present", () => {
+    assert.deepStrictEqual(
+      validateRequiredTemplateFiles(
+        assignment,
+        ["./README.md", "tests/test_main.py"],
+        new Set(["README.md", "tests/test_main.py"]),
+      ),
+      [],
+    )
+    assert.deepStrictEqual(
+      validateRequiredTemplateFiles(assignment, [], new Set()),
+      [],
+    )
+  })
 })
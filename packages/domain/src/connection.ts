@@ -16,3 +16,18 @@ export function normalizeUserAgent(
 export function resolveUserAgent(draft: ConnectionBase): string {
   return normalizeUserAgent(draft.userAgent) ?? DEFAULT_USER_AGENT
 }
+
+/**
+ * Whether a token expiry timestamp (ISO-8601) has passed. Missing or
+ * unparseable values are treated as not expired so the provider decides.
+ */
+export function isTokenExpired(
+  expiresAt: string | null | undefined,
+  now: number = Date.now(),
+): boolean {
+  if (!expiresAt) {
+    return false
+  }
+  const expiresAtMs = Date.parse(expiresAt)
+  return Number.isFinite(expiresAtMs) && now >= expiresAtMs
+}
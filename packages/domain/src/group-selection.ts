@@ -1,9 +1,18 @@
+import {
+  compileGroupNamePattern,
+  compileGroupNamePatterns,
+  compileGroupNameRegex,
+  type PatternPredicate,
+} from "./pattern-matching.js"
+import { allRosterMembers } from "./roster.js"
+import type { PersistedGroupSelection } from "./settings.js"
 import type {
   Assignment,
   Group,
   GroupSet,
   Roster,
   UsernameTeam,
+  ValidationResult,
 } from "./types.js"
 
 function resolveNamedGroups(roster: Roster, groupIds: string[]): Group[] {
@@ -48,3 +57,89 @@ export function resolveAssignmentGroups(
 
   return resolveGroupSetGroups(roster, groupSet)
 }
+
+/**
+ * Compiles a persisted group selection into one predicate. `all` matches
+ * every name; `multi-pattern` matches a name when any listed pattern does.
+ */
+export function compileGroupSelection(
+  selection: PersistedGroupSelection,
+): ValidationResult<PatternPredicate> {
+  if (selection.mode.kind === "all") {
+    return { ok: true, value: () => true }
+  }
+
+  return compileGroupNamePatterns(
+    selection.mode.patterns,
+    selection.matchCase ? "case-sensitive" : "case-insensitive",
+    selection.useRegex ? compileGroupNameRegex : compileGroupNamePattern,
+  )
+}
+
+export type MemberAssignmentGroup = {
+  assignment: Assignment
+  group: Group
+}
+
+/**
+ * Every assignment group the member belongs to, in assignment order. Teams
+ * in unnamed group sets are matched by the member's Git username.
+ */
+export function groupsForStudent(
+  roster: Roster,
+  memberId: string,
+): MemberAssignmentGroup[] {
+  const member = allRosterMembers(roster).find(
+    (candidate) => candidate.id === memberId,
+  )
+  if (member === undefined) {
+    return []
+  }
+
+  const matches: MemberAssignmentGroup[] = []
+  for (const assignment of roster.assignments) {
+    const groupSet = roster.groupSets.find(
+      (candidate) => candidate.id === assignment.groupSetId,
+    )
+    if (groupSet === undefined) {
+      continue
+    }
+
+    const teamIds =
+      groupSet.nameMode === "unnamed"
+        ? new Set(
+            groupSet.teams
+              .filter(
+                (team) =>
+                  member.gitUsername !== null &&
+                  team.gitUsernames.includes(member.gitUsername),
+              )
+              .map((team) => team.id),
+          )
+        : null
+    for (const group of resolveGroupSetGroups(roster, groupSet)) {
+      const isMember =
+        teamIds === null
+          ? group.memberIds.includes(memberId)
+          : teamIds.has(group.id)
+      if (isMember) {
+        matches.push({ assignment, group })
+      }
+    }
+  }
+  return matches
+}
+
+/** Assignments in which the member belongs to at least one group. */
+export function assignmentsForStudent(
+  roster: Roster,
+  memberId: string,
+): Assignment[] {
+  const assignments: Assignment[] = []
+  for (const { assignment } of groupsForStudent(roster, memberId)) {
+    if (!assignments.includes(assignment)) {
+      assignments.push(assignment)
+    }
+  }
+  return assignments
+}
@@ -3,6 +3,7 @@ export {
   previewImportGroupSet,
   previewReimportGroupSet,
   reimportGroupSet,
+  validateGroupSetImportCsv,
 } from "./group-set-import-export/csv-import.js"
 export {
   exportGroupSetRows,
@@ -14,5 +15,14 @@ export {
 } from "./group-set-import-export/repobee-import.js"
 export {
   buildRosterGitUsernameIndex,
+  defaultGroupNameMaxLength,
   importValidationError,
+  normalizeGroupName,
 } from "./group-set-import-export/shared.js"
+export {
+  collectAssignmentTeams,
+  exportTeamsCsvRows,
+  iterateTeamsCsvRows,
+  parseTeamsCsvRows,
+  teamsCsvHeaders,
+} from "./group-set-import-export/teams.js"
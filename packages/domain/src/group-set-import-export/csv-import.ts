@@ -6,12 +6,14 @@ import {
 import type {
   Group,
   GroupOrigin,
+  GroupSetImportCsvReport,
   GroupSetImportMemberKey,
   GroupSetImportMissingMember,
   GroupSetImportPreview,
   GroupSetImportResult,
   GroupSetImportRow,
   GroupSetImportSource,
+  GroupSetImportWarning,
   IdSequences,
   NamedGroupSet,
   Roster,
@@ -21,9 +23,11 @@ import { initialIdSequences } from "../types.js"
 import {
   buildMemberIndex,
   createImportConnection,
+  defaultGroupNameMaxLength,
   findRosterGroup,
   type GroupSetImportOptions,
   importValidationError,
+  normalizeGroupName,
   normalizeName,
   normalizeOptionalString,
   resolveImportedGroupSetName,
@@ -38,20 +42,32 @@ type ParsedGroupSetImportGroup = {
   memberKeys: string[]
 }
 
+type ParsedGroupSetImportRows = {
+  groups: ParsedGroupSetImportGroup[]
+  warnings: GroupSetImportWarning[]
+}
+
 function parseGroupSetImportRows(
   rows: readonly GroupSetImportRow[],
   memberKey: GroupSetImportMemberKey,
-): ValidationResult<ParsedGroupSetImportGroup[]> {
+): ValidationResult<ParsedGroupSetImportRows> {
   if (rows.length === 0) {
     return importValidationError("$", "CSV file has no data rows")
   }
 
   const groupOrder: string[] = []
   const groupsByNormalizedName = new Map<string, ParsedGroupSetImportGroup>()
-  const seenMemberships = new Set<string>()
+  // Untruncated names, so distinct long names that truncate to the same
+  // prefix are rejected instead of merged into one group.
+  const fullNamesByNormalizedName = new Map<string, string>()
+  // A member in two groups would make repository ownership ambiguous.
+  const groupByMember = new Map<string, ParsedGroupSetImportGroup>()
+  const warnings: GroupSetImportWarning[] = []
 
   for (const [index, row] of rows.entries()) {
-    const rawGroupName = row.group_name.trim()
+    const { name: rawGroupName, truncated } = normalizeGroupName(
+      row.group_name,
+    )
     if (rawGroupName.length === 0) {
       return importValidationError(
         `rows.${index}.group_name`,
@@ -60,6 +76,18 @@ function parseGroupSetImportRows(
     }
 
     const normalizedGroupName = normalizeName(rawGroupName)
+    const fullGroupName = normalizeName(
+      normalizeGroupName(row.group_name, Number.POSITIVE_INFINITY).name,
+    )
+    const existingFullName = fullNamesByNormalizedName.get(normalizedGroupName)
+    if (existingFullName !== undefined && existingFullName !== fullGroupName) {
+      return importValidationError(
+        `rows.${index}.group_name`,
+        `Line ${index + 2}: group_name is identical to another group after truncation to ${defaultGroupNameMaxLength} characters: '${rawGroupName}'`,
+      )
+    }
+    fullNamesByNormalizedName.set(normalizedGroupName, fullGroupName)
+
     let group = groupsByNormalizedName.get(normalizedGroupName)
     if (group === undefined) {
       group = {
@@ -69,6 +97,13 @@ function parseGroupSetImportRows(
       }
       groupsByNormalizedName.set(normalizedGroupName, group)
       groupOrder.push(normalizedGroupName)
+      if (truncated) {
+        warnings.push({
+          kind: "truncated",
+          groupName: rawGroupName,
+          originalLength: Array.from(row.group_name.trim()).length,
+        })
+      }
     }
 
     const memberValue =
@@ -80,16 +115,22 @@ function parseGroupSetImportRows(
       continue
     }
 
-    const membershipKey = `${normalizedGroupName}\u0000${memberValue}`
-    if (seenMemberships.has(membershipKey)) {
-      const label = memberKey === "email" ? "email" : "git_username"
+    const label = memberKey === "email" ? "email" : "git_username"
+    const existingGroup = groupByMember.get(memberValue)
+    if (existingGroup === group) {
       return importValidationError(
         "rows",
         `Duplicate membership: group '${rawGroupName}', ${label} '${memberValue}'`,
       )
     }
+    if (existingGroup !== undefined) {
+      return importValidationError(
+        `rows.${index}`,
+        `Line ${index + 2}: ${label} '${memberValue}' is in both group '${existingGroup.name}' and group '${group.name}'`,
+      )
+    }
 
-    seenMemberships.add(membershipKey)
+    groupByMember.set(memberValue, group)
     group.memberKeys.push(memberValue)
   }
 
@@ -101,7 +142,7 @@ function parseGroupSetImportRows(
     }
   }
 
-  return { ok: true, value: groups }
+  return { ok: true, value: { groups, warnings } }
 }
 
 function resolveGroupMemberIds(
@@ -151,6 +192,71 @@ function summarizeMissingMembers(
   return { missingMembers, totalMissing }
 }
 
+/**
+ * Checks a group-set CSV for structural problems without resolving members
+ * against a roster. Unlike import, every problem is collected instead of
+ * stopping at the first one. `columns` are the canonical row keys present in
+ * the file header.
+ */
+export function validateGroupSetImportCsv(
+  columns: readonly string[],
+  rows: readonly GroupSetImportRow[],
+  memberKey: GroupSetImportMemberKey = "email",
+): GroupSetImportCsvReport {
+  const label = memberKey === "email" ? "email" : "git_username"
+  const presentColumns = new Set(columns)
+  const missingColumns = ["group_name", label].filter(
+    (column) => !presentColumns.has(column),
+  )
+
+  const groupNames = new Map<string, string>()
+  const groupByMember = new Map<string, string>()
+  const emptyGroupNameLines: number[] = []
+  const duplicateMemberships: string[] = []
+
+  for (const [index, row] of rows.entries()) {
+    const line = index + 2
+    const { name: groupName } = normalizeGroupName(row.group_name)
+    if (groupName.length === 0) {
+      emptyGroupNameLines.push(line)
+      continue
+    }
+
+    const normalizedGroupName = normalizeName(groupName)
+    if (!groupNames.has(normalizedGroupName)) {
+      groupNames.set(normalizedGroupName, groupName)
+    }
+
+    const memberValue = normalizeOptionalString(
+      memberKey === "email" ? row.email : row.git_username,
+    )?.toLowerCase()
+    if (memberValue === undefined || memberValue === null) {
+      continue
+    }
+
+    const existingGroup = groupByMember.get(memberValue)
+    if (existingGroup === normalizedGroupName) {
+      duplicateMemberships.push(
+        `Line ${line}: ${label} '${memberValue}' is listed twice in group '${groupName}'`,
+      )
+    } else if (existingGroup !== undefined) {
+      duplicateMemberships.push(
+        `Line ${line}: ${label} '${memberValue}' is in both group '${groupNames.get(existingGroup)}' and group '${groupName}'`,
+      )
+    } else {
+      groupByMember.set(memberValue, normalizedGroupName)
+    }
+  }
+
+  return {
+    totalRows: rows.length,
+    groupCount: groupNames.size,
+    missingColumns,
+    emptyGroupNameLines,
+    duplicateMemberships,
+  }
+}
+
 export function previewImportGroupSet(
   roster: Roster,
   rows: readonly GroupSetImportRow[],
@@ -172,7 +278,7 @@ export function previewImportGroupSet(
 
   const index = buildMemberIndex(roster, memberKey)
   const { missingMembers, totalMissing } = summarizeMissingMembers(
-    parsed.value,
+    parsed.value.groups,
     index,
   )
 
@@ -180,7 +286,7 @@ export function previewImportGroupSet(
     ok: true,
     value: {
       mode: "import",
-      groups: parsed.value.map((group) => ({
+      groups: parsed.value.groups.map((group) => ({
         name: group.name,
         memberCount: resolveGroupMemberIds(group.memberKeys, index).length,
       })),
@@ -235,17 +341,17 @@ export function importGroupSet(
 
   const index = buildMemberIndex(roster, memberKey)
   const { missingMembers, totalMissing } = summarizeMissingMembers(
-    parsed.value,
+    parsed.value.groups,
     index,
   )
 
   let seq = sequences
 
   if (target.value === null) {
-    const groupAlloc = allocateGroupIds(seq, parsed.value.length)
+    const groupAlloc = allocateGroupIds(seq, parsed.value.groups.length)
     seq = groupAlloc.sequences
 
-    const groupsUpserted: Group[] = parsed.value.map((group, idx) => ({
+    const groupsUpserted: Group[] = parsed.value.groups.map((group, idx) => ({
       id: groupAlloc.ids[idx] as string,
       name: group.name,
       memberIds: resolveGroupMemberIds(group.memberKeys, index),
@@ -274,6 +380,7 @@ export function importGroupSet(
         deletedGroupIds: [],
         missingMembers,
         totalMissing,
+        warnings: parsed.value.warnings,
         idSequences: seq,
       },
     }
@@ -285,7 +392,7 @@ export function importGroupSet(
   const groupsUpserted: Group[] = []
   const appendedGroupIds: string[] = []
 
-  for (const parsedGroup of parsed.value) {
+  for (const parsedGroup of parsed.value.groups) {
     const memberIds = resolveGroupMemberIds(parsedGroup.memberKeys, index)
     const existing = existingByNormalizedName.get(parsedGroup.normalizedName)
 
@@ -325,6 +432,7 @@ export function importGroupSet(
       deletedGroupIds: [],
       missingMembers,
       totalMissing,
+      warnings: parsed.value.warnings,
       idSequences: seq,
     },
   }
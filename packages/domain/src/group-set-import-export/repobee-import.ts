@@ -303,6 +303,7 @@ export function replaceGroupSetFromRepoBee(
         deletedGroupIds: [],
         missingMembers: [],
         totalMissing: 0,
+        warnings: [],
         idSequences: seq,
       },
     }
@@ -321,6 +322,7 @@ export function replaceGroupSetFromRepoBee(
       deletedGroupIds: [],
       missingMembers: [],
       totalMissing: 0,
+      warnings: [],
       idSequences: seq,
     },
   }
@@ -1,5 +1,9 @@
 import { normalizeName } from "../name-normalization.js"
-import { normalizeEmail, normalizeOptionalString } from "../roster.js"
+import {
+  allRosterMembers,
+  normalizeEmail,
+  normalizeOptionalString,
+} from "../roster.js"
 import type {
   Group,
   GroupSet,
@@ -24,6 +28,26 @@ export type RepoBeeApplyOptions = {
   groupSetName?: string | null
 }
 
+export const defaultGroupNameMaxLength = 100
+
+/**
+ * Cleans an imported group name for display: drops control characters
+ * (which break CSV round-trips), trims, and caps the length in code points.
+ */
+export function normalizeGroupName(
+  value: string,
+  maxLength = defaultGroupNameMaxLength,
+): { name: string; truncated: boolean } {
+  const characters = Array.from(value.replace(/\p{Cc}/gu, "").trim())
+  if (characters.length <= maxLength) {
+    return { name: characters.join(""), truncated: false }
+  }
+  return {
+    name: characters.slice(0, maxLength).join("").trimEnd(),
+    truncated: true,
+  }
+}
+
 export function importValidationError<T>(
   path: string,
   message: string,
@@ -36,7 +60,7 @@ export function importValidationError<T>(
 
 function buildRosterEmailIndex(roster: Roster): Map<string, string | null> {
   const index = new Map<string, string | null>()
-  for (const member of roster.students.concat(roster.staff)) {
+  for (const member of allRosterMembers(roster)) {
     const key = normalizeEmail(member.email)
     if (key.length === 0) {
       continue
@@ -54,7 +78,7 @@ export function buildRosterGitUsernameIndex(
   roster: Roster,
 ): Map<string, string | null> {
   const index = new Map<string, string | null>()
-  for (const member of roster.students.concat(roster.staff)) {
+  for (const member of allRosterMembers(roster)) {
     const key = normalizeOptionalString(member.gitUsername)?.toLowerCase()
     if (!key) {
       continue
@@ -90,7 +114,13 @@ export function createImportConnection(
 
 function isEditableGroupSet(groupSet: GroupSet): boolean {
   const kind = groupSet.connection?.kind
-  return kind !== "system" && kind !== "canvas" && kind !== "moodle"
+  return (
+    kind !== "system" &&
+    kind !== "canvas" &&
+    kind !== "moodle" &&
+    kind !== "blackboard" &&
+    kind !== "brightspace"
+  )
 }
 
 function resolveTargetGroupSet(
@@ -0,0 +1,137 @@
+import { resolveAssignmentGroups } from "../group-selection.js"
+import type {
+  AssignmentTeam,
+  Roster,
+  TeamsCsvRow,
+  ValidationIssue,
+  ValidationResult,
+} from "../types.js"
+import { buildMemberById } from "./shared.js"
+
+export const teamsCsvHeaders = [
+  "team_name",
+  "member_git_username",
+  "assignment",
+] as const
+
+/**
+ * Lists every assignment's teams in roster order. Members without a Git
+ * username cannot be written to `teams.csv` and are left out.
+ */
+export function collectAssignmentTeams(roster: Roster): AssignmentTeam[] {
+  const memberById = buildMemberById(roster)
+  const teams: AssignmentTeam[] = []
+
+  for (const assignment of roster.assignments) {
+    const groupSet = roster.groupSets.find(
+      (candidate) => candidate.id === assignment.groupSetId,
+    )
+    if (groupSet === undefined) {
+      continue
+    }
+
+    if (groupSet.nameMode === "unnamed") {
+      for (const team of groupSet.teams) {
+        teams.push({
+          assignment: assignment.name,
+          teamName: team.gitUsernames.join("-"),
+          memberGitUsernames: [...team.gitUsernames],
+        })
+      }
+      continue
+    }
+
+    for (const group of resolveAssignmentGroups(roster, assignment)) {
+      teams.push({
+        assignment: assignment.name,
+        teamName: group.name,
+        memberGitUsernames: group.memberIds.flatMap((memberId) => {
+          const gitUsername = memberById.get(memberId)?.gitUsername ?? null
+          return gitUsername === null || gitUsername === "" ? [] : [gitUsername]
+        }),
+      })
+    }
+  }
+
+  return teams
+}
+
+/**
+ * Yields the rows of `exportTeamsCsvRows` one at a time, so large rosters can
+ * be written out without holding every row in memory.
+ */
+export function* iterateTeamsCsvRows(
+  teams: Iterable<AssignmentTeam>,
+): Generator<TeamsCsvRow> {
+  for (const team of teams) {
+    if (team.memberGitUsernames.length === 0) {
+      yield {
+        team_name: team.teamName,
+        member_git_username: "",
+        assignment: team.assignment,
+      }
+      continue
+    }
+    for (const gitUsername of team.memberGitUsernames) {
+      yield {
+        team_name: team.teamName,
+        member_git_username: gitUsername,
+        assignment: team.assignment,
+      }
+    }
+  }
+}
+
+export function exportTeamsCsvRows(
+  teams: readonly AssignmentTeam[],
+): TeamsCsvRow[] {
+  return [...iterateTeamsCsvRows(teams)]
+}
+
+/**
+ * Rebuilds teams from `teams.csv` rows. Rows sharing an assignment and team
+ * name form one team; a row without a member keeps an empty team.
+ */
+export function parseTeamsCsvRows(
+  rows: readonly Partial<TeamsCsvRow>[],
+): ValidationResult<AssignmentTeam[]> {
+  const teams = new Map<string, AssignmentTeam>()
+  const issues: ValidationIssue[] = []
+
+  for (const [index, row] of rows.entries()) {
+    const line = index + 2
+    const teamName = row.team_name?.trim() ?? ""
+    const assignment = row.assignment?.trim() ?? ""
+    if (teamName === "") {
+      issues.push({
+        path: `rows.${index}.team_name`,
+        message: `Line ${line}: team_name is required`,
+      })
+    }
+    if (assignment === "") {
+      issues.push({
+        path: `rows.${index}.assignment`,
+        message: `Line ${line}: assignment is required`,
+      })
+    }
+    if (teamName === "" || assignment === "") {
+      continue
+    }
+
+    const key = JSON.stringify([assignment, teamName])
+    let team = teams.get(key)
+    if (team === undefined) {
+      team = { assignment, teamName, memberGitUsernames: [] }
+      teams.set(key, team)
+    }
+    const gitUsername = row.member_git_username?.trim() ?? ""
+    if (gitUsername !== "" && !team.memberGitUsernames.includes(gitUsername)) {
+      team.memberGitUsernames.push(gitUsername)
+    }
+  }
+
+  if (issues.length > 0) {
+    return { ok: false, issues }
+  }
+  return { ok: true, value: [...teams.values()] }
+}
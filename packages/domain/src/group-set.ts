@@ -1,5 +1,9 @@
 import { allocateGroupId, allocateGroupSetId } from "./id-allocator.js"
-import { generateUniqueGroupName } from "./roster.js"
+import {
+  activeStudents,
+  allRosterMembers,
+  generateUniqueGroupName,
+} from "./roster.js"
 import type {
   Group,
   GroupOrigin,
@@ -11,6 +15,8 @@ import type {
   ResolveGitUsernamesResult,
   Roster,
   SystemGroupSetEnsureResult,
+  ValidationIssue,
+  ValidationResult,
 } from "./types.js"
 
 const SYSTEM_TYPE_INDIVIDUAL_STUDENTS = "individual_students" as const
@@ -81,9 +87,6 @@ function ensureIndividualStudentsSet(
     setIndex = roster.groupSets.length - 1
   }
 
-  const activeStudents = roster.students.filter(
-    (student) => student.status === "active",
-  )
   const set = roster.groupSets[setIndex] as NamedGroupSet
   const setGroupIds = new Set(set.groupIds)
 
@@ -109,7 +112,7 @@ function ensureIndividualStudentsSet(
   const neededGroupIds: string[] = []
   const neededGroupIdSet = new Set<string>()
 
-  for (const student of activeStudents) {
+  for (const student of activeStudents(roster)) {
     const existingIndex = existingByMember.get(student.id)
     if (existingIndex !== undefined) {
       const group = roster.groups[existingIndex]
@@ -289,8 +292,7 @@ export function ensureSystemGroupSets(
 
 export function activeMemberIds(roster: Roster, group: Group): string[] {
   const activeIds = new Set(
-    roster.students
-      .concat(roster.staff)
+    allRosterMembers(roster)
       .filter((member) => member.status === "active")
       .map((member) => member.id),
   )
@@ -303,7 +305,7 @@ export function resolveGitUsernames(
   memberIds: readonly string[],
 ): ResolveGitUsernamesResult {
   const memberById = new Map(
-    roster.students.concat(roster.staff).map((member) => [member.id, member]),
+    allRosterMembers(roster).map((member) => [member.id, member]),
   )
   const resolved: ResolvedGitUsername[] = []
   const missing: string[] = []
@@ -338,3 +340,414 @@ export function resolveGitUsernames(
     missing,
   }
 }
+
+export const DEFAULT_LMS_SYNC_MAX_AGE_HOURS = 24
+
+/**
+ * True when an LMS-connected group set was last synced more than
+ * `maxAgeHours` before `now`. Local, imported and system sets never go stale.
+ */
+export function isLmsGroupSetSyncStale(
+  groupSet: GroupSet,
+  now: Date,
+  maxAgeHours: number = DEFAULT_LMS_SYNC_MAX_AGE_HOURS,
+): boolean {
+  const connection = groupSet.connection
+  if (
+    connection === null ||
+    (connection.kind !== "canvas" &&
+      connection.kind !== "moodle" &&
+      connection.kind !== "blackboard" &&
+      connection.kind !== "brightspace")
+  ) {
+    return false
+  }
+
+  const lastUpdated = Date.parse(connection.lastUpdated)
+  if (Number.isNaN(lastUpdated)) {
+    return false
+  }
+
+  return now.getTime() - lastUpdated > maxAgeHours * 60 * 60 * 1000
+}
+
+// ---------------------------------------------------------------------------
+// Compaction
+// ---------------------------------------------------------------------------
+
+export type RosterCompactionReport = {
+  /** Empty groups that no group set lists. */
+  groupsRemoved: number
+  /** Group-set references to groups that no longer exist. */
+  danglingGroupIdsRemoved: number
+  /** Non-system group sets with no groups that no assignment uses. */
+  groupSetsRemoved: number
+}
+
+export type RosterCompactionResult = {
+  roster: Roster
+  report: RosterCompactionReport
+}
+
+/**
+ * Drops leftovers from reimports and deletions. Groups that an assignment
+ * has recorded a repository for are kept, as are system group sets, so
+ * compaction never loses repository history.
+ */
+export function compactRoster(roster: Roster): RosterCompactionResult {
+  const existingGroupIds = new Set(roster.groups.map((group) => group.id))
+  let danglingGroupIdsRemoved = 0
+  const groupSets = roster.groupSets.map((groupSet): GroupSet => {
+    if (groupSet.nameMode !== "named") {
+      return groupSet
+    }
+    const groupIds = groupSet.groupIds.filter((groupId) =>
+      existingGroupIds.has(groupId),
+    )
+    danglingGroupIdsRemoved += groupSet.groupIds.length - groupIds.length
+    return groupIds.length === groupSet.groupIds.length
+      ? groupSet
+      : { ...groupSet, groupIds }
+  })
+
+  const referencedSetIds = new Set(
+    roster.assignments.map((assignment) => assignment.groupSetId),
+  )
+  const keptGroupSets = groupSets.filter(
+    (groupSet) =>
+      groupSet.connection?.kind === "system" ||
+      referencedSetIds.has(groupSet.id) ||
+      (groupSet.nameMode === "named"
+        ? groupSet.groupIds.length > 0
+        : groupSet.teams.length > 0),
+  )
+
+  const listedGroupIds = new Set(
+    keptGroupSets.flatMap((groupSet) =>
+      groupSet.nameMode === "named" ? groupSet.groupIds : [],
+    ),
+  )
+  const recordedGroupIds = new Set(
+    roster.assignments.flatMap((assignment) =>
+      Object.keys(assignment.repositories),
+    ),
+  )
+  const keptGroups = roster.groups.filter(
+    (group) =>
+      group.memberIds.length > 0 ||
+      listedGroupIds.has(group.id) ||
+      recordedGroupIds.has(group.id),
+  )
+
+  const report: RosterCompactionReport = {
+    groupsRemoved: roster.groups.length - keptGroups.length,
+    danglingGroupIdsRemoved,
+    groupSetsRemoved: roster.groupSets.length - keptGroupSets.length,
+  }
+  if (
+    report.groupsRemoved === 0 &&
+    report.danglingGroupIdsRemoved === 0 &&
+    report.groupSetsRemoved === 0
+  ) {
+    return { roster, report }
+  }
+
+  return {
+    roster: { ...roster, groups: keptGroups, groupSets: keptGroupSets },
+    report,
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Splitting and merging
+// ---------------------------------------------------------------------------
+
+export type SplitGroupResult = {
+  roster: Roster
+  idSequences: IdSequences
+  /** The original group, keeping its id and the first `splitAt` members. */
+  original: Group
+  /** The new group holding the remaining members, listed after `original`. */
+  created: Group
+}
+
+export type MergeGroupsResult = {
+  roster: Roster
+  /** Group A, renamed and holding the members of both groups. */
+  merged: Group
+  /** Group B's id, dropped from the set and, unless still used, the roster. */
+  removedGroupId: string
+}
+
+function groupEditIssue(
+  path: string,
+  message: string,
+): ValidationResult<never> {
+  return { ok: false, issues: [{ path, message }] }
+}
+
+// Only local groups in non-system named sets can be edited; LMS-synced and
+// system groups are rebuilt on the next sync.
+function resolveEditableGroupSet(
+  roster: Roster,
+  groupSetId: string,
+): ValidationResult<NamedGroupSet> {
+  const groupSet = roster.groupSets.find((entry) => entry.id === groupSetId)
+  if (groupSet === undefined) {
+    return groupEditIssue("groupSetId", `Group set '${groupSetId}' not found.`)
+  }
+  if (groupSet.nameMode !== "named") {
+    return groupEditIssue(
+      "groupSetId",
+      `Group set '${groupSet.name}' does not have named groups.`,
+    )
+  }
+  if (groupSet.connection?.kind === "system") {
+    return groupEditIssue(
+      "groupSetId",
+      `System group set '${groupSet.name}' cannot be edited.`,
+    )
+  }
+  return { ok: true, value: groupSet }
+}
+
+function resolveEditableGroup(
+  roster: Roster,
+  groupSet: NamedGroupSet,
+  groupId: string,
+  path: string,
+): ValidationResult<Group> {
+  const group = groupSet.groupIds.includes(groupId)
+    ? roster.groups.find((entry) => entry.id === groupId)
+    : undefined
+  if (group === undefined) {
+    return groupEditIssue(
+      path,
+      `Group '${groupId}' is not in group set '${groupSet.name}'.`,
+    )
+  }
+  if (group.origin !== "local") {
+    return groupEditIssue(path, `Group '${group.name}' is not a local group.`)
+  }
+  return { ok: true, value: group }
+}
+
+function groupNamesInSet(
+  roster: Roster,
+  groupSet: NamedGroupSet,
+  excludedGroupIds: readonly string[],
+): Set<string> {
+  const listed = new Set(groupSet.groupIds)
+  return new Set(
+    roster.groups
+      .filter(
+        (group) => listed.has(group.id) && !excludedGroupIds.includes(group.id),
+      )
+      .map((group) => group.name),
+  )
+}
+
+function validateGroupName(
+  name: string,
+  existingNames: ReadonlySet<string>,
+  path: string,
+): ValidationIssue | null {
+  if (name === "") {
+    return { path, message: "Group name must not be empty." }
+  }
+  if (existingNames.has(name)) {
+    return { path, message: `A group named '${name}' already exists.` }
+  }
+  return null
+}
+
+function replaceGroupIds(
+  roster: Roster,
+  groupSetId: string,
+  groupIds: string[],
+): GroupSet[] {
+  return roster.groupSets.map((groupSet) =>
+    groupSet.id === groupSetId && groupSet.nameMode === "named"
+      ? { ...groupSet, groupIds }
+      : groupSet,
+  )
+}
+
+/**
+ * Splits a local group in two: the first `splitAt` members stay in the
+ * original group and the rest move to a new group. Without `newName`, the
+ * new group is named from its members' surnames. `splitAt` may be 0 or the
+ * member count, leaving one of the two groups empty.
+ */
+export function splitGroup(
+  roster: Roster,
+  idSequences: IdSequences,
+  groupSetId: string,
+  groupId: string,
+  splitAt: number,
+  newName?: string,
+): ValidationResult<SplitGroupResult> {
+  const groupSet = resolveEditableGroupSet(roster, groupSetId)
+  if (!groupSet.ok) {
+    return groupSet
+  }
+  const group = resolveEditableGroup(roster, groupSet.value, groupId, "groupId")
+  if (!group.ok) {
+    return group
+  }
+
+  const memberCount = group.value.memberIds.length
+  if (!Number.isInteger(splitAt) || splitAt < 0 || splitAt > memberCount) {
+    return groupEditIssue(
+      "splitAt",
+      `Split position must be a whole number from 0 to ${memberCount}.`,
+    )
+  }
+
+  const keptIds = group.value.memberIds.slice(0, splitAt)
+  const movedIds = group.value.memberIds.slice(splitAt)
+  const existingNames = groupNamesInSet(roster, groupSet.value, [])
+  let name: string
+  if (newName === undefined) {
+    const membersById = new Map(
+      allRosterMembers(roster).map((member) => [member.id, member]),
+    )
+    name = generateUniqueGroupName(
+      movedIds.flatMap((id) => {
+        const member = membersById.get(id)
+        return member === undefined ? [] : [member]
+      }),
+      existingNames,
+    )
+  } else {
+    name = newName.trim()
+    const nameIssue = validateGroupName(name, existingNames, "newName")
+    if (nameIssue !== null) {
+      return { ok: false, issues: [nameIssue] }
+    }
+  }
+
+  const allocation = allocateGroupId(idSequences)
+  const original: Group = { ...group.value, memberIds: keptIds }
+  const created: Group = {
+    id: allocation.id,
+    name,
+    memberIds: movedIds,
+    origin: "local",
+    lmsGroupId: null,
+  }
+  const groupIds = groupSet.value.groupIds.flatMap((id) =>
+    id === groupId ? [id, created.id] : [id],
+  )
+
+  return {
+    ok: true,
+    value: {
+      roster: {
+        ...roster,
+        groups: [
+          ...roster.groups.map((entry) =>
+            entry.id === groupId ? original : entry,
+          ),
+          created,
+        ],
+        groupSets: replaceGroupIds(roster, groupSetId, groupIds),
+      },
+      idSequences: allocation.sequences,
+      original,
+      created,
+    },
+  }
+}
+
+/**
+ * Merges group B into group A. A keeps its id and position, is renamed to
+ * `mergedName`, and lists A's members followed by B's. B leaves the set and
+ * is deleted unless another group set lists it or an assignment recorded a
+ * repository for it.
+ */
+export function mergeGroups(
+  roster: Roster,
+  groupSetId: string,
+  groupAId: string,
+  groupBId: string,
+  mergedName: string,
+): ValidationResult<MergeGroupsResult> {
+  const groupSet = resolveEditableGroupSet(roster, groupSetId)
+  if (!groupSet.ok) {
+    return groupSet
+  }
+  if (groupAId === groupBId) {
+    return groupEditIssue("groupBId", "A group cannot be merged with itself.")
+  }
+  const groupA = resolveEditableGroup(
+    roster,
+    groupSet.value,
+    groupAId,
+    "groupAId",
+  )
+  if (!groupA.ok) {
+    return groupA
+  }
+  const groupB = resolveEditableGroup(
+    roster,
+    groupSet.value,
+    groupBId,
+    "groupBId",
+  )
+  if (!groupB.ok) {
+    return groupB
+  }
+
+  const name = mergedName.trim()
+  const nameIssue = validateGroupName(
+    name,
+    groupNamesInSet(roster, groupSet.value, [groupAId, groupBId]),
+    "mergedName",
+  )
+  if (nameIssue !== null) {
+    return { ok: false, issues: [nameIssue] }
+  }
+
+  const merged: Group = {
+    ...groupA.value,
+    name,
+    memberIds: [
+      ...new Set([...groupA.value.memberIds, ...groupB.value.memberIds]),
+    ],
+  }
+  const groupSets = replaceGroupIds(
+    roster,
+    groupSetId,
+    groupSet.value.groupIds.filter((id) => id !== groupBId),
+  )
+  const stillUsed =
+    groupSets.some(
+      (entry) =>
+        entry.nameMode === "named" && entry.groupIds.includes(groupBId),
+    ) ||
+    roster.assignments.some((assignment) =>
+      Object.hasOwn(assignment.repositories, groupBId),
+    )
+
+  return {
+    ok: true,
+    value: {
+      roster: {
+        ...roster,
+        groups: roster.groups.flatMap((entry) => {
+          if (entry.id === groupAId) {
+            return [merged]
+          }
+          if (entry.id === groupBId && !stillUsed) {
+            return []
+          }
+          return [entry]
+        }),
+        groupSets,
+      },
+      merged,
+      removedGroupId: groupBId,
+    },
+  }
+}
@@ -3,6 +3,18 @@ import type { ValidationResult } from "./types.js"
 
 export type PatternPredicate = (value: string) => boolean
 
+/**
+ * Case handling for group-name patterns. LMS group names are usually
+ * capitalized (`Team A`), so instructor filters such as `team*` opt into
+ * case-insensitive matching.
+ */
+export type GroupNameMatchMode = "case-sensitive" | "case-insensitive"
+
+export type GroupNameCompiler = (
+  pattern: string,
+  mode?: GroupNameMatchMode,
+) => ValidationResult<PatternPredicate>
+
 const COMMON_OPTIONS = {
   separator: false,
   excludeDot: false,
@@ -183,6 +195,7 @@ function validateGroupNamePattern(pattern: string): ValidationResult<string> {
 
 export function compileGroupNamePattern(
   pattern: string,
+  mode: GroupNameMatchMode = "case-sensitive",
 ): ValidationResult<PatternPredicate> {
   const validation = validateGroupNamePattern(pattern)
   if (!validation.ok) return validation
@@ -192,10 +205,61 @@ export function compileGroupNamePattern(
       ok: true,
       value: outmatch(pattern, {
         ...COMMON_OPTIONS,
-        flags: "su",
+        flags: mode === "case-insensitive" ? "isu" : "su",
       }),
     }
   } catch (error) {
     return issue(errorMessage(error))
   }
 }
+
+/**
+ * Compiles each pattern on its own and matches a name when any of them
+ * matches. `compile` defaults to glob patterns; pass `compileGroupNameRegex`
+ * for a list of regular expressions.
+ */
+export function compileGroupNamePatterns(
+  patterns: readonly string[],
+  mode: GroupNameMatchMode = "case-sensitive",
+  compile: GroupNameCompiler = compileGroupNamePattern,
+): ValidationResult<PatternPredicate> {
+  const predicates: PatternPredicate[] = []
+
+  for (let index = 0; index < patterns.length; index += 1) {
+    const compilation = compile(patterns[index], mode)
+    if (!compilation.ok) {
+      return {
+        ok: false,
+        issues: compilation.issues.map((entry) => ({
+          ...entry,
+          path: `patterns.${index}`,
+        })),
+      }
+    }
+    predicates.push(compilation.value)
+  }
+
+  return {
+    ok: true,
+    value: (value) => predicates.some((predicate) => predicate(value)),
+  }
+}
+
+/**
+ * Compiles a regular expression over group names. Unlike glob patterns the
+ * expression is not anchored, so `^` and `$` must be written explicitly to
+ * match whole names.
+ */
+export function compileGroupNameRegex(
+  pattern: string,
+  mode: GroupNameMatchMode = "case-sensitive",
+): ValidationResult<PatternPredicate> {
+  try {
+    const regex = new RegExp(pattern, mode === "case-insensitive" ? "iu" : "u")
+    return { ok: true, value: (value) => regex.test(value) }
+  } catch (error) {
+    return issue(
+      error instanceof Error ? error.message : "Invalid regular expression.",
+    )
+  }
+}
@@ -1,8 +1,9 @@
 import { activeMemberIds, resolveGitUsernames } from "./group-set.js"
 import { importValidationError } from "./group-set-import-export.js"
-import { slugifyToken } from "./roster.js"
+import { allRosterMembers, slugifyToken } from "./roster.js"
 import type {
   Assignment,
+  GitProviderKind,
   Group,
   GroupSet,
   NamedGroupSet,
@@ -57,6 +58,45 @@ export function computeRepoName(
   return slugify(expandTemplate(template, assignment, group, options))
 }
 
+const repositoryNameMaxLength: Record<GitProviderKind, number> = {
+  github: 100,
+  gitlab: 255,
+  gitea: 100,
+}
+
+/**
+ * Returns the provider naming rules `name` breaks, empty when it is
+ * acceptable. Planned names are slugified, but recorded names are not.
+ */
+export function validateRepositoryName(
+  name: string,
+  provider: GitProviderKind,
+): string[] {
+  if (name.length === 0) {
+    return ["name is empty"]
+  }
+
+  const problems: string[] = []
+  const maxLength = repositoryNameMaxLength[provider]
+  if (name.length > maxLength) {
+    problems.push(`name is longer than ${maxLength} characters`)
+  }
+  if (!/^[A-Za-z0-9._-]+$/.test(name)) {
+    problems.push("name may only contain ASCII letters, digits, '.', '-', '_'")
+  }
+  if (/^[.-]/.test(name)) {
+    problems.push("name cannot start with '.' or '-'")
+  }
+  const lowered = name.toLowerCase()
+  if (lowered.endsWith(".git")) {
+    problems.push("name cannot end with '.git'")
+  }
+  if (provider === "gitlab" && lowered.endsWith(".atom")) {
+    problems.push("name cannot end with '.atom'")
+  }
+  return problems
+}
+
 function activeGroupGitUsernameToken(
   group: Group,
   memberById: ReadonlyMap<string, Roster["students"][number]>,
@@ -140,9 +180,7 @@ function planNamedGroups(
   const skippedGroups: SkippedGroup[] = []
   const resolvedGroups = resolveNamedGroupSetGroups(roster, groupSet)
   const memberById = new Map(
-    roster.students
-      .concat(roster.staff)
-      .map((member) => [member.id, member] as const),
+    allRosterMembers(roster).map((member) => [member.id, member] as const),
   )
 
   for (const group of resolvedGroups) {
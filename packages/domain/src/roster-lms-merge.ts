@@ -7,10 +7,15 @@ import {
 import type {
   IdSequences,
   LmsImportConflict,
+  LmsImportEmailChange,
+  LmsImportMatchKey,
+  LmsImportMatchPriority,
   MemberStatus,
   Roster,
   RosterImportFromLmsResult,
   RosterMember,
+  RosterMergeConflict,
+  RosterMergeResult,
 } from "./types.js"
 import { initialIdSequences } from "./types.js"
 
@@ -97,17 +102,90 @@ function isRosterMemberEquivalent(
   )
 }
 
+// Applies incoming identity and enrollment fields to a matched member while
+// keeping locally curated fields (Git username, department, manual drops).
+function mergeMatchedMember(
+  member: RosterMember,
+  match: RosterMember,
+): RosterMember {
+  const email = match.email || member.email
+  const lmsStatus: MemberStatus = match.lmsStatus ?? match.status
+  const hasManualDroppedOverride =
+    member.status === "dropped" && member.lmsStatus !== "dropped"
+  const status = hasManualDroppedOverride
+    ? "dropped"
+    : normalizeMissingEmailStatus(email, lmsStatus)
+  return {
+    id: member.id,
+    name: match.name,
+    email,
+    studentNumber: match.studentNumber ?? member.studentNumber,
+    gitUsername: member.gitUsername,
+    gitUsernameStatus: member.gitUsernameStatus,
+    status,
+    lmsStatus,
+    lmsUserId: match.lmsUserId ?? member.lmsUserId,
+    enrollmentType: match.enrollmentType,
+    enrollmentDisplay: match.enrollmentDisplay,
+    department: member.department,
+    institution: member.institution,
+    source: match.source,
+  }
+}
+
+function splitMembersByEnrollment(members: readonly RosterMember[]): {
+  students: RosterMember[]
+  staff: RosterMember[]
+} {
+  const students: RosterMember[] = []
+  const staff: RosterMember[] = []
+  for (const member of members) {
+    if (member.enrollmentType === "student") {
+      students.push(member)
+    } else {
+      staff.push(member)
+    }
+  }
+  return {
+    students: sortRosterMembers(students),
+    staff: sortRosterMembers(staff),
+  }
+}
+
 // ---------------------------------------------------------------------------
 // LMS merge
 // ---------------------------------------------------------------------------
 
+export type RosterLmsMergeOptions = {
+  matchPriority?: LmsImportMatchPriority
+  /**
+   * Whether LMS-sourced members absent from `incoming` are marked dropped.
+   * Disable when `incoming` is known to be partial. Defaults to true.
+   */
+  markMissingAsDropped?: boolean
+}
+
+const lmsImportMatchOrders: Record<
+  LmsImportMatchPriority,
+  readonly LmsImportMatchKey[]
+> = {
+  lmsUserId: ["lmsUserId", "email", "studentNumber"],
+  studentNumber: ["studentNumber", "email", "lmsUserId"],
+}
+
 /**
  * Merge an incoming LMS roster into the existing roster, preserving local data.
  *
- * - Matched members (by lmsUserId, email, studentNumber, then id): LMS fields
- *   updated, local fields (gitUsername, department, etc.) preserved.
+ * - Matched members (by lmsUserId, email, then studentNumber; or by
+ *   studentNumber, email, then lmsUserId when `matchPriority` is
+ *   `studentNumber`): LMS fields updated, local fields (gitUsername,
+ *   department, etc.) preserved.
  * - Ambiguous matches are reported as conflicts and left untouched.
- * - Existing LMS-sourced members not in incoming: marked "dropped".
+ * - A student-number match with a different email takes the LMS email and
+ *   is reported in `emailChanges`, so a student whose email changed between
+ *   semesters is not duplicated.
+ * - Existing LMS-sourced members not in incoming: marked "dropped", unless
+ *   `markMissingAsDropped` is false.
  * - Existing locally-added members (lmsUserId null): left unchanged.
  * - New incoming members: added as-is.
  * - Groups, groupSets, and assignments are preserved from the existing roster.
@@ -116,7 +194,10 @@ export function mergeRosterFromLmsWithConflicts(
   existing: Roster,
   incoming: Roster,
   sequences: IdSequences = initialIdSequences(),
+  options: RosterLmsMergeOptions = {},
 ): RosterImportFromLmsResult {
+  const matchOrder = lmsImportMatchOrders[options.matchPriority ?? "lmsUserId"]
+  const markMissingAsDropped = options.markMissingAsDropped ?? true
   const allExisting = [...existing.students, ...existing.staff]
   const allIncoming = [...incoming.students, ...incoming.staff]
 
@@ -143,83 +224,71 @@ export function mergeRosterFromLmsWithConflicts(
     }
   }
 
+  const existingIndexes: Record<
+    LmsImportMatchKey,
+    ReadonlyMap<string, string[]>
+  > = {
+    lmsUserId: existingByLmsUserId,
+    email: existingByEmail,
+    studentNumber: existingByStudentNumber,
+  }
+
   const conflicts: LmsImportConflict[] = []
   const conflictSignatures = new Set<string>()
   const conflictedExistingIds = new Set<string>()
   const matchedExistingIds = new Set<string>()
   const incomingByMatchedExistingId = new Map<string, RosterMember>()
+  const matchKeyByExistingId = new Map<string, LmsImportMatchKey>()
   const unmatchedIncoming: RosterMember[] = []
 
   for (const incomingMember of allIncoming) {
     const lmsUserId = normalizeOptionalString(incomingMember.lmsUserId)
     const normalizedEmail = normalizeEmail(incomingMember.email)
     const studentNumber = normalizeOptionalString(incomingMember.studentNumber)
+    const candidates: Record<LmsImportMatchKey, string | null> = {
+      lmsUserId,
+      email: normalizedEmail.length > 0 ? normalizedEmail : null,
+      studentNumber,
+    }
     let matchedExistingId: string | null = null
+    let matchedKey: LmsImportMatchKey | null = null
+    let conflicted = false
 
-    if (lmsUserId !== null) {
-      const available = availableIdsForMatch(
-        existingByLmsUserId.get(lmsUserId),
-        matchedExistingIds,
-      )
-      if (available.length > 1) {
-        recordConflict(
-          conflicts,
-          conflictSignatures,
-          "lmsUserId",
-          lmsUserId,
-          available,
-        )
-        for (const memberId of available) {
-          conflictedExistingIds.add(memberId)
-        }
+    for (const matchKey of matchOrder) {
+      const value = candidates[matchKey]
+      if (value === null) {
         continue
       }
-      matchedExistingId = available[0] ?? null
-    }
-
-    if (matchedExistingId === null && normalizedEmail.length > 0) {
       const available = availableIdsForMatch(
-        existingByEmail.get(normalizedEmail),
+        existingIndexes[matchKey].get(value),
         matchedExistingIds,
       )
       if (available.length > 1) {
         recordConflict(
           conflicts,
           conflictSignatures,
-          "email",
-          normalizedEmail,
+          matchKey,
+          value,
           available,
         )
         for (const memberId of available) {
           conflictedExistingIds.add(memberId)
         }
-        continue
+        conflicted = true
+        break
       }
       matchedExistingId = available[0] ?? null
+      if (matchedExistingId !== null) {
+        matchedKey = matchKey
+        break
+      }
     }
 
-    if (matchedExistingId === null && studentNumber !== null) {
-      const available = availableIdsForMatch(
-        existingByStudentNumber.get(studentNumber),
-        matchedExistingIds,
-      )
-      if (available.length > 1) {
-        recordConflict(
-          conflicts,
-          conflictSignatures,
-          "studentNumber",
-          studentNumber,
-          available,
-        )
-        for (const memberId of available) {
-          conflictedExistingIds.add(memberId)
-        }
-        continue
-      }
-      matchedExistingId = available[0] ?? null
+    if (conflicted) {
+      continue
     }
 
-    if (matchedExistingId === null) {
+    if (matchedExistingId === null || matchedKey === null) {
       unmatchedIncoming.push(incomingMember)
       continue
     }
@@ -244,6 +313,7 @@ export function mergeRosterFromLmsWithConflicts(
 
     matchedExistingIds.add(matchedExistingId)
     incomingByMatchedExistingId.set(matchedExistingId, incomingMember)
+    matchKeyByExistingId.set(matchedExistingId, matchedKey)
   }
 
   let membersAdded = 0
@@ -252,6 +322,12 @@ export function mergeRosterFromLmsWithConflicts(
   const membersMissingEmail = allIncoming.filter(
     (member) => normalizeEmail(member.email).length === 0,
   ).length
+  const membersMatchedBy: Record<LmsImportMatchKey, number> = {
+    lmsUserId: 0,
+    email: 0,
+    studentNumber: 0,
+  }
+  const emailChanges: LmsImportEmailChange[] = []
 
   const merged: RosterMember[] = []
 
@@ -259,36 +335,31 @@ export function mergeRosterFromLmsWithConflicts(
     const match = incomingByMatchedExistingId.get(member.id)
 
     if (match !== undefined) {
-      const email = match.email || member.email
-      const lmsStatus: MemberStatus = match.lmsStatus ?? match.status
-      const hasManualDroppedOverride =
-        member.status === "dropped" && member.lmsStatus !== "dropped"
-      const status = hasManualDroppedOverride
-        ? "dropped"
-        : normalizeMissingEmailStatus(email, lmsStatus)
-      const mergedMember: RosterMember = {
-        id: member.id,
-        name: match.name,
-        email,
-        studentNumber: match.studentNumber ?? member.studentNumber,
-        gitUsername: member.gitUsername,
-        gitUsernameStatus: member.gitUsernameStatus,
-        status,
-        lmsStatus,
-        lmsUserId: match.lmsUserId ?? member.lmsUserId,
-        enrollmentType: match.enrollmentType,
-        enrollmentDisplay: match.enrollmentDisplay,
-        department: member.department,
-        institution: member.institution,
-        source: match.source,
-      }
+      const mergedMember = mergeMatchedMember(member, match)
       merged.push(mergedMember)
+      const matchKey = matchKeyByExistingId.get(member.id) ?? "lmsUserId"
+      membersMatchedBy[matchKey] += 1
+      const previousEmail = normalizeEmail(member.email)
+      if (
+        matchKey === "studentNumber" &&
+        mergedMember.studentNumber !== null &&
+        previousEmail.length > 0 &&
+        previousEmail !== normalizeEmail(mergedMember.email)
+      ) {
+        emailChanges.push({
+          memberId: member.id,
+          studentNumber: mergedMember.studentNumber,
+          previousEmail: member.email,
+          email: mergedMember.email,
+        })
+      }
       if (isRosterMemberEquivalent(member, mergedMember)) {
         membersUnchanged += 1
       } else {
         membersUpdated += 1
       }
     } else if (
+      markMissingAsDropped &&
       member.lmsUserId !== null &&
       !conflictedExistingIds.has(member.id)
     ) {
@@ -305,7 +376,7 @@ export function mergeRosterFromLmsWithConflicts(
         membersUpdated += 1
       }
     } else {
-      // Locally-added member -> leave unchanged
+      // Locally-added member or partial import -> leave unchanged
       merged.push(member)
       membersUnchanged += 1
     }
@@ -325,21 +396,12 @@ export function mergeRosterFromLmsWithConflicts(
     membersAdded += 1
   }
 
-  // Split into students and staff by enrollment type
-  const students: RosterMember[] = []
-  const staff: RosterMember[] = []
-  for (const member of merged) {
-    if (member.enrollmentType === "student") {
-      students.push(member)
-    } else {
-      staff.push(member)
-    }
-  }
+  const { students, staff } = splitMembersByEnrollment(merged)
 
   const roster = {
     connection: incoming.connection,
-    students: sortRosterMembers(students),
-    staff: sortRosterMembers(staff),
+    students,
+    staff,
     groups: existing.groups,
     groupSets: existing.groupSets,
     assignments: existing.assignments,
@@ -353,9 +415,11 @@ export function mergeRosterFromLmsWithConflicts(
       membersUpdated,
       membersUnchanged,
       membersMissingEmail,
+      membersMatchedBy,
     },
     conflicts,
     totalConflicts: conflicts.length,
+    emailChanges,
   }
 }
 
@@ -366,3 +430,136 @@ export function mergeRosterFromLms(
 ): Roster {
   return mergeRosterFromLmsWithConflicts(existing, incoming, sequences).roster
 }
+
+// ---------------------------------------------------------------------------
+// Roster union
+// ---------------------------------------------------------------------------
+
+/**
+ * Combine two rosters (e.g. a CSV import and an LMS import) without
+ * duplicating members.
+ *
+ * - Right-hand members match left-hand ones by lmsUserId, then email, and are
+ *   updated with the same field rules as an LMS merge.
+ * - An email match whose lmsUserIds differ is reported as a conflict; the
+ *   left-hand member is kept unchanged and the right-hand one is skipped.
+ * - Unmatched right-hand members are appended with freshly allocated ids.
+ * - Groups, group sets and assignments are unioned by id, keeping the
+ *   left-hand version on collisions. Right-hand group memberships are
+ *   rewritten to the merged member ids; conflicted members are left out.
+ */
+export function mergeRosters(
+  left: Roster,
+  right: Roster,
+  sequences: IdSequences = initialIdSequences(),
+): RosterMergeResult {
+  const leftMembers = [...left.students, ...left.staff]
+  const leftByLmsUserId = new Map<string, RosterMember>()
+  const leftByEmail = new Map<string, RosterMember>()
+  for (const member of leftMembers) {
+    const lmsUserId = normalizeOptionalString(member.lmsUserId)
+    if (lmsUserId !== null && !leftByLmsUserId.has(lmsUserId)) {
+      leftByLmsUserId.set(lmsUserId, member)
+    }
+    const email = normalizeEmail(member.email)
+    if (email.length > 0 && !leftByEmail.has(email)) {
+      leftByEmail.set(email, member)
+    }
+  }
+
+  const conflicts: RosterMergeConflict[] = []
+  const updatedById = new Map<string, RosterMember>()
+  const mergedIdByRightId = new Map<string, string>()
+  const appended: RosterMember[] = []
+  let seq = sequences
+
+  for (const member of [...right.students, ...right.staff]) {
+    const lmsUserId = normalizeOptionalString(member.lmsUserId)
+    const email = normalizeEmail(member.email)
+    const byLmsUserId =
+      lmsUserId === null ? undefined : leftByLmsUserId.get(lmsUserId)
+    const byEmail = email.length === 0 ? undefined : leftByEmail.get(email)
+
+    if (byLmsUserId === undefined && byEmail !== undefined) {
+      const leftLmsUserId = normalizeOptionalString(byEmail.lmsUserId)
+      if (
+        lmsUserId !== null &&
+        leftLmsUserId !== null &&
+        leftLmsUserId !== lmsUserId
+      ) {
+        conflicts.push({
+          email,
+          leftMemberId: byEmail.id,
+          leftLmsUserId,
+          rightMemberId: member.id,
+          rightLmsUserId: lmsUserId,
+        })
+        continue
+      }
+    }
+
+    const match = byLmsUserId ?? byEmail
+    if (match === undefined) {
+      const alloc = allocateMemberId(seq)
+      seq = alloc.sequences
+      appended.push({
+        ...member,
+        id: alloc.id,
+        status: normalizeMissingEmailStatus(member.email, member.status),
+      })
+      mergedIdByRightId.set(member.id, alloc.id)
+      continue
+    }
+
+    const base = updatedById.get(match.id) ?? match
+    updatedById.set(match.id, mergeMatchedMember(base, member))
+    mergedIdByRightId.set(member.id, match.id)
+  }
+
+  const { students, staff } = splitMembersByEnrollment([
+    ...leftMembers.map((member) => updatedById.get(member.id) ?? member),
+    ...appended,
+  ])
+
+  const leftGroupIds = new Set(left.groups.map((group) => group.id))
+  const rightGroups = right.groups
+    .filter((group) => !leftGroupIds.has(group.id))
+    .map((group) => ({
+      ...group,
+      memberIds: [
+        ...new Set(
+          group.memberIds.flatMap((memberId) => {
+            const mergedId = mergedIdByRightId.get(memberId)
+            return mergedId === undefined ? [] : [mergedId]
+          }),
+        ),
+      ],
+    }))
+  const leftGroupSetIds = new Set(left.groupSets.map((groupSet) => groupSet.id))
+  const leftAssignmentIds = new Set(
+    left.assignments.map((assignment) => assignment.id),
+  )
+
+  return {
+    roster: {
+      connection: left.connection,
+      students,
+      staff,
+      groups: [...left.groups, ...rightGroups],
+      groupSets: [
+        ...left.groupSets,
+        ...right.groupSets.filter(
+          (groupSet) => !leftGroupSetIds.has(groupSet.id),
+        ),
+      ],
+      assignments: [
+        ...left.assignments,
+        ...right.assignments.filter(
+          (assignment) => !leftAssignmentIds.has(assignment.id),
+        ),
+      ],
+    },
+    idSequences: seq,
+    conflicts,
+  }
+}
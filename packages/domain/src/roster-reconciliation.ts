@@ -1,5 +1,5 @@
 import { allocateMemberId } from "./id-allocator.js"
-import { normalizeOptionalString } from "./roster.js"
+import { allRosterMembers, normalizeOptionalString } from "./roster.js"
 import type { IdSequences, Roster, RosterMember } from "./types.js"
 
 export type ReconcileRosterFromGitUsernamesResult = {
@@ -10,7 +10,7 @@ export type ReconcileRosterFromGitUsernamesResult = {
 
 function buildGitUsernameIndex(roster: Roster): Map<string, string> {
   const index = new Map<string, string>()
-  for (const member of roster.students.concat(roster.staff)) {
+  for (const member of allRosterMembers(roster)) {
     const key = normalizeOptionalString(member.gitUsername)?.toLowerCase()
     if (!key || index.has(key)) {
       continue
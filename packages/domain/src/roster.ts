@@ -1,4 +1,5 @@
 import type {
+  EnrollmentType,
   MemberStatus,
   Roster,
   RosterMember,
@@ -190,6 +191,384 @@ export function normalizeRoster(
   }
 }
 
+// ---------------------------------------------------------------------------
+// Roster queries
+// ---------------------------------------------------------------------------
+
+export function allRosterMembers(roster: Roster): RosterMember[] {
+  return roster.students.concat(roster.staff)
+}
+
+export function activeStudents(roster: Roster): RosterMember[] {
+  return roster.students.filter((student) => student.status === "active")
+}
+
+/** Students whose status is `incomplete` or `dropped`. */
+export function inactiveStudents(roster: Roster): RosterMember[] {
+  return roster.students.filter((student) => student.status !== "active")
+}
+
+/**
+ * Active students counted once per member id. LMS rosters can list a student
+ * once per section they are enrolled in.
+ */
+export function uniqueActiveStudentCount(roster: Roster): number {
+  return new Set(activeStudents(roster).map((student) => student.id)).size
+}
+
+/**
+ * Students and staff with this enrollment type. A TA who is also enrolled as
+ * a student is listed under both types, once per entry.
+ */
+export function membersByEnrollmentType(
+  roster: Roster,
+  enrollmentType: EnrollmentType,
+): RosterMember[] {
+  return allRosterMembers(roster).filter(
+    (member) => member.enrollmentType === enrollmentType,
+  )
+}
+
+/**
+ * Whether two different active members share an email, ignoring case and
+ * surrounding whitespace. Members without an email and repeated entries for
+ * the same member id never count as duplicates.
+ */
+export function hasDuplicateEmails(roster: Roster): boolean {
+  const memberIdByEmail = new Map<string, string>()
+  for (const member of allRosterMembers(roster)) {
+    const email = normalizeEmail(member.email)
+    if (member.status !== "active" || email.length === 0) {
+      continue
+    }
+    const existingId = memberIdByEmail.get(email)
+    if (existingId !== undefined && existingId !== member.id) {
+      return true
+    }
+    memberIdByEmail.set(email, member.id)
+  }
+  return false
+}
+
+/** First student or staff member whose email matches, ignoring case. */
+export function findMemberByEmail(
+  roster: Roster,
+  email: string,
+): RosterMember | undefined {
+  const key = normalizeEmail(email)
+  if (key.length === 0) {
+    return undefined
+  }
+  return allRosterMembers(roster).find(
+    (member) => normalizeEmail(member.email) === key,
+  )
+}
+
+export function findMemberByLmsUserId(
+  roster: Roster,
+  lmsUserId: string,
+): RosterMember | undefined {
+  const key = lmsUserId.trim()
+  if (key.length === 0) {
+    return undefined
+  }
+  return allRosterMembers(roster).find((member) => member.lmsUserId === key)
+}
+
+/** First student or staff member with this student number. */
+export function findMemberByStudentNumber(
+  roster: Roster,
+  studentNumber: string,
+): RosterMember | undefined {
+  const key = studentNumber.trim()
+  if (key.length === 0) {
+    return undefined
+  }
+  return allRosterMembers(roster).find(
+    (member) => normalizeOptionalString(member.studentNumber) === key,
+  )
+}
+
+// ---------------------------------------------------------------------------
+// Member export
+// ---------------------------------------------------------------------------
+
+/** Member export columns; roster file import reads the same names back. */
+export const rosterMemberCsvHeaders = [
+  "name",
+  "email",
+  "student_number",
+  "git_username",
+  "status",
+  "enrollment_type",
+] as const
+
+export type RosterMemberCsvRow = Record<
+  (typeof rosterMemberCsvHeaders)[number],
+  string
+>
+
+export function rosterMemberCsvRow(member: RosterMember): RosterMemberCsvRow {
+  return {
+    name: member.name,
+    email: member.email,
+    student_number: member.studentNumber ?? "",
+    git_username: member.gitUsername ?? "",
+    status: member.status,
+    enrollment_type: member.enrollmentType,
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Member removal
+// ---------------------------------------------------------------------------
+
+/**
+ * `deactivate` marks the member `dropped` and keeps group memberships, so
+ * the member only disappears from active-member views; `purge` deletes the
+ * member and strips their id from every group.
+ */
+export type RosterMemberRemovalMode = "deactivate" | "purge"
+
+export type RosterMemberRemoval = {
+  memberId: string
+  /** False when no student or staff member has this id. */
+  found: boolean
+  /** Groups that listed the member, in roster order. */
+  affectedGroupIds: string[]
+}
+
+export type RemoveRosterMembersResult = {
+  roster: Roster
+  removals: RosterMemberRemoval[]
+}
+
+/**
+ * Removes members by id without leaving dangling group references. Returns
+ * one removal per requested id, in request order; duplicate ids are
+ * reported once.
+ */
+export function removeRosterMembers(
+  roster: Roster,
+  memberIds: readonly string[],
+  mode: RosterMemberRemovalMode,
+): RemoveRosterMembersResult {
+  const requestedIds = [...new Set(memberIds)]
+  const knownIds = new Set(allRosterMembers(roster).map((member) => member.id))
+  const removedIds = new Set(requestedIds.filter((id) => knownIds.has(id)))
+
+  const removals = requestedIds.map((memberId) => ({
+    memberId,
+    found: removedIds.has(memberId),
+    affectedGroupIds: removedIds.has(memberId)
+      ? roster.groups
+          .filter((group) => group.memberIds.includes(memberId))
+          .map((group) => group.id)
+      : [],
+  }))
+
+  if (removedIds.size === 0) {
+    return { roster, removals }
+  }
+
+  if (mode === "deactivate") {
+    const deactivate = (member: RosterMember): RosterMember =>
+      removedIds.has(member.id) ? { ...member, status: "dropped" } : member
+    return {
+      roster: {
+        ...roster,
+        students: roster.students.map(deactivate),
+        staff: roster.staff.map(deactivate),
+      },
+      removals,
+    }
+  }
+
+  const keep = (member: RosterMember) => !removedIds.has(member.id)
+  return {
+    roster: {
+      ...roster,
+      students: roster.students.filter(keep),
+      staff: roster.staff.filter(keep),
+      groups: roster.groups.map((group) =>
+        group.memberIds.some((id) => removedIds.has(id))
+          ? {
+              ...group,
+              memberIds: group.memberIds.filter((id) => !removedIds.has(id)),
+            }
+          : group,
+      ),
+    },
+    removals,
+  }
+}
+
+export function removeRosterMember(
+  roster: Roster,
+  memberId: string,
+  mode: RosterMemberRemovalMode,
+): { roster: Roster; removal: RosterMemberRemoval } {
+  const result = removeRosterMembers(roster, [memberId], mode)
+  return { roster: result.roster, removal: result.removals[0] }
+}
+
+// ---------------------------------------------------------------------------
+// Roster diff
+// ---------------------------------------------------------------------------
+
+export type RosterMemberChange = {
+  before: RosterMember
+  after: RosterMember
+}
+
+export type RosterDiff = {
+  added: RosterMember[]
+  removed: RosterMember[]
+  nameChanged: RosterMemberChange[]
+  emailChanged: RosterMemberChange[]
+}
+
+export const rosterDiffCsvHeaders = [
+  "change",
+  "id",
+  "name",
+  "email",
+  "previous_name",
+  "previous_email",
+] as const
+
+/**
+ * Compares two snapshots of the same roster, students and staff alike.
+ * Members are paired by id first, then by LMS user id, so a member that was
+ * re-imported under a new id still counts as the same person.
+ */
+export function diffRosters(before: Roster, after: Roster): RosterDiff {
+  const beforeMembers = allRosterMembers(before)
+  const afterMembers = allRosterMembers(after)
+  const unmatchedBefore = new Map(
+    beforeMembers.map((member) => [member.id, member] as const),
+  )
+  const pairs: RosterMemberChange[] = []
+  const unmatchedAfter: RosterMember[] = []
+
+  for (const member of afterMembers) {
+    const previous = unmatchedBefore.get(member.id)
+    if (previous === undefined) {
+      unmatchedAfter.push(member)
+      continue
+    }
+    unmatchedBefore.delete(member.id)
+    pairs.push({ before: previous, after: member })
+  }
+
+  const added: RosterMember[] = []
+  for (const member of unmatchedAfter) {
+    const previous =
+      member.lmsUserId === null
+        ? undefined
+        : [...unmatchedBefore.values()].find(
+            (candidate) => candidate.lmsUserId === member.lmsUserId,
+          )
+    if (previous === undefined) {
+      added.push(member)
+      continue
+    }
+    unmatchedBefore.delete(previous.id)
+    pairs.push({ before: previous, after: member })
+  }
+
+  return {
+    added,
+    removed: beforeMembers.filter((member) => unmatchedBefore.has(member.id)),
+    nameChanged: pairs.filter((pair) => pair.before.name !== pair.after.name),
+    emailChanged: pairs.filter(
+      (pair) =>
+        normalizeEmail(pair.before.email) !== normalizeEmail(pair.after.email),
+    ),
+  }
+}
+
+export function isRosterDiffEmpty(diff: RosterDiff): boolean {
+  return (
+    diff.added.length === 0 &&
+    diff.removed.length === 0 &&
+    diff.nameChanged.length === 0 &&
+    diff.emailChanged.length === 0
+  )
+}
+
+function formatMemberLine(member: RosterMember): string {
+  return member.email === "" ? member.name : `${member.name} <${member.email}>`
+}
+
+/** Renders the diff as Markdown sections, leaving out empty sections. */
+export function formatRosterDiffMarkdown(diff: RosterDiff): string {
+  if (isRosterDiffEmpty(diff)) {
+    return "No roster changes.\n"
+  }
+
+  const sections: string[] = []
+  const addSection = (title: string, lines: string[]) => {
+    if (lines.length > 0) {
+      sections.push(
+        [`## ${title} (${lines.length})`, "", ...lines].join("\n"),
+      )
+    }
+  }
+  addSection(
+    "Added",
+    diff.added.map((member) => `- ${formatMemberLine(member)}`),
+  )
+  addSection(
+    "Removed",
+    diff.removed.map((member) => `- ${formatMemberLine(member)}`),
+  )
+  addSection(
+    "Name changes",
+    diff.nameChanged.map(
+      ({ before, after }) => `- ${before.name} → ${after.name}`,
+    ),
+  )
+  addSection(
+    "Email changes",
+    diff.emailChanged.map(
+      ({ before, after }) =>
+        `- ${after.name}: ${before.email || "(none)"} → ${after.email || "(none)"}`,
+    ),
+  )
+  return `${sections.join("\n\n")}\n`
+}
+
+/**
+ * One row per change, in `rosterDiffCsvHeaders` order. A member whose name
+ * and email both changed produces two rows.
+ */
+export function rosterDiffCsvRows(diff: RosterDiff): string[][] {
+  const memberRow = (change: string, member: RosterMember) => [
+    change,
+    member.id,
+    member.name,
+    member.email,
+    "",
+    "",
+  ]
+  const changeRow = (change: string, { before, after }: RosterMemberChange) => [
+    change,
+    after.id,
+    after.name,
+    after.email,
+    before.name,
+    before.email,
+  ]
+
+  return [
+    ...diff.added.map((member) => memberRow("added", member)),
+    ...diff.removed.map((member) => memberRow("removed", member)),
+    ...diff.nameChanged.map((pair) => changeRow("name_changed", pair)),
+    ...diff.emailChanged.map((pair) => changeRow("email_changed", pair)),
+  ]
+}
+
 // ---------------------------------------------------------------------------
 // Name parsing and group naming
 // ---------------------------------------------------------------------------
@@ -47,6 +47,16 @@ const rosterConnectionSchema = z
       courseId: z.string(),
       lastUpdated: z.string(),
     }),
+    z.object({
+      kind: z.literal("blackboard"),
+      courseId: z.string(),
+      lastUpdated: z.string(),
+    }),
+    z.object({
+      kind: z.literal("brightspace"),
+      courseId: z.string(),
+      lastUpdated: z.string(),
+    }),
     z.object({
       kind: z.literal("import"),
       sourceFilename: z.string(),
@@ -105,6 +115,18 @@ const groupSetConnectionSchema = z
       groupingId: z.string(),
       lastUpdated: z.string(),
     }),
+    z.object({
+      kind: z.literal("blackboard"),
+      courseId: z.string(),
+      groupSetId: z.string(),
+      lastUpdated: z.string(),
+    }),
+    z.object({
+      kind: z.literal("brightspace"),
+      courseId: z.string(),
+      groupSetId: z.string(),
+      lastUpdated: z.string(),
+    }),
     z.object({
       kind: z.literal("import"),
       sourceFilename: z.string(),
@@ -134,6 +156,8 @@ const assignmentSchema = z.object({
   groupSetId: localGroupSetIdSchema,
   repositoryTemplate: repositoryTemplateSchema.nullable().optional(),
   templateCommitSha: z.string().nullable().optional(),
+  deadline: z.iso.datetime({ offset: true }).nullable().optional(),
+  requiredFiles: z.array(z.string()).optional(),
   repositories: z.record(z.string(), z.string()).default({}),
 })
 
@@ -191,7 +215,12 @@ const idSequencesSchema = z.object({
 })
 
 function isLmsConnectionKind(kind: string): boolean {
-  return kind === "canvas" || kind === "moodle"
+  return (
+    kind === "canvas" ||
+    kind === "moodle" ||
+    kind === "blackboard" ||
+    kind === "brightspace"
+  )
 }
 
 function isLmsMemberSource(source: string): boolean {
@@ -72,8 +72,9 @@ const persistedLmsConnectionSchema = z
   .object({
     id: z.string(),
     name: z.string(),
-    provider: z.enum(["canvas", "moodle"]),
+    provider: z.enum(["canvas", "moodle", "blackboard", "brightspace"]),
     ...persistedConnectionFields,
+    tokenExpiresAt: z.string().nullable().optional(),
   })
   .strict()
 
@@ -175,6 +176,25 @@ const persistedAnalysisSidebarSettingsSchema = z
   })
   .strict()
 
+const groupSelectionModeSchema = z.discriminatedUnion("kind", [
+  z.object({ kind: z.literal("all") }).strict(),
+  z
+    .object({
+      kind: z.literal("multi-pattern"),
+      patterns: z.array(z.string().min(1)).min(1),
+    })
+    .strict(),
+])
+
+const persistedGroupSelectionSchema = z
+  .object({
+    mode: groupSelectionModeSchema,
+    matchCase: z.boolean(),
+    useRegex: z.boolean(),
+  })
+  .strict()
+  .default({ mode: { kind: "all" }, matchCase: true, useRegex: false })
+
 const persistedAnalysisConcurrencySchema = z
   .object({
     repoParallelism: z.number().int().min(1).max(8).default(3),
@@ -412,6 +432,7 @@ const persistedAppPreferencesFields = {
     .default(null),
   defaultExtensions: extensionsSchema().default([...DEFAULT_EXTENSIONS]),
   analysisConcurrency: persistedAnalysisConcurrencySchema,
+  groupSelection: persistedGroupSelectionSchema,
 } as const
 
 function prunePersistedPreferences<
@@ -473,6 +494,14 @@ export type AppAppearance = z.infer<typeof appAppearanceSchema>
 export type PersistedAnalysisSidebarSettings = z.infer<
   typeof persistedAnalysisSidebarSettingsSchema
 >
+/**
+ * How the Copy from Group Set dialog picks source groups. Patterns are kept
+ * as a list, so a comma inside a group name is matched literally.
+ */
+export type GroupSelectionMode = z.infer<typeof groupSelectionModeSchema>
+export type PersistedGroupSelection = z.infer<
+  typeof persistedGroupSelectionSchema
+>
 export type PersistedAnalysisConcurrency = z.infer<
   typeof persistedAnalysisConcurrencySchema
 >
@@ -698,6 +727,11 @@ export const defaultAppPreferences: PersistedAppPreferences = {
     repoParallelism: 3,
     filesPerRepo: 4,
   },
+  groupSelection: {
+    mode: { kind: "all" },
+    matchCase: true,
+    useRegex: false,
+  },
 }
 
 export function composeAppSettings(
@@ -25,7 +25,7 @@ export const enrollmentTypeKinds = [
 ] as const
 export const groupOriginKinds = ["system", "lms", "local"] as const
 
-export type LmsProviderKind = "canvas" | "moodle"
+export type LmsProviderKind = "canvas" | "moodle" | "blackboard" | "brightspace"
 export type GitProviderKind = (typeof gitProviderKinds)[number]
 export type CourseBacking = "lms" | "repobee"
 export type ProviderKind = LmsProviderKind | GitProviderKind | "git"
@@ -36,7 +36,7 @@ export type GroupOrigin = (typeof groupOriginKinds)[number]
 export type GitIdentityMode = "email" | "username"
 
 export type ActiveTab = "roster" | "groups-assignments" | "analysis"
-export type FileFormat = "csv" | "xlsx" | "json" | "txt"
+export type FileFormat = "csv" | "xlsx" | "json" | "txt" | "yaml"
 export type ThemePreference = "system" | "light" | "dark"
 export type WindowChromeMode = "system" | "hiddenInset"
 export type DateFormatPreference = "MDY" | "DMY"
@@ -54,6 +54,16 @@ export type RosterConnection =
       courseId: string
       lastUpdated: string
     }
+  | {
+      kind: "blackboard"
+      courseId: string
+      lastUpdated: string
+    }
+  | {
+      kind: "brightspace"
+      courseId: string
+      lastUpdated: string
+    }
   | {
       kind: "import"
       sourceFilename: string
@@ -86,17 +96,52 @@ export type Roster = {
   assignments: Assignment[]
 }
 
+export type LmsImportMatchKey = "lmsUserId" | "email" | "studentNumber"
+
+/**
+ * Which identifier an LMS import tries first. `studentNumber` suits
+ * institutions whose official exports key students by number, not email.
+ */
+export type LmsImportMatchPriority = "lmsUserId" | "studentNumber"
+
 export type LmsImportConflict = {
-  matchKey: "lmsUserId" | "email" | "studentNumber"
+  matchKey: LmsImportMatchKey
   value: string
   matchedIds: string[]
 }
 
+export type RosterMergeConflict = {
+  email: string
+  leftMemberId: string
+  leftLmsUserId: string
+  rightMemberId: string
+  rightLmsUserId: string
+}
+
+export type RosterMergeResult = {
+  roster: Roster
+  idSequences: IdSequences
+  conflicts: RosterMergeConflict[]
+}
+
 export type RosterImportFromLmsSummary = {
   membersAdded: number
   membersUpdated: number
   membersUnchanged: number
   membersMissingEmail: number
+  /** Matched existing members, by the identifier that resolved the match. */
+  membersMatchedBy: Record<LmsImportMatchKey, number>
+}
+
+/**
+ * An existing member matched by student number whose LMS email differs,
+ * typically after an email change between semesters.
+ */
+export type LmsImportEmailChange = {
+  memberId: string
+  studentNumber: string
+  previousEmail: string
+  email: string
 }
 
 export type RosterImportFromLmsResult = {
@@ -105,6 +150,7 @@ export type RosterImportFromLmsResult = {
   summary: RosterImportFromLmsSummary
   conflicts: LmsImportConflict[]
   totalConflicts: number
+  emailChanges: LmsImportEmailChange[]
 }
 
 export type Group = {
@@ -137,6 +183,18 @@ export type GroupSetConnection =
       groupingId: string
       lastUpdated: string
     }
+  | {
+      kind: "blackboard"
+      courseId: string
+      groupSetId: string
+      lastUpdated: string
+    }
+  | {
+      kind: "brightspace"
+      courseId: string
+      groupSetId: string
+      lastUpdated: string
+    }
   | {
       kind: "import"
       sourceFilename: string
@@ -171,6 +229,10 @@ export type Assignment = {
   groupSetId: string
   repositoryTemplate?: RepositoryTemplate | null
   templateCommitSha?: string | null
+  /** ISO 8601 due date; validation warns once it has passed. */
+  deadline?: string | null
+  /** Paths, relative to the template root, the template must contain. */
+  requiredFiles?: string[]
   repositories: Record<string /* groupId */, string /* repoName */>
 }
 
@@ -352,6 +414,18 @@ export type GroupSetRenamedGroup = {
   to: string
 }
 
+/**
+ * Structural findings for a group-set CSV, gathered without a roster. Line
+ * numbers count the header as line 1.
+ */
+export type GroupSetImportCsvReport = {
+  totalRows: number
+  groupCount: number
+  missingColumns: string[]
+  emptyGroupNameLines: number[]
+  duplicateMemberships: string[]
+}
+
 export type GroupSetImportFormat = "group-set-csv" | "repobee-students"
 
 export type GroupSetImportMemberKey = "email" | "gitUsername"
@@ -378,6 +452,13 @@ export type GroupSetImportPreview =
       unchangedTeams: string[][]
     }
 
+export type GroupSetImportWarning = {
+  kind: "truncated"
+  /** Group name as imported, after truncation. */
+  groupName: string
+  originalLength: number
+}
+
 export type GroupSetImportResult = {
   mode: "import" | "replace"
   groupSet: GroupSet
@@ -385,6 +466,7 @@ export type GroupSetImportResult = {
   deletedGroupIds: string[]
   missingMembers: GroupSetImportMissingMember[]
   totalMissing: number
+  warnings: GroupSetImportWarning[]
   idSequences: IdSequences
 }
 
@@ -394,6 +476,20 @@ export type GroupSetExportRow = {
   email: string
 }
 
+/** A team bound to one assignment, with members identified by Git username. */
+export type AssignmentTeam = {
+  assignment: string
+  teamName: string
+  memberGitUsernames: string[]
+}
+
+/** One `teams.csv` row: a team member, or a team with no members. */
+export type TeamsCsvRow = {
+  team_name: string
+  member_git_username: string
+  assignment: string
+}
+
 export type RepoOperationMode = "create" | "clone" | "update"
 
 export type RepoCollisionKind = "already_exists" | "not_found"
@@ -463,6 +559,9 @@ export type RosterValidationKind =
   | "invalid_git_username"
   | "unassigned_student"
   | "student_in_multiple_groups_in_assignment"
+  | "assignment_deadline_passed"
+  | "stale_lms_group_set"
+  | "missing_required_file"
 
 export type RosterValidationIssue = {
   kind: RosterValidationKind
@@ -1,12 +1,17 @@
 import { resolveAssignmentGroups } from "./group-selection.js"
-import { systemSetsMissing } from "./group-set.js"
+import { isLmsGroupSetSyncStale, systemSetsMissing } from "./group-set.js"
 import { normalizeName } from "./name-normalization.js"
 import { computeRepoName, defaultRepoTemplate } from "./repository-planning.js"
-import { normalizeEmail } from "./roster.js"
+import {
+  activeStudents,
+  allRosterMembers,
+  normalizeEmail,
+} from "./roster.js"
 import type {
   Assignment,
   GitIdentityMode,
   Group,
+  GroupSet,
   NamedGroupSet,
   Roster,
   RosterValidationIssue,
@@ -69,6 +74,8 @@ function validateGroupSetOriginConsistency(
           return group.origin === "system"
         case "canvas":
         case "moodle":
+        case "blackboard":
+        case "brightspace":
           return group.origin === "lms"
         case "import":
           return group.origin === "local" && group.lmsGroupId === null
@@ -234,9 +241,7 @@ export function validateRoster(roster: Roster): RosterValidationResult {
     })
   }
 
-  const allMemberIds = roster.students
-    .concat(roster.staff)
-    .map((member) => member.id)
+  const allMemberIds = allRosterMembers(roster).map((member) => member.id)
   const duplicateMemberIds = findDuplicateStrings(allMemberIds)
   if (duplicateMemberIds.length > 0) {
     issues.push({
@@ -368,16 +373,68 @@ export function validateRoster(roster: Roster): RosterValidationResult {
   return { issues }
 }
 
+export type AssignmentValidationOptions = {
+  /** Reference time for deadline and LMS sync checks; skipped when omitted. */
+  now?: Date
+  /** Defaults to `DEFAULT_LMS_SYNC_MAX_AGE_HOURS`. */
+  lmsSyncMaxAgeHours?: number
+}
+
+function validateAssignmentDeadline(
+  assignment: Assignment,
+  now: Date | undefined,
+): RosterValidationIssue[] {
+  if (now === undefined || !assignment.deadline) {
+    return []
+  }
+
+  const deadline = Date.parse(assignment.deadline)
+  if (Number.isNaN(deadline) || now.getTime() <= deadline) {
+    return []
+  }
+
+  return [
+    {
+      kind: "assignment_deadline_passed",
+      affectedIds: [assignment.id],
+      context: assignment.deadline,
+    },
+  ]
+}
+
+function validateGroupSetFreshness(
+  groupSet: GroupSet | undefined,
+  options: AssignmentValidationOptions | undefined,
+): RosterValidationIssue[] {
+  if (
+    groupSet === undefined ||
+    options?.now === undefined ||
+    !isLmsGroupSetSyncStale(groupSet, options.now, options.lmsSyncMaxAgeHours)
+  ) {
+    return []
+  }
+
+  return [
+    {
+      kind: "stale_lms_group_set",
+      affectedIds: [groupSet.id],
+      context: groupSet.name,
+    },
+  ]
+}
+
 export function validateAssignment(
   roster: Roster,
   assignmentId: string,
   identityMode: GitIdentityMode,
+  options?: AssignmentValidationOptions,
 ): RosterValidationResult {
   return validateAssignmentWithTemplate(
     roster,
     assignmentId,
     identityMode,
     defaultRepoTemplate,
+    options,
   )
 }
 
@@ -386,6 +443,7 @@ export function validateAssignmentWithTemplate(
   assignmentId: string,
   identityMode: GitIdentityMode,
   template: string,
+  options?: AssignmentValidationOptions,
 ): RosterValidationResult {
   const assignment = roster.assignments.find(
     (candidate) => candidate.id === assignmentId,
@@ -397,21 +455,27 @@ export function validateAssignmentWithTemplate(
   const groupSet = roster.groupSets.find(
     (candidate) => candidate.id === assignment.groupSetId,
   )
+  const timeIssues = [
+    ...validateAssignmentDeadline(assignment, options?.now),
+    ...validateGroupSetFreshness(groupSet, options),
+  ]
+
   if (groupSet?.nameMode === "unnamed") {
     return {
-      issues: validateUnnamedAssignmentWithTemplate(
-        groupSet,
-        assignment,
-        template,
-      ),
+      issues: [
+        ...validateUnnamedAssignmentWithTemplate(
+          groupSet,
+          assignment,
+          template,
+        ),
+        ...timeIssues,
+      ],
     }
   }
 
   const groups = resolveAssignmentGroups(roster, assignment)
   const memberLookup = new Map(
-    roster.students
-      .concat(roster.staff)
-      .map((member) => [member.id, member] as const),
+    allRosterMembers(roster).map((member) => [member.id, member] as const),
   )
   const issues: RosterValidationIssue[] = []
 
@@ -498,8 +562,7 @@ export function validateAssignmentWithTemplate(
     })
   }
 
-  const unassignedActiveStudents = roster.students
-    .filter((member) => member.status === "active")
+  const unassignedActiveStudents = activeStudents(roster)
     .filter((member) => !assignedActiveStudents.has(member.id))
     .map((member) => member.id)
   if (unassignedActiveStudents.length > 0) {
@@ -529,9 +592,39 @@ export function validateAssignmentWithTemplate(
     })
   }
 
+  issues.push(...timeIssues)
   return { issues }
 }
 
+/**
+ * Trims required template paths, uses `/` separators, drops leading `./` or
+ * `/` and removes blanks and duplicates, keeping the first occurrence.
+ */
+export function normalizeRequiredFiles(files: readonly string[]): string[] {
+  const normalized = files
+    .map((file) => file.trim().replaceAll("\\", "/").replace(/^(\.?\/)+/, ""))
+    .filter((file) => file.length > 0)
+  return [...new Set(normalized)]
+}
+
+/**
+ * One `missing_required_file` issue per required file absent from the
+ * template. `presentFiles` holds the normalized paths found in the template.
+ */
+export function validateRequiredTemplateFiles(
+  assignment: Assignment,
+  requiredFiles: readonly string[],
+  presentFiles: ReadonlySet<string>,
+): RosterValidationIssue[] {
+  return normalizeRequiredFiles(requiredFiles)
+    .filter((file) => !presentFiles.has(file))
+    .map((file): RosterValidationIssue => ({
+      kind: "missing_required_file",
+      affectedIds: [assignment.id],
+      context: file,
+    }))
+}
+
 export function isBlockingValidationKind(kind: RosterValidationKind): boolean {
   switch (kind) {
     case "duplicate_student_id":
@@ -546,12 +639,15 @@ export function isBlockingValidationKind(kind: RosterValidationKind): boolean {
     case "system_group_sets_missing":
     case "invalid_enrollment_partition":
     case "invalid_group_origin":
+    case "missing_required_file":
       return true
     case "missing_email":
     case "missing_git_username":
     case "invalid_git_username":
     case "unassigned_student":
     case "student_in_multiple_groups_in_assignment":
+    case "assignment_deadline_passed":
+    case "stale_lms_group_set":
       return false
   }
 }
@@ -47,6 +47,8 @@ function inferMediaType(format: FileFormat | null): string | null {
       return "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
     case "txt":
       return "text/plain"
+    case "yaml":
+      return "application/yaml"
     default:
       return "text/plain"
   }
@@ -104,6 +106,13 @@ function inferFormatFromFile(file: MockReadableFile): FileFormat | null {
   if (loweredName.endsWith(".txt") || file.mediaType === "text/plain") {
     return "txt"
   }
+  if (
+    loweredName.endsWith(".yaml") ||
+    loweredName.endsWith(".yml") ||
+    file.mediaType === "application/yaml"
+  ) {
+    return "yaml"
+  }
   return null
 }
 
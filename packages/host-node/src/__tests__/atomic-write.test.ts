@@ -3,7 +3,14 @@ import { mkdir, mkdtemp, readdir, readFile, writeFile } from "node:fs/promises"
 import { tmpdir } from "node:os"
 import { join } from "node:path"
 import { describe, it } from "node:test"
-import { createWriteQueue, writeTextFileAtomic } from "../index.js"
+import {
+  cleanupAtomicTempFiles,
+  createWriteQueue,
+  JsonFileValidationError,
+  type JsonFileValidator,
+  writeJsonFileAtomicValidated,
+  writeTextFileAtomic,
+} from "../index.js"
 
 describe("writeTextFileAtomic", () => {
   it("replaces existing file content", async () => {
@@ -39,6 +46,86 @@ describe("writeTextFileAtomic", () => {
     const afterEntries = await readdir(root)
     assert.deepStrictEqual(afterEntries.sort(), beforeEntries.sort())
   })
+
+  it("recovers from a crashed write without losing the final content", async () => {
+    const root = await mkdtemp(join(tmpdir(), "repo-edu-host-node-"))
+    const targetPath = join(root, "app-settings.json")
+
+    await writeTextFileAtomic(targetPath, '{"theme":"light"}')
+    // A crash between write and rename leaves a partial temp file behind.
+    await writeFile(join(root, ".app-settings.json.1.2.crashed.tmp"), '{"th')
+
+    await cleanupAtomicTempFiles(root)
+    await writeTextFileAtomic(targetPath, '{"theme":"dark"}', undefined, {
+      sync: false,
+    })
+
+    assert.deepStrictEqual(await readdir(root), ["app-settings.json"])
+    assert.equal(await readFile(targetPath, "utf8"), '{"theme":"dark"}')
+  })
+})
+
+const requireStringTheme: JsonFileValidator = (value) =>
+  typeof (value as { theme?: unknown } | null)?.theme === "string"
+    ? { ok: true }
+    : { ok: false, issues: [{ path: "theme", message: "Expected a string." }] }
+
+describe("writeJsonFileAtomicValidated", () => {
+  it("writes indented JSON when the value passes validation", async () => {
+    const root = await mkdtemp(join(tmpdir(), "repo-edu-host-node-"))
+    const targetPath = join(root, "app-settings.json")
+
+    const content = await writeJsonFileAtomicValidated(
+      targetPath,
+      { theme: "dark" },
+      requireStringTheme,
+    )
+
+    assert.equal(content, '{\n  "theme": "dark"\n}')
+    assert.equal(await readFile(targetPath, "utf8"), content)
+  })
+
+  it("leaves the file on disk unchanged when validation fails", async () => {
+    const root = await mkdtemp(join(tmpdir(), "repo-edu-host-node-"))
+    const targetPath = join(root, "app-settings.json")
+    await writeFile(targetPath, '{"theme":"light"}', "utf8")
+
+    await assert.rejects(
+      writeJsonFileAtomicValidated(
+        targetPath,
+        { theme: 42 },
+        requireStringTheme,
+      ),
+      (error: unknown) => {
+        assert.ok(error instanceof JsonFileValidationError)
+        assert.equal(error.path, targetPath)
+        assert.deepStrictEqual(error.issues, [
+          { path: "theme", message: "Expected a string." },
+        ])
+        return true
+      },
+    )
+
+    assert.equal(await readFile(targetPath, "utf8"), '{"theme":"light"}')
+    assert.deepStrictEqual(await readdir(root), ["app-settings.json"])
+  })
+
+  it("validates the serialized form rather than the in-memory value", async () => {
+    const root = await mkdtemp(join(tmpdir(), "repo-edu-host-node-"))
+    const targetPath = join(root, "app-settings.json")
+
+    // `undefined` properties are dropped by JSON.stringify.
+    await assert.rejects(
+      writeJsonFileAtomicValidated(
+        targetPath,
+        { theme: undefined },
+        requireStringTheme,
+      ),
+      JsonFileValidationError,
+    )
+
+    assert.deepStrictEqual(await readdir(root), [])
+  })
 })
 
 describe("createWriteQueue", () => {
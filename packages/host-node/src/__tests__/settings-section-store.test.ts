@@ -125,6 +125,30 @@ describe("createNodeSettingsSectionStore", () => {
       }
     })
   })
+
+  it("refuses to save an invalid section over the existing file", async () => {
+    await withSettingsDirectory(async (settingsDirectory) => {
+      const store = createNodeSettingsSectionStore({
+        settingsDirectory,
+        fileName: "preferences.json",
+        unit: "preferences",
+        validate: validateTestSection,
+      })
+      const sectionPath = join(settingsDirectory, "preferences.json")
+      await store.save({ kind: "test-section", value: "saved" })
+      const before = await readFile(sectionPath, "utf8")
+
+      await assert.rejects(
+        store.save({ kind: "wrong" } as unknown as TestSection),
+        /Refusing to write invalid JSON/,
+      )
+
+      assert.equal(await readFile(sectionPath, "utf8"), before)
+      assert.deepStrictEqual(await readdir(settingsDirectory), [
+        "preferences.json",
+      ])
+    })
+  })
 })
 
 describe("recoverUnsupportedCompositeSettingsFile", () => {
@@ -0,0 +1,116 @@
+import assert from "node:assert/strict"
+import { chmod, mkdir, mkdtemp, rm } from "node:fs/promises"
+import { tmpdir } from "node:os"
+import { join } from "node:path"
+import { describe, it } from "node:test"
+import { createNodeSettingsSectionStore } from "../settings-section-store.js"
+import {
+  checkStorageDirectoryAccess,
+  isStoragePermissionError,
+  StoragePermissionError,
+  toStoragePermissionError,
+} from "../storage-access.js"
+
+// Root ignores file modes and Windows ignores POSIX modes, so read-only
+// directories cannot be simulated there.
+const skipReadOnly =
+  process.platform === "win32" || process.getuid?.() === 0
+    ? "read-only directories cannot be simulated for this user"
+    : false
+
+async function withTempRoot<T>(run: (root: string) => Promise<T>): Promise<T> {
+  const root = await mkdtemp(join(tmpdir(), "repo-edu-host-node-access-"))
+  try {
+    return await run(root)
+  } finally {
+    await chmod(root, 0o700).catch(() => {})
+    await rm(root, { recursive: true, force: true })
+  }
+}
+
+function errnoError(code: string): NodeJS.ErrnoException {
+  return Object.assign(new Error(code), { code })
+}
+
+describe("toStoragePermissionError", () => {
+  it("wraps permission and read-only file system errors", () => {
+    for (const code of ["EACCES", "EPERM", "EROFS"]) {
+      const wrapped = toStoragePermissionError(errnoError(code), "/data")
+      assert.ok(isStoragePermissionError(wrapped))
+      assert.equal(wrapped.path, "/data")
+      assert.equal(wrapped.code, code)
+    }
+  })
+
+  it("returns other errors unchanged", () => {
+    const error = errnoError("ENOSPC")
+    assert.equal(toStoragePermissionError(error, "/data"), error)
+    assert.equal(toStoragePermissionError("boom", "/data"), "boom")
+  })
+})
+
+describe("checkStorageDirectoryAccess", () => {
+  it("reports no issues for writable and not-yet-created directories", async () => {
+    await withTempRoot(async (root) => {
+      await mkdir(join(root, "settings"))
+
+      const issues = await checkStorageDirectoryAccess([
+        join(root, "settings"),
+        join(root, "courses", "nested"),
+      ])
+
+      assert.deepStrictEqual(issues, [])
+    })
+  })
+
+  it(
+    "reports read-only directories without throwing",
+    { skip: skipReadOnly },
+    async () => {
+      await withTempRoot(async (root) => {
+        const settings = join(root, "settings")
+        await mkdir(settings)
+        await chmod(settings, 0o500)
+        await chmod(root, 0o500)
+
+        const issues = await checkStorageDirectoryAccess([
+          settings,
+          join(root, "courses"),
+        ])
+
+        assert.deepStrictEqual(
+          issues.map((issue) => [issue.path, issue.access]),
+          [
+            [settings, "write"],
+            [root, "write"],
+          ],
+        )
+        assert.equal(issues[0]?.code, "EACCES")
+      })
+    },
+  )
+
+  it(
+    "surfaces settings saves into read-only directories as permission errors",
+    { skip: skipReadOnly },
+    async () => {
+      await withTempRoot(async (root) => {
+        await chmod(root, 0o500)
+        const settingsDirectory = join(root, "settings")
+        const store = createNodeSettingsSectionStore({
+          settingsDirectory,
+          fileName: "preferences.json",
+          unit: "preferences",
+          validate: (value) => ({ ok: true, value }),
+        })
+
+        await assert.rejects(
+          () => store.save({ theme: "dark" }),
+          (error: unknown) =>
+            error instanceof StoragePermissionError &&
+            error.path === settingsDirectory,
+        )
+      })
+    },
+  )
+})
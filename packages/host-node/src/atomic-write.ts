@@ -1,5 +1,5 @@
 import { randomUUID } from "node:crypto"
-import { mkdir, readdir, rename, rm, stat, writeFile } from "node:fs/promises"
+import { mkdir, open, readdir, rename, rm, stat } from "node:fs/promises"
 import { basename, dirname, join } from "node:path"
 
 function throwIfAborted(signal?: AbortSignal) {
@@ -36,12 +36,41 @@ export async function cleanupAtomicTempFiles(directory: string): Promise<void> {
   await Promise.all(removals)
 }
 
+export type WriteTextFileAtomicOptions = {
+  /**
+   * Flush the temporary file to disk before the rename so a crash cannot
+   * leave a renamed but empty file behind. Defaults to `true`.
+   */
+  sync?: boolean
+}
+
+// Directory fsync makes the rename itself durable. Windows cannot open
+// directories for syncing, so the step is best-effort everywhere.
+async function syncDirectory(directory: string): Promise<void> {
+  if (process.platform === "win32") {
+    return
+  }
+  const handle = await open(directory, "r").catch(() => null)
+  if (handle === null) {
+    return
+  }
+  try {
+    await handle.sync()
+  } catch {
+    // Some file systems reject fsync on directories.
+  } finally {
+    await handle.close()
+  }
+}
+
 export async function writeTextFileAtomic(
   path: string,
   content: string,
   signal?: AbortSignal,
+  options?: WriteTextFileAtomicOptions,
 ): Promise<void> {
   throwIfAborted(signal)
+  const sync = options?.sync ?? true
   const parentDirectory = dirname(path)
   await mkdir(parentDirectory, { recursive: true })
   throwIfAborted(signal)
@@ -49,14 +78,74 @@ export async function writeTextFileAtomic(
   const existing = await stat(path).catch(() => null)
 
   try {
-    await writeFile(temporaryPath, content, {
-      encoding: "utf8",
-      mode: existing?.mode,
-    })
+    const handle = await open(temporaryPath, "w", existing?.mode)
+    try {
+      await handle.writeFile(content, "utf8")
+      if (sync) {
+        await handle.sync()
+      }
+    } finally {
+      await handle.close()
+    }
     throwIfAborted(signal)
     await rename(temporaryPath, path)
   } catch (error) {
     await rm(temporaryPath, { force: true }).catch(() => {})
     throw error
   }
+
+  if (sync) {
+    await syncDirectory(parentDirectory)
+  }
+}
+
+export type JsonFileValidationIssue = {
+  path: string
+  message: string
+}
+
+export type JsonFileValidator = (
+  value: unknown,
+) => { ok: true } | { ok: false; issues: readonly JsonFileValidationIssue[] }
+
+export class JsonFileValidationError extends Error {
+  readonly path: string
+  readonly issues: readonly JsonFileValidationIssue[]
+
+  constructor(path: string, issues: readonly JsonFileValidationIssue[]) {
+    super(
+      `Refusing to write invalid JSON to ${path}: ${issues
+        .map((issue) => `${issue.path}: ${issue.message}`)
+        .join("; ")}`,
+    )
+    this.name = "JsonFileValidationError"
+    this.path = path
+    this.issues = issues
+  }
+}
+
+/**
+ * Serializes `value` as indented JSON and writes it atomically, but only when
+ * the serialized document passes `validate`. The check runs on the parsed
+ * output so values that do not survive `JSON.stringify` are caught too; an
+ * invalid value leaves the existing file untouched.
+ */
+export async function writeJsonFileAtomicValidated(
+  path: string,
+  value: unknown,
+  validate: JsonFileValidator,
+  signal?: AbortSignal,
+  options?: WriteTextFileAtomicOptions,
+): Promise<string> {
+  throwIfAborted(signal)
+  const content = JSON.stringify(value, null, 2)
+  const validation = validate(
+    content === undefined ? undefined : JSON.parse(content),
+  )
+  if (!validation.ok) {
+    throw new JsonFileValidationError(path, validation.issues)
+  }
+
+  await writeTextFileAtomic(path, content, signal, options)
+  return content
 }
@@ -65,9 +65,16 @@ export const workspaceDependencies = [
 
 export type { ResolveRepoEduAppDataRootOptions } from "./app-data-root.js"
 export { resolveRepoEduAppDataRoot } from "./app-data-root.js"
+export type {
+  JsonFileValidationIssue,
+  JsonFileValidator,
+  WriteTextFileAtomicOptions,
+} from "./atomic-write.js"
 export {
   cleanupAtomicTempFiles,
   createWriteQueue,
+  JsonFileValidationError,
+  writeJsonFileAtomicValidated,
   writeTextFileAtomic,
 } from "./atomic-write.js"
 export type {
@@ -80,6 +87,16 @@ export {
   createNodeSettingsSectionStore,
   recoverUnsupportedCompositeSettingsFile,
 } from "./settings-section-store.js"
+export type {
+  StorageAccessIssue,
+  StorageAccessMode,
+} from "./storage-access.js"
+export {
+  checkStorageDirectoryAccess,
+  isStoragePermissionError,
+  StoragePermissionError,
+  toStoragePermissionError,
+} from "./storage-access.js"
 
 function throwIfAborted(signal?: AbortSignal) {
   if (signal?.aborted) {
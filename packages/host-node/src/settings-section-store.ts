@@ -3,8 +3,9 @@ import { basename, dirname, extname, join } from "node:path"
 import {
   cleanupAtomicTempFiles,
   createWriteQueue,
-  writeTextFileAtomic,
+  writeJsonFileAtomicValidated,
 } from "./atomic-write.js"
+import { toStoragePermissionError } from "./storage-access.js"
 
 export type NodeSettingsRecoveryUnit =
   | "credentials"
@@ -158,16 +159,18 @@ export function createNodeSettingsSectionStore<T>({
       }
     },
 
+    // Sections are validated before they reach disk so a bad in-memory value
+    // cannot replace a good settings file.
     async save(section: T, signal?: AbortSignal) {
       await enqueueWrite(async () => {
         throwIfAborted(signal)
-        await mkdir(settingsDirectory, { recursive: true })
-        throwIfAborted(signal)
-        await writeTextFileAtomic(
-          path,
-          JSON.stringify(section, null, 2),
-          signal,
-        )
+        try {
+          await mkdir(settingsDirectory, { recursive: true })
+          throwIfAborted(signal)
+          await writeJsonFileAtomicValidated(path, section, validate, signal)
+        } catch (error) {
+          throw toStoragePermissionError(error, settingsDirectory)
+        }
       })
     },
 
@@ -0,0 +1,112 @@
+import { constants } from "node:fs"
+import { access, stat } from "node:fs/promises"
+import { dirname } from "node:path"
+
+// EROFS is included because read-only mounts (containers, CI images) are the
+// usual way storage becomes unwritable without a chmod.
+const permissionErrorCodes = new Set(["EACCES", "EPERM", "EROFS"])
+
+export type StorageAccessMode = "read" | "write"
+
+export type StorageAccessIssue = {
+  path: string
+  access: StorageAccessMode
+  code: string
+  message: string
+}
+
+/** A storage operation the OS refused for lack of permission. */
+export class StoragePermissionError extends Error {
+  readonly path: string
+  readonly code: string
+
+  constructor(path: string, code: string, cause?: unknown) {
+    super(
+      `Permission denied (${code}) for ${path}. Check that the directory is writable or choose another storage location.`,
+      { cause },
+    )
+    this.name = "StoragePermissionError"
+    this.path = path
+    this.code = code
+  }
+}
+
+function errorCode(error: unknown): string | undefined {
+  return (error as NodeJS.ErrnoException | null)?.code
+}
+
+export function isStoragePermissionError(
+  error: unknown,
+): error is StoragePermissionError {
+  return error instanceof StoragePermissionError
+}
+
+/**
+ * Rewraps OS permission failures as `StoragePermissionError`; other errors
+ * are returned unchanged so callers can `throw toStoragePermissionError(...)`.
+ */
+export function toStoragePermissionError(
+  error: unknown,
+  path: string,
+): unknown {
+  const code = errorCode(error)
+  return code !== undefined && permissionErrorCodes.has(code)
+    ? new StoragePermissionError(path, code, error)
+    : error
+}
+
+async function nearestExistingAncestor(path: string): Promise<string> {
+  let current = path
+  for (;;) {
+    const exists = await stat(current).then(
+      () => true,
+      () => false,
+    )
+    const parent = dirname(current)
+    if (exists || parent === current) {
+      return current
+    }
+    current = parent
+  }
+}
+
+async function probe(
+  path: string,
+  mode: StorageAccessMode,
+): Promise<StorageAccessIssue | null> {
+  try {
+    await access(path, mode === "read" ? constants.R_OK : constants.W_OK)
+    return null
+  } catch (error) {
+    const code = errorCode(error) ?? "UNKNOWN"
+    return {
+      path,
+      access: mode,
+      code,
+      message: `Cannot ${mode} ${path} (${code}).`,
+    }
+  }
+}
+
+/**
+ * Probes that each storage directory can be read and written. Directories
+ * that do not exist yet are checked through the nearest existing ancestor,
+ * which is where they would be created. Never throws.
+ */
+export async function checkStorageDirectoryAccess(
+  directories: readonly string[],
+): Promise<StorageAccessIssue[]> {
+  const issues: StorageAccessIssue[] = []
+  for (const directory of directories) {
+    const existing = await nearestExistingAncestor(directory)
+    const modes: StorageAccessMode[] =
+      existing === directory ? ["read", "write"] : ["write"]
+    for (const mode of modes) {
+      const issue = await probe(existing, mode)
+      if (issue !== null) {
+        issues.push(issue)
+      }
+    }
+  }
+  return issues
+}
@@ -6,7 +6,7 @@ import type {
   PatchFileStatus,
   TeamPermission,
 } from "../index.js"
-import { packageId, supportedGitProviders } from "../index.js"
+import { packageId, supportedGitProviders, webhookEvents } from "../index.js"
 
 describe("integrations-git-contract", () => {
   it("exports the correct packageId", () => {
@@ -39,6 +39,10 @@ describe("integrations-git-contract", () => {
     assert.equal(statuses.length, 4)
   })
 
+  it("webhookEvents covers push, pull_request, and issue", () => {
+    assert.deepEqual([...webhookEvents], ["push", "pull_request", "issue"])
+  })
+
   it("GitProviderClient interface covers all expected operations", () => {
     const methodNames: (keyof GitProviderClient)[] = [
       "verifyConnection",
@@ -46,14 +50,27 @@ describe("integrations-git-contract", () => {
       "createRepositories",
       "createTeam",
       "assignRepositoriesToTeam",
+      "listTeamMembers",
+      "removeTeamMembers",
+      "addCollaborator",
       "getRepositoryDefaultBranchHead",
       "getTemplateDiff",
       "createBranch",
       "createPullRequest",
+      "listPullRequests",
       "resolveRepositoryCloneUrls",
       "listRepositories",
+      "getCommitCount",
+      "archiveRepository",
+      "unarchiveRepository",
+      "forkRepository",
+      "syncFork",
+      "protectBranch",
+      "unprotectBranch",
+      "createWebhook",
+      "deleteWebhook",
     ]
-    assert.equal(methodNames.length, 11)
+    assert.equal(methodNames.length, 24)
 
     // Verify the interface is structurally implementable
     const client: GitProviderClient = {
@@ -71,10 +88,14 @@ describe("integrations-git-contract", () => {
         membersNotFound: [],
       }),
       assignRepositoriesToTeam: async () => {},
+      listTeamMembers: async () => null,
+      removeTeamMembers: async () => {},
+      addCollaborator: async () => {},
       getRepositoryDefaultBranchHead: async () => null,
       getTemplateDiff: async () => null,
       createBranch: async () => {},
       createPullRequest: async () => ({ url: "", created: true }),
+      listPullRequests: async () => [],
       resolveRepositoryCloneUrls: async () => ({
         resolved: [],
         missing: [],
@@ -82,6 +103,19 @@ describe("integrations-git-contract", () => {
       listRepositories: async () => ({
         repositories: [],
       }),
+      getCommitCount: async () => null,
+      archiveRepository: async () => {},
+      unarchiveRepository: async () => {},
+      forkRepository: async () => ({
+        repositoryName: "fork",
+        repositoryUrl: "",
+        cloneUrl: "",
+      }),
+      syncFork: async () => ({ branch: "main", updated: false }),
+      protectBranch: async () => {},
+      unprotectBranch: async () => {},
+      createWebhook: async () => ({ id: "1", url: "", events: [] }),
+      deleteWebhook: async () => {},
     }
     assert.ok(client)
   })
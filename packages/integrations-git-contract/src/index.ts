@@ -15,6 +15,8 @@ export type GitConnectionDraft = ConnectionBase & {
 export type GitUsernameStatus = {
   username: string
   exists: boolean
+  /** The provider refused the lookup, so `exists` is not conclusive. */
+  rateLimited?: boolean
 }
 
 export type CreateRepositoriesRequest = {
@@ -22,6 +24,8 @@ export type CreateRepositoriesRequest = {
   repositoryNames: string[]
   visibility: RepositoryTemplateVisibility
   autoInit: boolean
+  /** Parallel creations; defaults to a provider-specific limit. */
+  concurrency?: number
 }
 
 export type CreatedRepository = {
@@ -66,6 +70,24 @@ export type AssignRepositoriesToTeamRequest = {
   permission: TeamPermission
 }
 
+export type TeamMembersRequest = {
+  organization: string
+  teamName: string
+}
+
+export type RemoveTeamMembersRequest = {
+  organization: string
+  teamName: string
+  memberUsernames: string[]
+}
+
+export type AddCollaboratorRequest = {
+  owner: string
+  repositoryName: string
+  username: string
+  permission: TeamPermission
+}
+
 export type RepositoryHeadRequest = {
   owner: string
   repositoryName: string
@@ -76,6 +98,15 @@ export type RepositoryHead = {
   branchName: string
 }
 
+export type CommitCountRequest = {
+  owner: string
+  repositoryName: string
+  /** Defaults to the repository's default branch. */
+  branch: string | null
+  /** ISO timestamp; only commits authored at or after it are counted. */
+  since: string | null
+}
+
 export type PatchFileStatus = "added" | "modified" | "removed" | "renamed"
 
 export type PatchFile = {
@@ -119,9 +150,37 @@ export type CreatePullRequestResult = {
   created: boolean
 }
 
+export type PullRequestStateFilter = "open" | "closed" | "all"
+
+export type ListPullRequestsRequest = {
+  owner: string
+  repositoryName: string
+  state: PullRequestStateFilter
+}
+
+export type PullRequestSummary = {
+  number: number
+  title: string
+  /** Provider username of the author; `null` when the account is gone. */
+  author: string | null
+  headBranch: string
+  baseBranch: string
+  createdAt: string
+  /** Merged pull requests report `merged` rather than `closed`. */
+  state: "open" | "closed" | "merged"
+  url: string
+}
+
+export type GitCloneUrlScheme = "https" | "ssh"
+
 export type ResolveRepositoryCloneUrlsRequest = {
   organization: string
   repositoryNames: string[]
+  /**
+   * Defaults to `https`, which embeds the connection token. SSH URLs carry no
+   * credentials and rely on the user's SSH agent.
+   */
+  scheme?: GitCloneUrlScheme
 }
 
 export type ListRepositoriesRequest = {
@@ -156,6 +215,80 @@ export type ResolveRepositoryCloneUrlsResult = {
   missing: string[]
 }
 
+export type RepositoryArchiveRequest = {
+  owner: string
+  repositoryName: string
+}
+
+export type ForkRepositoryRequest = {
+  owner: string
+  repositoryName: string
+  /** Organization that receives the fork. */
+  organization: string
+  /** Fork name; the source repository name when omitted. */
+  name?: string
+  /** Copy only the default branch instead of every branch. */
+  defaultBranchOnly: boolean
+}
+
+export type SyncForkRequest = {
+  owner: string
+  repositoryName: string
+  /** Fork branch to update; the fork's default branch when omitted. */
+  branch?: string
+}
+
+export type SyncForkResult = {
+  branch: string
+  /** False when the branch already matched upstream. */
+  updated: boolean
+}
+
+export type BranchProtectionOptions = {
+  requirePullRequestReviews: boolean
+  /** Drop approvals when new commits are pushed. */
+  dismissStaleReviews: boolean
+  requiredApprovingReviewCount: number
+}
+
+export type ProtectBranchRequest = {
+  owner: string
+  repositoryName: string
+  branch: string
+  options: BranchProtectionOptions
+}
+
+export type UnprotectBranchRequest = {
+  owner: string
+  repositoryName: string
+  branch: string
+}
+
+export const webhookEvents = ["push", "pull_request", "issue"] as const
+
+export type WebhookEvent = (typeof webhookEvents)[number]
+
+export type CreateWebhookRequest = {
+  owner: string
+  repositoryName: string
+  /** Endpoint that receives JSON event payloads. */
+  url: string
+  events: WebhookEvent[]
+}
+
+export type Webhook = {
+  /** Provider hook ID, stringified for providers with numeric IDs. */
+  id: string
+  url: string
+  events: WebhookEvent[]
+}
+
+export type DeleteWebhookRequest = {
+  owner: string
+  repositoryName: string
+  webhookId: string
+}
+
 export type GitProviderClient = {
   verifyConnection(
     draft: GitConnectionDraft,
@@ -181,6 +314,27 @@ export type GitProviderClient = {
     request: AssignRepositoriesToTeamRequest,
     signal?: AbortSignal,
   ): Promise<void>
+  /** Member usernames of the team, or null when the team does not exist. */
+  listTeamMembers(
+    draft: GitConnectionDraft,
+    request: TeamMembersRequest,
+    signal?: AbortSignal,
+  ): Promise<string[] | null>
+  /** Usernames that are not members of the team are ignored. */
+  removeTeamMembers(
+    draft: GitConnectionDraft,
+    request: RemoveTeamMembersRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+  /**
+   * Grants one user direct access to a repository, outside any team.
+   * Updates the permission when the user is already a collaborator.
+   */
+  addCollaborator(
+    draft: GitConnectionDraft,
+    request: AddCollaboratorRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
   getRepositoryDefaultBranchHead(
     draft: GitConnectionDraft,
     request: RepositoryHeadRequest,
@@ -201,6 +355,12 @@ export type GitProviderClient = {
     request: CreatePullRequestRequest,
     signal?: AbortSignal,
   ): Promise<CreatePullRequestResult>
+  /** GitLab merge requests are reported as pull requests. */
+  listPullRequests(
+    draft: GitConnectionDraft,
+    request: ListPullRequestsRequest,
+    signal?: AbortSignal,
+  ): Promise<PullRequestSummary[]>
   resolveRepositoryCloneUrls(
     draft: GitConnectionDraft,
     request: ResolveRepositoryCloneUrlsRequest,
@@ -211,4 +371,62 @@ export type GitProviderClient = {
     request: ListRepositoriesRequest,
     signal?: AbortSignal,
   ): Promise<ListRepositoriesResult>
+  /**
+   * Commits reachable from the branch, or null when the repository or branch
+   * does not exist. An empty repository has zero commits.
+   */
+  getCommitCount(
+    draft: GitConnectionDraft,
+    request: CommitCountRequest,
+    signal?: AbortSignal,
+  ): Promise<number | null>
+  /** Makes the repository read-only; resolves when it is already archived. */
+  archiveRepository(
+    draft: GitConnectionDraft,
+    request: RepositoryArchiveRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+  unarchiveRepository(
+    draft: GitConnectionDraft,
+    request: RepositoryArchiveRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+  /** Only GitHub supports forking; other providers reject. */
+  forkRepository(
+    draft: GitConnectionDraft,
+    request: ForkRepositoryRequest,
+    signal?: AbortSignal,
+  ): Promise<CreatedRepository>
+  /** Merges upstream changes into a fork branch. GitHub only. */
+  syncFork(
+    draft: GitConnectionDraft,
+    request: SyncForkRequest,
+    signal?: AbortSignal,
+  ): Promise<SyncForkResult>
+  /**
+   * Blocks force-pushes and deletion of the branch, optionally requiring
+   * pull request reviews. GitHub only.
+   */
+  protectBranch(
+    draft: GitConnectionDraft,
+    request: ProtectBranchRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+  /** Resolves without error when the branch is not protected. GitHub only. */
+  unprotectBranch(
+    draft: GitConnectionDraft,
+    request: UnprotectBranchRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+  createWebhook(
+    draft: GitConnectionDraft,
+    request: CreateWebhookRequest,
+    signal?: AbortSignal,
+  ): Promise<Webhook>
+  /** Resolves without error when the hook is already gone. */
+  deleteWebhook(
+    draft: GitConnectionDraft,
+    request: DeleteWebhookRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
 }
@@ -0,0 +1,67 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import { retryOnRateLimit } from "../concurrency.js"
+
+class RateLimited extends Error {}
+
+const isRateLimited = (error: unknown) => error instanceof RateLimited
+const backoff = { maxAttempts: 3, baseDelayMs: 100, maxDelayMs: 150 }
+
+describe("retryOnRateLimit", () => {
+  it("backs off exponentially and retries rate-limited operations", async () => {
+    const delays: number[] = []
+    let attempts = 0
+
+    const result = await retryOnRateLimit(
+      async () => {
+        attempts += 1
+        if (attempts < 3) throw new RateLimited()
+        return "created"
+      },
+      isRateLimited,
+      {
+        backoff,
+        sleep: async (delayMs) => {
+          delays.push(delayMs)
+        },
+      },
+    )
+
+    assert.equal(result, "created")
+    assert.deepStrictEqual(delays, [100, 150])
+  })
+
+  it("gives up after the configured number of attempts", async () => {
+    let attempts = 0
+
+    await assert.rejects(
+      retryOnRateLimit(
+        async () => {
+          attempts += 1
+          throw new RateLimited()
+        },
+        isRateLimited,
+        { backoff, sleep: async () => {} },
+      ),
+      RateLimited,
+    )
+    assert.equal(attempts, 3)
+  })
+
+  it("rethrows other errors without retrying", async () => {
+    let attempts = 0
+
+    await assert.rejects(
+      retryOnRateLimit(
+        async () => {
+          attempts += 1
+          throw new Error("Bad credentials")
+        },
+        isRateLimited,
+        { backoff, sleep: async () => {} },
+      ),
+      /Bad credentials/,
+    )
+    assert.equal(attempts, 1)
+  })
+})
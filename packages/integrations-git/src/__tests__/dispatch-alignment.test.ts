@@ -10,12 +10,25 @@ const expectedDispatchMethods: Record<keyof GitProviderClient, true> = {
   createRepositories: true,
   createTeam: true,
   assignRepositoriesToTeam: true,
+  listTeamMembers: true,
+  removeTeamMembers: true,
+  addCollaborator: true,
   getRepositoryDefaultBranchHead: true,
   getTemplateDiff: true,
   createBranch: true,
   createPullRequest: true,
+  listPullRequests: true,
   resolveRepositoryCloneUrls: true,
   listRepositories: true,
+  getCommitCount: true,
+  archiveRepository: true,
+  unarchiveRepository: true,
+  forkRepository: true,
+  syncFork: true,
+  protectBranch: true,
+  unprotectBranch: true,
+  createWebhook: true,
+  deleteWebhook: true,
 }
 
 const fakeHttpPort: HttpPort = {
@@ -0,0 +1,66 @@
+import { sleepWithSignal } from "@repo-edu/concurrency"
+import type { GitProviderKind } from "@repo-edu/domain/types"
+
+// Upper bound on parallel `/users/{name}` lookups; keeps large rosters fast
+// without tripping secondary rate limits.
+export const usernameCheckConcurrency = 8
+
+// Parallel repository creations per `createRepositories` call. GitHub's
+// secondary rate limits penalize concurrent content-creating requests, so it
+// stays low; GitLab allows 2000 authenticated requests per minute and Gitea
+// has no API rate limit by default.
+export const repositoryCreateConcurrency: Record<GitProviderKind, number> = {
+  github: 4,
+  gitlab: 8,
+  gitea: 8,
+}
+
+export type RateLimitBackoff = {
+  maxAttempts: number
+  baseDelayMs: number
+  maxDelayMs: number
+}
+
+export const defaultRateLimitBackoff: RateLimitBackoff = {
+  maxAttempts: 4,
+  baseDelayMs: 1_000,
+  maxDelayMs: 60_000,
+}
+
+export type RateLimitSleep = (
+  delayMs: number,
+  signal?: AbortSignal,
+) => Promise<void>
+
+/**
+ * Runs `operation`, pausing with exponential backoff and retrying while it
+ * fails with a rate-limit error. The last error is rethrown once attempts
+ * are exhausted; other errors are rethrown immediately.
+ */
+export async function retryOnRateLimit<T>(
+  operation: () => Promise<T>,
+  isRateLimited: (error: unknown) => boolean,
+  options?: {
+    signal?: AbortSignal
+    backoff?: RateLimitBackoff
+    sleep?: RateLimitSleep
+  },
+): Promise<T> {
+  const backoff = options?.backoff ?? defaultRateLimitBackoff
+  const sleep = options?.sleep ?? sleepWithSignal
+
+  for (let attempt = 1; ; attempt += 1) {
+    try {
+      return await operation()
+    } catch (error) {
+      if (attempt >= backoff.maxAttempts || !isRateLimited(error)) {
+        throw error
+      }
+      const delayMs = Math.min(
+        backoff.baseDelayMs * 2 ** (attempt - 1),
+        backoff.maxDelayMs,
+      )
+      await sleep(delayMs, options?.signal)
+    }
+  }
+}
@@ -31,4 +31,76 @@ describe("gitea branch-review", () => {
       assert.ok(result.url.includes("pulls/1"))
     })
   })
+
+  describe("listPullRequests", () => {
+    it("lists pull requests and reports merged ones as merged", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/api/v1/repos/my-org/repo-1/pulls?state=all",
+          status: 200,
+          body: [
+            {
+              number: 7,
+              title: "Lab 2",
+              user: { login: "carol" },
+              head: { ref: "lab-2" },
+              base: { ref: "main" },
+              created_at: "2026-03-01T10:00:00Z",
+              state: "closed",
+              merged: true,
+              html_url: "https://gitea.example.com/my-org/repo-1/pulls/7",
+            },
+          ],
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      const pullRequests = await client.listPullRequests(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        state: "all",
+      })
+
+      assert.deepStrictEqual(pullRequests, [
+        {
+          number: 7,
+          title: "Lab 2",
+          author: "carol",
+          headBranch: "lab-2",
+          baseBranch: "main",
+          createdAt: "2026-03-01T10:00:00Z",
+          state: "merged",
+          url: "https://gitea.example.com/my-org/repo-1/pulls/7",
+        },
+      ])
+    })
+  })
+
+  describe("protectBranch", () => {
+    it("rejects branch protection changes", async () => {
+      const client = createGiteaClient(createMockHttpPort([]))
+      const request = {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        branch: "main",
+      }
+
+      await assert.rejects(
+        client.protectBranch(baseDraft, {
+          ...request,
+          options: {
+            requirePullRequestReviews: false,
+            dismissStaleReviews: false,
+            requiredApprovingReviewCount: 0,
+          },
+        }),
+        /Branch protection is only supported on GitHub/,
+      )
+      await assert.rejects(
+        client.unprotectBranch(baseDraft, request),
+        /Branch protection is only supported on GitHub/,
+      )
+    })
+  })
 })
@@ -142,4 +142,56 @@ describe("gitea discovery", () => {
       assert.deepStrictEqual(result.repositories, [])
     })
   })
+
+  describe("getCommitCount", () => {
+    it("counts commits since a timestamp on the default branch", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern:
+            /\/api\/v1\/repos\/course-org\/lab1-alice\/commits\?since=2026-09-01T00%3A00%3A00.000Z&limit=50&page=1/,
+          status: 200,
+          body: [{ sha: "c2" }, { sha: "c1" }],
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      const count = await client.getCommitCount(baseDraft, {
+        owner: "course-org",
+        repositoryName: "lab1-alice",
+        branch: null,
+        since: "2026-09-01T00:00:00.000Z",
+      })
+
+      assert.equal(count, 2)
+    })
+
+    it("returns null for missing repositories and zero for empty ones", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: /\/api\/v1\/repos\/course-org\/empty-repo\/commits/,
+          status: 409,
+          body: { message: "Git Repository is empty." },
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      const request = { owner: "course-org", branch: null, since: null }
+      assert.equal(
+        await client.getCommitCount(baseDraft, {
+          ...request,
+          repositoryName: "missing-repo",
+        }),
+        null,
+      )
+      assert.equal(
+        await client.getCommitCount(baseDraft, {
+          ...request,
+          repositoryName: "empty-repo",
+        }),
+        0,
+      )
+    })
+  })
 })
@@ -114,6 +114,45 @@ describe("gitea repositories", () => {
         failed: [],
       })
     })
+
+    it("creates repositories concurrently up to the requested limit", async () => {
+      let inFlight = 0
+      let maxInFlight = 0
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          inFlight += 1
+          maxInFlight = Math.max(maxInFlight, inFlight)
+          await new Promise((resolve) => setTimeout(resolve, 5))
+          inFlight -= 1
+          const { name } = JSON.parse(request.body ?? "{}") as { name: string }
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({
+              html_url: `https://gitea.example.com/course-org/${name}`,
+              clone_url: `https://gitea.example.com/course-org/${name}.git`,
+            }),
+          }
+        },
+      }
+      const repositoryNames = ["r1", "r2", "r3", "r4", "r5", "r6"]
+
+      const client = createGiteaClient(http)
+      const result = await client.createRepositories(baseDraft, {
+        organization: "course-org",
+        repositoryNames,
+        visibility: "private",
+        autoInit: false,
+        concurrency: 3,
+      })
+
+      assert.equal(maxInFlight, 3)
+      assert.deepStrictEqual(
+        result.created.map((repository) => repository.repositoryName),
+        repositoryNames,
+      )
+    })
   })
 
   describe("createRepositories alreadyExisted", () => {
@@ -207,5 +246,98 @@ describe("gitea repositories", () => {
       assert.equal(result.resolved[0]?.repositoryName, "repo-1")
       assert.ok(result.resolved[0]?.cloneUrl.includes("token:gitea-test-token"))
     })
+
+    it("returns SSH clone URLs without credentials when requested", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/api/v1/repos/course-org/repo-1",
+          status: 200,
+          body: {
+            clone_url: "https://gitea.example.com/course-org/repo-1.git",
+            ssh_url: "git@gitea.example.com:course-org/repo-1.git",
+          },
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      const result = await client.resolveRepositoryCloneUrls(baseDraft, {
+        organization: "course-org",
+        repositoryNames: ["repo-1"],
+        scheme: "ssh",
+      })
+
+      assert.deepStrictEqual(result, {
+        resolved: [
+          {
+            repositoryName: "repo-1",
+            cloneUrl: "git@gitea.example.com:course-org/repo-1.git",
+          },
+        ],
+        missing: [],
+      })
+    })
+  })
+
+  describe("archiveRepository", () => {
+    it("patches the archived flag on the repository", async () => {
+      const captured: Array<{ method?: string; url: string; body: unknown }> =
+        []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push({
+            method: request.method,
+            url: request.url,
+            body: JSON.parse(request.body ?? "null"),
+          })
+          return {
+            status: 200,
+            statusText: "OK",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({ name: "repo-1" }),
+          }
+        },
+      }
+
+      const client = createGiteaClient(http)
+      const request = { owner: "my-org", repositoryName: "repo-1" }
+      await client.archiveRepository(baseDraft, request)
+      await client.unarchiveRepository(baseDraft, request)
+
+      assert.deepStrictEqual(
+        captured.map((entry) => `${entry.method} ${entry.url}`),
+        [
+          "PATCH https://gitea.example.com/api/v1/repos/my-org/repo-1",
+          "PATCH https://gitea.example.com/api/v1/repos/my-org/repo-1",
+        ],
+      )
+      assert.deepStrictEqual(
+        captured.map((entry) => entry.body),
+        [{ archived: true }, { archived: false }],
+      )
+    })
+  })
+
+  describe("forkRepository", () => {
+    it("rejects forking and fork sync", async () => {
+      const client = createGiteaClient(createMockHttpPort([]))
+
+      await assert.rejects(
+        client.forkRepository(baseDraft, {
+          owner: "upstream-org",
+          repositoryName: "starter",
+          organization: "my-org",
+          defaultBranchOnly: false,
+        }),
+        /Forking repositories is only supported on GitHub/,
+      )
+      await assert.rejects(
+        client.syncFork(baseDraft, {
+          owner: "my-org",
+          repositoryName: "starter",
+        }),
+        /Syncing forks is only supported on GitHub/,
+      )
+    })
   })
 })
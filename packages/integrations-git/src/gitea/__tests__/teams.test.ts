@@ -6,7 +6,7 @@ import type {
   HttpResponse,
 } from "@repo-edu/host-runtime-contract"
 import { createGiteaClient } from "../gitea-client.js"
-import { baseDraft } from "./harness.js"
+import { baseDraft, createMockHttpPort } from "./harness.js"
 
 describe("gitea teams", () => {
   describe("createTeam", () => {
@@ -148,4 +148,112 @@ describe("gitea teams", () => {
       assert.ok(capturedUrls[0]?.includes("/teams/42/repos/course-org/repo-1"))
     })
   })
+
+  describe("addCollaborator", () => {
+    it("puts the user on the repository collaborators list", async () => {
+      const captured: HttpRequest[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(request)
+          return {
+            status: 204,
+            statusText: "No Content",
+            headers: { "content-type": "application/json" },
+            body: "",
+          }
+        },
+      }
+
+      const client = createGiteaClient(http)
+      await client.addCollaborator(baseDraft, {
+        owner: "course-org",
+        repositoryName: "repo-1",
+        username: "alice",
+        permission: "push",
+      })
+
+      assert.equal(captured.length, 1)
+      assert.equal(captured[0]?.method, "PUT")
+      assert.ok(
+        captured[0]?.url.includes(
+          "/api/v1/repos/course-org/repo-1/collaborators/alice",
+        ),
+      )
+      assert.deepStrictEqual(JSON.parse(captured[0]?.body ?? ""), {
+        permission: "write",
+      })
+    })
+  })
+
+  describe("team membership", () => {
+    const teamsRoute = {
+      method: "GET",
+      urlPattern: /\/orgs\/course-org\/teams$/,
+      status: 200,
+      body: [{ id: 42, name: "hw1-team" }],
+    }
+
+    it("lists member logins of the named team", async () => {
+      const http = createMockHttpPort([
+        teamsRoute,
+        {
+          method: "GET",
+          urlPattern: "/api/v1/teams/42/members",
+          status: 200,
+          body: [{ login: "alice" }, { login: "bob" }],
+        },
+      ])
+
+      const members = await createGiteaClient(http).listTeamMembers(baseDraft, {
+        organization: "course-org",
+        teamName: "HW1-Team",
+      })
+
+      assert.deepStrictEqual(members, ["alice", "bob"])
+    })
+
+    it("reports a missing team as null", async () => {
+      const http = createMockHttpPort([teamsRoute])
+
+      const members = await createGiteaClient(http).listTeamMembers(baseDraft, {
+        organization: "course-org",
+        teamName: "hw2-team",
+      })
+
+      assert.equal(members, null)
+    })
+
+    it("deletes team memberships and ignores non-members", async () => {
+      const deleted: string[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          if (request.method === "DELETE") {
+            deleted.push(request.url)
+            const status = request.url.endsWith("/members/bob") ? 404 : 204
+            return {
+              status,
+              statusText: status === 204 ? "No Content" : "Not Found",
+              headers: { "content-type": "application/json" },
+              body: "",
+            }
+          }
+          return {
+            status: 200,
+            statusText: "OK",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify([{ id: 42, name: "hw1-team" }]),
+          }
+        },
+      }
+
+      await createGiteaClient(http).removeTeamMembers(baseDraft, {
+        organization: "course-org",
+        teamName: "hw1-team",
+        memberUsernames: ["alice", "bob"],
+      })
+
+      assert.equal(deleted.length, 2)
+      assert.ok(deleted[0]?.endsWith("/api/v1/teams/42/members/alice"))
+    })
+  })
 })
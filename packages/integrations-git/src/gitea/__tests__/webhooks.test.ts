@@ -0,0 +1,94 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import { createGiteaClient } from "../gitea-client.js"
+import { baseDraft, createMockHttpPort } from "./harness.js"
+
+describe("gitea webhooks", () => {
+  describe("createWebhook", () => {
+    it("registers a gitea hook with Gitea event names", async () => {
+      let capturedBody: unknown = null
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          capturedBody = JSON.parse(request.body ?? "null")
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({ id: 7 }),
+          }
+        },
+      }
+
+      const client = createGiteaClient(http)
+      const webhook = await client.createWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        url: "https://grader.example.com/hook",
+        events: ["push", "pull_request"],
+      })
+
+      assert.equal(webhook.id, "7")
+      assert.deepStrictEqual(capturedBody, {
+        type: "gitea",
+        active: true,
+        events: ["push", "pull_request"],
+        config: {
+          url: "https://grader.example.com/hook",
+          content_type: "json",
+        },
+      })
+    })
+
+    it("throws when the repository rejects the hook", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "POST",
+          urlPattern: "/api/v1/repos/my-org/repo-1/hooks",
+          status: 403,
+          body: { message: "Forbidden" },
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      await assert.rejects(
+        client.createWebhook(baseDraft, {
+          owner: "my-org",
+          repositoryName: "repo-1",
+          url: "https://grader.example.com/hook",
+          events: ["push"],
+        }),
+        /Failed to create webhook on 'my-org\/repo-1' \(403\): Forbidden/,
+      )
+    })
+  })
+
+  describe("deleteWebhook", () => {
+    it("deletes the hook and tolerates a missing one", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "DELETE",
+          urlPattern: "/api/v1/repos/my-org/repo-1/hooks/7",
+          status: 204,
+          body: null,
+        },
+      ])
+
+      const client = createGiteaClient(http)
+      await client.deleteWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        webhookId: "7",
+      })
+      await client.deleteWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        webhookId: "8",
+      })
+    })
+  })
+})
@@ -1,14 +1,52 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  PullRequestSummary,
+} from "@repo-edu/integrations-git-contract"
 import { isNoChangesMessage, toErrorMessage } from "./errors.js"
 import { readRepositoryFile } from "./repository-api.js"
 import { giteaRequest, resolveApiBase } from "./transport.js"
 
 type BranchReviewCapability = Pick<
   GitProviderClient,
-  "createBranch" | "createPullRequest"
+  | "createBranch"
+  | "createPullRequest"
+  | "listPullRequests"
+  | "protectBranch"
+  | "unprotectBranch"
 >
 
+function toPullRequestSummary(entry: unknown): PullRequestSummary | null {
+  if (typeof entry !== "object" || entry === null) return null
+  const record = entry as {
+    number?: unknown
+    title?: unknown
+    user?: { login?: unknown } | null
+    head?: { ref?: unknown } | null
+    base?: { ref?: unknown } | null
+    created_at?: unknown
+    state?: unknown
+    merged?: unknown
+    html_url?: unknown
+  }
+  if (typeof record.number !== "number") return null
+  return {
+    number: record.number,
+    title: typeof record.title === "string" ? record.title : "",
+    author: typeof record.user?.login === "string" ? record.user.login : null,
+    headBranch: typeof record.head?.ref === "string" ? record.head.ref : "",
+    baseBranch: typeof record.base?.ref === "string" ? record.base.ref : "",
+    createdAt: typeof record.created_at === "string" ? record.created_at : "",
+    state:
+      record.merged === true
+        ? "merged"
+        : record.state === "open"
+          ? "open"
+          : "closed",
+    url: typeof record.html_url === "string" ? record.html_url : "",
+  }
+}
+
 export function createGiteaBranchReview(
   http: HttpPort,
 ): BranchReviewCapability {
@@ -181,5 +219,40 @@ export function createGiteaBranchReview(
           : null
       return { url: typeof url === "string" ? url : "", created: false }
     },
+    async listPullRequests(draft, request, signal) {
+      if (!resolveApiBase(draft)) throw new Error("Gitea baseUrl is required.")
+      const route = `/repos/${encodeURIComponent(request.owner)}/${encodeURIComponent(request.repositoryName)}/pulls`
+      const pullRequests: PullRequestSummary[] = []
+      const perPage = 50
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const response = await giteaRequest(
+          http,
+          draft,
+          "GET",
+          `${route}?state=${request.state}&limit=${perPage}&page=${page}`,
+          undefined,
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list Gitea pull requests (${response.status}): ${toErrorMessage(response.data) || "unknown error"}`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        for (const entry of response.data) {
+          const summary = toPullRequestSummary(entry)
+          if (summary !== null) pullRequests.push(summary)
+        }
+        if (response.data.length < perPage) break
+      }
+      return pullRequests
+    },
+    async protectBranch() {
+      throw new Error("Branch protection is only supported on GitHub.")
+    },
+    async unprotectBranch() {
+      throw new Error("Branch protection is only supported on GitHub.")
+    },
   }
 }
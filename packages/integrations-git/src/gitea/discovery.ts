@@ -6,7 +6,10 @@ import type {
 } from "@repo-edu/integrations-git-contract"
 import { giteaRequest, resolveApiBase } from "./transport.js"
 
-type DiscoveryCapability = Pick<GitProviderClient, "listRepositories">
+type DiscoveryCapability = Pick<
+  GitProviderClient,
+  "listRepositories" | "getCommitCount"
+>
 
 export function createGiteaDiscovery(http: HttpPort): DiscoveryCapability {
   return {
@@ -61,5 +64,43 @@ export function createGiteaDiscovery(http: HttpPort): DiscoveryCapability {
       }
       return { repositories }
     },
+    async getCommitCount(draft, request, signal) {
+      const owner = encodeURIComponent(request.owner)
+      const repo = encodeURIComponent(request.repositoryName)
+      const filters = [
+        request.branch === null
+          ? null
+          : `sha=${encodeURIComponent(request.branch)}`,
+        request.since === null
+          ? null
+          : `since=${encodeURIComponent(request.since)}`,
+      ].filter((filter) => filter !== null)
+      const perPage = 50
+      let count = 0
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const query = [...filters, `limit=${perPage}`, `page=${page}`]
+        const response = await giteaRequest(
+          http,
+          draft,
+          "GET",
+          `/repos/${owner}/${repo}/commits?${query.join("&")}`,
+          undefined,
+          signal,
+        )
+        if (response.status === 404) return null
+        // Gitea answers 409 for a repository without any commits.
+        if (response.status === 409) return 0
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list commits for '${request.owner}/${request.repositoryName}' (${response.status}).`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        count += response.data.length
+        if (response.data.length < perPage) break
+      }
+      return count
+    },
   }
 }
@@ -25,3 +25,14 @@ export function isAlreadyExists(status: number, data: unknown): boolean {
 export function isNoChangesMessage(message: string): boolean {
   return /already exists|no commits|no changes|same as current/i.test(message)
 }
+
+export class GiteaRateLimitError extends Error {
+  constructor() {
+    super("Gitea rate limit exceeded (HTTP 429).")
+    this.name = "GiteaRateLimitError"
+  }
+}
+
+export function isRateLimitError(error: unknown): boolean {
+  return error instanceof GiteaRateLimitError
+}
@@ -7,6 +7,7 @@ import { createGiteaIdentity } from "./identity.js"
 import { createGiteaRepositories } from "./repositories.js"
 import { createGiteaTeams } from "./teams.js"
 import { createGiteaTemplateChanges } from "./template-changes.js"
+import { createGiteaWebhooks } from "./webhooks.js"
 
 export function createGiteaClient(http: HttpPort): GitProviderClient {
   return guardGitProviderClient({
@@ -16,5 +17,6 @@ export function createGiteaClient(http: HttpPort): GitProviderClient {
     ...createGiteaTemplateChanges(http),
     ...createGiteaBranchReview(http),
     ...createGiteaDiscovery(http),
+    ...createGiteaWebhooks(http),
   })
 }
@@ -1,5 +1,10 @@
+import { mapBounded } from "@repo-edu/concurrency"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  GitUsernameStatus,
+} from "@repo-edu/integrations-git-contract"
+import { usernameCheckConcurrency } from "../concurrency.js"
 import { giteaRequest, resolveApiBase } from "./transport.js"
 import { isActiveUser } from "./users.js"
 
@@ -30,30 +35,36 @@ export function createGiteaIdentity(http: HttpPort): IdentityCapability {
       if (!resolveApiBase(draft)) {
         return usernames.map((username) => ({ username, exists: false }))
       }
-      const results = []
-      for (const username of usernames) {
-        if (signal?.aborted) break
-        try {
-          const response = await giteaRequest(
-            http,
-            draft,
-            "GET",
-            `/users/${encodeURIComponent(username)}`,
-            undefined,
-            signal,
-          )
-          results.push({
-            username,
-            exists:
-              response.status >= 200 &&
-              response.status < 300 &&
-              isActiveUser(response.data, username),
-          })
-        } catch {
-          results.push({ username, exists: false })
-        }
-      }
-      return results
+      const results = await mapBounded(
+        usernames,
+        usernameCheckConcurrency,
+        async (username): Promise<GitUsernameStatus | null> => {
+          if (signal?.aborted) return null
+          try {
+            const response = await giteaRequest(
+              http,
+              draft,
+              "GET",
+              `/users/${encodeURIComponent(username)}`,
+              undefined,
+              signal,
+            )
+            if (response.status === 429) {
+              return { username, exists: false, rateLimited: true }
+            }
+            return {
+              username,
+              exists:
+                response.status >= 200 &&
+                response.status < 300 &&
+                isActiveUser(response.data, username),
+            }
+          } catch {
+            return { username, exists: false }
+          }
+        },
+      )
+      return results.filter((result) => result !== null)
     },
   }
 }
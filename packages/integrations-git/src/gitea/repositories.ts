@@ -1,7 +1,24 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitConnectionDraft,
+  GitProviderClient,
+  RepositoryArchiveRequest,
+} from "@repo-edu/integrations-git-contract"
+import {
+  repositoryCreateConcurrency,
+  retryOnRateLimit,
+} from "../concurrency.js"
+import {
+  createRepositoriesBounded,
+  type RepositoryCreateOutcome,
+} from "../repository-create.js"
 import { withGiteaToken } from "./auth.js"
-import { isAlreadyExists, toErrorMessage } from "./errors.js"
+import {
+  GiteaRateLimitError,
+  isAlreadyExists,
+  isRateLimitError,
+  toErrorMessage,
+} from "./errors.js"
 import {
   extractRepositoryCloneUrl,
   extractRepositoryUrls,
@@ -11,9 +28,36 @@ import { giteaRequest, resolveApiBase } from "./transport.js"
 
 type RepositoriesCapability = Pick<
   GitProviderClient,
-  "createRepositories" | "resolveRepositoryCloneUrls"
+  | "createRepositories"
+  | "resolveRepositoryCloneUrls"
+  | "archiveRepository"
+  | "unarchiveRepository"
+  | "forkRepository"
+  | "syncFork"
 >
 
+async function setGiteaRepositoryArchived(
+  http: HttpPort,
+  draft: GitConnectionDraft,
+  request: RepositoryArchiveRequest,
+  archived: boolean,
+  signal?: AbortSignal,
+): Promise<void> {
+  const response = await giteaRequest(
+    http,
+    draft,
+    "PATCH",
+    `/repos/${encodeURIComponent(request.owner)}/${encodeURIComponent(request.repositoryName)}`,
+    JSON.stringify({ archived }),
+    signal,
+  )
+  if (response.status < 200 || response.status >= 300) {
+    throw new Error(
+      `Failed to ${archived ? "archive" : "unarchive"} repository '${request.owner}/${request.repositoryName}' (${response.status}): ${toErrorMessage(response.data)}`,
+    )
+  }
+}
+
 export function createGiteaRepositories(
   http: HttpPort,
 ): RepositoriesCapability {
@@ -22,79 +66,105 @@ export function createGiteaRepositories(
       if (!request.organization || !resolveApiBase(draft)) {
         return { created: [], alreadyExisted: [], failed: [] }
       }
-      const created = []
-      const alreadyExisted = []
-      const failed = []
-      for (const repositoryName of request.repositoryNames) {
-        if (signal?.aborted) break
-        try {
-          const response = await giteaRequest(
-            http,
-            draft,
-            "POST",
-            `/orgs/${encodeURIComponent(request.organization)}/repos`,
-            JSON.stringify({
-              name: repositoryName,
-              private: request.visibility !== "public",
-              auto_init: request.autoInit,
-            }),
-            signal,
-          )
-          if (response.status >= 200 && response.status < 300) {
-            const urls = extractRepositoryUrls(response.data)
-            if (urls === null) {
-              failed.push({
-                repositoryName,
-                reason: "Provider returned incomplete repository URLs.",
-              })
-            } else {
-              created.push({
+      return createRepositoriesBounded(
+        request.repositoryNames,
+        request.concurrency ?? repositoryCreateConcurrency.gitea,
+        async (repositoryName): Promise<RepositoryCreateOutcome> => {
+          try {
+            const response = await retryOnRateLimit(
+              async () => {
+                const response = await giteaRequest(
+                  http,
+                  draft,
+                  "POST",
+                  `/orgs/${encodeURIComponent(request.organization)}/repos`,
+                  JSON.stringify({
+                    name: repositoryName,
+                    private: request.visibility !== "public",
+                    auto_init: request.autoInit,
+                  }),
+                  signal,
+                )
+                if (response.status === 429) {
+                  throw new GiteaRateLimitError()
+                }
+                return response
+              },
+              isRateLimitError,
+              { signal },
+            )
+            if (response.status >= 200 && response.status < 300) {
+              const urls = extractRepositoryUrls(response.data)
+              if (urls === null) {
+                return {
+                  kind: "failed",
+                  failure: {
+                    repositoryName,
+                    reason: "Provider returned incomplete repository URLs.",
+                  },
+                }
+              }
+              return {
+                kind: "created",
+                repository: {
+                  repositoryName,
+                  repositoryUrl: urls.repositoryUrl,
+                  cloneUrl: withGiteaToken(urls.cloneUrl, draft.token),
+                },
+              }
+            }
+            if (isAlreadyExists(response.status, response.data)) {
+              const urls = await resolveExistingRepositoryUrls(
+                http,
+                draft,
+                request.organization,
                 repositoryName,
-                repositoryUrl: urls.repositoryUrl,
-                cloneUrl: withGiteaToken(urls.cloneUrl, draft.token),
-              })
+                signal,
+              )
+              if (urls === null) {
+                return {
+                  kind: "failed",
+                  failure: {
+                    repositoryName,
+                    reason: "Repository exists but URL lookup failed.",
+                  },
+                }
+              }
+              return {
+                kind: "existing",
+                repository: {
+                  repositoryName,
+                  repositoryUrl: urls.repositoryUrl,
+                  cloneUrl: withGiteaToken(urls.cloneUrl, draft.token),
+                },
+              }
             }
-            continue
-          }
-          if (isAlreadyExists(response.status, response.data)) {
-            const urls = await resolveExistingRepositoryUrls(
-              http,
-              draft,
-              request.organization,
-              repositoryName,
-              signal,
-            )
-            if (urls === null) {
-              failed.push({
+            return {
+              kind: "failed",
+              failure: {
                 repositoryName,
-                reason: "Repository exists but URL lookup failed.",
-              })
-            } else {
-              alreadyExisted.push({
+                reason:
+                  toErrorMessage(response.data) || `HTTP ${response.status}`,
+              },
+            }
+          } catch (error) {
+            return {
+              kind: "failed",
+              failure: {
                 repositoryName,
-                repositoryUrl: urls.repositoryUrl,
-                cloneUrl: withGiteaToken(urls.cloneUrl, draft.token),
-              })
+                reason: error instanceof Error ? error.message : String(error),
+              },
             }
-            continue
           }
-          failed.push({
-            repositoryName,
-            reason: toErrorMessage(response.data) || `HTTP ${response.status}`,
-          })
-        } catch (error) {
-          failed.push({
-            repositoryName,
-            reason: error instanceof Error ? error.message : String(error),
-          })
-        }
-      }
-      return { created, alreadyExisted, failed }
+        },
+        signal,
+      )
     },
     async resolveRepositoryCloneUrls(draft, request, signal) {
       if (!request.organization || !resolveApiBase(draft)) {
         return { resolved: [], missing: [...request.repositoryNames] }
       }
+      const scheme = request.scheme ?? "https"
       const resolved = []
       const missing = []
       for (const repositoryName of request.repositoryNames) {
@@ -116,17 +186,30 @@ export function createGiteaRepositories(
             `Failed to resolve repository '${repositoryName}' (${response.status}).`,
           )
         }
-        const cloneUrl = extractRepositoryCloneUrl(response.data)
+        const cloneUrl = extractRepositoryCloneUrl(response.data, scheme)
         if (cloneUrl === null) {
           missing.push(repositoryName)
           continue
         }
         resolved.push({
           repositoryName,
-          cloneUrl: withGiteaToken(cloneUrl, draft.token),
+          cloneUrl:
+            scheme === "ssh" ? cloneUrl : withGiteaToken(cloneUrl, draft.token),
         })
       }
       return { resolved, missing }
     },
+    async archiveRepository(draft, request, signal) {
+      await setGiteaRepositoryArchived(http, draft, request, true, signal)
+    },
+    async unarchiveRepository(draft, request, signal) {
+      await setGiteaRepositoryArchived(http, draft, request, false, signal)
+    },
+    async forkRepository() {
+      throw new Error("Forking repositories is only supported on GitHub.")
+    },
+    async syncFork() {
+      throw new Error("Syncing forks is only supported on GitHub.")
+    },
   }
 }
@@ -1,5 +1,6 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
 import type {
+  GitCloneUrlScheme,
   GitConnectionDraft,
   PatchFile,
 } from "@repo-edu/integrations-git-contract"
@@ -10,9 +11,13 @@ export type GiteaRepositoryUrls = {
   cloneUrl: string
 }
 
-export function extractRepositoryCloneUrl(data: unknown): string | null {
+export function extractRepositoryCloneUrl(
+  data: unknown,
+  scheme: GitCloneUrlScheme = "https",
+): string | null {
   if (typeof data !== "object" || data === null) return null
-  const cloneUrl = (data as { clone_url?: unknown }).clone_url
+  const repository = data as { clone_url?: unknown; ssh_url?: unknown }
+  const cloneUrl = scheme === "ssh" ? repository.ssh_url : repository.clone_url
   return typeof cloneUrl === "string" ? cloneUrl : null
 }
 
@@ -57,7 +57,11 @@ async function resolveTeamId(
 
 type TeamsCapability = Pick<
   GitProviderClient,
-  "createTeam" | "assignRepositoriesToTeam"
+  | "createTeam"
+  | "assignRepositoriesToTeam"
+  | "listTeamMembers"
+  | "removeTeamMembers"
+  | "addCollaborator"
 >
 
 export function createGiteaTeams(http: HttpPort): TeamsCapability {
@@ -156,5 +160,90 @@ export function createGiteaTeams(http: HttpPort): TeamsCapability {
         )
       }
     },
+    async listTeamMembers(draft, request, signal) {
+      if (!resolveApiBase(draft)) throw new Error("Gitea baseUrl is required.")
+      const teamId = await resolveTeamId(
+        http,
+        draft,
+        request.organization,
+        request.teamName,
+        signal,
+      )
+      if (teamId === null) return null
+      const usernames: string[] = []
+      const perPage = 50
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const response = await giteaRequest(
+          http,
+          draft,
+          "GET",
+          `/teams/${teamId}/members?limit=${perPage}&page=${page}`,
+          undefined,
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list members of Gitea team '${request.teamName}' (${response.status}).`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        for (const entry of response.data) {
+          const login = (entry as { login?: unknown } | null)?.login
+          if (typeof login === "string") usernames.push(login)
+        }
+        if (response.data.length < perPage) break
+      }
+      return usernames
+    },
+    async removeTeamMembers(draft, request, signal) {
+      if (!resolveApiBase(draft)) throw new Error("Gitea baseUrl is required.")
+      const teamId = await resolveTeamId(
+        http,
+        draft,
+        request.organization,
+        request.teamName,
+        signal,
+      )
+      if (teamId === null) {
+        throw new Error(`Failed to resolve Gitea team '${request.teamName}'.`)
+      }
+      for (const username of request.memberUsernames) {
+        if (signal?.aborted) break
+        const member = await giteaRequest(
+          http,
+          draft,
+          "DELETE",
+          `/teams/${teamId}/members/${encodeURIComponent(username)}`,
+          undefined,
+          signal,
+        )
+        if (
+          (member.status >= 200 && member.status < 300) ||
+          member.status === 404
+        ) {
+          continue
+        }
+        throw new Error(
+          `Failed to remove '${username}' from Gitea team '${request.teamName}' (${member.status}).`,
+        )
+      }
+    },
+    async addCollaborator(draft, request, signal) {
+      if (!resolveApiBase(draft)) throw new Error("Gitea baseUrl is required.")
+      const response = await giteaRequest(
+        http,
+        draft,
+        "PUT",
+        `/repos/${encodeURIComponent(request.owner)}/${encodeURIComponent(request.repositoryName)}/collaborators/${encodeURIComponent(request.username)}`,
+        JSON.stringify({ permission: mapTeamPermission(request.permission) }),
+        signal,
+      )
+      if (response.status < 200 || response.status >= 300) {
+        throw new Error(
+          `Failed to add '${request.username}' to Gitea repository '${request.repositoryName}' (${response.status}).`,
+        )
+      }
+    },
   }
 }
@@ -0,0 +1,73 @@
+import type { HttpPort } from "@repo-edu/host-runtime-contract"
+import type {
+  GitProviderClient,
+  WebhookEvent,
+} from "@repo-edu/integrations-git-contract"
+import { toErrorMessage } from "./errors.js"
+import { giteaRequest } from "./transport.js"
+
+type WebhooksCapability = Pick<
+  GitProviderClient,
+  "createWebhook" | "deleteWebhook"
+>
+
+const giteaWebhookEvents: Record<WebhookEvent, string> = {
+  push: "push",
+  pull_request: "pull_request",
+  issue: "issues",
+}
+
+export function createGiteaWebhooks(http: HttpPort): WebhooksCapability {
+  return {
+    async createWebhook(draft, request, signal) {
+      const route = `/repos/${encodeURIComponent(request.owner)}/${encodeURIComponent(request.repositoryName)}`
+      const response = await giteaRequest(
+        http,
+        draft,
+        "POST",
+        `${route}/hooks`,
+        JSON.stringify({
+          type: "gitea",
+          active: true,
+          events: request.events.map((event) => giteaWebhookEvents[event]),
+          config: { url: request.url, content_type: "json" },
+        }),
+        signal,
+      )
+      const id = (response.data as { id?: unknown } | null)?.id
+      if (
+        response.status < 200 ||
+        response.status >= 300 ||
+        typeof id !== "number"
+      ) {
+        throw new Error(
+          `Failed to create webhook on '${request.owner}/${request.repositoryName}' (${response.status}): ${toErrorMessage(response.data)}`,
+        )
+      }
+      return {
+        id: String(id),
+        url: request.url,
+        events: [...request.events],
+      }
+    },
+    async deleteWebhook(draft, request, signal) {
+      const route = `/repos/${encodeURIComponent(request.owner)}/${encodeURIComponent(request.repositoryName)}`
+      const response = await giteaRequest(
+        http,
+        draft,
+        "DELETE",
+        `${route}/hooks/${encodeURIComponent(request.webhookId)}`,
+        undefined,
+        signal,
+      )
+      if (
+        (response.status < 200 || response.status >= 300) &&
+        response.status !== 404
+      ) {
+        throw new Error(
+          `Failed to delete webhook '${request.webhookId}' (${response.status}).`,
+        )
+      }
+    },
+  }
+}
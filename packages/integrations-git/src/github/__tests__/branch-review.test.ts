@@ -123,4 +123,229 @@ describe("github branch-review", () => {
       assert.equal(result.url, "https://github.com/test-org/repo-1/pull/5")
     })
   })
+
+  describe("listPullRequests", () => {
+    it("maps pull requests and reports merged ones as merged", async () => {
+      const requestedUrls: string[] = []
+      const routes = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/repos/test-org/repo-1/pulls",
+          status: 200,
+          body: [
+            {
+              number: 3,
+              title: "Submit lab 1",
+              user: { login: "alice" },
+              head: { ref: "lab-1" },
+              base: { ref: "main" },
+              created_at: "2026-03-01T10:00:00Z",
+              state: "open",
+              merged_at: null,
+              html_url: "https://github.com/test-org/repo-1/pull/3",
+            },
+            {
+              number: 2,
+              title: "Draft",
+              user: null,
+              head: { ref: "draft" },
+              base: { ref: "main" },
+              created_at: "2026-02-01T10:00:00Z",
+              state: "closed",
+              merged_at: "2026-02-02T10:00:00Z",
+              html_url: "https://github.com/test-org/repo-1/pull/2",
+            },
+          ],
+        },
+      ])
+      const http: HttpPort = {
+        fetch(request) {
+          requestedUrls.push(request.url)
+          return routes.fetch(request)
+        },
+      }
+
+      const client = createGitHubClient(http)
+      const pullRequests = await client.listPullRequests(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        state: "all",
+      })
+
+      assert.ok(requestedUrls[0]?.includes("state=all"))
+      assert.deepStrictEqual(pullRequests, [
+        {
+          number: 3,
+          title: "Submit lab 1",
+          author: "alice",
+          headBranch: "lab-1",
+          baseBranch: "main",
+          createdAt: "2026-03-01T10:00:00Z",
+          state: "open",
+          url: "https://github.com/test-org/repo-1/pull/3",
+        },
+        {
+          number: 2,
+          title: "Draft",
+          author: null,
+          headBranch: "draft",
+          baseBranch: "main",
+          createdAt: "2026-02-01T10:00:00Z",
+          state: "merged",
+          url: "https://github.com/test-org/repo-1/pull/2",
+        },
+      ])
+    })
+  })
+
+  describe("protectBranch", () => {
+    it("blocks force-pushes and requires the configured reviews", async () => {
+      let captured: { method?: string; url: string; body: unknown } | null =
+        null
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured = {
+            method: request.method,
+            url: request.url,
+            body: JSON.parse(request.body ?? "null"),
+          }
+          return {
+            status: 200,
+            statusText: "OK",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({}),
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      await client.protectBranch(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        branch: "main",
+        options: {
+          requirePullRequestReviews: true,
+          dismissStaleReviews: true,
+          requiredApprovingReviewCount: 2,
+        },
+      })
+
+      assert.equal(captured?.method, "PUT")
+      assert.ok(
+        captured?.url.endsWith(
+          "/repos/test-org/repo-1/branches/main/protection",
+        ),
+      )
+      assert.deepStrictEqual(captured?.body, {
+        required_status_checks: null,
+        enforce_admins: null,
+        required_pull_request_reviews: {
+          dismiss_stale_reviews: true,
+          required_approving_review_count: 2,
+        },
+        restrictions: null,
+        allow_force_pushes: false,
+        allow_deletions: false,
+      })
+    })
+
+    it("omits review rules when reviews are not required", async () => {
+      let capturedBody: unknown = null
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          capturedBody = JSON.parse(request.body ?? "null")
+          return {
+            status: 200,
+            statusText: "OK",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({}),
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      await client.protectBranch(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        branch: "main",
+        options: {
+          requirePullRequestReviews: false,
+          dismissStaleReviews: true,
+          requiredApprovingReviewCount: 1,
+        },
+      })
+
+      assert.equal(
+        (capturedBody as { required_pull_request_reviews: unknown })
+          .required_pull_request_reviews,
+        null,
+      )
+    })
+
+    it("surfaces errors for missing branches", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "PUT",
+          urlPattern: "/repos/test-org/repo-1/branches/missing/protection",
+          status: 404,
+          body: { message: "Branch not found" },
+        },
+      ])
+
+      const client = createGitHubClient(http)
+      await assert.rejects(
+        client.protectBranch(baseDraft, {
+          owner: "test-org",
+          repositoryName: "repo-1",
+          branch: "missing",
+          options: {
+            requirePullRequestReviews: false,
+            dismissStaleReviews: false,
+            requiredApprovingReviewCount: 0,
+          },
+        }),
+        /Branch not found/,
+      )
+    })
+  })
+
+  describe("unprotectBranch", () => {
+    it("deletes the protection rule and tolerates unprotected branches", async () => {
+      const captured: string[] = []
+      let status = 204
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(`${request.method} ${request.url}`)
+          return {
+            status,
+            statusText: "",
+            headers: { "content-type": "application/json" },
+            body:
+              status === 404
+                ? JSON.stringify({ message: "Branch not protected" })
+                : "",
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      const request = {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        branch: "main",
+      }
+      await client.unprotectBranch(baseDraft, request)
+      status = 404
+      await client.unprotectBranch(baseDraft, request)
+
+      assert.equal(captured.length, 2)
+      assert.ok(
+        captured.every(
+          (entry) =>
+            entry.startsWith("DELETE ") &&
+            entry.endsWith("/repos/test-org/repo-1/branches/main/protection"),
+        ),
+      )
+    })
+  })
 })
@@ -89,4 +89,56 @@ describe("github discovery", () => {
       )
     })
   })
+
+  describe("getCommitCount", () => {
+    it("counts commits on the requested branch since a timestamp", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern:
+            /\/repos\/test-org\/lab1-alice\/commits\?(?=.*sha=main)(?=.*since=2026-09-01)/,
+          status: 200,
+          body: [{ sha: "c3" }, { sha: "c2" }, { sha: "c1" }],
+        },
+      ])
+
+      const client = createGitHubClient(http)
+      const count = await client.getCommitCount(baseDraft, {
+        owner: "test-org",
+        repositoryName: "lab1-alice",
+        branch: "main",
+        since: "2026-09-01T00:00:00.000Z",
+      })
+
+      assert.equal(count, 3)
+    })
+
+    it("returns null for missing repositories and zero for empty ones", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: /\/repos\/test-org\/empty-repo\/commits/,
+          status: 409,
+          body: { message: "Git Repository is empty." },
+        },
+      ])
+
+      const client = createGitHubClient(http)
+      const request = { owner: "test-org", branch: null, since: null }
+      assert.equal(
+        await client.getCommitCount(baseDraft, {
+          ...request,
+          repositoryName: "missing-repo",
+        }),
+        null,
+      )
+      assert.equal(
+        await client.getCommitCount(baseDraft, {
+          ...request,
+          repositoryName: "empty-repo",
+        }),
+        0,
+      )
+    })
+  })
 })
@@ -5,6 +5,7 @@ import type {
   HttpRequest,
   HttpResponse,
 } from "@repo-edu/host-runtime-contract"
+import { usernameCheckConcurrency } from "../../concurrency.js"
 import { createGitHubClient } from "../github-client.js"
 import { baseDraft, createMockHttpPort, findUserAgent } from "./harness.js"
 
@@ -120,6 +121,62 @@ describe("github identity", () => {
       assert.deepStrictEqual(result[1], { username: "nobody", exists: false })
     })
 
+    it("checks usernames in parallel up to the concurrency limit", async () => {
+      let inFlight = 0
+      let maxInFlight = 0
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          inFlight += 1
+          maxInFlight = Math.max(maxInFlight, inFlight)
+          await new Promise((resolve) => setTimeout(resolve, 5))
+          inFlight -= 1
+          const login = request.url.split("/users/")[1]
+          return {
+            status: 200,
+            statusText: "OK",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({ login, id: 1 }),
+          }
+        },
+      }
+      const usernames = Array.from({ length: 20 }, (_, i) => `student-${i}`)
+
+      const client = createGitHubClient(http)
+      const result = await client.verifyGitUsernames(baseDraft, usernames)
+
+      assert.deepStrictEqual(result.map((status) => status.username), usernames)
+      assert.equal(result.every((status) => status.exists), true)
+      assert.equal(maxInFlight, usernameCheckConcurrency)
+    })
+
+    it("reports rate-limited lookups separately from missing users", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/users/alice",
+          status: 403,
+          body: { message: "API rate limit exceeded for user ID 1." },
+        },
+        {
+          method: "GET",
+          urlPattern: "/users/nobody",
+          status: 404,
+          body: { message: "Not Found" },
+        },
+      ])
+
+      const client = createGitHubClient(http)
+      const result = await client.verifyGitUsernames(baseDraft, [
+        "alice",
+        "nobody",
+      ])
+
+      assert.deepStrictEqual(result, [
+        { username: "alice", exists: false, rateLimited: true },
+        { username: "nobody", exists: false },
+      ])
+    })
+
     it("respects abort signal", async () => {
       const controller = new AbortController()
       controller.abort()
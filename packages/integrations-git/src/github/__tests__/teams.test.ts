@@ -107,4 +107,108 @@ describe("github teams", () => {
       )
     })
   })
+
+  describe("addCollaborator", () => {
+    it("puts the user on the repository with the mapped permission", async () => {
+      const captured: HttpRequest[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(request)
+          return {
+            status: 204,
+            statusText: "No Content",
+            headers: {},
+            body: "",
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      await client.addCollaborator(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        username: "alice",
+        permission: "pull",
+      })
+
+      assert.equal(captured.length, 1)
+      assert.equal(captured[0]?.method, "PUT")
+      assert.ok(
+        captured[0]?.url.includes("/repos/test-org/repo-1/collaborators/alice"),
+      )
+      assert.deepStrictEqual(JSON.parse(captured[0]?.body ?? ""), {
+        permission: "pull",
+      })
+    })
+  })
+
+  describe("team membership", () => {
+    it("lists member logins of the team resolved from its name", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/orgs/test-org/teams/hw1-team/members?role=member",
+          status: 200,
+          body: [{ login: "alice" }, { login: "bob" }],
+        },
+        {
+          method: "GET",
+          urlPattern: "/orgs/test-org/teams/hw1-team/members",
+          status: 200,
+          body: [{ login: "alice" }, { login: "bob" }, { login: "instructor" }],
+        },
+      ])
+
+      const client = createGitHubClient(http)
+      const members = await client.listTeamMembers(baseDraft, {
+        organization: "test-org",
+        teamName: "HW1 Team",
+      })
+
+      assert.deepStrictEqual(members, ["alice", "bob"])
+    })
+
+    it("reports a missing team as null", async () => {
+      const client = createGitHubClient(createMockHttpPort([]))
+      const members = await client.listTeamMembers(baseDraft, {
+        organization: "test-org",
+        teamName: "hw1-team",
+      })
+
+      assert.equal(members, null)
+    })
+
+    it("removes memberships and ignores users who are not members", async () => {
+      const captured: HttpRequest[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(request)
+          const status = request.url.endsWith("/memberships/bob") ? 404 : 204
+          return {
+            status,
+            statusText: status === 204 ? "No Content" : "Not Found",
+            headers: { "content-type": "application/json" },
+            body: "",
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      await client.removeTeamMembers(baseDraft, {
+        organization: "test-org",
+        teamName: "hw1-team",
+        memberUsernames: ["alice", "bob"],
+      })
+
+      assert.deepStrictEqual(
+        captured.map((request) => request.method),
+        ["DELETE", "DELETE"],
+      )
+      assert.ok(
+        captured[0]?.url.includes(
+          "/orgs/test-org/teams/hw1-team/memberships/alice",
+        ),
+      )
+    })
+  })
 })
@@ -0,0 +1,89 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import { createGitHubClient } from "../github-client.js"
+import { baseDraft, createMockHttpPort } from "./harness.js"
+
+describe("github webhooks", () => {
+  describe("createWebhook", () => {
+    it("registers a JSON web hook with GitHub event names", async () => {
+      let capturedBody: unknown = null
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          capturedBody = JSON.parse(request.body ?? "null")
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({ id: 42 }),
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      const webhook = await client.createWebhook(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        url: "https://grader.example.com/hook",
+        events: ["push", "issue"],
+      })
+
+      assert.deepStrictEqual(webhook, {
+        id: "42",
+        url: "https://grader.example.com/hook",
+        events: ["push", "issue"],
+      })
+      assert.deepStrictEqual(capturedBody, {
+        name: "web",
+        active: true,
+        events: ["push", "issues"],
+        config: {
+          url: "https://grader.example.com/hook",
+          content_type: "json",
+        },
+      })
+    })
+  })
+
+  describe("deleteWebhook", () => {
+    it("deletes the hook by id", async () => {
+      const capturedUrls: string[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          capturedUrls.push(`${request.method} ${request.url}`)
+          return {
+            status: 204,
+            statusText: "No Content",
+            headers: {},
+            body: "",
+          }
+        },
+      }
+
+      const client = createGitHubClient(http)
+      await client.deleteWebhook(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        webhookId: "42",
+      })
+
+      assert.deepStrictEqual(capturedUrls, [
+        "DELETE https://api.github.com/repos/test-org/repo-1/hooks/42",
+      ])
+    })
+
+    it("treats a missing hook as already deleted", async () => {
+      const client = createGitHubClient(createMockHttpPort([]))
+
+      await client.deleteWebhook(baseDraft, {
+        owner: "test-org",
+        repositoryName: "repo-1",
+        webhookId: "42",
+      })
+    })
+  })
+})
@@ -1,8 +1,12 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  PullRequestSummary,
+} from "@repo-edu/integrations-git-contract"
 import {
   isAlreadyExistsError,
   isNoChangesError,
+  isNotFoundError,
   toErrorMessage,
 } from "./errors.js"
 import {
@@ -13,7 +17,11 @@ import { createOctokit } from "./transport.js"
 
 type BranchReviewCapability = Pick<
   GitProviderClient,
-  "createBranch" | "createPullRequest"
+  | "createBranch"
+  | "createPullRequest"
+  | "listPullRequests"
+  | "protectBranch"
+  | "unprotectBranch"
 >
 
 export function createGitHubBranchReview(
@@ -129,5 +137,72 @@ export function createGitHubBranchReview(
         return { url: url ?? "", created: false }
       }
     },
+    async listPullRequests(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const pullRequests: PullRequestSummary[] = []
+      for await (const page of octokit.paginate.iterator(octokit.pulls.list, {
+        owner: request.owner,
+        repo: request.repositoryName,
+        state: request.state,
+        per_page: 100,
+        request: { signal },
+      })) {
+        if (signal?.aborted) break
+        for (const pull of page.data) {
+          pullRequests.push({
+            number: pull.number,
+            title: pull.title,
+            author: pull.user?.login ?? null,
+            headBranch: pull.head.ref,
+            baseBranch: pull.base.ref,
+            createdAt: pull.created_at,
+            state: pull.merged_at
+              ? "merged"
+              : pull.state === "open"
+                ? "open"
+                : "closed",
+            url: pull.html_url,
+          })
+        }
+      }
+      return pullRequests
+    },
+    async protectBranch(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const { options } = request
+      // The endpoint replaces the whole protection rule, so unset sections
+      // are sent as explicit nulls.
+      await octokit.repos.updateBranchProtection({
+        owner: request.owner,
+        repo: request.repositoryName,
+        branch: request.branch,
+        required_status_checks: null,
+        enforce_admins: null,
+        required_pull_request_reviews: options.requirePullRequestReviews
+          ? {
+              dismiss_stale_reviews: options.dismissStaleReviews,
+              required_approving_review_count:
+                options.requiredApprovingReviewCount,
+            }
+          : null,
+        restrictions: null,
+        allow_force_pushes: false,
+        allow_deletions: false,
+        request: { signal },
+      })
+    },
+    async unprotectBranch(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      try {
+        await octokit.repos.deleteBranchProtection({
+          owner: request.owner,
+          repo: request.repositoryName,
+          branch: request.branch,
+          request: { signal },
+        })
+      } catch (error) {
+        if (!isNotFoundError(error)) throw error
+      }
+    },
   }
 }
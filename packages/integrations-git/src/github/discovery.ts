@@ -4,10 +4,13 @@ import type {
   GitProviderClient,
   ListRepositoriesResult,
 } from "@repo-edu/integrations-git-contract"
-import { isNotFoundError } from "./errors.js"
+import { isNotFoundError, toErrorStatus } from "./errors.js"
 import { createOctokit } from "./transport.js"
 
-type DiscoveryCapability = Pick<GitProviderClient, "listRepositories">
+type DiscoveryCapability = Pick<
+  GitProviderClient,
+  "listRepositories" | "getCommitCount"
+>
 
 export function createGitHubDiscovery(http: HttpPort): DiscoveryCapability {
   return {
@@ -53,5 +56,31 @@ export function createGitHubDiscovery(http: HttpPort): DiscoveryCapability {
       )
       return { repositories }
     },
+    async getCommitCount(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      let count = 0
+      try {
+        for await (const page of octokit.paginate.iterator(
+          octokit.repos.listCommits,
+          {
+            owner: request.owner,
+            repo: request.repositoryName,
+            ...(request.branch === null ? {} : { sha: request.branch }),
+            ...(request.since === null ? {} : { since: request.since }),
+            per_page: 100,
+            request: { signal },
+          },
+        )) {
+          if (signal?.aborted) break
+          count += page.data.length
+        }
+      } catch (error) {
+        if (isNotFoundError(error)) return null
+        // GitHub answers 409 for a repository without any commits.
+        if (toErrorStatus(error) === 409) return 0
+        throw error
+      }
+      return count
+    },
   }
 }
@@ -37,6 +37,15 @@ export function isNotFoundError(error: unknown): boolean {
   return toErrorStatus(error) === 404
 }
 
+// GitHub reports primary and secondary rate limits as 403 or 429.
+export function isRateLimitError(error: unknown): boolean {
+  const status = toErrorStatus(error)
+  if (status === 429) {
+    return true
+  }
+  return status === 403 && /rate limit/i.test(toErrorMessage(error))
+}
+
 export function isNoChangesError(error: unknown): boolean {
   return /no commits between|no changes|already exists|unprocessable entity/i.test(
     toErrorMessage(error),
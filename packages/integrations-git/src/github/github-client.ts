@@ -7,6 +7,7 @@ import { createGitHubIdentity } from "./identity.js"
 import { createGitHubRepositories } from "./repositories.js"
 import { createGitHubTeams } from "./teams.js"
 import { createGitHubTemplateChanges } from "./template-changes.js"
+import { createGitHubWebhooks } from "./webhooks.js"
 
 export function createGitHubClient(http: HttpPort): GitProviderClient {
   return guardGitProviderClient({
@@ -16,5 +17,6 @@ export function createGitHubClient(http: HttpPort): GitProviderClient {
     ...createGitHubTemplateChanges(http),
     ...createGitHubBranchReview(http),
     ...createGitHubDiscovery(http),
+    ...createGitHubWebhooks(http),
   })
 }
@@ -1,5 +1,7 @@
 import type { HttpPort, HttpRequest } from "@repo-edu/host-runtime-contract"
 
+const nullBodyStatuses = new Set([204, 205, 304])
+
 /**
  * Creates a Fetch API-compatible function that delegates to HttpPort.
  * Used by Octokit's `request.fetch` option.
@@ -46,7 +48,12 @@ export function createHttpPortFetch(
 
     const httpResponse = await http.fetch(request)
 
-    return new Response(httpResponse.body, {
+    // The Response constructor rejects any body, even an empty one, on
+    // null-body statuses such as GitHub's 204 replies to deletes.
+    const body = nullBodyStatuses.has(httpResponse.status)
+      ? null
+      : httpResponse.body
+    return new Response(body, {
       status: httpResponse.status,
       statusText: httpResponse.statusText,
       headers: httpResponse.headers,
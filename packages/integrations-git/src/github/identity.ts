@@ -1,5 +1,11 @@
+import { mapBounded } from "@repo-edu/concurrency"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  GitUsernameStatus,
+} from "@repo-edu/integrations-git-contract"
+import { usernameCheckConcurrency } from "../concurrency.js"
+import { isRateLimitError } from "./errors.js"
 import { createOctokit } from "./transport.js"
 
 type IdentityCapability = Pick<
@@ -20,17 +26,25 @@ export function createGitHubIdentity(http: HttpPort): IdentityCapability {
     },
     async verifyGitUsernames(draft, usernames, signal) {
       const octokit = createOctokit(http, draft)
-      const results = []
-      for (const username of usernames) {
-        if (signal?.aborted) break
-        try {
-          await octokit.users.getByUsername({ username, request: { signal } })
-          results.push({ username, exists: true })
-        } catch {
-          results.push({ username, exists: false })
-        }
-      }
-      return results
+      const results = await mapBounded(
+        usernames,
+        usernameCheckConcurrency,
+        async (username): Promise<GitUsernameStatus | null> => {
+          if (signal?.aborted) return null
+          try {
+            await octokit.users.getByUsername({
+              username,
+              request: { signal },
+            })
+            return { username, exists: true }
+          } catch (error) {
+            return isRateLimitError(error)
+              ? { username, exists: false, rateLimited: true }
+              : { username, exists: false }
+          }
+        },
+      )
+      return results.filter((result) => result !== null)
     },
   }
 }
@@ -1,67 +1,117 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitConnectionDraft,
+  GitProviderClient,
+  RepositoryArchiveRequest,
+} from "@repo-edu/integrations-git-contract"
+import {
+  repositoryCreateConcurrency,
+  retryOnRateLimit,
+} from "../concurrency.js"
+import {
+  createRepositoriesBounded,
+  type RepositoryCreateOutcome,
+} from "../repository-create.js"
 import { withGitHubToken } from "./auth.js"
 import {
   isAlreadyExistsError,
   isNotFoundError,
+  isRateLimitError,
   toErrorMessage,
 } from "./errors.js"
 import { createOctokit } from "./transport.js"
 
 type RepositoriesCapability = Pick<
   GitProviderClient,
-  "createRepositories" | "resolveRepositoryCloneUrls"
+  | "createRepositories"
+  | "resolveRepositoryCloneUrls"
+  | "archiveRepository"
+  | "unarchiveRepository"
+  | "forkRepository"
+  | "syncFork"
 >
 
+async function setGitHubRepositoryArchived(
+  http: HttpPort,
+  draft: GitConnectionDraft,
+  request: RepositoryArchiveRequest,
+  archived: boolean,
+  signal?: AbortSignal,
+): Promise<void> {
+  const octokit = createOctokit(http, draft)
+  await octokit.repos.update({
+    owner: request.owner,
+    repo: request.repositoryName,
+    archived,
+    request: { signal },
+  })
+}
+
 export function createGitHubRepositories(
   http: HttpPort,
 ): RepositoriesCapability {
   return {
     async createRepositories(draft, request, signal) {
       const octokit = createOctokit(http, draft)
-      const created = []
-      const alreadyExisted = []
-      const failed = []
-      for (const repositoryName of request.repositoryNames) {
-        if (signal?.aborted) break
-        try {
-          const response = await octokit.repos.createInOrg({
-            org: request.organization,
-            name: repositoryName,
-            private: request.visibility !== "public",
-            auto_init: request.autoInit,
-            request: { signal },
-          })
-          created.push({
-            repositoryName,
-            repositoryUrl: response.data.html_url,
-            cloneUrl: withGitHubToken(response.data.clone_url, draft.token),
-          })
-        } catch (error) {
-          if (isAlreadyExistsError(error)) {
-            try {
-              const existing = await octokit.repos.get({
-                owner: request.organization,
-                repo: repositoryName,
-                request: { signal },
-              })
-              alreadyExisted.push({
+      return createRepositoriesBounded(
+        request.repositoryNames,
+        request.concurrency ?? repositoryCreateConcurrency.github,
+        async (repositoryName): Promise<RepositoryCreateOutcome> => {
+          try {
+            const response = await retryOnRateLimit(
+              () =>
+                octokit.repos.createInOrg({
+                  org: request.organization,
+                  name: repositoryName,
+                  private: request.visibility !== "public",
+                  auto_init: request.autoInit,
+                  request: { signal },
+                }),
+              isRateLimitError,
+              { signal },
+            )
+            return {
+              kind: "created",
+              repository: {
+                repositoryName,
+                repositoryUrl: response.data.html_url,
+                cloneUrl: withGitHubToken(response.data.clone_url, draft.token),
+              },
+            }
+          } catch (error) {
+            if (!isAlreadyExistsError(error)) {
+              return {
+                kind: "failed",
+                failure: { repositoryName, reason: toErrorMessage(error) },
+              }
+            }
+          }
+          try {
+            const existing = await octokit.repos.get({
+              owner: request.organization,
+              repo: repositoryName,
+              request: { signal },
+            })
+            return {
+              kind: "existing",
+              repository: {
                 repositoryName,
                 repositoryUrl: existing.data.html_url,
                 cloneUrl: withGitHubToken(existing.data.clone_url, draft.token),
-              })
-            } catch (lookupError) {
-              failed.push({
+              },
+            }
+          } catch (lookupError) {
+            return {
+              kind: "failed",
+              failure: {
                 repositoryName,
                 reason: `Already exists but lookup failed: ${toErrorMessage(lookupError)}`,
-              })
+              },
             }
-            continue
           }
-          failed.push({ repositoryName, reason: toErrorMessage(error) })
-        }
-      }
-      return { created, alreadyExisted, failed }
+        },
+        signal,
+      )
     },
     async resolveRepositoryCloneUrls(draft, request, signal) {
       const octokit = createOctokit(http, draft)
@@ -77,7 +127,10 @@ export function createGitHubRepositories(
           })
           resolved.push({
             repositoryName,
-            cloneUrl: withGitHubToken(response.data.clone_url, draft.token),
+            cloneUrl:
+              request.scheme === "ssh"
+                ? response.data.ssh_url
+                : withGitHubToken(response.data.clone_url, draft.token),
           })
         } catch (error) {
           if (!isNotFoundError(error)) throw error
@@ -86,5 +139,48 @@ export function createGitHubRepositories(
       }
       return { resolved, missing }
     },
+    async archiveRepository(draft, request, signal) {
+      await setGitHubRepositoryArchived(http, draft, request, true, signal)
+    },
+    async unarchiveRepository(draft, request, signal) {
+      await setGitHubRepositoryArchived(http, draft, request, false, signal)
+    },
+    async forkRepository(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      // Forking is asynchronous on GitHub; the 202 response already carries
+      // the fork's URLs even though its contents may still be copying.
+      const { data } = await octokit.repos.createFork({
+        owner: request.owner,
+        repo: request.repositoryName,
+        organization: request.organization,
+        name: request.name,
+        default_branch_only: request.defaultBranchOnly,
+        request: { signal },
+      })
+      return {
+        repositoryName: data.name,
+        repositoryUrl: data.html_url,
+        cloneUrl: withGitHubToken(data.clone_url, draft.token),
+      }
+    },
+    async syncFork(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const branch =
+        request.branch ??
+        (
+          await octokit.repos.get({
+            owner: request.owner,
+            repo: request.repositoryName,
+            request: { signal },
+          })
+        ).data.default_branch
+      const { data } = await octokit.repos.mergeUpstream({
+        owner: request.owner,
+        repo: request.repositoryName,
+        branch,
+        request: { signal },
+      })
+      return { branch, updated: data.merge_type !== "none" }
+    },
   }
 }
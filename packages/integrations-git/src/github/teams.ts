@@ -28,7 +28,11 @@ function teamSlugFromName(name: string): string {
 
 type TeamsCapability = Pick<
   GitProviderClient,
-  "createTeam" | "assignRepositoriesToTeam"
+  | "createTeam"
+  | "assignRepositoriesToTeam"
+  | "listTeamMembers"
+  | "removeTeamMembers"
+  | "addCollaborator"
 >
 
 export function createGitHubTeams(http: HttpPort): TeamsCapability {
@@ -90,5 +94,58 @@ export function createGitHubTeams(http: HttpPort): TeamsCapability {
         })
       }
     },
+    async listTeamMembers(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const usernames: string[] = []
+      try {
+        for await (const page of octokit.paginate.iterator(
+          octokit.teams.listMembersInOrg,
+          {
+            org: request.organization,
+            team_slug: teamSlugFromName(request.teamName),
+            // Team maintainers are staff, not roster members to sync.
+            role: "member",
+            per_page: 100,
+            request: { signal },
+          },
+        )) {
+          if (signal?.aborted) break
+          for (const member of page.data) {
+            usernames.push(member.login)
+          }
+        }
+      } catch (error) {
+        if (isNotFoundError(error)) return null
+        throw error
+      }
+      return usernames
+    },
+    async removeTeamMembers(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const teamSlug = teamSlugFromName(request.teamName)
+      for (const username of request.memberUsernames) {
+        if (signal?.aborted) break
+        try {
+          await octokit.teams.removeMembershipForUserInOrg({
+            org: request.organization,
+            team_slug: teamSlug,
+            username,
+            request: { signal },
+          })
+        } catch (error) {
+          if (!isNotFoundError(error)) throw error
+        }
+      }
+    },
+    async addCollaborator(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      await octokit.repos.addCollaborator({
+        owner: request.owner,
+        repo: request.repositoryName,
+        username: request.username,
+        permission: mapTeamPermission(request.permission),
+        request: { signal },
+      })
+    },
   }
 }
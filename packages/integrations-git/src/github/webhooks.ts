@@ -0,0 +1,53 @@
+import type { HttpPort } from "@repo-edu/host-runtime-contract"
+import type {
+  GitProviderClient,
+  WebhookEvent,
+} from "@repo-edu/integrations-git-contract"
+import { isNotFoundError } from "./errors.js"
+import { createOctokit } from "./transport.js"
+
+type WebhooksCapability = Pick<
+  GitProviderClient,
+  "createWebhook" | "deleteWebhook"
+>
+
+const githubWebhookEvents: Record<WebhookEvent, string> = {
+  push: "push",
+  pull_request: "pull_request",
+  issue: "issues",
+}
+
+export function createGitHubWebhooks(http: HttpPort): WebhooksCapability {
+  return {
+    async createWebhook(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      const { data } = await octokit.repos.createWebhook({
+        owner: request.owner,
+        repo: request.repositoryName,
+        name: "web",
+        active: true,
+        events: request.events.map((event) => githubWebhookEvents[event]),
+        config: { url: request.url, content_type: "json" },
+        request: { signal },
+      })
+      return {
+        id: String(data.id),
+        url: request.url,
+        events: [...request.events],
+      }
+    },
+    async deleteWebhook(draft, request, signal) {
+      const octokit = createOctokit(http, draft)
+      try {
+        await octokit.repos.deleteWebhook({
+          owner: request.owner,
+          repo: request.repositoryName,
+          hook_id: Number(request.webhookId),
+          request: { signal },
+        })
+      } catch (error) {
+        if (!isNotFoundError(error)) throw error
+      }
+    },
+  }
+}
@@ -6,7 +6,7 @@ import type {
   HttpResponse,
 } from "@repo-edu/host-runtime-contract"
 import { createGitLabClient } from "../gitlab-client.js"
-import { baseDraft } from "./harness.js"
+import { baseDraft, createMockHttpPort } from "./harness.js"
 
 describe("gitlab branch-review", () => {
   describe("createPullRequest", () => {
@@ -61,4 +61,104 @@ describe("gitlab branch-review", () => {
       assert.ok(result.url.includes("merge_requests/1"))
     })
   })
+
+  describe("listPullRequests", () => {
+    it("drops open merge requests from closed listings", async () => {
+      const mergeRequests = [
+        {
+          iid: 4,
+          title: "Lab 1",
+          author: { username: "alice" },
+          source_branch: "lab-1",
+          target_branch: "main",
+          created_at: "2026-03-01T10:00:00Z",
+          state: "opened",
+          web_url:
+            "https://gitlab.example.com/my-org/repo-1/-/merge_requests/4",
+        },
+        {
+          iid: 3,
+          title: "Lab 0",
+          author: { username: "bob" },
+          source_branch: "lab-0",
+          target_branch: "main",
+          created_at: "2026-02-01T10:00:00Z",
+          state: "merged",
+          web_url:
+            "https://gitlab.example.com/my-org/repo-1/-/merge_requests/3",
+        },
+      ]
+      const routes = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/projects/100/merge_requests",
+          status: 200,
+          body: mergeRequests,
+        },
+        {
+          method: "GET",
+          urlPattern: "/projects/my-org%2Frepo-1",
+          status: 200,
+          body: { id: 100 },
+        },
+      ])
+      const requestedUrls: string[] = []
+      const http: HttpPort = {
+        fetch(request) {
+          requestedUrls.push(request.url)
+          return routes.fetch(request)
+        },
+      }
+
+      const client = createGitLabClient(http)
+      const pullRequests = await client.listPullRequests(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        state: "closed",
+      })
+
+      assert.ok(
+        requestedUrls.some((url) => url.includes("merge_requests?state=all")),
+      )
+      assert.deepStrictEqual(pullRequests, [
+        {
+          number: 3,
+          title: "Lab 0",
+          author: "bob",
+          headBranch: "lab-0",
+          baseBranch: "main",
+          createdAt: "2026-02-01T10:00:00Z",
+          state: "merged",
+          url: "https://gitlab.example.com/my-org/repo-1/-/merge_requests/3",
+        },
+      ])
+    })
+  })
+
+  describe("protectBranch", () => {
+    it("rejects branch protection changes", async () => {
+      const client = createGitLabClient(createMockHttpPort([]))
+      const request = {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        branch: "main",
+      }
+
+      await assert.rejects(
+        client.protectBranch(baseDraft, {
+          ...request,
+          options: {
+            requirePullRequestReviews: false,
+            dismissStaleReviews: false,
+            requiredApprovingReviewCount: 0,
+          },
+        }),
+        /Branch protection is only supported on GitHub/,
+      )
+      await assert.rejects(
+        client.unprotectBranch(baseDraft, request),
+        /Branch protection is only supported on GitHub/,
+      )
+    })
+  })
 })
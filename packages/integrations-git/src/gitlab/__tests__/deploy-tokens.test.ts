@@ -0,0 +1,192 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import { createGitLabDeployTokensClient } from "../deploy-tokens.js"
+import { baseDraft, createMockHttpPort } from "./harness.js"
+
+const project = { owner: "my-org/sub", repositoryName: "repo-1" }
+const tokensUrl =
+  "https://gitlab.example.com/api/v4/projects/my-org%2Fsub%2Frepo-1/deploy_tokens"
+
+describe("gitlab deploy tokens", () => {
+  describe("listDeployTokens", () => {
+    it("lists project deploy tokens", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/projects/my-org%2Fsub%2Frepo-1/deploy_tokens",
+          status: 200,
+          body: [
+            {
+              id: 1,
+              name: "ci-clone",
+              username: "gitlab+deploy-token-1",
+              expires_at: "2026-12-31T00:00:00.000Z",
+              scopes: ["read_repository", "read_package_registry"],
+              revoked: false,
+              expired: false,
+            },
+            {
+              id: 2,
+              name: "registry",
+              username: "gitlab+deploy-token-2",
+              expires_at: null,
+              scopes: ["read_registry", "write_registry"],
+              revoked: true,
+              expired: false,
+            },
+          ],
+        },
+      ])
+
+      const client = createGitLabDeployTokensClient(http)
+      const tokens = await client.listDeployTokens(baseDraft, project)
+
+      assert.deepStrictEqual(tokens, [
+        {
+          id: 1,
+          name: "ci-clone",
+          username: "gitlab+deploy-token-1",
+          scopes: ["read_repository"],
+          expiresAt: "2026-12-31T00:00:00.000Z",
+          revoked: false,
+          token: null,
+        },
+        {
+          id: 2,
+          name: "registry",
+          username: "gitlab+deploy-token-2",
+          scopes: ["read_registry", "write_registry"],
+          expiresAt: null,
+          revoked: true,
+          token: null,
+        },
+      ])
+    })
+
+    it("throws when the project is missing", async () => {
+      const client = createGitLabDeployTokensClient(createMockHttpPort([]))
+
+      await assert.rejects(
+        client.listDeployTokens(baseDraft, {
+          owner: "my-org",
+          repositoryName: "missing",
+        }),
+        /Failed to list deploy tokens for 'my-org\/missing' \(404\)/,
+      )
+    })
+  })
+
+  describe("createDeployToken", () => {
+    it("posts the name and scopes and returns the token secret", async () => {
+      let captured: HttpRequest | null = null
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured = request
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({
+              id: 3,
+              name: "ci-clone",
+              username: "gitlab+deploy-token-3",
+              expires_at: null,
+              scopes: ["read_repository"],
+              revoked: false,
+              token: "gldt-secret",
+            }),
+          }
+        },
+      }
+
+      const client = createGitLabDeployTokensClient(http)
+      const token = await client.createDeployToken(baseDraft, {
+        ...project,
+        name: "ci-clone",
+        scopes: ["read_repository"],
+      })
+
+      assert.equal(token.id, 3)
+      assert.equal(token.token, "gldt-secret")
+      const request = captured as HttpRequest | null
+      assert.equal(request?.method, "POST")
+      assert.equal(request?.url, tokensUrl)
+      assert.deepStrictEqual(JSON.parse(request?.body ?? "null"), {
+        name: "ci-clone",
+        scopes: ["read_repository"],
+      })
+    })
+
+    it("surfaces GitLab validation messages", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "POST",
+          urlPattern: "/deploy_tokens",
+          status: 400,
+          body: { message: "scopes is invalid" },
+        },
+      ])
+
+      const client = createGitLabDeployTokensClient(http)
+      await assert.rejects(
+        client.createDeployToken(baseDraft, {
+          ...project,
+          name: "bad",
+          scopes: [],
+        }),
+        /Failed to create deploy token 'bad'.*\(400\): scopes is invalid/,
+      )
+    })
+  })
+
+  describe("revokeDeployToken", () => {
+    it("deletes the token and tolerates tokens that are already gone", async () => {
+      const captured: string[] = []
+      let status = 204
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(`${request.method} ${request.url}`)
+          return { status, statusText: "", headers: {}, body: "" }
+        },
+      }
+
+      const client = createGitLabDeployTokensClient(http)
+      await client.revokeDeployToken(baseDraft, { ...project, tokenId: 3 })
+      status = 404
+      await client.revokeDeployToken(baseDraft, { ...project, tokenId: 3 })
+
+      assert.deepStrictEqual(captured, [
+        `DELETE ${tokensUrl}/3`,
+        `DELETE ${tokensUrl}/3`,
+      ])
+    })
+
+    it("does not call GitLab once the caller has aborted", async () => {
+      let calls = 0
+      const http: HttpPort = {
+        async fetch(): Promise<HttpResponse> {
+          calls += 1
+          return { status: 204, statusText: "", headers: {}, body: "" }
+        },
+      }
+      const controller = new AbortController()
+      controller.abort()
+
+      const client = createGitLabDeployTokensClient(http)
+      await assert.rejects(
+        client.revokeDeployToken(
+          baseDraft,
+          { ...project, tokenId: 3 },
+          controller.signal,
+        ),
+        { name: "AbortError" },
+      )
+      assert.equal(calls, 0)
+    })
+  })
+})
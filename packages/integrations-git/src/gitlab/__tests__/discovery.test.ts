@@ -219,4 +219,50 @@ describe("gitlab discovery", () => {
       )
     })
   })
+
+  describe("getCommitCount", () => {
+    it("sums commit pages for the encoded project path", async () => {
+      const firstPage = Array.from({ length: 100 }, (_, index) => ({
+        id: `c${index}`,
+      }))
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern:
+            /\/projects\/my-group%2Flab1-alice\/repository\/commits\?ref_name=main&per_page=100&page=1/,
+          status: 200,
+          body: firstPage,
+        },
+        {
+          method: "GET",
+          urlPattern:
+            /\/projects\/my-group%2Flab1-alice\/repository\/commits\?ref_name=main&per_page=100&page=2/,
+          status: 200,
+          body: [{ id: "c100" }, { id: "c101" }],
+        },
+      ])
+
+      const client = createGitLabClient(http)
+      const count = await client.getCommitCount(baseDraft, {
+        owner: "my-group",
+        repositoryName: "lab1-alice",
+        branch: "main",
+        since: null,
+      })
+
+      assert.equal(count, 102)
+    })
+
+    it("returns null when the project does not exist", async () => {
+      const client = createGitLabClient(createMockHttpPort([]))
+      const count = await client.getCommitCount(baseDraft, {
+        owner: "my-group",
+        repositoryName: "missing",
+        branch: null,
+        since: null,
+      })
+
+      assert.equal(count, null)
+    })
+  })
 })
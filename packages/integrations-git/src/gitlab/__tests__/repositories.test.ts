@@ -210,6 +210,54 @@ describe("gitlab repositories", () => {
         `Expected URL-encoded path, got: ${capturedUrl}`,
       )
     })
+
+    it("creates repositories concurrently up to the requested limit", async () => {
+      let inFlight = 0
+      let maxInFlight = 0
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          if (request.url.includes("/groups/")) {
+            return {
+              status: 200,
+              statusText: "OK",
+              headers: { "content-type": "application/json" },
+              body: JSON.stringify({ id: 42, path: "my-group" }),
+            }
+          }
+          inFlight += 1
+          maxInFlight = Math.max(maxInFlight, inFlight)
+          await new Promise((resolve) => setTimeout(resolve, 5))
+          inFlight -= 1
+          const { path } = JSON.parse(request.body ?? "{}") as { path: string }
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({
+              id: 100,
+              web_url: `https://gitlab.example.com/my-group/${path}`,
+              http_url_to_repo: `https://gitlab.example.com/my-group/${path}.git`,
+            }),
+          }
+        },
+      }
+      const repositoryNames = ["r1", "r2", "r3", "r4", "r5", "r6"]
+
+      const client = createGitLabClient(http)
+      const result = await client.createRepositories(baseDraft, {
+        organization: "my-group",
+        repositoryNames,
+        visibility: "private",
+        autoInit: false,
+        concurrency: 3,
+      })
+
+      assert.equal(maxInFlight, 3)
+      assert.deepStrictEqual(
+        result.created.map((repository) => repository.repositoryName),
+        repositoryNames,
+      )
+    })
   })
 
   describe("createRepositories alreadyExisted", () => {
@@ -317,5 +365,98 @@ describe("gitlab repositories", () => {
         result.resolved[0]?.cloneUrl.includes("oauth2:glpat-test-token"),
       )
     })
+
+    it("returns SSH clone URLs without credentials when requested", async () => {
+      const http = createMockHttpPort([
+        {
+          method: "GET",
+          urlPattern: "/projects/my-group%2Frepo-1",
+          status: 200,
+          body: {
+            http_url_to_repo: "https://gitlab.example.com/my-group/repo-1.git",
+            ssh_url_to_repo: "git@gitlab.example.com:my-group/repo-1.git",
+          },
+        },
+      ])
+
+      const client = createGitLabClient(http)
+      const result = await client.resolveRepositoryCloneUrls(baseDraft, {
+        organization: "my-group",
+        repositoryNames: ["repo-1"],
+        scheme: "ssh",
+      })
+
+      assert.deepStrictEqual(result, {
+        resolved: [
+          {
+            repositoryName: "repo-1",
+            cloneUrl: "git@gitlab.example.com:my-group/repo-1.git",
+          },
+        ],
+        missing: [],
+      })
+    })
+  })
+
+  describe("archiveRepository", () => {
+    it("posts to the project archive and unarchive endpoints", async () => {
+      const captured: string[] = []
+      const http: HttpPort = {
+        async fetch(request: HttpRequest): Promise<HttpResponse> {
+          captured.push(`${request.method} ${request.url}`)
+          return {
+            status: 201,
+            statusText: "Created",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify({ id: 100 }),
+          }
+        },
+      }
+
+      const client = createGitLabClient(http)
+      const request = { owner: "my-org/sub", repositoryName: "repo-1" }
+      await client.archiveRepository(baseDraft, request)
+      await client.unarchiveRepository(baseDraft, request)
+
+      assert.deepStrictEqual(captured, [
+        "POST https://gitlab.example.com/api/v4/projects/my-org%2Fsub%2Frepo-1/archive",
+        "POST https://gitlab.example.com/api/v4/projects/my-org%2Fsub%2Frepo-1/unarchive",
+      ])
+    })
+
+    it("throws when the project is missing", async () => {
+      const client = createGitLabClient(createMockHttpPort([]))
+
+      await assert.rejects(
+        client.archiveRepository(baseDraft, {
+          owner: "my-org",
+          repositoryName: "missing",
+        }),
+        /Failed to archive project 'my-org\/missing' \(404\)/,
+      )
+    })
+  })
+
+  describe("forkRepository", () => {
+    it("rejects forking and fork sync", async () => {
+      const client = createGitLabClient(createMockHttpPort([]))
+
+      await assert.rejects(
+        client.forkRepository(baseDraft, {
+          owner: "upstream-org",
+          repositoryName: "starter",
+          organization: "my-org",
+          defaultBranchOnly: false,
+        }),
+        /Forking repositories is only supported on GitHub/,
+      )
+      await assert.rejects(
+        client.syncFork(baseDraft, {
+          owner: "my-org",
+          repositoryName: "starter",
+        }),
+        /Syncing forks is only supported on GitHub/,
+      )
+    })
   })
 })
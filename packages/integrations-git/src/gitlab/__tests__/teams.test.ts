@@ -179,4 +179,155 @@ describe("gitlab teams", () => {
       assert.ok(capturedShareUrls[0]?.includes("/projects/100/share"))
     })
   })
+
+  describe("addCollaborator", () => {
+    function createMembersHttpPort(memberStatus: number): {
+      http: HttpPort
+      writes: HttpRequest[]
+    } {
+      const writes: HttpRequest[] = []
+      const respond = (status: number, body: unknown): HttpResponse => ({
+        status,
+        statusText: status < 300 ? "OK" : "Error",
+        headers: { "content-type": "application/json" },
+        body: JSON.stringify(body),
+      })
+      return {
+        writes,
+        http: {
+          async fetch(request: HttpRequest): Promise<HttpResponse> {
+            if (request.url.includes("/projects/my-org%2Frepo-1")) {
+              return respond(200, { id: 100 })
+            }
+            if (request.url.includes("username=alice")) {
+              return respond(200, [
+                { id: 5, username: "alice", state: "active" },
+              ])
+            }
+            if (request.url.includes("/projects/100/members")) {
+              writes.push(request)
+              return request.method === "POST"
+                ? respond(memberStatus, {})
+                : respond(200, {})
+            }
+            return respond(404, { message: "Not Found" })
+          },
+        },
+      }
+    }
+
+    it("adds the user as a project member", async () => {
+      const { http, writes } = createMembersHttpPort(201)
+
+      await createGitLabClient(http).addCollaborator(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        username: "alice",
+        permission: "push",
+      })
+
+      assert.equal(writes.length, 1)
+      assert.equal(writes[0]?.method, "POST")
+      assert.deepStrictEqual(JSON.parse(writes[0]?.body ?? ""), {
+        user_id: 5,
+        access_level: 30,
+      })
+    })
+
+    it("updates the access level of an existing member", async () => {
+      const { http, writes } = createMembersHttpPort(409)
+
+      await createGitLabClient(http).addCollaborator(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        username: "alice",
+        permission: "admin",
+      })
+
+      assert.equal(writes.length, 2)
+      assert.equal(writes[1]?.method, "PUT")
+      assert.ok(writes[1]?.url.endsWith("/projects/100/members/5"))
+      assert.deepStrictEqual(JSON.parse(writes[1]?.body ?? ""), {
+        access_level: 40,
+      })
+    })
+  })
+
+  describe("team membership", () => {
+    function createTeamHttpPort(): {
+      http: HttpPort
+      requests: HttpRequest[]
+    } {
+      const requests: HttpRequest[] = []
+      const respond = (status: number, body: unknown): HttpResponse => ({
+        status,
+        statusText: status < 300 ? "OK" : "Error",
+        headers: { "content-type": "application/json" },
+        body: JSON.stringify(body),
+      })
+      return {
+        requests,
+        http: {
+          async fetch(request: HttpRequest): Promise<HttpResponse> {
+            requests.push(request)
+            if (request.url.includes("/groups/my-org%2Fteam-hw1")) {
+              return respond(200, { id: 77 })
+            }
+            if (request.url.includes("/groups/77/members?")) {
+              return respond(200, [
+                { id: 5, username: "alice", access_level: 30 },
+                { id: 1, username: "instructor", access_level: 50 },
+                { id: 2, username: "ta", access_level: 40 },
+              ])
+            }
+            if (request.url.includes("username=alice")) {
+              return respond(200, [
+                { id: 5, username: "alice", state: "active" },
+              ])
+            }
+            if (request.url.endsWith("/groups/77/members/5")) {
+              return respond(204, {})
+            }
+            return respond(404, { message: "Not Found" })
+          },
+        },
+      }
+    }
+
+    it("lists direct members of the team subgroup without maintainers", async () => {
+      const { http } = createTeamHttpPort()
+
+      const members = await createGitLabClient(http).listTeamMembers(
+        baseDraft,
+        { organization: "my-org", teamName: "hw1" },
+      )
+
+      assert.deepStrictEqual(members, ["alice"])
+    })
+
+    it("reports a missing team as null", async () => {
+      const { http } = createTeamHttpPort()
+
+      const members = await createGitLabClient(http).listTeamMembers(
+        baseDraft,
+        { organization: "my-org", teamName: "hw2" },
+      )
+
+      assert.equal(members, null)
+    })
+
+    it("removes group members and skips unknown users", async () => {
+      const { http, requests } = createTeamHttpPort()
+
+      await createGitLabClient(http).removeTeamMembers(baseDraft, {
+        organization: "my-org",
+        teamName: "hw1",
+        memberUsernames: ["alice", "nobody"],
+      })
+
+      const deletes = requests.filter((request) => request.method === "DELETE")
+      assert.equal(deletes.length, 1)
+      assert.ok(deletes[0]?.url.endsWith("/groups/77/members/5"))
+    })
+  })
 })
@@ -0,0 +1,101 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import { createGitLabClient } from "../gitlab-client.js"
+import { baseDraft } from "./harness.js"
+
+function createProjectHttpPort(
+  onHookRequest: (request: HttpRequest) => HttpResponse,
+): HttpPort {
+  return {
+    async fetch(request: HttpRequest): Promise<HttpResponse> {
+      if (
+        request.method === "GET" &&
+        request.url.includes("/projects/my-org%2Frepo-1")
+      ) {
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify({ id: 100 }),
+        }
+      }
+      return onHookRequest(request)
+    },
+  }
+}
+
+describe("gitlab webhooks", () => {
+  describe("createWebhook", () => {
+    it("sends every event flag explicitly", async () => {
+      let captured: HttpRequest | null = null
+      const http = createProjectHttpPort((request) => {
+        captured = request
+        return {
+          status: 201,
+          statusText: "Created",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify({ id: 5 }),
+        }
+      })
+
+      const client = createGitLabClient(http)
+      const webhook = await client.createWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        url: "https://grader.example.com/hook",
+        events: ["pull_request"],
+      })
+
+      assert.equal(webhook.id, "5")
+      const request = captured as HttpRequest | null
+      assert.equal(request?.method, "POST")
+      assert.equal(
+        request?.url,
+        "https://gitlab.example.com/api/v4/projects/100/hooks",
+      )
+      assert.deepStrictEqual(JSON.parse(request?.body ?? "null"), {
+        url: "https://grader.example.com/hook",
+        push_events: false,
+        merge_requests_events: true,
+        issues_events: false,
+      })
+    })
+  })
+
+  describe("deleteWebhook", () => {
+    it("deletes the hook and tolerates a missing one", async () => {
+      const deleted: string[] = []
+      const http = createProjectHttpPort((request) => {
+        deleted.push(`${request.method} ${request.url}`)
+        return {
+          status: request.url.endsWith("/hooks/5") ? 204 : 404,
+          statusText: "",
+          headers: { "content-type": "application/json" },
+          body: "",
+        }
+      })
+
+      const client = createGitLabClient(http)
+      await client.deleteWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        webhookId: "5",
+      })
+      await client.deleteWebhook(baseDraft, {
+        owner: "my-org",
+        repositoryName: "repo-1",
+        webhookId: "6",
+      })
+
+      assert.deepStrictEqual(deleted, [
+        "DELETE https://gitlab.example.com/api/v4/projects/100/hooks/5",
+        "DELETE https://gitlab.example.com/api/v4/projects/100/hooks/6",
+      ])
+    })
+  })
+})
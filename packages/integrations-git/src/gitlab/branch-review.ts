@@ -1,14 +1,64 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  PullRequestSummary,
+} from "@repo-edu/integrations-git-contract"
 import { gitLabDataMessage, isNoChangesMessage } from "./errors.js"
 import { fileExistsInBranch, resolveProjectId } from "./repository-api.js"
 import { createGitLabApi, gitLabRestGet, gitLabRestPost } from "./transport.js"
 
 type BranchReviewCapability = Pick<
   GitProviderClient,
-  "createBranch" | "createPullRequest"
+  | "createBranch"
+  | "createPullRequest"
+  | "listPullRequests"
+  | "protectBranch"
+  | "unprotectBranch"
 >
 
+// GitLab's `closed` filter excludes merged requests, unlike GitHub and Gitea,
+// so closed listings fetch everything and drop the open ones.
+const gitLabStateFilters = {
+  open: "opened",
+  closed: "all",
+  all: "all",
+} as const
+
+function toPullRequestSummary(entry: unknown): PullRequestSummary | null {
+  if (typeof entry !== "object" || entry === null) return null
+  const record = entry as {
+    iid?: unknown
+    title?: unknown
+    author?: { username?: unknown } | null
+    source_branch?: unknown
+    target_branch?: unknown
+    created_at?: unknown
+    state?: unknown
+    web_url?: unknown
+  }
+  if (typeof record.iid !== "number") return null
+  return {
+    number: record.iid,
+    title: typeof record.title === "string" ? record.title : "",
+    author:
+      typeof record.author?.username === "string"
+        ? record.author.username
+        : null,
+    headBranch:
+      typeof record.source_branch === "string" ? record.source_branch : "",
+    baseBranch:
+      typeof record.target_branch === "string" ? record.target_branch : "",
+    createdAt: typeof record.created_at === "string" ? record.created_at : "",
+    state:
+      record.state === "opened"
+        ? "open"
+        : record.state === "merged"
+          ? "merged"
+          : "closed",
+    url: typeof record.web_url === "string" ? record.web_url : "",
+  }
+}
+
 export function createGitLabBranchReview(
   http: HttpPort,
 ): BranchReviewCapability {
@@ -153,5 +203,44 @@ export function createGitLabBranchReview(
         created: false,
       }
     },
+    async listPullRequests(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const projectPath = `${request.owner}/${request.repositoryName}`
+      const projectId = await resolveProjectId(api, projectPath)
+      if (projectId === null) {
+        throw new Error(`GitLab project '${projectPath}' was not found.`)
+      }
+      const pullRequests: PullRequestSummary[] = []
+      const perPage = 100
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const response = await gitLabRestGet(
+          http,
+          draft,
+          `/projects/${projectId}/merge_requests?state=${gitLabStateFilters[request.state]}&per_page=${perPage}&page=${page}`,
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list merge requests (${response.status}): ${gitLabDataMessage(response.data)}`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        for (const entry of response.data) {
+          const summary = toPullRequestSummary(entry)
+          if (summary === null) continue
+          if (request.state === "closed" && summary.state === "open") continue
+          pullRequests.push(summary)
+        }
+        if (response.data.length < perPage) break
+      }
+      return pullRequests
+    },
+    async protectBranch() {
+      throw new Error("Branch protection is only supported on GitHub.")
+    },
+    async unprotectBranch() {
+      throw new Error("Branch protection is only supported on GitHub.")
+    },
   }
 }
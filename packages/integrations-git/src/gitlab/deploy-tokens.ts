@@ -0,0 +1,152 @@
+import type { HttpPort } from "@repo-edu/host-runtime-contract"
+import type { GitConnectionDraft } from "@repo-edu/integrations-git-contract"
+import { invoke } from "../invocation-guard.js"
+import { gitLabDataMessage } from "./errors.js"
+import { gitLabRestDelete, gitLabRestGet, gitLabRestPost } from "./transport.js"
+
+export const gitLabDeployTokenScopes = [
+  "read_repository",
+  "read_registry",
+  "write_registry",
+] as const
+
+export type GitLabDeployTokenScope = (typeof gitLabDeployTokenScopes)[number]
+
+export type GitLabDeployToken = {
+  id: number
+  name: string
+  username: string
+  scopes: GitLabDeployTokenScope[]
+  expiresAt: string | null
+  revoked: boolean
+  /** Token secret; GitLab only returns it from creation. */
+  token: string | null
+}
+
+export type GitLabDeployTokensRequest = {
+  owner: string
+  repositoryName: string
+}
+
+export type CreateGitLabDeployTokenRequest = GitLabDeployTokensRequest & {
+  name: string
+  scopes: GitLabDeployTokenScope[]
+}
+
+export type RevokeGitLabDeployTokenRequest = GitLabDeployTokensRequest & {
+  tokenId: number
+}
+
+/**
+ * Per-project deploy tokens. GitLab-only, so these operations live outside
+ * `GitProviderClient` and the provider dispatch.
+ */
+export type GitLabDeployTokensClient = {
+  listDeployTokens(
+    draft: GitConnectionDraft,
+    request: GitLabDeployTokensRequest,
+    signal?: AbortSignal,
+  ): Promise<GitLabDeployToken[]>
+  createDeployToken(
+    draft: GitConnectionDraft,
+    request: CreateGitLabDeployTokenRequest,
+    signal?: AbortSignal,
+  ): Promise<GitLabDeployToken>
+  /** Resolves without error when the token is already gone. */
+  revokeDeployToken(
+    draft: GitConnectionDraft,
+    request: RevokeGitLabDeployTokenRequest,
+    signal?: AbortSignal,
+  ): Promise<void>
+}
+
+function isDeployTokenScope(value: unknown): value is GitLabDeployTokenScope {
+  return (gitLabDeployTokenScopes as readonly unknown[]).includes(value)
+}
+
+function toDeployToken(data: unknown): GitLabDeployToken | null {
+  if (typeof data !== "object" || data === null) return null
+  const record = data as Record<string, unknown>
+  if (typeof record.id !== "number" || typeof record.name !== "string") {
+    return null
+  }
+  return {
+    id: record.id,
+    name: record.name,
+    username: typeof record.username === "string" ? record.username : "",
+    // Scopes outside the supported set (for example package registry
+    // scopes) are dropped rather than failing the listing.
+    scopes: Array.isArray(record.scopes)
+      ? record.scopes.filter(isDeployTokenScope)
+      : [],
+    expiresAt: typeof record.expires_at === "string" ? record.expires_at : null,
+    revoked: record.revoked === true,
+    token: typeof record.token === "string" ? record.token : null,
+  }
+}
+
+// The URL-encoded project path is accepted in place of the numeric ID.
+function deployTokensPath(request: GitLabDeployTokensRequest): string {
+  const projectPath = `${request.owner}/${request.repositoryName}`
+  return `/projects/${encodeURIComponent(projectPath)}/deploy_tokens`
+}
+
+export function createGitLabDeployTokensClient(
+  http: HttpPort,
+): GitLabDeployTokensClient {
+  return {
+    listDeployTokens: (draft, request, signal) =>
+      invoke(signal, async () => {
+        const response = await gitLabRestGet(
+          http,
+          draft,
+          deployTokensPath(request),
+          signal,
+        )
+        if (
+          response.status < 200 ||
+          response.status >= 300 ||
+          !Array.isArray(response.data)
+        ) {
+          throw new Error(
+            `Failed to list deploy tokens for '${request.owner}/${request.repositoryName}' (${response.status}): ${gitLabDataMessage(response.data)}`,
+          )
+        }
+        return response.data.flatMap((entry) => toDeployToken(entry) ?? [])
+      }),
+    createDeployToken: (draft, request, signal) =>
+      invoke(signal, async () => {
+        const response = await gitLabRestPost(
+          http,
+          draft,
+          deployTokensPath(request),
+          { name: request.name, scopes: request.scopes },
+          signal,
+        )
+        const token = toDeployToken(response.data)
+        if (response.status < 200 || response.status >= 300 || !token) {
+          throw new Error(
+            `Failed to create deploy token '${request.name}' for '${request.owner}/${request.repositoryName}' (${response.status}): ${gitLabDataMessage(response.data)}`,
+          )
+        }
+        return token
+      }),
+    revokeDeployToken: (draft, request, signal) =>
+      invoke(signal, async () => {
+        const response = await gitLabRestDelete(
+          http,
+          draft,
+          `${deployTokensPath(request)}/${request.tokenId}`,
+          signal,
+        )
+        if (
+          (response.status < 200 || response.status >= 300) &&
+          response.status !== 404
+        ) {
+          throw new Error(
+            `Failed to revoke deploy token ${request.tokenId} (${response.status}): ${gitLabDataMessage(response.data)}`,
+          )
+        }
+      }),
+  }
+}
@@ -4,9 +4,9 @@ import type {
   GitProviderClient,
   ListRepositoriesResult,
 } from "@repo-edu/integrations-git-contract"
-import { isNotFoundError } from "./errors.js"
+import { gitLabDataMessage, isNotFoundError } from "./errors.js"
 import { resolveGroupId } from "./namespace.js"
-import { createGitLabApi } from "./transport.js"
+import { createGitLabApi, gitLabRestGet } from "./transport.js"
 import { resolveGitLabUserId } from "./users.js"
 
 function normalizeNamespacePath(namespace: string): string {
@@ -62,7 +62,10 @@ function appendProjects(
   }
 }
 
-type DiscoveryCapability = Pick<GitProviderClient, "listRepositories">
+type DiscoveryCapability = Pick<
+  GitProviderClient,
+  "listRepositories" | "getCommitCount"
+>
 
 export function createGitLabDiscovery(http: HttpPort): DiscoveryCapability {
   return {
@@ -105,5 +108,40 @@ export function createGitLabDiscovery(http: HttpPort): DiscoveryCapability {
       }
       return { repositories }
     },
+    async getCommitCount(draft, request, signal) {
+      const projectPath = encodeURIComponent(
+        `${request.owner}/${request.repositoryName}`,
+      )
+      const filters = [
+        request.branch === null
+          ? null
+          : `ref_name=${encodeURIComponent(request.branch)}`,
+        request.since === null
+          ? null
+          : `since=${encodeURIComponent(request.since)}`,
+      ].filter((filter) => filter !== null)
+      const perPage = 100
+      let count = 0
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const query = [...filters, `per_page=${perPage}`, `page=${page}`]
+        const response = await gitLabRestGet(
+          http,
+          draft,
+          `/projects/${projectPath}/repository/commits?${query.join("&")}`,
+          signal,
+        )
+        if (response.status === 404) return null
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list commits (${response.status}): ${gitLabDataMessage(response.data)}`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        count += response.data.length
+        if (response.data.length < perPage) break
+      }
+      return count
+    },
   }
 }
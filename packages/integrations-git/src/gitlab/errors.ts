@@ -55,6 +55,10 @@ export function isNotFoundError(error: unknown): boolean {
   return /404|not found/i.test(description)
 }
 
+export function isRateLimitError(error: unknown): boolean {
+  return gitLabErrorStatus(error) === 429
+}
+
 export function isNoChangesMessage(message: string): boolean {
   return /already exists|no commits|no changes|branch.*exists/i.test(message)
 }
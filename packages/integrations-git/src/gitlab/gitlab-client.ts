@@ -7,6 +7,7 @@ import { createGitLabIdentity } from "./identity.js"
 import { createGitLabRepositories } from "./repositories.js"
 import { createGitLabTeams } from "./teams.js"
 import { createGitLabTemplateChanges } from "./template-changes.js"
+import { createGitLabWebhooks } from "./webhooks.js"
 
 export function createGitLabClient(http: HttpPort): GitProviderClient {
   return guardGitProviderClient({
@@ -16,5 +17,6 @@ export function createGitLabClient(http: HttpPort): GitProviderClient {
     ...createGitLabTemplateChanges(http),
     ...createGitLabBranchReview(http),
     ...createGitLabDiscovery(http),
+    ...createGitLabWebhooks(http),
   })
 }
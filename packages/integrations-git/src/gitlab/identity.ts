@@ -1,5 +1,11 @@
+import { mapBounded } from "@repo-edu/concurrency"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  GitUsernameStatus,
+} from "@repo-edu/integrations-git-contract"
+import { usernameCheckConcurrency } from "../concurrency.js"
+import { isRateLimitError } from "./errors.js"
 import { createGitLabApi } from "./transport.js"
 import { isActiveExactMatch } from "./users.js"
 
@@ -24,20 +30,25 @@ export function createGitLabIdentity(http: HttpPort): IdentityCapability {
     },
     async verifyGitUsernames(draft, usernames, signal) {
       const api = createGitLabApi(http, draft, signal)
-      const results = []
-      for (const username of usernames) {
-        if (signal?.aborted) break
-        try {
-          const users = await api.Users.all({ username })
-          results.push({
-            username,
-            exists: users.some((user) => isActiveExactMatch(user, username)),
-          })
-        } catch {
-          results.push({ username, exists: false })
-        }
-      }
-      return results
+      const results = await mapBounded(
+        usernames,
+        usernameCheckConcurrency,
+        async (username): Promise<GitUsernameStatus | null> => {
+          if (signal?.aborted) return null
+          try {
+            const users = await api.Users.all({ username })
+            return {
+              username,
+              exists: users.some((user) => isActiveExactMatch(user, username)),
+            }
+          } catch (error) {
+            return isRateLimitError(error)
+              ? { username, exists: false, rateLimited: true }
+              : { username, exists: false }
+          }
+        },
+      )
+      return results.filter((result) => result !== null)
     },
   }
 }
@@ -1 +1,11 @@
+export {
+  type CreateGitLabDeployTokenRequest,
+  createGitLabDeployTokensClient,
+  type GitLabDeployToken,
+  type GitLabDeployTokensClient,
+  type GitLabDeployTokenScope,
+  gitLabDeployTokenScopes,
+  type GitLabDeployTokensRequest,
+  type RevokeGitLabDeployTokenRequest,
+} from "./deploy-tokens.js"
 export { createGitLabClient } from "./gitlab-client.js"
@@ -1,10 +1,24 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitConnectionDraft,
+  GitProviderClient,
+  RepositoryArchiveRequest,
+} from "@repo-edu/integrations-git-contract"
+import {
+  repositoryCreateConcurrency,
+  retryOnRateLimit,
+} from "../concurrency.js"
+import {
+  createRepositoriesBounded,
+  type RepositoryCreateOutcome,
+} from "../repository-create.js"
 import { withGitLabToken } from "./auth.js"
 import {
+  gitLabDataMessage,
   gitLabErrorMessage,
   isAlreadyExistsError,
   isNotFoundError,
+  isRateLimitError,
 } from "./errors.js"
 import { resolveGroupId } from "./namespace.js"
 import {
@@ -12,13 +26,43 @@ import {
   extractProjectCloneUrl,
   extractProjectUrls,
 } from "./repository-api.js"
-import { createGitLabApi } from "./transport.js"
+import { createGitLabApi, gitLabRestPost } from "./transport.js"
 
 type RepositoriesCapability = Pick<
   GitProviderClient,
-  "createRepositories" | "resolveRepositoryCloneUrls"
+  | "createRepositories"
+  | "resolveRepositoryCloneUrls"
+  | "archiveRepository"
+  | "unarchiveRepository"
+  | "forkRepository"
+  | "syncFork"
 >
 
+// `POST /projects/:id/archive` and `/unarchive` are idempotent, and the
+// URL-encoded project path is accepted in place of the numeric ID.
+async function setGitLabProjectArchived(
+  http: HttpPort,
+  draft: GitConnectionDraft,
+  request: RepositoryArchiveRequest,
+  archived: boolean,
+  signal?: AbortSignal,
+): Promise<void> {
+  const projectPath = `${request.owner}/${request.repositoryName}`
+  const action = archived ? "archive" : "unarchive"
+  const response = await gitLabRestPost(
+    http,
+    draft,
+    `/projects/${encodeURIComponent(projectPath)}/${action}`,
+    {},
+    signal,
+  )
+  if (response.status < 200 || response.status >= 300) {
+    throw new Error(
+      `Failed to ${action} project '${projectPath}' (${response.status}): ${gitLabDataMessage(response.data)}`,
+    )
+  }
+}
+
 export function createGitLabRepositories(
   http: HttpPort,
 ): RepositoriesCapability {
@@ -41,68 +85,89 @@ export function createGitLabRepositories(
         }
       }
 
-      const created = []
-      const alreadyExisted = []
-      const failed = []
-      for (const repositoryName of request.repositoryNames) {
-        if (signal?.aborted) break
-        try {
-          const urls = await createProject(
-            api,
-            namespaceId,
-            repositoryName,
-            request.visibility,
-            request.autoInit,
-          )
-          if (urls === null) {
-            failed.push({
-              repositoryName,
-              reason: "Provider returned incomplete repository URLs.",
-            })
-          } else {
-            created.push({
-              repositoryName,
-              repositoryUrl: urls.repositoryUrl,
-              cloneUrl: withGitLabToken(urls.cloneUrl, draft.token),
-            })
+      return createRepositoriesBounded(
+        request.repositoryNames,
+        request.concurrency ?? repositoryCreateConcurrency.gitlab,
+        async (repositoryName): Promise<RepositoryCreateOutcome> => {
+          try {
+            const urls = await retryOnRateLimit(
+              () =>
+                createProject(
+                  api,
+                  namespaceId,
+                  repositoryName,
+                  request.visibility,
+                  request.autoInit,
+                ),
+              isRateLimitError,
+              { signal },
+            )
+            if (urls === null) {
+              return {
+                kind: "failed",
+                failure: {
+                  repositoryName,
+                  reason: "Provider returned incomplete repository URLs.",
+                },
+              }
+            }
+            return {
+              kind: "created",
+              repository: {
+                repositoryName,
+                repositoryUrl: urls.repositoryUrl,
+                cloneUrl: withGitLabToken(urls.cloneUrl, draft.token),
+              },
+            }
+          } catch (error) {
+            if (!isAlreadyExistsError(error)) {
+              return {
+                kind: "failed",
+                failure: { repositoryName, reason: gitLabErrorMessage(error) },
+              }
+            }
           }
-        } catch (error) {
-          if (isAlreadyExistsError(error)) {
-            try {
-              const project = await api.Projects.show(
-                `${request.organization}/${repositoryName}`,
-              )
-              const urls = extractProjectUrls(project)
-              if (urls === null) {
-                failed.push({
+          try {
+            const project = await api.Projects.show(
+              `${request.organization}/${repositoryName}`,
+            )
+            const urls = extractProjectUrls(project)
+            if (urls === null) {
+              return {
+                kind: "failed",
+                failure: {
                   repositoryName,
                   reason: "Repository exists but URLs could not be resolved.",
-                })
-              } else {
-                alreadyExisted.push({
-                  repositoryName,
-                  repositoryUrl: urls.repositoryUrl,
-                  cloneUrl: withGitLabToken(urls.cloneUrl, draft.token),
-                })
+                },
               }
-            } catch (lookupError) {
-              failed.push({
+            }
+            return {
+              kind: "existing",
+              repository: {
+                repositoryName,
+                repositoryUrl: urls.repositoryUrl,
+                cloneUrl: withGitLabToken(urls.cloneUrl, draft.token),
+              },
+            }
+          } catch (lookupError) {
+            return {
+              kind: "failed",
+              failure: {
                 repositoryName,
                 reason: `Repository exists but lookup failed: ${gitLabErrorMessage(lookupError)}`,
-              })
+              },
             }
-            continue
           }
-          failed.push({ repositoryName, reason: gitLabErrorMessage(error) })
-        }
-      }
-      return { created, alreadyExisted, failed }
+        },
+        signal,
+      )
     },
     async resolveRepositoryCloneUrls(draft, request, signal) {
       if (!request.organization) {
         return { resolved: [], missing: [...request.repositoryNames] }
       }
       const api = createGitLabApi(http, draft, signal)
+      const scheme = request.scheme ?? "https"
       const resolved = []
       const missing = []
       for (const repositoryName of request.repositoryNames) {
@@ -111,14 +176,17 @@ export function createGitLabRepositories(
           const project = await api.Projects.show(
             `${request.organization}/${repositoryName}`,
           )
-          const cloneUrl = extractProjectCloneUrl(project)
+          const cloneUrl = extractProjectCloneUrl(project, scheme)
           if (cloneUrl === null) {
             missing.push(repositoryName)
             continue
           }
           resolved.push({
             repositoryName,
-            cloneUrl: withGitLabToken(cloneUrl, draft.token),
+            cloneUrl:
+              scheme === "ssh"
+                ? cloneUrl
+                : withGitLabToken(cloneUrl, draft.token),
           })
         } catch (error) {
           if (!isNotFoundError(error)) throw error
@@ -127,5 +195,17 @@ export function createGitLabRepositories(
       }
       return { resolved, missing }
     },
+    async archiveRepository(draft, request, signal) {
+      await setGitLabProjectArchived(http, draft, request, true, signal)
+    },
+    async unarchiveRepository(draft, request, signal) {
+      await setGitLabProjectArchived(http, draft, request, false, signal)
+    },
+    async forkRepository() {
+      throw new Error("Forking repositories is only supported on GitHub.")
+    },
+    async syncFork() {
+      throw new Error("Syncing forks is only supported on GitHub.")
+    },
   }
 }
@@ -1,6 +1,7 @@
 import type { Gitlab } from "@gitbeaker/rest"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
 import type {
+  GitCloneUrlScheme,
   GitConnectionDraft,
   PatchFile,
 } from "@repo-edu/integrations-git-contract"
@@ -12,9 +13,17 @@ export type GitLabProjectUrls = {
   cloneUrl: string
 }
 
-export function extractProjectCloneUrl(project: unknown): string | null {
+export function extractProjectCloneUrl(
+  project: unknown,
+  scheme: GitCloneUrlScheme = "https",
+): string | null {
   if (typeof project !== "object" || project === null) return null
-  const cloneUrl = (project as { http_url_to_repo?: unknown }).http_url_to_repo
+  const record = project as {
+    http_url_to_repo?: unknown
+    ssh_url_to_repo?: unknown
+  }
+  const cloneUrl =
+    scheme === "ssh" ? record.ssh_url_to_repo : record.http_url_to_repo
   return typeof cloneUrl === "string" ? cloneUrl : null
 }
 
@@ -1,8 +1,17 @@
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import type {
+  GitProviderClient,
+  TeamPermission,
+} from "@repo-edu/integrations-git-contract"
 import { resolveGroupId } from "./namespace.js"
 import { resolveProjectId } from "./repository-api.js"
-import { createGitLabApi, gitLabRestPost } from "./transport.js"
+import {
+  createGitLabApi,
+  gitLabRestDelete,
+  gitLabRestGet,
+  gitLabRestPost,
+  gitLabRestPut,
+} from "./transport.js"
 import { resolveGitLabUserId } from "./users.js"
 
 function toTeamPathSlug(name: string): string {
@@ -14,9 +23,22 @@ function toTeamPathSlug(name: string): string {
   return slug.startsWith("team-") ? slug : `team-${slug}`
 }
 
+const maintainerAccessLevel = 40
+
+// Reporter, Developer and Maintainer access levels.
+function toAccessLevel(permission: TeamPermission): number {
+  if (permission === "admin") return maintainerAccessLevel
+  if (permission === "pull") return 20
+  return 30
+}
+
 type TeamsCapability = Pick<
   GitProviderClient,
-  "createTeam" | "assignRepositoriesToTeam"
+  | "createTeam"
+  | "assignRepositoriesToTeam"
+  | "listTeamMembers"
+  | "removeTeamMembers"
+  | "addCollaborator"
 >
 
 export function createGitLabTeams(http: HttpPort): TeamsCapability {
@@ -66,12 +88,7 @@ export function createGitLabTeams(http: HttpPort): TeamsCapability {
 
       const membersAdded: string[] = []
       const membersNotFound: string[] = []
-      const accessLevel =
-        request.permission === "admin"
-          ? 40
-          : request.permission === "pull"
-            ? 20
-            : 30
+      const accessLevel = toAccessLevel(request.permission)
       for (const username of request.memberUsernames) {
         if (signal?.aborted) break
         const userId = await resolveGitLabUserId(api, username)
@@ -106,12 +123,7 @@ export function createGitLabTeams(http: HttpPort): TeamsCapability {
       if (teamId === null) {
         throw new Error(`GitLab team '${teamPath}' not found.`)
       }
-      const groupAccess =
-        request.permission === "admin"
-          ? 40
-          : request.permission === "pull"
-            ? 20
-            : 30
+      const groupAccess = toAccessLevel(request.permission)
       for (const repositoryName of request.repositoryNames) {
         if (signal?.aborted) break
         const projectPath = `${request.organization}/${repositoryName}`
@@ -137,5 +149,108 @@ export function createGitLabTeams(http: HttpPort): TeamsCapability {
         )
       }
     },
+    async listTeamMembers(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const teamPath = `${request.organization}/${toTeamPathSlug(request.teamName)}`
+      const teamId = await resolveGroupId(api, teamPath)
+      if (teamId === null) return null
+      const usernames: string[] = []
+      const perPage = 100
+      for (let page = 1; ; page += 1) {
+        if (signal?.aborted) break
+        const response = await gitLabRestGet(
+          http,
+          draft,
+          `/groups/${teamId}/members?per_page=${perPage}&page=${page}`,
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          throw new Error(
+            `Failed to list members of team '${request.teamName}' (${response.status}).`,
+          )
+        }
+        if (!Array.isArray(response.data)) break
+        for (const entry of response.data) {
+          const member = entry as {
+            username?: unknown
+            access_level?: unknown
+          } | null
+          // Maintainers and owners (such as the subgroup creator) are staff.
+          if (
+            typeof member?.access_level === "number" &&
+            member.access_level >= maintainerAccessLevel
+          ) {
+            continue
+          }
+          if (typeof member?.username === "string") {
+            usernames.push(member.username)
+          }
+        }
+        if (response.data.length < perPage) break
+      }
+      return usernames
+    },
+    async removeTeamMembers(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const teamPath = `${request.organization}/${toTeamPathSlug(request.teamName)}`
+      const teamId = await resolveGroupId(api, teamPath)
+      if (teamId === null) {
+        throw new Error(`GitLab team '${teamPath}' not found.`)
+      }
+      for (const username of request.memberUsernames) {
+        if (signal?.aborted) break
+        const userId = await resolveGitLabUserId(api, username)
+        if (userId === null) continue
+        const response = await gitLabRestDelete(
+          http,
+          draft,
+          `/groups/${teamId}/members/${userId}`,
+          signal,
+        )
+        if (
+          (response.status >= 200 && response.status < 300) ||
+          response.status === 404
+        ) {
+          continue
+        }
+        throw new Error(
+          `Failed to remove '${username}' from team '${request.teamName}' (${response.status}).`,
+        )
+      }
+    },
+    async addCollaborator(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const projectPath = `${request.owner}/${request.repositoryName}`
+      const projectId = await resolveProjectId(api, projectPath)
+      if (projectId === null) {
+        throw new Error(`GitLab project '${projectPath}' not found.`)
+      }
+      const userId = await resolveGitLabUserId(api, request.username)
+      if (userId === null) {
+        throw new Error(`GitLab user '${request.username}' not found.`)
+      }
+      const accessLevel = toAccessLevel(request.permission)
+      let response = await gitLabRestPost(
+        http,
+        draft,
+        `/projects/${projectId}/members`,
+        { userId, accessLevel },
+        signal,
+      )
+      if (response.status === 409) {
+        response = await gitLabRestPut(
+          http,
+          draft,
+          `/projects/${projectId}/members/${userId}`,
+          { accessLevel },
+          signal,
+        )
+      }
+      if (response.status < 200 || response.status >= 300) {
+        throw new Error(
+          `Failed to add '${request.username}' to '${projectPath}' (${response.status}).`,
+        )
+      }
+    },
   }
 }
@@ -378,6 +378,16 @@ export async function gitLabRestPost(
   return gitLabRestRequest(http, draft, "POST", path, body, signal)
 }
 
+export async function gitLabRestPut(
+  http: HttpPort,
+  draft: GitConnectionDraft,
+  path: string,
+  body: Record<string, unknown>,
+  signal?: AbortSignal,
+): Promise<{ status: number; data: unknown }> {
+  return gitLabRestRequest(http, draft, "PUT", path, body, signal)
+}
+
 export async function gitLabRestGet(
   http: HttpPort,
   draft: GitConnectionDraft,
@@ -386,3 +396,12 @@ export async function gitLabRestGet(
 ): Promise<{ status: number; data: unknown }> {
   return gitLabRestRequest(http, draft, "GET", path, undefined, signal)
 }
+
+export async function gitLabRestDelete(
+  http: HttpPort,
+  draft: GitConnectionDraft,
+  path: string,
+  signal?: AbortSignal,
+): Promise<{ status: number; data: unknown }> {
+  return gitLabRestRequest(http, draft, "DELETE", path, undefined, signal)
+}
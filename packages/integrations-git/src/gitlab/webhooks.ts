@@ -0,0 +1,79 @@
+import type { HttpPort } from "@repo-edu/host-runtime-contract"
+import type { GitProviderClient } from "@repo-edu/integrations-git-contract"
+import { gitLabDataMessage } from "./errors.js"
+import { resolveProjectId } from "./repository-api.js"
+import {
+  createGitLabApi,
+  gitLabRestDelete,
+  gitLabRestPost,
+} from "./transport.js"
+
+type WebhooksCapability = Pick<
+  GitProviderClient,
+  "createWebhook" | "deleteWebhook"
+>
+
+export function createGitLabWebhooks(http: HttpPort): WebhooksCapability {
+  return {
+    async createWebhook(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const projectPath = `${request.owner}/${request.repositoryName}`
+      const projectId = await resolveProjectId(api, projectPath)
+      if (projectId === null) {
+        throw new Error(`GitLab project '${projectPath}' was not found.`)
+      }
+      // GitLab enables push events unless told otherwise, so every flag is
+      // sent explicitly.
+      const events = new Set(request.events)
+      const response = await gitLabRestPost(
+        http,
+        draft,
+        `/projects/${projectId}/hooks`,
+        {
+          url: request.url,
+          pushEvents: events.has("push"),
+          mergeRequestsEvents: events.has("pull_request"),
+          issuesEvents: events.has("issue"),
+        },
+        signal,
+      )
+      const id = (response.data as { id?: unknown } | null)?.id
+      if (
+        response.status < 200 ||
+        response.status >= 300 ||
+        typeof id !== "number"
+      ) {
+        throw new Error(
+          `Failed to create webhook on '${projectPath}' (${response.status}): ${gitLabDataMessage(response.data)}`,
+        )
+      }
+      return {
+        id: String(id),
+        url: request.url,
+        events: [...request.events],
+      }
+    },
+    async deleteWebhook(draft, request, signal) {
+      const api = createGitLabApi(http, draft, signal)
+      const projectId = await resolveProjectId(
+        api,
+        `${request.owner}/${request.repositoryName}`,
+      )
+      if (projectId === null) return
+      const response = await gitLabRestDelete(
+        http,
+        draft,
+        `/projects/${projectId}/hooks/${encodeURIComponent(request.webhookId)}`,
+        signal,
+      )
+      if (
+        (response.status < 200 || response.status >= 300) &&
+        response.status !== 404
+      ) {
+        throw new Error(
+          `Failed to delete webhook '${request.webhookId}' (${response.status}): ${gitLabDataMessage(response.data)}`,
+        )
+      }
+    },
+  }
+}
@@ -11,7 +11,17 @@ export const workspaceDependencies = [contractPackageId] as const
 
 export { createGiteaClient } from "./gitea/index.js"
 export { createGitHubClient } from "./github/index.js"
-export { createGitLabClient } from "./gitlab/index.js"
+export {
+  createGitLabClient,
+  type CreateGitLabDeployTokenRequest,
+  createGitLabDeployTokensClient,
+  type GitLabDeployToken,
+  type GitLabDeployTokensClient,
+  type GitLabDeployTokenScope,
+  gitLabDeployTokenScopes,
+  type GitLabDeployTokensRequest,
+  type RevokeGitLabDeployTokenRequest,
+} from "./gitlab/index.js"
 
 export function createGitProviderClient(
   provider: GitProviderKind,
@@ -65,6 +75,27 @@ export function createGitProviderDispatch(http: HttpPort): GitProviderClient {
         signal,
       )
     },
+    listTeamMembers(draft, request, signal) {
+      return resolveClient(draft.provider).listTeamMembers(
+        draft,
+        request,
+        signal,
+      )
+    },
+    removeTeamMembers(draft, request, signal) {
+      return resolveClient(draft.provider).removeTeamMembers(
+        draft,
+        request,
+        signal,
+      )
+    },
+    addCollaborator(draft, request, signal) {
+      return resolveClient(draft.provider).addCollaborator(
+        draft,
+        request,
+        signal,
+      )
+    },
     getRepositoryDefaultBranchHead(draft, request, signal) {
       return resolveClient(draft.provider).getRepositoryDefaultBranchHead(
         draft,
@@ -89,6 +120,13 @@ export function createGitProviderDispatch(http: HttpPort): GitProviderClient {
         signal,
       )
     },
+    listPullRequests(draft, request, signal) {
+      return resolveClient(draft.provider).listPullRequests(
+        draft,
+        request,
+        signal,
+      )
+    },
     resolveRepositoryCloneUrls(draft, request, signal) {
       return resolveClient(draft.provider).resolveRepositoryCloneUrls(
         draft,
@@ -103,5 +141,52 @@ export function createGitProviderDispatch(http: HttpPort): GitProviderClient {
         signal,
       )
     },
+    getCommitCount(draft, request, signal) {
+      return resolveClient(draft.provider).getCommitCount(
+        draft,
+        request,
+        signal,
+      )
+    },
+    archiveRepository(draft, request, signal) {
+      return resolveClient(draft.provider).archiveRepository(
+        draft,
+        request,
+        signal,
+      )
+    },
+    unarchiveRepository(draft, request, signal) {
+      return resolveClient(draft.provider).unarchiveRepository(
+        draft,
+        request,
+        signal,
+      )
+    },
+    forkRepository(draft, request, signal) {
+      return resolveClient(draft.provider).forkRepository(
+        draft,
+        request,
+        signal,
+      )
+    },
+    syncFork(draft, request, signal) {
+      return resolveClient(draft.provider).syncFork(draft, request, signal)
+    },
+    protectBranch(draft, request, signal) {
+      return resolveClient(draft.provider).protectBranch(draft, request, signal)
+    },
+    unprotectBranch(draft, request, signal) {
+      return resolveClient(draft.provider).unprotectBranch(
+        draft,
+        request,
+        signal,
+      )
+    },
+    createWebhook(draft, request, signal) {
+      return resolveClient(draft.provider).createWebhook(draft, request, signal)
+    },
+    deleteWebhook(draft, request, signal) {
+      return resolveClient(draft.provider).deleteWebhook(draft, request, signal)
+    },
   }
 }
@@ -6,7 +6,7 @@ function throwIfCallerAborted(signal?: AbortSignal): void {
   }
 }
 
-async function invoke<T>(
+export async function invoke<T>(
   signal: AbortSignal | undefined,
   operation: () => Promise<T>,
 ): Promise<T> {
@@ -37,6 +37,12 @@ export function guardGitProviderClient(
       invoke(signal, () =>
         client.assignRepositoriesToTeam(draft, request, signal),
       ),
+    listTeamMembers: (draft, request, signal) =>
+      invoke(signal, () => client.listTeamMembers(draft, request, signal)),
+    removeTeamMembers: (draft, request, signal) =>
+      invoke(signal, () => client.removeTeamMembers(draft, request, signal)),
+    addCollaborator: (draft, request, signal) =>
+      invoke(signal, () => client.addCollaborator(draft, request, signal)),
     getRepositoryDefaultBranchHead: (draft, request, signal) =>
       invoke(signal, () =>
         client.getRepositoryDefaultBranchHead(draft, request, signal),
@@ -47,11 +53,31 @@ export function guardGitProviderClient(
       invoke(signal, () => client.createBranch(draft, request, signal)),
     createPullRequest: (draft, request, signal) =>
       invoke(signal, () => client.createPullRequest(draft, request, signal)),
+    listPullRequests: (draft, request, signal) =>
+      invoke(signal, () => client.listPullRequests(draft, request, signal)),
     resolveRepositoryCloneUrls: (draft, request, signal) =>
       invoke(signal, () =>
         client.resolveRepositoryCloneUrls(draft, request, signal),
       ),
     listRepositories: (draft, request, signal) =>
       invoke(signal, () => client.listRepositories(draft, request, signal)),
+    getCommitCount: (draft, request, signal) =>
+      invoke(signal, () => client.getCommitCount(draft, request, signal)),
+    archiveRepository: (draft, request, signal) =>
+      invoke(signal, () => client.archiveRepository(draft, request, signal)),
+    unarchiveRepository: (draft, request, signal) =>
+      invoke(signal, () => client.unarchiveRepository(draft, request, signal)),
+    forkRepository: (draft, request, signal) =>
+      invoke(signal, () => client.forkRepository(draft, request, signal)),
+    syncFork: (draft, request, signal) =>
+      invoke(signal, () => client.syncFork(draft, request, signal)),
+    protectBranch: (draft, request, signal) =>
+      invoke(signal, () => client.protectBranch(draft, request, signal)),
+    unprotectBranch: (draft, request, signal) =>
+      invoke(signal, () => client.unprotectBranch(draft, request, signal)),
+    createWebhook: (draft, request, signal) =>
+      invoke(signal, () => client.createWebhook(draft, request, signal)),
+    deleteWebhook: (draft, request, signal) =>
+      invoke(signal, () => client.deleteWebhook(draft, request, signal)),
   }
 }
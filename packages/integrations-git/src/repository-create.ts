@@ -0,0 +1,51 @@
+import { mapBounded } from "@repo-edu/concurrency"
+import type {
+  CreatedRepository,
+  CreateRepositoriesResult,
+  FailedRepositoryCreate,
+} from "@repo-edu/integrations-git-contract"
+
+export type RepositoryCreateOutcome =
+  | { kind: "created"; repository: CreatedRepository }
+  | { kind: "existing"; repository: CreatedRepository }
+  | { kind: "failed"; failure: FailedRepositoryCreate }
+
+/**
+ * Creates repositories with at most `maxConcurrency` requests in flight and
+ * collects the outcomes in request order. Names not yet started when the
+ * signal aborts are left out of the result.
+ */
+export async function createRepositoriesBounded(
+  repositoryNames: readonly string[],
+  maxConcurrency: number,
+  createOne: (repositoryName: string) => Promise<RepositoryCreateOutcome>,
+  signal?: AbortSignal,
+): Promise<CreateRepositoriesResult> {
+  const outcomes = await mapBounded(
+    repositoryNames,
+    Math.max(1, maxConcurrency),
+    async (repositoryName) =>
+      signal?.aborted ? null : createOne(repositoryName),
+  )
+
+  const result: CreateRepositoriesResult = {
+    created: [],
+    alreadyExisted: [],
+    failed: [],
+  }
+  for (const outcome of outcomes) {
+    if (outcome === null) continue
+    switch (outcome.kind) {
+      case "created":
+        result.created.push(outcome.repository)
+        break
+      case "existing":
+        result.alreadyExisted.push(outcome.repository)
+        break
+      case "failed":
+        result.failed.push(outcome.failure)
+        break
+    }
+  }
+  return result
+}
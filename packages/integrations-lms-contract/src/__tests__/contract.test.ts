@@ -8,10 +8,12 @@ describe("integrations-lms-contract", () => {
     assert.equal(packageId, "@repo-edu/integrations-lms-contract")
   })
 
-  it("supportedLmsProviders contains canvas and moodle", () => {
+  it("supportedLmsProviders contains every LMS provider", () => {
     assert.ok(supportedLmsProviders.includes("canvas"))
     assert.ok(supportedLmsProviders.includes("moodle"))
-    assert.equal(supportedLmsProviders.length, 2)
+    assert.ok(supportedLmsProviders.includes("blackboard"))
+    assert.ok(supportedLmsProviders.includes("brightspace"))
+    assert.equal(supportedLmsProviders.length, 4)
   })
 
   it("LmsClient interface covers all expected operations", () => {
@@ -19,16 +21,26 @@ describe("integrations-lms-contract", () => {
       "verifyConnection",
       "listCourses",
       "fetchRoster",
+      "fetchRosterPage",
       "listGroupSets",
       "fetchGroupSet",
     ]
-    assert.equal(methodNames.length, 5)
+    assert.equal(methodNames.length, 6)
 
     // Verify the interface is structurally implementable
     const client: LmsClient = {
       verifyConnection: async () => ({ verified: true }),
       listCourses: async () => [],
       fetchRoster: async () => [],
+      fetchRosterPage: async (_draft, _courseId, request) => ({
+        items: [],
+        pagination: {
+          ...request,
+          hasNext: false,
+          totalCount: 0,
+          nextCursor: null,
+        },
+      }),
       listGroupSets: async () => [],
       fetchGroupSet: async () => ({
         groupSet: {
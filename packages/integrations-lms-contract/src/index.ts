@@ -3,10 +3,36 @@ import type { LmsProviderKind } from "@repo-edu/domain/types"
 
 export const packageId = "@repo-edu/integrations-lms-contract"
 
-export const supportedLmsProviders = ["canvas", "moodle"] as const
+export const supportedLmsProviders = [
+  "canvas",
+  "moodle",
+  "blackboard",
+  "brightspace",
+] as const
+
+/** Providers whose adapters implement `listGroupSets` and `fetchGroupSet`. */
+export const groupSetLmsProviders: readonly LmsProviderKind[] = [
+  "canvas",
+  "moodle",
+  "blackboard",
+]
 
 export type LmsConnectionDraft = ConnectionBase & {
   provider: LmsProviderKind
+  /** ISO-8601 expiry of `token`, when the issuer reported one. */
+  tokenExpiresAt?: string | null
+}
+
+/** Account that owns the verified token, as reported by the LMS. */
+export type LmsTokenUser = {
+  name: string | null
+  email: string | null
+}
+
+export type LmsConnectionVerification = {
+  verified: boolean
+  /** Present only for verified tokens. */
+  user?: LmsTokenUser
 }
 
 export type LmsCourseSummary = {
@@ -15,6 +41,16 @@ export type LmsCourseSummary = {
   code: string | null
 }
 
+export type LmsCourseState = "available" | "completed" | "deleted"
+
+/** Narrows `listCourses`; omitted fields do not filter. */
+export type LmsCourseFilter = {
+  termId?: string
+  state?: LmsCourseState
+  /** Role the token holder has in the course; Canvas defaults to `teacher`. */
+  enrollmentType?: string
+}
+
 export type LmsGroupSetSummary = {
   id: string
   name: string
@@ -50,14 +86,62 @@ export type LmsFetchedGroupSet = {
   groups: RemoteLmsGroup[]
 }
 
+export type LmsPageRequest = {
+  /** 1-based page number. */
+  page: number
+  perPage: number
+  /**
+   * `nextCursor` from the previous page. Providers with cursor pagination
+   * fetch the page it points at instead of `page`; others ignore it.
+   */
+  cursor?: string | null
+}
+
+export type LmsPagination = {
+  page: number
+  perPage: number
+  hasNext: boolean
+  /** Total item count when the provider reports one. */
+  totalCount: number | null
+  /**
+   * Opaque cursor for the next page when the provider paginates by cursor
+   * (Canvas `Link` headers); null on the last page or for page numbers only.
+   */
+  nextCursor: string | null
+}
+
+export type LmsPage<TItem> = {
+  items: TItem[]
+  pagination: LmsPagination
+}
+
+export type LmsAuthErrorKind = "unauthorized" | "forbidden" | "expired"
+
+/** `Error.name` that adapters give credential failures. */
+export const lmsAuthErrorName = "LmsAuthError"
+
+/**
+ * Credential failure reported by an LMS, recognized by `name` equal to
+ * `lmsAuthErrorName`. `unauthorized` (401) means the token is missing or
+ * invalid; `forbidden` (403) means it lacks permission for the resource;
+ * `expired` means its recorded expiry has passed and no request was sent.
+ * Adapters throw it as `LmsAuthError` from `@repo-edu/integrations-lms`.
+ */
+export type LmsAuthFailure = Error & {
+  kind: LmsAuthErrorKind
+  provider: LmsProviderKind
+}
+
 export type LmsClient = {
   verifyConnection(
     draft: LmsConnectionDraft,
     signal?: AbortSignal,
-  ): Promise<{ verified: boolean }>
+  ): Promise<LmsConnectionVerification>
+  /** Only Canvas supports `filter`; other providers reject a non-empty one. */
   listCourses(
     draft: LmsConnectionDraft,
     signal?: AbortSignal,
+    filter?: LmsCourseFilter,
   ): Promise<LmsCourseSummary[]>
   fetchRoster(
     draft: LmsConnectionDraft,
@@ -65,6 +149,12 @@ export type LmsClient = {
     signal?: AbortSignal,
     onProgress?: (message: string) => void,
   ): Promise<RemoteLmsMember[]>
+  fetchRosterPage(
+    draft: LmsConnectionDraft,
+    courseId: string,
+    request: LmsPageRequest,
+    signal?: AbortSignal,
+  ): Promise<LmsPage<RemoteLmsMember>>
   listGroupSets(
     draft: LmsConnectionDraft,
     courseId: string,
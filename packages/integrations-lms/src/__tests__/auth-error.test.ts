@@ -0,0 +1,22 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import { lmsAuthErrorName } from "@repo-edu/integrations-lms-contract"
+import {
+  isLmsAuthError,
+  LmsAuthError,
+  lmsAuthErrorKindForStatus,
+} from "../auth-error.js"
+
+describe("LmsAuthError", () => {
+  it("classifies 401 and 403 as distinct auth error kinds", () => {
+    assert.equal(lmsAuthErrorKindForStatus(401), "unauthorized")
+    assert.equal(lmsAuthErrorKindForStatus(403), "forbidden")
+    assert.equal(lmsAuthErrorKindForStatus(404), null)
+
+    const error = new LmsAuthError("forbidden", "canvas", "denied")
+    assert.ok(isLmsAuthError(error))
+    assert.equal(error.kind, "forbidden")
+    assert.equal(error.name, lmsAuthErrorName)
+    assert.equal(isLmsAuthError(new Error("denied")), false)
+  })
+})
@@ -0,0 +1,319 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { createBlackboardClient } from "../blackboard/index.js"
+
+const baseDraft: LmsConnectionDraft = {
+  provider: "blackboard",
+  baseUrl: "https://learn.example.edu",
+  token: "blackboard-token",
+}
+
+type MockRoute = {
+  urlPattern: string | RegExp
+  status: number
+  body: unknown
+}
+
+function createMockHttpPort(routes: MockRoute[]): HttpPort {
+  return {
+    async fetch(request: HttpRequest): Promise<HttpResponse> {
+      for (const route of routes) {
+        const urlMatch =
+          typeof route.urlPattern === "string"
+            ? request.url.includes(route.urlPattern)
+            : route.urlPattern.test(request.url)
+
+        if (urlMatch) {
+          return {
+            status: route.status,
+            statusText: route.status < 300 ? "OK" : "Error",
+            headers: { "content-type": "application/json" },
+            body: JSON.stringify(route.body),
+          }
+        }
+      }
+
+      return {
+        status: 404,
+        statusText: "Not Found",
+        headers: { "content-type": "application/json" },
+        body: JSON.stringify({ status: 404, message: "Not Found" }),
+      }
+    },
+  }
+}
+
+describe("createBlackboardClient", () => {
+  it("verifies the connection via users/me and sends bearer auth", async () => {
+    let captured: HttpRequest | undefined
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        captured = request
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify({
+            id: "_1_1",
+            userName: "teacher",
+            name: { given: "Grace", family: "Hopper" },
+            contact: { email: "grace@example.edu" },
+          }),
+        }
+      },
+    }
+
+    const client = createBlackboardClient(http)
+    const result = await client.verifyConnection(baseDraft)
+
+    assert.deepStrictEqual(result, {
+      verified: true,
+      user: { name: "Grace Hopper", email: "grace@example.edu" },
+    })
+    assert.equal(
+      captured?.url,
+      "https://learn.example.edu/learn/api/public/v1/users/me",
+    )
+    assert.equal(captured?.headers?.Authorization, "Bearer blackboard-token")
+  })
+
+  it("lists instructor courses across paginated responses", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: /offset=100/,
+        status: 200,
+        body: {
+          results: [
+            { courseId: "_3_1", course: { id: "_3_1", name: "Algorithms" } },
+          ],
+        },
+      },
+      {
+        urlPattern: "/learn/api/public/v1/users/me/courses?role=Instructor",
+        status: 200,
+        body: {
+          results: [
+            {
+              courseId: "_2_1",
+              course: { id: "_2_1", name: "Databases", courseId: "DB-101" },
+            },
+          ],
+          paging: {
+            nextPage:
+              "/learn/api/public/v1/users/me/courses?role=Instructor&expand=course&limit=100&offset=100",
+          },
+        },
+      },
+    ])
+
+    const client = createBlackboardClient(http)
+    const result = await client.listCourses(baseDraft)
+
+    assert.deepStrictEqual(result, [
+      { id: "_2_1", name: "Databases", code: "DB-101" },
+      { id: "_3_1", name: "Algorithms", code: null },
+    ])
+  })
+
+  it("fetches and normalizes a roster with students and staff by role", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "/learn/api/public/v1/courses/_2_1/users?expand=user",
+        status: 200,
+        body: {
+          results: [
+            {
+              userId: "_10_1",
+              courseRoleId: "Student",
+              availability: { available: "Yes" },
+              user: {
+                id: "_10_1",
+                userName: "ada",
+                studentId: "S-10",
+                name: { given: "Ada", family: "Lovelace" },
+                contact: { email: "ada@example.edu" },
+              },
+            },
+            {
+              userId: "_11_1",
+              courseRoleId: "TeachingAssistant",
+              availability: { available: "No" },
+              user: { id: "_11_1", userName: "grace" },
+            },
+          ],
+        },
+      },
+    ])
+
+    const client = createBlackboardClient(http)
+    const result = await client.fetchRoster(baseDraft, "_2_1")
+
+    assert.deepStrictEqual(result, [
+      {
+        id: "remote-_10_1",
+        lmsUserId: "_10_1",
+        name: "Ada Lovelace",
+        email: "ada@example.edu",
+        studentNumber: "S-10",
+        enrollmentType: "student",
+        enrollmentDisplay: "Student",
+        source: "blackboard",
+        status: "active",
+        lmsStatus: "active",
+      },
+      {
+        id: "remote-_11_1",
+        lmsUserId: "_11_1",
+        name: "grace",
+        email: null,
+        studentNumber: null,
+        enrollmentType: "ta",
+        enrollmentDisplay: "TeachingAssistant",
+        source: "blackboard",
+        status: "dropped",
+        lmsStatus: "dropped",
+      },
+    ])
+  })
+
+  it("lists group sets with group counts", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "/learn/api/public/v2/courses/_2_1/groups/sets?limit=100",
+        status: 200,
+        body: { results: [{ id: "_50_1", name: "Project Teams" }] },
+      },
+      {
+        urlPattern: "/learn/api/public/v2/courses/_2_1/groups?limit=100",
+        status: 200,
+        body: {
+          results: [
+            { id: "_60_1", name: "Team A", groupSetId: "_50_1" },
+            { id: "_61_1", name: "Team B", groupSetId: "_50_1" },
+            { id: "_62_1", name: "Loose Group" },
+          ],
+        },
+      },
+    ])
+
+    const client = createBlackboardClient(http)
+    const result = await client.listGroupSets(baseDraft, "_2_1")
+
+    assert.deepStrictEqual(result, [
+      { id: "_50_1", name: "Project Teams", groupCount: 2 },
+    ])
+  })
+
+  it("fetches a full group set with members", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "/learn/api/public/v2/courses/_2_1/groups/sets/_50_1",
+        status: 200,
+        body: { id: "_50_1", name: "Project Teams" },
+      },
+      {
+        urlPattern: "/learn/api/public/v2/courses/_2_1/groups/_60_1/users",
+        status: 200,
+        body: { results: [{ userId: "_10_1" }, { userId: "_12_1" }] },
+      },
+      {
+        urlPattern: "/learn/api/public/v2/courses/_2_1/groups?limit=100",
+        status: 200,
+        body: {
+          results: [
+            { id: "_60_1", name: "Team A", groupSetId: "_50_1" },
+            { id: "_62_1", name: "Other", groupSetId: "_51_1" },
+          ],
+        },
+      },
+    ])
+
+    const client = createBlackboardClient(http)
+    const result = await client.fetchGroupSet(baseDraft, "_2_1", "_50_1")
+
+    assert.deepStrictEqual(result, {
+      groupSet: { id: "_50_1", name: "Project Teams" },
+      groups: [
+        {
+          id: "_60_1",
+          name: "Team A",
+          memberLmsUserIds: ["_10_1", "_12_1"],
+        },
+      ],
+    })
+  })
+
+  it("throws on non-success list responses", async () => {
+    const client = createBlackboardClient(createMockHttpPort([]))
+
+    await assert.rejects(
+      () => client.listGroupSets(baseDraft, "_2_1"),
+      /Blackboard request failed with status 404/,
+    )
+  })
+
+  it("fetches a single roster page by offset and limit", async () => {
+    const requestedUrls: string[] = []
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        requestedUrls.push(request.url)
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify({
+            results: [
+              {
+                userId: "_11_1",
+                courseRoleId: "TeachingAssistant",
+                availability: { available: "Yes" },
+                user: { id: "_11_1", userName: "grace" },
+              },
+            ],
+            paging: {
+              nextPage: "/learn/api/public/v1/courses/_2_1/users?offset=75",
+            },
+          }),
+        }
+      },
+    }
+
+    const client = createBlackboardClient(http)
+    const result = await client.fetchRosterPage(baseDraft, "_2_1", {
+      page: 3,
+      perPage: 25,
+    })
+
+    assert.equal(
+      requestedUrls[0],
+      "https://learn.example.edu/learn/api/public/v1/courses/_2_1/users?expand=user&offset=50&limit=25",
+    )
+    assert.deepStrictEqual(
+      result.items.map((member) => [member.lmsUserId, member.enrollmentType]),
+      [["_11_1", "ta"]],
+    )
+    assert.deepStrictEqual(result.pagination, {
+      page: 3,
+      perPage: 25,
+      hasNext: true,
+      totalCount: null,
+      nextCursor: null,
+    })
+  })
+
+  it("rejects course filters", async () => {
+    const client = createBlackboardClient(createMockHttpPort([]))
+
+    await assert.rejects(
+      () =>
+        client.listCourses(baseDraft, undefined, { termId: "2026-spring" }),
+      /Blackboard does not support course filters/,
+    )
+  })
+})
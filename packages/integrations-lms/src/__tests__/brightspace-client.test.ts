@@ -0,0 +1,202 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { isLmsAuthError } from "../auth-error.js"
+import { createBrightspaceClient } from "../brightspace/index.js"
+
+const baseDraft: LmsConnectionDraft = {
+  provider: "brightspace",
+  baseUrl: "https://school.brightspace.com/d2l/",
+  token: "brightspace-token",
+}
+
+type MockRoute = {
+  urlPattern: string | RegExp
+  status: number
+  body: unknown
+}
+
+function createMockHttpPort(routes: MockRoute[]): {
+  http: HttpPort
+  requests: HttpRequest[]
+} {
+  const requests: HttpRequest[] = []
+  return {
+    requests,
+    http: {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        requests.push(request)
+        for (const route of routes) {
+          const urlMatch =
+            typeof route.urlPattern === "string"
+              ? request.url.includes(route.urlPattern)
+              : route.urlPattern.test(request.url)
+
+          if (urlMatch) {
+            return {
+              status: route.status,
+              statusText: route.status < 300 ? "OK" : "Error",
+              headers: { "content-type": "application/json" },
+              body: JSON.stringify(route.body),
+            }
+          }
+        }
+
+        return {
+          status: 404,
+          statusText: "Not Found",
+          headers: { "content-type": "application/json" },
+          body: "",
+        }
+      },
+    },
+  }
+}
+
+describe("createBrightspaceClient", () => {
+  it("verifies the connection via whoami and sends bearer auth", async () => {
+    const { http, requests } = createMockHttpPort([
+      {
+        urlPattern: "/d2l/api/lp/1.47/users/whoami",
+        status: 200,
+        body: { Identifier: "170", FirstName: "Grace", LastName: "Hopper" },
+      },
+    ])
+
+    const client = createBrightspaceClient(http)
+    const result = await client.verifyConnection(baseDraft)
+
+    assert.deepStrictEqual(result, {
+      verified: true,
+      user: { name: "Grace Hopper", email: null },
+    })
+    assert.equal(
+      requests[0]?.url,
+      "https://school.brightspace.com/d2l/api/lp/1.47/users/whoami",
+    )
+    assert.equal(
+      requests[0]?.headers?.Authorization,
+      "Bearer brightspace-token",
+    )
+  })
+
+  it("lists course enrollments across bookmarked pages", async () => {
+    const { http, requests } = createMockHttpPort([
+      {
+        urlPattern: /bookmark=6606/,
+        status: 200,
+        body: {
+          PagingInfo: { Bookmark: "6610", HasMoreItems: false },
+          Items: [{ OrgUnit: { Id: 6610, Name: "Algorithms", Code: "" } }],
+        },
+      },
+      {
+        urlPattern: "/d2l/api/lp/1.47/enrollments/myenrollments/",
+        status: 200,
+        body: {
+          PagingInfo: { Bookmark: "6606", HasMoreItems: true },
+          Items: [{ OrgUnit: { Id: 6606, Name: "Databases", Code: "DB-101" } }],
+        },
+      },
+    ])
+
+    const client = createBrightspaceClient(http)
+    const result = await client.listCourses(baseDraft)
+
+    assert.deepStrictEqual(result, [
+      { id: "6606", name: "Databases", code: "DB-101" },
+      { id: "6610", name: "Algorithms", code: null },
+    ])
+    assert.match(requests[0]?.url ?? "", /\?orgUnitTypeId=3$/)
+  })
+
+  it("fetches and normalizes the classlist by role", async () => {
+    const { http } = createMockHttpPort([
+      {
+        urlPattern: "/d2l/api/le/1.47/6606/classlist/",
+        status: 200,
+        body: [
+          {
+            Identifier: "210",
+            DisplayName: "Ada Lovelace",
+            Username: "ada",
+            OrgDefinedId: "S-10",
+            Email: "ada@example.edu",
+            ClasslistRoleDisplayName: "Student",
+          },
+          {
+            Identifier: "211",
+            DisplayName: "",
+            Username: "grace",
+            OrgDefinedId: "",
+            Email: null,
+            ClasslistRoleDisplayName: "Teaching Assistant",
+          },
+        ],
+      },
+    ])
+
+    const client = createBrightspaceClient(http)
+    const result = await client.fetchRoster(baseDraft, "6606")
+
+    assert.deepStrictEqual(result, [
+      {
+        id: "remote-210",
+        lmsUserId: "210",
+        name: "Ada Lovelace",
+        email: "ada@example.edu",
+        studentNumber: "S-10",
+        enrollmentType: "student",
+        enrollmentDisplay: "Student",
+        source: "brightspace",
+        status: "active",
+        lmsStatus: "active",
+      },
+      {
+        id: "remote-211",
+        lmsUserId: "211",
+        name: "grace",
+        email: null,
+        studentNumber: null,
+        enrollmentType: "ta",
+        enrollmentDisplay: "Teaching Assistant",
+        source: "brightspace",
+        status: "active",
+        lmsStatus: "active",
+      },
+    ])
+  })
+
+  it("reports rejected tokens as LmsAuthError", async () => {
+    const { http } = createMockHttpPort([
+      {
+        urlPattern: "/classlist/",
+        status: 403,
+        body: { Errors: [] },
+      },
+    ])
+
+    const client = createBrightspaceClient(http)
+
+    await assert.rejects(client.fetchRoster(baseDraft, "6606"), (error) => {
+      assert.ok(isLmsAuthError(error))
+      assert.equal(error.kind, "forbidden")
+      return true
+    })
+  })
+
+  it("rejects operations that are not implemented yet", async () => {
+    const { http } = createMockHttpPort([])
+    const client = createBrightspaceClient(http)
+
+    await assert.rejects(
+      client.listGroupSets(baseDraft, "6606"),
+      /Brightspace does not support group sets/,
+    )
+  })
+})
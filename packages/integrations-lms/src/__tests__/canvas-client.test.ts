@@ -6,6 +6,7 @@ import type {
   HttpResponse,
 } from "@repo-edu/host-runtime-contract"
 import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { isLmsAuthError } from "../auth-error.js"
 import { createCanvasClient } from "../canvas/index.js"
 
 const baseDraft: LmsConnectionDraft = {
@@ -67,7 +68,11 @@ describe("createCanvasClient", () => {
           status: 200,
           statusText: "OK",
           headers: { "content-type": "application/json" },
-          body: JSON.stringify({ id: 1, name: "Teacher" }),
+          body: JSON.stringify({
+            id: 1,
+            name: "Teacher",
+            primary_email: "teacher@example.edu",
+          }),
         }
       },
     }
@@ -75,7 +80,10 @@ describe("createCanvasClient", () => {
     const client = createCanvasClient(http)
     const result = await client.verifyConnection(baseDraft)
 
-    assert.deepStrictEqual(result, { verified: true })
+    assert.deepStrictEqual(result, {
+      verified: true,
+      user: { name: "Teacher", email: "teacher@example.edu" },
+    })
     assert.equal(capturedHeaders?.Authorization, "Bearer canvas-token")
     assert.equal(capturedHeaders?.["User-Agent"], "repo-edu")
   })
@@ -134,6 +142,112 @@ describe("createCanvasClient", () => {
     ])
   })
 
+  it("follows Link headers until no next link is sent", async () => {
+    const api = "https://canvas.example.com/api/v1"
+    const pageUrls = [
+      `${api}/courses?enrollment_type=teacher&per_page=100`,
+      `${api}/courses?page=bookmark:b2&per_page=100`,
+      `${api}/courses?page=bookmark:b3&per_page=100`,
+    ]
+    const firstLink = `<${api}/courses?page=first&per_page=100>; rel="first"`
+    const requestedUrls: string[] = []
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        requestedUrls.push(request.url)
+        const index = pageUrls.indexOf(request.url)
+        assert.notEqual(index, -1, `unexpected request ${request.url}`)
+        const nextUrl = pageUrls[index + 1]
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: {
+            link:
+              nextUrl === undefined
+                ? firstLink
+                : `<${nextUrl}>; rel="next", ${firstLink}`,
+          },
+          body: JSON.stringify([
+            { id: index + 1, name: `Course ${index + 1}` },
+          ]),
+        }
+      },
+    }
+
+    const client = createCanvasClient(http)
+    const result = await client.listCourses(baseDraft)
+
+    assert.deepStrictEqual(
+      result.map((course) => course.id),
+      ["1", "2", "3"],
+    )
+    assert.deepStrictEqual(requestedUrls, pageUrls)
+  })
+
+  it("passes course state and enrollment type filters as query parameters", async () => {
+    const requestedUrls: string[] = []
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        requestedUrls.push(request.url)
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify([]),
+        }
+      },
+    }
+    const client = createCanvasClient(http)
+
+    await client.listCourses(baseDraft, undefined, { state: "completed" })
+    await client.listCourses(baseDraft, undefined, { enrollmentType: "ta" })
+    await client.listCourses(baseDraft, undefined, {
+      state: "available",
+      enrollmentType: "designer",
+    })
+
+    assert.deepStrictEqual(requestedUrls, [
+      "https://canvas.example.com/api/v1/courses?enrollment_type=teacher&state[]=completed&per_page=100",
+      "https://canvas.example.com/api/v1/courses?enrollment_type=ta&per_page=100",
+      "https://canvas.example.com/api/v1/courses?enrollment_type=designer&state[]=available&per_page=100",
+    ])
+  })
+
+  it("filters courses by enrollment term", async () => {
+    const http = createMockHttpPort([
+      {
+        method: "GET",
+        urlPattern:
+          "/api/v1/courses?enrollment_type=teacher&state[]=available&per_page=100",
+        status: 200,
+        body: [
+          {
+            id: 1,
+            name: "Algorithms",
+            course_code: "CS101",
+            enrollment_term_id: 7,
+          },
+          {
+            id: 2,
+            name: "Databases",
+            course_code: "DB",
+            enrollment_term_id: 8,
+          },
+          { id: 3, name: "Sandbox", course_code: null },
+        ],
+      },
+    ])
+
+    const client = createCanvasClient(http)
+    const result = await client.listCourses(baseDraft, undefined, {
+      termId: "7",
+      state: "available",
+    })
+
+    assert.deepStrictEqual(result, [
+      { id: "1", name: "Algorithms", code: "CS101" },
+    ])
+  })
+
   it("fetches and normalizes a roster with students and staff by enrollment type", async () => {
     const http = createMockHttpPort([
       {
@@ -548,4 +662,140 @@ describe("createCanvasClient", () => {
 
     assert.equal(result.groupSet.name, "Project Groups")
   })
+
+  it("fetches group memberships concurrently up to the configured limit", async () => {
+    let inFlight = 0
+    let peakInFlight = 0
+    const groupIds = [301, 302, 303, 304, 305]
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        const json = (body: unknown): HttpResponse => ({
+          status: 200,
+          statusText: "OK",
+          headers: { "content-type": "application/json" },
+          body: JSON.stringify(body),
+        })
+
+        if (request.url.includes("/group_categories/set-1/groups")) {
+          return json(groupIds.map((id) => ({ id, name: `Group ${id}` })))
+        }
+        if (request.url.includes("/group_categories/set-1")) {
+          return json({ id: "set-1", name: "Teams" })
+        }
+
+        const match = request.url.match(/\/groups\/(\d+)\/memberships/)
+        assert.ok(match, `Unexpected request ${request.url}`)
+        inFlight += 1
+        peakInFlight = Math.max(peakInFlight, inFlight)
+        // Later groups resolve first so ordering relies on input position.
+        await new Promise((resolve) =>
+          setTimeout(resolve, 360 - Number(match[1])),
+        )
+        inFlight -= 1
+        return json([{ user_id: Number(match[1]) * 10 }])
+      },
+    }
+
+    const client = createCanvasClient(http, { membershipConcurrency: 3 })
+    const result = await client.fetchGroupSet(baseDraft, "course-1", "set-1")
+
+    assert.equal(peakInFlight, 3)
+    assert.deepStrictEqual(
+      result.groups.map((group) => group.memberLmsUserIds),
+      groupIds.map((id) => [String(id * 10)]),
+    )
+  })
+
+  it("distinguishes unauthorized tokens from forbidden resources", async () => {
+    const http = createMockHttpPort([
+      { urlPattern: "/courses?", status: 401, body: { errors: [] } },
+      { urlPattern: "/courses/course-1/users", status: 403, body: {} },
+    ])
+    const client = createCanvasClient(http)
+
+    await assert.rejects(
+      () => client.listCourses(baseDraft),
+      (error: unknown) =>
+        isLmsAuthError(error) &&
+        error.kind === "unauthorized" &&
+        error.provider === "canvas",
+    )
+    await assert.rejects(
+      () => client.fetchRoster(baseDraft, "course-1"),
+      (error: unknown) => isLmsAuthError(error) && error.kind === "forbidden",
+    )
+  })
+
+  it("fetches the page a cursor points at instead of the page number", async () => {
+    const api = "https://canvas.example.com/api/v1"
+    const cursor = `${api}/courses/course-1/users?include[]=enrollments&page=bookmark:b2`
+    const nextCursor = `${api}/courses/course-1/users?include[]=enrollments&page=bookmark:b3`
+    const requestedUrls: string[] = []
+    const http: HttpPort = {
+      async fetch(request: HttpRequest): Promise<HttpResponse> {
+        requestedUrls.push(request.url)
+        return {
+          status: 200,
+          statusText: "OK",
+          headers: { link: `<${nextCursor}>; rel="next"` },
+          body: JSON.stringify([{ id: 4, name: "Ada Lovelace" }]),
+        }
+      },
+    }
+    const client = createCanvasClient(http)
+
+    const page = await client.fetchRosterPage(baseDraft, "course-1", {
+      page: 2,
+      perPage: 1,
+      cursor,
+    })
+
+    assert.deepStrictEqual(requestedUrls, [cursor])
+    assert.deepStrictEqual(
+      page.items.map((member) => member.lmsUserId),
+      ["4"],
+    )
+    assert.equal(page.pagination.hasNext, true)
+    assert.equal(page.pagination.nextCursor, nextCursor)
+  })
+
+  it("rejects page cursors for another Canvas instance", async () => {
+    const client = createCanvasClient(createMockHttpPort([]))
+
+    await assert.rejects(
+      client.fetchRosterPage(baseDraft, "course-1", {
+        page: 2,
+        perPage: 1,
+        cursor: "https://attacker.example.com/api/v1/courses/course-1/users",
+      }),
+      /does not belong to this connection/,
+    )
+  })
+
+  it("resolves roster page enrollment types from included enrollments", async () => {
+    const http = createMockHttpPort([
+      {
+        method: "GET",
+        urlPattern: "/courses/course-1/users?include[]=enrollments&page=1",
+        status: 200,
+        body: [
+          {
+            id: 7,
+            name: "Grace Hopper",
+            enrollments: [{ type: "TaEnrollment", enrollment_state: "active" }],
+          },
+        ],
+      },
+    ])
+    const client = createCanvasClient(http)
+
+    const result = await client.fetchRosterPage(baseDraft, "course-1", {
+      page: 1,
+      perPage: 50,
+    })
+
+    assert.equal(result.items[0]?.lmsUserId, "7")
+    assert.equal(result.items[0]?.enrollmentType, "ta")
+    assert.equal(result.pagination.hasNext, false)
+  })
 })
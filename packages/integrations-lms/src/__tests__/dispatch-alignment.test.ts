@@ -2,12 +2,14 @@ import assert from "node:assert/strict"
 import { describe, it } from "node:test"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
 import type { LmsClient } from "@repo-edu/integrations-lms-contract"
+import { isLmsAuthError } from "../auth-error.js"
 import { createLmsProviderDispatch } from "../index.js"
 
 const expectedDispatchMethods: Record<keyof LmsClient, true> = {
   verifyConnection: true,
   listCourses: true,
   fetchRoster: true,
+  fetchRosterPage: true,
   listGroupSets: true,
   fetchGroupSet: true,
 }
@@ -34,4 +36,27 @@ describe("lms provider dispatch", () => {
       )
     }
   })
+  it("rejects expired tokens before sending a verification request", async () => {
+    let fetches = 0
+    const dispatch = createLmsProviderDispatch({
+      async fetch() {
+        fetches += 1
+        throw new Error("Unexpected request.")
+      },
+    })
+
+    await assert.rejects(
+      dispatch.verifyConnection({
+        provider: "canvas",
+        baseUrl: "https://canvas.example.com",
+        token: "canvas-token",
+        tokenExpiresAt: "2000-01-01T00:00:00.000Z",
+      }),
+      (error: unknown) =>
+        isLmsAuthError(error) &&
+        error.kind === "expired" &&
+        error.provider === "canvas",
+    )
+    assert.equal(fetches, 0)
+  })
 })
@@ -6,6 +6,7 @@ import type {
   HttpResponse,
 } from "@repo-edu/host-runtime-contract"
 import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { isLmsAuthError } from "../auth-error.js"
 import { createMoodleClient } from "../moodle/index.js"
 
 const baseDraft: LmsConnectionDraft = {
@@ -61,7 +62,10 @@ describe("createMoodleClient", () => {
           status: 200,
           statusText: "OK",
           headers: { "content-type": "application/json" },
-          body: JSON.stringify({ sitename: "Example Moodle" }),
+          body: JSON.stringify({
+            sitename: "Example Moodle",
+            fullname: "Ada Teacher",
+          }),
         }
       },
     }
@@ -69,7 +73,10 @@ describe("createMoodleClient", () => {
     const client = createMoodleClient(http)
     const result = await client.verifyConnection(baseDraft)
 
-    assert.deepStrictEqual(result, { verified: true })
+    assert.deepStrictEqual(result, {
+      verified: true,
+      user: { name: "Ada Teacher", email: null },
+    })
     assert.ok(capturedUrl.includes("wsfunction=core_webservice_get_site_info"))
     assert.ok(capturedUrl.includes("wstoken=moodle-token"))
     assert.equal(capturedHeaders?.["User-Agent"], "repo-edu")
@@ -203,58 +210,79 @@ describe("createMoodleClient", () => {
     assert.equal(progress.includes("Loaded 2 enrolled users from LMS."), true)
   })
 
-  it("lists group sets", async () => {
-    const http = createMockHttpPort([
+  it("lists group sets with group counts from core_group_get_groupings", async () => {
+    const requestedUrls: string[] = []
+    const routes = createMockHttpPort([
       {
         urlPattern: "wsfunction=core_group_get_course_groupings",
         status: 200,
-        body: [{ id: 30, name: "Lab Sections", groupcount: 2 }],
+        body: [
+          { id: 30, courseid: 7, name: "Lab Sections" },
+          { id: 31, courseid: 7, name: "Empty Set" },
+        ],
+      },
+      {
+        urlPattern: "wsfunction=core_group_get_groupings",
+        status: 200,
+        body: [
+          {
+            id: 30,
+            name: "Lab Sections",
+            groups: [{ id: 101 }, { id: 102 }],
+          },
+          { id: 31, name: "Empty Set", groups: [] },
+        ],
       },
     ])
+    const http: HttpPort = {
+      fetch(request) {
+        requestedUrls.push(request.url)
+        return routes.fetch(request)
+      },
+    }
 
     const client = createMoodleClient(http)
-    const result = await client.listGroupSets(baseDraft, "course-1")
+    const result = await client.listGroupSets(baseDraft, "7")
 
     assert.deepStrictEqual(result, [
       { id: "30", name: "Lab Sections", groupCount: 2 },
+      { id: "31", name: "Empty Set", groupCount: 0 },
     ])
+    const groupingsUrl = new URL(requestedUrls[1] ?? "")
+    assert.equal(groupingsUrl.searchParams.get("groupingids[0]"), "30")
+    assert.equal(groupingsUrl.searchParams.get("groupingids[1]"), "31")
+    assert.equal(groupingsUrl.searchParams.get("returngroups"), "1")
   })
 
-  it("fetches a full group set", async () => {
+  it("fetches a full group set from its grouping and group members", async () => {
     const http = createMockHttpPort([
       {
-        urlPattern: /wsfunction=core_group_get_course_groupings/,
+        urlPattern: "wsfunction=core_group_get_groupings",
         status: 200,
-        body: [{ id: 30, name: "Lab Sections", groupcount: 2 }],
+        body: [
+          {
+            id: 30,
+            courseid: 7,
+            name: "Lab Sections",
+            groups: [
+              { id: 101, name: "Section A" },
+              { id: 102, name: "Section B" },
+            ],
+          },
+        ],
       },
       {
-        urlPattern: /wsfunction=core_group_get_course_groups/,
+        urlPattern: "wsfunction=core_group_get_group_members",
         status: 200,
         body: [
-          {
-            id: 101,
-            name: "Section A",
-            groupingid: 30,
-            members: [{ userid: 11 }, { userid: 12 }],
-          },
-          {
-            id: 102,
-            name: "Section B",
-            groupingids: [30],
-            members: [{ userid: 13 }],
-          },
-          {
-            id: 103,
-            name: "Other Set Group",
-            groupingid: 31,
-            members: [{ userid: 14 }],
-          },
+          { groupid: 101, userids: [11, 12] },
+          { groupid: 102, userids: [13] },
         ],
       },
     ])
 
     const client = createMoodleClient(http)
-    const result = await client.fetchGroupSet(baseDraft, "course-1", "30")
+    const result = await client.fetchGroupSet(baseDraft, "7", "30")
 
     assert.deepStrictEqual(result.groups, [
       {
@@ -273,4 +301,133 @@ describe("createMoodleClient", () => {
       name: "Lab Sections",
     })
   })
+
+  it("rejects a grouping that belongs to another course", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "wsfunction=core_group_get_groupings",
+        status: 200,
+        body: [{ id: 30, courseid: 8, name: "Lab Sections", groups: [] }],
+      },
+    ])
+
+    const client = createMoodleClient(http)
+
+    await assert.rejects(
+      () => client.fetchGroupSet(baseDraft, "7", "30"),
+      /Moodle grouping 30 was not found in course 7/,
+    )
+  })
+
+  it("surfaces missing-permission exceptions from group calls", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "wsfunction=core_group_get_course_groupings",
+        status: 200,
+        body: {
+          exception: "required_capability_exception",
+          errorcode: "nopermissions",
+          message:
+            "Sorry, but you do not currently have permissions to do that.",
+        },
+      },
+    ])
+
+    const client = createMoodleClient(http)
+
+    await assert.rejects(
+      () => client.listGroupSets(baseDraft, "7"),
+      /core_group_get_course_groupings \(nopermissions\): Sorry/,
+    )
+  })
+
+  it("maps Moodle token and capability exceptions to auth errors", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "wsfunction=core_course_get_courses",
+        status: 200,
+        body: { exception: "moodle_exception", errorcode: "invalidtoken" },
+      },
+      {
+        urlPattern: "wsfunction=core_enrol_get_enrolled_users",
+        status: 200,
+        body: {
+          exception: "required_capability_exception",
+          errorcode: "nopermissions",
+        },
+      },
+    ])
+    const client = createMoodleClient(http)
+
+    await assert.rejects(
+      () => client.listCourses(baseDraft),
+      (error: unknown) =>
+        isLmsAuthError(error) &&
+        error.kind === "unauthorized" &&
+        error.provider === "moodle",
+    )
+    await assert.rejects(
+      () => client.fetchRoster(baseDraft, "7"),
+      (error: unknown) => isLmsAuthError(error) && error.kind === "forbidden",
+    )
+  })
+
+  it("slices the whole roster into pages", async () => {
+    const http = createMockHttpPort([
+      {
+        urlPattern: "wsfunction=core_enrol_get_enrolled_users",
+        status: 200,
+        body: [
+          {
+            id: 11,
+            fullname: "Ada Lovelace",
+            roles: [{ shortname: "student" }],
+          },
+          {
+            id: 12,
+            fullname: "Alan Turing",
+            roles: [{ shortname: "student" }],
+          },
+          {
+            id: 13,
+            fullname: "Grace Hopper",
+            roles: [{ shortname: "student" }],
+          },
+        ],
+      },
+    ])
+    const client = createMoodleClient(http)
+
+    const first = await client.fetchRosterPage(baseDraft, "course-1", {
+      page: 1,
+      perPage: 2,
+    })
+    const last = await client.fetchRosterPage(baseDraft, "course-1", {
+      page: 2,
+      perPage: 2,
+    })
+
+    assert.deepStrictEqual(
+      first.items.map((member) => member.lmsUserId),
+      ["11", "12"],
+    )
+    assert.deepStrictEqual(first.pagination, {
+      page: 1,
+      perPage: 2,
+      hasNext: true,
+      totalCount: 3,
+      nextCursor: null,
+    })
+    assert.deepStrictEqual(last.items.map((member) => member.lmsUserId), ["13"])
+    assert.equal(last.pagination.hasNext, false)
+  })
+
+  it("rejects course filters", async () => {
+    const client = createMoodleClient(createMockHttpPort([]))
+
+    await assert.rejects(
+      () => client.listCourses(baseDraft, undefined, { state: "completed" }),
+      /Moodle does not support course filters/,
+    )
+  })
 })
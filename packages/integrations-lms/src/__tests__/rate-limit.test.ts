@@ -0,0 +1,286 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type { HttpPort, HttpResponse } from "@repo-edu/host-runtime-contract"
+import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { createCanvasClient } from "../canvas/index.js"
+import { createMoodleClient } from "../moodle/index.js"
+import {
+  applyRetryJitter,
+  defaultLmsRetryConfig,
+  LmsRateLimitedError,
+  parseRetryAfterMs,
+  resolveRateLimitDelayMs,
+} from "../rate-limit.js"
+import { type LmsRequestLogEvent, redactRequestUrl } from "../request-log.js"
+
+const canvasDraft: LmsConnectionDraft = {
+  provider: "canvas",
+  baseUrl: "https://canvas.example.com",
+  token: "canvas-token",
+}
+
+const moodleDraft: LmsConnectionDraft = {
+  provider: "moodle",
+  baseUrl: "https://moodle.example.com",
+  token: "moodle-token",
+}
+
+function createSequenceHttpPort(responses: HttpResponse[]): {
+  http: HttpPort
+  calls: () => number
+} {
+  let index = 0
+  return {
+    http: {
+      async fetch(): Promise<HttpResponse> {
+        const response = responses[Math.min(index, responses.length - 1)]
+        index += 1
+        return response
+      },
+    },
+    calls: () => index,
+  }
+}
+
+function response(
+  status: number,
+  body: unknown,
+  headers: Record<string, string> = {},
+): HttpResponse {
+  return {
+    status,
+    statusText: status < 300 ? "OK" : "Error",
+    headers: { "content-type": "application/json", ...headers },
+    body: JSON.stringify(body),
+  }
+}
+
+function recordingSleep() {
+  const delays: number[] = []
+  return {
+    delays,
+    sleep: async (delayMs: number) => {
+      delays.push(delayMs)
+    },
+  }
+}
+
+describe("LMS rate-limit retry", () => {
+  it("waits for the Canvas Retry-After header before retrying", async () => {
+    const { http, calls } = createSequenceHttpPort([
+      response(429, { errors: [] }, { "retry-after": "2" }),
+      response(200, [{ id: 1, name: "Course", course_code: "C1" }]),
+    ])
+    const { delays, sleep } = recordingSleep()
+    const client = createCanvasClient(http, {
+      retry: { strategy: "respect-header" },
+      sleep,
+    })
+
+    const courses = await client.listCourses(canvasDraft)
+
+    assert.deepEqual(courses, [{ id: "1", name: "Course", code: "C1" }])
+    assert.deepEqual(delays, [2000])
+    assert.equal(calls(), 2)
+  })
+
+  it("treats a Canvas 403 with an empty rate-limit budget as throttling", async () => {
+    const { http, calls } = createSequenceHttpPort([
+      response(403, "403 Forbidden (Rate Limit Exceeded)", {
+        "x-rate-limit-remaining": "0.0",
+      }),
+      response(200, []),
+    ])
+    const { delays, sleep } = recordingSleep()
+    const client = createCanvasClient(http, {
+      retry: { jitter: "none" },
+      sleep,
+    })
+
+    assert.deepEqual(await client.listCourses(canvasDraft), [])
+    assert.deepEqual(delays, [defaultLmsRetryConfig.baseDelayMs])
+    assert.equal(calls(), 2)
+  })
+
+  it("surfaces LmsRateLimitedError once attempts are exhausted", async () => {
+    const { http, calls } = createSequenceHttpPort([
+      response(429, {}, { "retry-after": "1" }),
+    ])
+    const { delays, sleep } = recordingSleep()
+    const client = createCanvasClient(http, {
+      retry: { maxAttempts: 3 },
+      sleep,
+    })
+
+    await assert.rejects(
+      () => client.listCourses(canvasDraft),
+      (error: unknown) => {
+        assert.ok(error instanceof LmsRateLimitedError)
+        assert.equal(error.status, 429)
+        assert.equal(error.retryAfterMs, 1000)
+        return true
+      },
+    )
+    assert.deepEqual(delays, [1000, 1000])
+    assert.equal(calls(), 3)
+  })
+
+  it("retries Moodle 429 responses", async () => {
+    const { http, calls } = createSequenceHttpPort([
+      response(429, {}),
+      response(200, [{ id: 2, fullname: "Moodle Course", shortname: "M1" }]),
+    ])
+    const { delays, sleep } = recordingSleep()
+    const client = createMoodleClient(http, {
+      retry: { strategy: "fixed", baseDelayMs: 250, jitter: "none" },
+      sleep,
+    })
+
+    const courses = await client.listCourses(moodleDraft)
+
+    assert.deepEqual(courses, [{ id: "2", name: "Moodle Course", code: "M1" }])
+    assert.deepEqual(delays, [250])
+    assert.equal(calls(), 2)
+  })
+
+  it("resolves delays per strategy and caps them at maxDelayMs", () => {
+    const base = {
+      ...defaultLmsRetryConfig,
+      baseDelayMs: 100,
+      maxDelayMs: 500,
+    }
+    const fixed = { ...base, strategy: "fixed" as const }
+    const exponential = { ...base, strategy: "exponential" as const }
+    const respectHeader = { ...base, strategy: "respect-header" as const }
+
+    assert.equal(resolveRateLimitDelayMs(fixed, 3, 2000), 100)
+    assert.equal(resolveRateLimitDelayMs(exponential, 3, null), 400)
+    assert.equal(resolveRateLimitDelayMs(exponential, 4, null), 500)
+    assert.equal(resolveRateLimitDelayMs(respectHeader, 1, 300), 300)
+    assert.equal(resolveRateLimitDelayMs(respectHeader, 2, null), 200)
+    assert.equal(resolveRateLimitDelayMs(respectHeader, 1, 9000), 500)
+  })
+
+  it("spreads full-jitter delays between zero and the strategy delay", () => {
+    const config = { ...defaultLmsRetryConfig, jitter: "full" as const }
+    const delays = Array.from({ length: 100 }, () =>
+      applyRetryJitter(config, 1000, config.baseDelayMs),
+    )
+
+    assert.ok(delays.every((delay) => delay >= 0 && delay < 1000))
+    assert.ok(new Set(delays).size > 1, "full jitter produced equal delays")
+  })
+
+  it("grows decorrelated jitter from the previous delay", () => {
+    const config = {
+      ...defaultLmsRetryConfig,
+      baseDelayMs: 100,
+      maxDelayMs: 1000,
+      jitter: "decorrelated" as const,
+    }
+
+    assert.equal(applyRetryJitter(config, 100, 100, () => 0), 100)
+    assert.equal(applyRetryJitter(config, 100, 200, () => 0.5), 350)
+    assert.equal(applyRetryJitter(config, 100, 900, () => 0.99), 1000)
+  })
+
+  it("jitters computed delays but honors Retry-After as sent", async () => {
+    const { http } = createSequenceHttpPort([
+      response(429, {}),
+      response(429, {}, { "retry-after": "2" }),
+      response(200, []),
+    ])
+    const { delays, sleep } = recordingSleep()
+    const client = createCanvasClient(http, {
+      retry: { baseDelayMs: 400 },
+      sleep,
+      random: () => 0.5,
+    })
+
+    assert.deepEqual(await client.listCourses(canvasDraft), [])
+    assert.deepEqual(delays, [200, 2000])
+  })
+
+  it("parses Retry-After seconds and HTTP dates", () => {
+    const now = Date.parse("2025-01-01T00:00:00Z")
+
+    assert.equal(parseRetryAfterMs("2", now), 2000)
+    assert.equal(parseRetryAfterMs("Wed, 01 Jan 2025 00:00:05 GMT", now), 5000)
+    assert.equal(parseRetryAfterMs("Tue, 31 Dec 2024 23:59:00 GMT", now), 0)
+    assert.equal(parseRetryAfterMs("soon", now), null)
+    assert.equal(parseRetryAfterMs(undefined, now), null)
+  })
+})
+
+describe("LMS request logging", () => {
+  it("records the URL, status and retries under one request id", async () => {
+    const { http } = createSequenceHttpPort([
+      response(429, {}, { "retry-after": "1" }),
+      response(200, []),
+    ])
+    const events: LmsRequestLogEvent[] = []
+    const client = createCanvasClient(http, {
+      sleep: recordingSleep().sleep,
+      onRequestLog: (event) => events.push(event),
+    })
+
+    await client.listCourses(canvasDraft)
+
+    assert.deepEqual(
+      events.map((event) => [event.kind, event.attempt]),
+      [
+        ["response", 1],
+        ["retry", 1],
+        ["response", 2],
+      ],
+    )
+    assert.equal(new Set(events.map((event) => event.requestId)).size, 1)
+    assert.ok(events[0]?.url.startsWith("https://canvas.example.com/api/v1/"))
+    assert.equal(events[0]?.method, "GET")
+    const last = events[2]
+    assert.ok(last?.kind === "response")
+    assert.equal(last.status, 200)
+    assert.ok(last.durationMs >= 0)
+  })
+
+  it("redacts Moodle tokens from logged URLs", async () => {
+    const { http } = createSequenceHttpPort([response(200, [])])
+    const events: LmsRequestLogEvent[] = []
+    const client = createMoodleClient(http, {
+      onRequestLog: (event) => events.push(event),
+    })
+
+    await client.listCourses(moodleDraft)
+
+    assert.ok(events.length > 0)
+    for (const event of events) {
+      assert.ok(!event.url.includes("moodle-token"))
+      assert.ok(event.url.includes("wstoken=REDACTED"))
+    }
+  })
+
+  it("records network failures before rethrowing them", async () => {
+    const events: LmsRequestLogEvent[] = []
+    const client = createCanvasClient(
+      {
+        async fetch(): Promise<HttpResponse> {
+          throw new Error("Connection refused")
+        },
+      },
+      { onRequestLog: (event) => events.push(event) },
+    )
+
+    await assert.rejects(() => client.listCourses(canvasDraft))
+
+    assert.equal(events.length, 1)
+    const [event] = events
+    assert.ok(event?.kind === "failure")
+    assert.equal(event.message, "Connection refused")
+  })
+
+  it("leaves URLs without credentials unchanged", () => {
+    const url = "https://canvas.example.com/api/v1/courses?per_page=100"
+    assert.equal(redactRequestUrl(url), url)
+    assert.equal(redactRequestUrl("not a url"), "not a url")
+  })
+})
@@ -0,0 +1,112 @@
+import assert from "node:assert/strict"
+import { describe, it } from "node:test"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import type { LmsConnectionDraft } from "@repo-edu/integrations-lms-contract"
+import { createCanvasClient } from "../canvas/index.js"
+import { createMoodleClient } from "../moodle/index.js"
+import { redactedValue } from "../request-log.js"
+import {
+  createTimeoutHttpPort,
+  isLmsRequestTimeoutError,
+  LmsRequestTimeoutError,
+} from "../timeout.js"
+
+const canvasDraft: LmsConnectionDraft = {
+  provider: "canvas",
+  baseUrl: "https://canvas.example.com",
+  token: "canvas-token",
+}
+
+const moodleDraft: LmsConnectionDraft = {
+  provider: "moodle",
+  baseUrl: "https://moodle.example.com",
+  token: "moodle-token",
+}
+
+// Never answers and ignores abort signals, like an unreachable server.
+function createHangingHttpPort(): {
+  http: HttpPort
+  requests: HttpRequest[]
+} {
+  const requests: HttpRequest[] = []
+  return {
+    http: {
+      fetch(request: HttpRequest): Promise<HttpResponse> {
+        requests.push(request)
+        return new Promise(() => {})
+      },
+    },
+    requests,
+  }
+}
+
+describe("LMS request timeout", () => {
+  it("fails a hanging Canvas request with the URL and elapsed time", async () => {
+    const { http, requests } = createHangingHttpPort()
+    const client = createCanvasClient(http, { timeoutMs: 20 })
+
+    await assert.rejects(client.listCourses(canvasDraft), (error) => {
+      assert.ok(isLmsRequestTimeoutError(error))
+      assert.match(error.url, /^https:\/\/canvas\.example\.com\/api\/v1\//)
+      assert.ok(error.elapsedMs >= 20)
+      return true
+    })
+    assert.equal(requests.length, 1)
+    assert.equal(requests[0]?.signal?.aborted, true)
+  })
+
+  it("redacts Moodle tokens from the timed-out URL", async () => {
+    const { http } = createHangingHttpPort()
+    const client = createMoodleClient(http, { timeoutMs: 20 })
+
+    await assert.rejects(client.listCourses(moodleDraft), (error) => {
+      assert.ok(error instanceof LmsRequestTimeoutError)
+      assert.ok(!error.url.includes("moodle-token"))
+      assert.ok(error.url.includes(redactedValue))
+      return true
+    })
+  })
+
+  it("keeps caller aborts distinct from timeouts", async () => {
+    const http: HttpPort = {
+      fetch: (request) =>
+        new Promise((_, reject) => {
+          request.signal?.addEventListener("abort", () => {
+            reject(
+              new DOMException("The operation was aborted.", "AbortError"),
+            )
+          })
+        }),
+    }
+    const controller = new AbortController()
+    const port = createTimeoutHttpPort(http, { timeoutMs: 1_000 })
+
+    const pending = port.fetch({
+      url: "https://canvas.example.com/api/v1/courses",
+      signal: controller.signal,
+    })
+    controller.abort()
+
+    await assert.rejects(pending, { name: "AbortError" })
+  })
+
+  it("returns responses that arrive before the timeout", async () => {
+    const http: HttpPort = {
+      fetch: async () => ({
+        status: 200,
+        statusText: "OK",
+        headers: {},
+        body: "ok",
+      }),
+    }
+    const port = createTimeoutHttpPort(http, { timeoutMs: 1_000 })
+
+    const response = await port.fetch({ url: "https://canvas.example.com" })
+
+    assert.equal(response.body, "ok")
+  })
+})
@@ -0,0 +1,41 @@
+import type { LmsProviderKind } from "@repo-edu/domain/types"
+import {
+  type LmsAuthErrorKind,
+  lmsAuthErrorName,
+  type LmsAuthFailure,
+} from "@repo-edu/integrations-lms-contract"
+
+/**
+ * Credential failure reported by an LMS. `unauthorized` (401) means the token
+ * is missing or invalid and the user must reconnect; `forbidden` (403) means
+ * the token is valid but lacks permission for the requested resource;
+ * `expired` means the token's recorded expiry has passed and no request was
+ * sent.
+ */
+export class LmsAuthError extends Error implements LmsAuthFailure {
+  readonly kind: LmsAuthErrorKind
+  readonly provider: LmsProviderKind
+
+  constructor(
+    kind: LmsAuthErrorKind,
+    provider: LmsProviderKind,
+    message: string,
+  ) {
+    super(message)
+    this.name = lmsAuthErrorName
+    this.kind = kind
+    this.provider = provider
+  }
+}
+
+export function lmsAuthErrorKindForStatus(
+  status: number,
+): LmsAuthErrorKind | null {
+  if (status === 401) return "unauthorized"
+  if (status === 403) return "forbidden"
+  return null
+}
+
+export function isLmsAuthError(error: unknown): error is LmsAuthError {
+  return error instanceof LmsAuthError
+}
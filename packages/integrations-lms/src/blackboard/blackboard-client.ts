@@ -0,0 +1,516 @@
+import { mapBounded } from "@repo-edu/concurrency"
+import { resolveUserAgent } from "@repo-edu/domain/connection"
+import type { HttpPort, HttpResponse } from "@repo-edu/host-runtime-contract"
+import type {
+  LmsClient,
+  LmsConnectionDraft,
+  LmsConnectionVerification,
+  LmsCourseFilter,
+  LmsCourseSummary,
+  LmsFetchedGroupSet,
+  LmsGroupSetSummary,
+  LmsPage,
+  LmsPageRequest,
+  LmsTokenUser,
+  RemoteLmsGroup,
+  RemoteLmsMember,
+} from "@repo-edu/integrations-lms-contract"
+import { LmsAuthError, lmsAuthErrorKindForStatus } from "../auth-error.js"
+import {
+  type LmsClientOptions,
+  resolveMembershipConcurrency,
+} from "../client-options.js"
+import { isEmptyLmsCourseFilter } from "../course-filter.js"
+import { normalizePageRequest } from "../pagination.js"
+import { createRateLimitedHttpPort } from "../rate-limit.js"
+import { createTimeoutHttpPort } from "../timeout.js"
+
+class BlackboardRequestStatusError extends Error {
+  readonly status: number
+
+  constructor(status: number) {
+    super(`Blackboard request failed with status ${status}.`)
+    this.name = "BlackboardRequestStatusError"
+    this.status = status
+  }
+}
+
+function toBlackboardStatusError(status: number): Error {
+  const authKind = lmsAuthErrorKindForStatus(status)
+  return authKind === null
+    ? new BlackboardRequestStatusError(status)
+    : new LmsAuthError(
+        authKind,
+        "blackboard",
+        `Blackboard request failed with status ${status}.`,
+      )
+}
+
+function resolveOrigin(draft: LmsConnectionDraft): string {
+  const base = draft.baseUrl.replace(/\/+$/, "")
+  const apiIndex = base.indexOf("/learn/api/public")
+  return apiIndex === -1 ? base : base.slice(0, apiIndex)
+}
+
+function resolveUrl(draft: LmsConnectionDraft, pathOrUrl: string): string {
+  if (/^https?:\/\//.test(pathOrUrl)) {
+    return pathOrUrl
+  }
+
+  const path = pathOrUrl.startsWith("/learn/api/public")
+    ? pathOrUrl
+    : `/learn/api/public${pathOrUrl}`
+  return `${resolveOrigin(draft)}${path}`
+}
+
+function parseJsonBody(response: HttpResponse): unknown {
+  if (response.body === "") {
+    return null
+  }
+
+  try {
+    return JSON.parse(response.body)
+  } catch {
+    return response.body
+  }
+}
+
+async function blackboardRequest(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  pathOrUrl: string,
+  signal?: AbortSignal,
+): Promise<{ status: number; data: unknown }> {
+  const response = await http.fetch({
+    url: resolveUrl(draft, pathOrUrl),
+    method: "GET",
+    headers: {
+      Authorization: `Bearer ${draft.token}`,
+      Accept: "application/json",
+      "User-Agent": resolveUserAgent(draft),
+    },
+    signal,
+  })
+
+  return {
+    status: response.status,
+    data: parseJsonBody(response),
+  }
+}
+
+// Blackboard list endpoints wrap items in `results` and advertise the next
+// page as a relative `paging.nextPage` URL.
+async function fetchPaginatedResults(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  initialPath: string,
+  signal?: AbortSignal,
+  onPage?: (page: number, loaded: number) => void,
+): Promise<unknown[]> {
+  const items: unknown[] = []
+  let nextPath: string | null = initialPath
+  let page = 0
+
+  while (nextPath) {
+    page += 1
+    const response = await blackboardRequest(http, draft, nextPath, signal)
+    if (response.status < 200 || response.status >= 300) {
+      throw toBlackboardStatusError(response.status)
+    }
+
+    const record = (response.data ?? {}) as {
+      results?: unknown
+      paging?: { nextPage?: unknown }
+    }
+    if (Array.isArray(record.results)) {
+      items.push(...record.results)
+    }
+
+    onPage?.(page, items.length)
+    nextPath =
+      typeof record.paging?.nextPage === "string" &&
+      record.paging.nextPage !== ""
+        ? record.paging.nextPage
+        : null
+  }
+
+  return items
+}
+
+// Blackboard pages by offset/limit and does not report a total count.
+async function fetchResultsPage<TItem>(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  path: string,
+  request: LmsPageRequest,
+  map: (item: unknown) => TItem,
+  signal?: AbortSignal,
+): Promise<LmsPage<TItem>> {
+  const { page, perPage } = normalizePageRequest(request)
+  const separator = path.includes("?") ? "&" : "?"
+  const response = await blackboardRequest(
+    http,
+    draft,
+    `${path}${separator}offset=${(page - 1) * perPage}&limit=${perPage}`,
+    signal,
+  )
+  if (response.status < 200 || response.status >= 300) {
+    throw toBlackboardStatusError(response.status)
+  }
+
+  const record = (response.data ?? {}) as {
+    results?: unknown
+    paging?: { nextPage?: unknown }
+  }
+  return {
+    items: Array.isArray(record.results) ? record.results.map(map) : [],
+    pagination: {
+      page,
+      perPage,
+      hasNext:
+        typeof record.paging?.nextPage === "string" &&
+        record.paging.nextPage !== "",
+      totalCount: null,
+      nextCursor: null,
+    },
+  }
+}
+
+const BLACKBOARD_ROLE_TO_ENROLLMENT: Record<string, string> = {
+  Student: "student",
+  Instructor: "teacher",
+  TeachingAssistant: "ta",
+  Grader: "ta",
+  CourseBuilder: "designer",
+  Guest: "observer",
+}
+
+function toCourseSummary(membership: unknown): LmsCourseSummary {
+  const record = (membership ?? {}) as {
+    courseId?: unknown
+    course?: { id?: unknown; name?: unknown; courseId?: unknown }
+  }
+  const course = record.course ?? {}
+
+  return {
+    id: String(course.id ?? record.courseId ?? ""),
+    name: typeof course.name === "string" ? course.name : "Untitled Course",
+    code: typeof course.courseId === "string" ? course.courseId : null,
+  }
+}
+
+function resolveBlackboardStatus(
+  membership: unknown,
+): "active" | "dropped" | "incomplete" {
+  const record = membership as { availability?: { available?: unknown } }
+  switch (record.availability?.available) {
+    case "Yes":
+      return "active"
+    case "No":
+    case "Disabled":
+      return "dropped"
+    default:
+      return "incomplete"
+  }
+}
+
+function toTokenUser(user: unknown): LmsTokenUser {
+  const record = (user ?? {}) as {
+    name?: { given?: unknown; family?: unknown }
+    contact?: { email?: unknown }
+  }
+  const fullName = [record.name?.given, record.name?.family]
+    .filter((part) => typeof part === "string" && part !== "")
+    .join(" ")
+  const email = record.contact?.email
+
+  return {
+    name: fullName || null,
+    email: typeof email === "string" ? email : null,
+  }
+}
+
+function toRemoteMember(membership: unknown): RemoteLmsMember {
+  const record = (membership ?? {}) as {
+    userId?: unknown
+    courseRoleId?: unknown
+    user?: {
+      id?: unknown
+      userName?: unknown
+      studentId?: unknown
+      name?: { given?: unknown; family?: unknown }
+      contact?: { email?: unknown }
+    }
+  }
+  const user = record.user ?? {}
+  const id = String(user.id ?? record.userId ?? "")
+  const given = typeof user.name?.given === "string" ? user.name.given : ""
+  const family = typeof user.name?.family === "string" ? user.name.family : ""
+  const fullName = [given, family].filter((part) => part !== "").join(" ")
+  const role =
+    typeof record.courseRoleId === "string" ? record.courseRoleId : null
+  const lmsStatus = resolveBlackboardStatus(membership)
+
+  return {
+    id: `remote-${id}`,
+    lmsUserId: id,
+    name:
+      fullName !== ""
+        ? fullName
+        : typeof user.userName === "string"
+          ? user.userName
+          : id,
+    email: typeof user.contact?.email === "string" ? user.contact.email : null,
+    studentNumber: typeof user.studentId === "string" ? user.studentId : null,
+    enrollmentType:
+      (role === null ? undefined : BLACKBOARD_ROLE_TO_ENROLLMENT[role]) ??
+      "other",
+    enrollmentDisplay: role,
+    source: "blackboard",
+    status: lmsStatus,
+    lmsStatus,
+  }
+}
+
+function groupSetIdOf(group: unknown): string | null {
+  const record = (group ?? {}) as { groupSetId?: unknown }
+  return record.groupSetId === undefined || record.groupSetId === null
+    ? null
+    : String(record.groupSetId)
+}
+
+function toGroup(group: unknown, memberIds: string[]): RemoteLmsGroup {
+  const record = (group ?? {}) as { id?: unknown; name?: unknown }
+
+  return {
+    id: String(record.id ?? ""),
+    name: typeof record.name === "string" ? record.name : "Untitled Group",
+    memberLmsUserIds: memberIds,
+  }
+}
+
+function toGroupMemberIds(memberships: unknown[]): string[] {
+  return memberships.flatMap((membership) => {
+    const record = membership as { userId?: unknown }
+    if (record.userId === undefined || record.userId === null) {
+      return []
+    }
+
+    return [String(record.userId)]
+  })
+}
+
+async function fetchCourseGroups(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  courseId: string,
+  signal?: AbortSignal,
+  onPage?: (page: number, loaded: number) => void,
+): Promise<unknown[]> {
+  return fetchPaginatedResults(
+    http,
+    draft,
+    `/v2/courses/${encodeURIComponent(courseId)}/groups?limit=100`,
+    signal,
+    onPage,
+  )
+}
+
+async function fetchGroupSetSummaries(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  courseId: string,
+  signal?: AbortSignal,
+): Promise<LmsGroupSetSummary[]> {
+  const [groupSets, groups] = await Promise.all([
+    fetchPaginatedResults(
+      http,
+      draft,
+      `/v2/courses/${encodeURIComponent(courseId)}/groups/sets?limit=100`,
+      signal,
+    ),
+    fetchCourseGroups(http, draft, courseId, signal),
+  ])
+
+  return groupSets.map((groupSet) => {
+    const record = (groupSet ?? {}) as { id?: unknown; name?: unknown }
+    const id = String(record.id ?? "")
+    return {
+      id,
+      name: typeof record.name === "string" ? record.name : `Group Set ${id}`,
+      groupCount: groups.filter((group) => groupSetIdOf(group) === id).length,
+    }
+  })
+}
+
+export function createBlackboardClient(
+  transport: HttpPort,
+  options?: LmsClientOptions,
+): LmsClient {
+  const http = createRateLimitedHttpPort(
+    createTimeoutHttpPort(transport, options),
+    options,
+  )
+  const membershipConcurrency = resolveMembershipConcurrency(options)
+
+  return {
+    async verifyConnection(
+      draft: LmsConnectionDraft,
+      signal?: AbortSignal,
+    ): Promise<LmsConnectionVerification> {
+      try {
+        const response = await blackboardRequest(
+          http,
+          draft,
+          "/v1/users/me",
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          return { verified: false }
+        }
+        return { verified: true, user: toTokenUser(response.data) }
+      } catch {
+        return { verified: false }
+      }
+    },
+
+    async listCourses(
+      draft: LmsConnectionDraft,
+      signal?: AbortSignal,
+      filter?: LmsCourseFilter,
+    ): Promise<LmsCourseSummary[]> {
+      if (!isEmptyLmsCourseFilter(filter)) {
+        throw new Error("Blackboard does not support course filters.")
+      }
+      const memberships = await fetchPaginatedResults(
+        http,
+        draft,
+        "/v1/users/me/courses?role=Instructor&expand=course&limit=100",
+        signal,
+      )
+
+      return memberships.map(toCourseSummary)
+    },
+
+    async fetchRoster(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      signal?: AbortSignal,
+      onProgress?: (message: string) => void,
+    ): Promise<RemoteLmsMember[]> {
+      const memberships = await fetchPaginatedResults(
+        http,
+        draft,
+        `/v1/courses/${encodeURIComponent(courseId)}/users?expand=user&limit=100`,
+        signal,
+        (page, loaded) => {
+          onProgress?.(
+            `Loading course members from LMS (page ${page}, ${loaded} loaded)`,
+          )
+        },
+      )
+
+      const members = memberships.map(toRemoteMember)
+      const studentCount = members.filter(
+        (member) => member.enrollmentType === "student",
+      ).length
+      const staffCount = members.length - studentCount
+      onProgress?.(
+        `Loaded ${studentCount} students and ${staffCount} staff from LMS.`,
+      )
+      return members
+    },
+
+    async fetchRosterPage(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      request: LmsPageRequest,
+      signal?: AbortSignal,
+    ): Promise<LmsPage<RemoteLmsMember>> {
+      return fetchResultsPage(
+        http,
+        draft,
+        `/v1/courses/${encodeURIComponent(courseId)}/users?expand=user`,
+        request,
+        toRemoteMember,
+        signal,
+      )
+    },
+
+    async listGroupSets(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      signal?: AbortSignal,
+    ): Promise<LmsGroupSetSummary[]> {
+      return fetchGroupSetSummaries(http, draft, courseId, signal)
+    },
+
+    async fetchGroupSet(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      groupSetId: string,
+      signal?: AbortSignal,
+      onProgress?: (message: string) => void,
+    ): Promise<LmsFetchedGroupSet> {
+      const encodedCourseId = encodeURIComponent(courseId)
+      const [groupSetResponse, courseGroups] = await Promise.all([
+        blackboardRequest(
+          http,
+          draft,
+          `/v2/courses/${encodedCourseId}/groups/sets/${encodeURIComponent(
+            groupSetId,
+          )}`,
+          signal,
+        ),
+        fetchCourseGroups(http, draft, courseId, signal, (page, loaded) => {
+          onProgress?.(`Fetched group page ${page} (${loaded} groups loaded)`)
+        }),
+      ])
+
+      const setGroups = courseGroups.filter(
+        (group) => groupSetIdOf(group) === groupSetId,
+      )
+      const groups = await mapBounded(
+        setGroups,
+        membershipConcurrency,
+        async (group) => {
+          const record = group as { id?: unknown; name?: unknown }
+          const groupId = String(record.id ?? "")
+          const groupName =
+            typeof record.name === "string" ? record.name : `Group ${groupId}`
+          const memberships = await fetchPaginatedResults(
+            http,
+            draft,
+            `/v2/courses/${encodedCourseId}/groups/${encodeURIComponent(
+              groupId,
+            )}/users?limit=100`,
+            signal,
+            (_page, loaded) => {
+              onProgress?.(
+                `Loading members for group ${groupName} (${loaded} loaded)`,
+              )
+            },
+          )
+          return toGroup(group, toGroupMemberIds(memberships))
+        },
+      )
+
+      const groupSetRecord = (
+        groupSetResponse.status >= 200 && groupSetResponse.status < 300
+          ? (groupSetResponse.data ?? {})
+          : {}
+      ) as { name?: unknown }
+
+      return {
+        groupSet: {
+          id: groupSetId,
+          name:
+            typeof groupSetRecord.name === "string"
+              ? groupSetRecord.name
+              : `Group Set ${groupSetId}`,
+        },
+        groups,
+      }
+    },
+  }
+}
@@ -0,0 +1 @@
+export { createBlackboardClient } from "./blackboard-client.js"
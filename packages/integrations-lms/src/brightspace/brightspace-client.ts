@@ -0,0 +1,334 @@
+import { resolveUserAgent } from "@repo-edu/domain/connection"
+import type { HttpPort, HttpResponse } from "@repo-edu/host-runtime-contract"
+import type {
+  LmsClient,
+  LmsConnectionDraft,
+  LmsConnectionVerification,
+  LmsCourseFilter,
+  LmsCourseSummary,
+  LmsFetchedGroupSet,
+  LmsGroupSetSummary,
+  LmsPage,
+  LmsPageRequest,
+  LmsTokenUser,
+  RemoteLmsMember,
+} from "@repo-edu/integrations-lms-contract"
+import { LmsAuthError, lmsAuthErrorKindForStatus } from "../auth-error.js"
+import type { LmsClientOptions } from "../client-options.js"
+import { isEmptyLmsCourseFilter } from "../course-filter.js"
+import { sliceIntoPage } from "../pagination.js"
+import { createRateLimitedHttpPort } from "../rate-limit.js"
+import { createTimeoutHttpPort } from "../timeout.js"
+
+const LP_API = "/d2l/api/lp/1.47"
+const LE_API = "/d2l/api/le/1.47"
+
+// Org unit type 3 is "Course Offering"; enrollments also include the
+// organization, departments and semesters the user belongs to.
+const COURSE_OFFERING_TYPE_ID = 3
+
+class BrightspaceRequestStatusError extends Error {
+  readonly status: number
+
+  constructor(status: number) {
+    super(`Brightspace request failed with status ${status}.`)
+    this.name = "BrightspaceRequestStatusError"
+    this.status = status
+  }
+}
+
+function toBrightspaceStatusError(status: number): Error {
+  const authKind = lmsAuthErrorKindForStatus(status)
+  return authKind === null
+    ? new BrightspaceRequestStatusError(status)
+    : new LmsAuthError(
+        authKind,
+        "brightspace",
+        `Brightspace request failed with status ${status}.`,
+      )
+}
+
+function resolveOrigin(draft: LmsConnectionDraft): string {
+  const base = draft.baseUrl.replace(/\/+$/, "")
+  const apiIndex = base.indexOf("/d2l/")
+  return apiIndex === -1 ? base : base.slice(0, apiIndex)
+}
+
+function parseJsonBody(response: HttpResponse): unknown {
+  if (response.body === "") {
+    return null
+  }
+
+  try {
+    return JSON.parse(response.body)
+  } catch {
+    return response.body
+  }
+}
+
+async function brightspaceRequest(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  path: string,
+  signal?: AbortSignal,
+): Promise<{ status: number; data: unknown }> {
+  const response = await http.fetch({
+    url: `${resolveOrigin(draft)}${path}`,
+    method: "GET",
+    headers: {
+      Authorization: `Bearer ${draft.token}`,
+      Accept: "application/json",
+      "User-Agent": resolveUserAgent(draft),
+    },
+    signal,
+  })
+
+  return {
+    status: response.status,
+    data: parseJsonBody(response),
+  }
+}
+
+// Brightspace paged result sets return `Items` plus an opaque
+// `PagingInfo.Bookmark` that is passed back to fetch the next page.
+async function fetchPagedResultSet(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  path: string,
+  signal?: AbortSignal,
+): Promise<unknown[]> {
+  const items: unknown[] = []
+  const separator = path.includes("?") ? "&" : "?"
+  let bookmark: string | null = null
+
+  do {
+    const response = await brightspaceRequest(
+      http,
+      draft,
+      bookmark === null
+        ? path
+        : `${path}${separator}bookmark=${encodeURIComponent(bookmark)}`,
+      signal,
+    )
+    if (response.status < 200 || response.status >= 300) {
+      throw toBrightspaceStatusError(response.status)
+    }
+
+    const record = (response.data ?? {}) as {
+      Items?: unknown
+      PagingInfo?: { Bookmark?: unknown; HasMoreItems?: unknown }
+    }
+    if (Array.isArray(record.Items)) {
+      items.push(...record.Items)
+    }
+
+    bookmark =
+      record.PagingInfo?.HasMoreItems === true &&
+      typeof record.PagingInfo.Bookmark === "string"
+        ? record.PagingInfo.Bookmark
+        : null
+  } while (bookmark !== null)
+
+  return items
+}
+
+// Classlist roles are named per institution; match the common defaults.
+const BRIGHTSPACE_ROLE_TO_ENROLLMENT: Record<string, string> = {
+  student: "student",
+  learner: "student",
+  instructor: "teacher",
+  teacher: "teacher",
+  "teaching assistant": "ta",
+  ta: "ta",
+  grader: "ta",
+  designer: "designer",
+  "course builder": "designer",
+  auditor: "observer",
+  observer: "observer",
+}
+
+function toCourseSummary(enrollment: unknown): LmsCourseSummary {
+  const record = (enrollment ?? {}) as {
+    OrgUnit?: { Id?: unknown; Name?: unknown; Code?: unknown }
+  }
+  const orgUnit = record.OrgUnit ?? {}
+
+  return {
+    id: String(orgUnit.Id ?? ""),
+    name: typeof orgUnit.Name === "string" ? orgUnit.Name : "Untitled Course",
+    code:
+      typeof orgUnit.Code === "string" && orgUnit.Code !== ""
+        ? orgUnit.Code
+        : null,
+  }
+}
+
+function toTokenUser(user: unknown): LmsTokenUser {
+  const record = (user ?? {}) as { FirstName?: unknown; LastName?: unknown }
+  const fullName = [record.FirstName, record.LastName]
+    .filter((part) => typeof part === "string" && part !== "")
+    .join(" ")
+
+  // WhoAmI does not expose the user's email address.
+  return { name: fullName || null, email: null }
+}
+
+function toRemoteMember(classlistUser: unknown): RemoteLmsMember {
+  const record = (classlistUser ?? {}) as {
+    Identifier?: unknown
+    DisplayName?: unknown
+    Username?: unknown
+    OrgDefinedId?: unknown
+    Email?: unknown
+    ClasslistRoleDisplayName?: unknown
+  }
+  const id = String(record.Identifier ?? "")
+  const role =
+    typeof record.ClasslistRoleDisplayName === "string"
+      ? record.ClasslistRoleDisplayName
+      : null
+
+  return {
+    id: `remote-${id}`,
+    lmsUserId: id,
+    name:
+      typeof record.DisplayName === "string" && record.DisplayName !== ""
+        ? record.DisplayName
+        : typeof record.Username === "string"
+          ? record.Username
+          : id,
+    email:
+      typeof record.Email === "string" && record.Email !== ""
+        ? record.Email
+        : null,
+    studentNumber:
+      typeof record.OrgDefinedId === "string" && record.OrgDefinedId !== ""
+        ? record.OrgDefinedId
+        : null,
+    enrollmentType:
+      (role === null
+        ? undefined
+        : BRIGHTSPACE_ROLE_TO_ENROLLMENT[role.toLowerCase()]) ?? "other",
+    enrollmentDisplay: role,
+    source: "brightspace",
+    // The classlist only lists users with an active enrollment.
+    status: "active",
+    lmsStatus: "active",
+  }
+}
+
+/**
+ * Brightspace (D2L) Valence REST adapter. Courses and rosters are
+ * supported; the remaining operations are not implemented yet.
+ */
+export function createBrightspaceClient(
+  transport: HttpPort,
+  options?: LmsClientOptions,
+): LmsClient {
+  const http = createRateLimitedHttpPort(
+    createTimeoutHttpPort(transport, options),
+    options,
+  )
+
+  async function listAllCourses(
+    draft: LmsConnectionDraft,
+    signal?: AbortSignal,
+  ): Promise<LmsCourseSummary[]> {
+    const enrollments = await fetchPagedResultSet(
+      http,
+      draft,
+      `${LP_API}/enrollments/myenrollments/?orgUnitTypeId=${COURSE_OFFERING_TYPE_ID}`,
+      signal,
+    )
+
+    return enrollments.map(toCourseSummary)
+  }
+
+  async function fetchClasslist(
+    draft: LmsConnectionDraft,
+    courseId: string,
+    signal?: AbortSignal,
+  ): Promise<RemoteLmsMember[]> {
+    const response = await brightspaceRequest(
+      http,
+      draft,
+      `${LE_API}/${encodeURIComponent(courseId)}/classlist/`,
+      signal,
+    )
+    if (response.status < 200 || response.status >= 300) {
+      throw toBrightspaceStatusError(response.status)
+    }
+
+    return Array.isArray(response.data) ? response.data.map(toRemoteMember) : []
+  }
+
+  return {
+    async verifyConnection(
+      draft: LmsConnectionDraft,
+      signal?: AbortSignal,
+    ): Promise<LmsConnectionVerification> {
+      try {
+        const response = await brightspaceRequest(
+          http,
+          draft,
+          `${LP_API}/users/whoami`,
+          signal,
+        )
+        if (response.status < 200 || response.status >= 300) {
+          return { verified: false }
+        }
+        return { verified: true, user: toTokenUser(response.data) }
+      } catch {
+        return { verified: false }
+      }
+    },
+
+    async listCourses(
+      draft: LmsConnectionDraft,
+      signal?: AbortSignal,
+      filter?: LmsCourseFilter,
+    ): Promise<LmsCourseSummary[]> {
+      if (!isEmptyLmsCourseFilter(filter)) {
+        throw new Error("Brightspace does not support course filters.")
+      }
+      return listAllCourses(draft, signal)
+    },
+
+    async fetchRoster(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      signal?: AbortSignal,
+      onProgress?: (message: string) => void,
+    ): Promise<RemoteLmsMember[]> {
+      onProgress?.("Loading course members from LMS")
+      const members = await fetchClasslist(draft, courseId, signal)
+      const studentCount = members.filter(
+        (member) => member.enrollmentType === "student",
+      ).length
+      const staffCount = members.length - studentCount
+      onProgress?.(
+        `Loaded ${studentCount} students and ${staffCount} staff from LMS.`,
+      )
+      return members
+    },
+
+    async fetchRosterPage(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      request: LmsPageRequest,
+      signal?: AbortSignal,
+    ): Promise<LmsPage<RemoteLmsMember>> {
+      return sliceIntoPage(
+        await fetchClasslist(draft, courseId, signal),
+        request,
+      )
+    },
+
+    async listGroupSets(): Promise<LmsGroupSetSummary[]> {
+      throw new Error("Brightspace does not support group sets yet.")
+    },
+    async fetchGroupSet(): Promise<LmsFetchedGroupSet> {
+      throw new Error("Brightspace does not support group sets yet.")
+    },
+  }
+}
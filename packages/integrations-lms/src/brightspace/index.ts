@@ -0,0 +1 @@
+export { createBrightspaceClient } from "./brightspace-client.js"
@@ -1,14 +1,35 @@
+import { mapBounded } from "@repo-edu/concurrency"
 import { resolveUserAgent } from "@repo-edu/domain/connection"
 import type { HttpPort, HttpResponse } from "@repo-edu/host-runtime-contract"
 import type {
   LmsClient,
   LmsConnectionDraft,
+  LmsConnectionVerification,
+  LmsCourseFilter,
   LmsCourseSummary,
   LmsFetchedGroupSet,
   LmsGroupSetSummary,
+  LmsPage,
+  LmsPageRequest,
+  LmsTokenUser,
   RemoteLmsGroup,
   RemoteLmsMember,
 } from "@repo-edu/integrations-lms-contract"
+import {
+  isLmsAuthError,
+  LmsAuthError,
+  lmsAuthErrorKindForStatus,
+} from "../auth-error.js"
+import {
+  type LmsClientOptions,
+  resolveMembershipConcurrency,
+} from "../client-options.js"
+import { normalizePageRequest } from "../pagination.js"
+import {
+  createRateLimitedHttpPort,
+  isTooManyRequestsResponse,
+} from "../rate-limit.js"
+import { createTimeoutHttpPort } from "../timeout.js"
 
 class CanvasRequestStatusError extends Error {
   readonly status: number
@@ -20,6 +41,17 @@ class CanvasRequestStatusError extends Error {
   }
 }
 
+function toCanvasStatusError(status: number): Error {
+  const authKind = lmsAuthErrorKindForStatus(status)
+  return authKind === null
+    ? new CanvasRequestStatusError(status)
+    : new LmsAuthError(
+        authKind,
+        "canvas",
+        `Canvas request failed with status ${status}.`,
+      )
+}
+
 function resolveApiBase(draft: LmsConnectionDraft): string {
   const base = draft.baseUrl.replace(/\/+$/, "")
   return base.endsWith("/api/v1") ? base : `${base}/api/v1`
@@ -89,6 +121,8 @@ function extractNextLink(linkHeader: string | undefined): string | null {
   return null
 }
 
+// Follows `rel="next"` Link URLs, which Canvas recommends over page numbers
+// because they stay stable while items change between requests.
 async function fetchPaginatedArray(
   http: HttpPort,
   draft: LmsConnectionDraft,
@@ -104,7 +138,7 @@ async function fetchPaginatedArray(
     page += 1
     const response = await canvasRequest(http, draft, nextUrl, signal)
     if (response.status < 200 || response.status >= 300) {
-      throw new CanvasRequestStatusError(response.status)
+      throw toCanvasStatusError(response.status)
     }
 
     if (Array.isArray(response.data)) {
@@ -118,10 +152,86 @@ async function fetchPaginatedArray(
   return items
 }
 
-function isCanvasAuthStatusError(error: unknown): boolean {
+async function fetchCanvasPage(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  path: string,
+  request: LmsPageRequest,
+  signal?: AbortSignal,
+): Promise<LmsPage<unknown>> {
+  const { page, perPage } = normalizePageRequest(request)
+  const separator = path.includes("?") ? "&" : "?"
+  const cursor = request.cursor ?? null
+  // Cursors are requested with the bearer token, so they must point at this
+  // Canvas instance.
+  if (cursor !== null && !cursor.startsWith(`${resolveApiBase(draft)}/`)) {
+    throw new Error("Canvas page cursor does not belong to this connection.")
+  }
+  const response = await canvasRequest(
+    http,
+    draft,
+    cursor ?? `${path}${separator}page=${page}&per_page=${perPage}`,
+    signal,
+  )
+  if (response.status < 200 || response.status >= 300) {
+    throw toCanvasStatusError(response.status)
+  }
+
+  // Canvas only sends X-Total-Count on some endpoints and deployments.
+  const totalCount = Number.parseInt(
+    response.headers["x-total-count"] ?? "",
+    10,
+  )
+  const nextCursor = extractNextLink(response.headers.link)
+  return {
+    items: Array.isArray(response.data) ? response.data : [],
+    pagination: {
+      page,
+      perPage,
+      hasNext: nextCursor !== null,
+      totalCount: Number.isNaN(totalCount) ? null : totalCount,
+      nextCursor: nextCursor === null ? null : resolveUrl(draft, nextCursor),
+    },
+  }
+}
+
+// Canvas throttles with 403 "Rate Limit Exceeded" once the request cost
+// bucket is empty, reporting the remaining budget in X-Rate-Limit-Remaining.
+function isCanvasRateLimitedResponse(response: HttpResponse): boolean {
+  if (isTooManyRequestsResponse(response)) {
+    return true
+  }
+
+  const remaining = response.headers["x-rate-limit-remaining"]
+  return (
+    response.status === 403 &&
+    remaining !== undefined &&
+    Number.parseFloat(remaining) <= 0
+  )
+}
+
+// Canvas has no term parameter on `/courses`, so terms filter client-side on
+// `enrollment_term_id`.
+function toCanvasCoursesRoute(filter: LmsCourseFilter | undefined): string {
+  const enrollmentType = encodeURIComponent(filter?.enrollmentType ?? "teacher")
+  const query = [`enrollment_type=${enrollmentType}`]
+  if (filter?.state !== undefined) {
+    query.push(`state[]=${filter.state}`)
+  }
+  query.push("per_page=100")
+  return `/courses?${query.join("&")}`
+}
+
+function matchesCourseTerm(
+  course: unknown,
+  filter: LmsCourseFilter | undefined,
+): boolean {
+  if (filter?.termId === undefined) return true
+  const record = (course ?? {}) as { enrollment_term_id?: unknown }
+  const termId = record.enrollment_term_id
   return (
-    error instanceof CanvasRequestStatusError &&
-    (error.status === 401 || error.status === 403)
+    (typeof termId === "number" || typeof termId === "string") &&
+    String(termId) === filter.termId
   )
 }
 
@@ -139,6 +249,21 @@ function toCourseSummary(course: unknown): LmsCourseSummary {
   }
 }
 
+// `primary_email` is only returned when the token may read its own profile.
+function toTokenUser(user: unknown): LmsTokenUser {
+  const record = (user ?? {}) as {
+    name?: unknown
+    primary_email?: unknown
+    email?: unknown
+  }
+  const email = record.primary_email ?? record.email
+
+  return {
+    name: typeof record.name === "string" ? record.name : null,
+    email: typeof email === "string" ? email : null,
+  }
+}
+
 function resolveCanvasEnrollmentStatus(
   user: unknown,
 ): "active" | "dropped" | "incomplete" {
@@ -159,6 +284,30 @@ function resolveCanvasEnrollmentStatus(
   return "incomplete"
 }
 
+const CANVAS_ENROLLMENT_TYPE_TO_ROLE: Record<string, string> = {
+  StudentEnrollment: "student",
+  TeacherEnrollment: "teacher",
+  TaEnrollment: "ta",
+  ObserverEnrollment: "observer",
+  DesignerEnrollment: "designer",
+}
+
+// Users listed across all enrollment types carry their roles in `enrollments`.
+function resolveCanvasEnrollmentType(user: unknown): string {
+  const enrollments = (user as { enrollments?: unknown }).enrollments
+  if (Array.isArray(enrollments)) {
+    for (const enrollment of enrollments) {
+      const type = (enrollment as { type?: unknown }).type
+      const role =
+        typeof type === "string" ? CANVAS_ENROLLMENT_TYPE_TO_ROLE[type] : null
+      if (role) {
+        return role
+      }
+    }
+  }
+  return "student"
+}
+
 function toRemoteMember(user: unknown): RemoteLmsMember {
   const record = (user ?? {}) as {
     id?: unknown
@@ -321,7 +470,7 @@ async function fetchGroupSetSummaries(
 
     return groupSets.map(toGroupSetSummary)
   } catch (error) {
-    if (!isCanvasAuthStatusError(error)) {
+    if (!isLmsAuthError(error)) {
       throw error
     }
 
@@ -380,6 +529,7 @@ async function fetchGroupsForSet(
   draft: LmsConnectionDraft,
   courseId: string,
   groupSetId: string,
+  membershipConcurrency: number,
   signal?: AbortSignal,
   onProgress?: (message: string) => void,
 ): Promise<RemoteLmsGroup[]> {
@@ -395,7 +545,7 @@ async function fetchGroupsForSet(
       },
     )
   } catch (error) {
-    if (!isCanvasAuthStatusError(error)) {
+    if (!isLmsAuthError(error)) {
       throw error
     }
 
@@ -413,8 +563,7 @@ async function fetchGroupsForSet(
     )
   }
 
-  const result: RemoteLmsGroup[] = []
-  for (const group of groups) {
+  return mapBounded(groups, membershipConcurrency, async (group) => {
     const record = group as { id?: unknown; name?: unknown }
     const groupId = String(record.id ?? "")
     const groupName =
@@ -431,21 +580,32 @@ async function fetchGroupsForSet(
       },
     )
 
-    result.push(toGroup(group, toGroupMemberIds(memberships)))
-  }
-
-  return result
+    return toGroup(group, toGroupMemberIds(memberships))
+  })
 }
 
-export function createCanvasClient(http: HttpPort): LmsClient {
+export function createCanvasClient(
+  transport: HttpPort,
+  options?: LmsClientOptions,
+): LmsClient {
+  const http = createRateLimitedHttpPort(
+    createTimeoutHttpPort(transport, options),
+    options,
+    isCanvasRateLimitedResponse,
+  )
+  const membershipConcurrency = resolveMembershipConcurrency(options)
+
   return {
     async verifyConnection(
       draft: LmsConnectionDraft,
       signal?: AbortSignal,
-    ): Promise<{ verified: boolean }> {
+    ): Promise<LmsConnectionVerification> {
       try {
         const response = await canvasRequest(http, draft, "/users/self", signal)
-        return { verified: response.status >= 200 && response.status < 300 }
+        if (response.status < 200 || response.status >= 300) {
+          return { verified: false }
+        }
+        return { verified: true, user: toTokenUser(response.data) }
       } catch {
         return { verified: false }
       }
@@ -454,15 +614,18 @@ export function createCanvasClient(http: HttpPort): LmsClient {
     async listCourses(
       draft: LmsConnectionDraft,
       signal?: AbortSignal,
+      filter?: LmsCourseFilter,
     ): Promise<LmsCourseSummary[]> {
       const courses = await fetchPaginatedArray(
         http,
         draft,
-        "/courses?enrollment_type=teacher&per_page=100",
+        toCanvasCoursesRoute(filter),
         signal,
       )
 
-      return courses.map(toCourseSummary)
+      return courses
+        .filter((course) => matchesCourseTerm(course, filter))
+        .map(toCourseSummary)
     },
 
     async fetchRoster(
@@ -559,6 +722,29 @@ export function createCanvasClient(http: HttpPort): LmsClient {
       return [...studentMembers, ...staffMembers]
     },
 
+    async fetchRosterPage(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      request: LmsPageRequest,
+      signal?: AbortSignal,
+    ): Promise<LmsPage<RemoteLmsMember>> {
+      const page = await fetchCanvasPage(
+        http,
+        draft,
+        `/courses/${encodeURIComponent(courseId)}/users?include[]=enrollments`,
+        request,
+        signal,
+      )
+
+      return {
+        ...page,
+        items: page.items.map((user) => ({
+          ...toRemoteMember(user),
+          enrollmentType: resolveCanvasEnrollmentType(user),
+        })),
+      }
+    },
+
     async listGroupSets(
       draft: LmsConnectionDraft,
       courseId: string,
@@ -581,6 +767,7 @@ export function createCanvasClient(http: HttpPort): LmsClient {
           draft,
           courseId,
           groupSetId,
+          membershipConcurrency,
           signal,
           onProgress,
         ),
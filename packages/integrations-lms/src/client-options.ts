@@ -0,0 +1,25 @@
+import type { LmsRetryConfig, LmsSleep } from "./rate-limit.js"
+import type { LmsRequestLogger } from "./request-log.js"
+
+export type LmsClientOptions = {
+  retry?: Partial<LmsRetryConfig>
+  sleep?: LmsSleep
+  /** Source of `[0, 1)` values for retry jitter; defaults to `Math.random`. */
+  random?: () => number
+  membershipConcurrency?: number
+  /** Per-attempt request timeout; defaults to `defaultLmsRequestTimeoutMs`. */
+  timeoutMs?: number
+  /** Receives one event per HTTP attempt and per rate-limit retry. */
+  onRequestLog?: LmsRequestLogger
+}
+
+export const defaultMembershipConcurrency = 10
+
+export function resolveMembershipConcurrency(
+  options: LmsClientOptions | undefined,
+): number {
+  return Math.max(
+    1,
+    Math.floor(options?.membershipConcurrency ?? defaultMembershipConcurrency),
+  )
+}
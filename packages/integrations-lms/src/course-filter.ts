@@ -0,0 +1,12 @@
+import type { LmsCourseFilter } from "@repo-edu/integrations-lms-contract"
+
+export function isEmptyLmsCourseFilter(
+  filter: LmsCourseFilter | undefined,
+): boolean {
+  return (
+    filter === undefined ||
+    (filter.termId === undefined &&
+      filter.state === undefined &&
+      filter.enrollmentType === undefined)
+  )
+}
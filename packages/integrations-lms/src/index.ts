@@ -1,29 +1,68 @@
+import { isTokenExpired } from "@repo-edu/domain/connection"
 import type { LmsProviderKind } from "@repo-edu/domain/types"
 import type { HttpPort } from "@repo-edu/host-runtime-contract"
-import type { LmsClient } from "@repo-edu/integrations-lms-contract"
-import { packageId as contractPackageId } from "@repo-edu/integrations-lms-contract"
+import {
+  type LmsClient,
+  packageId as contractPackageId,
+} from "@repo-edu/integrations-lms-contract"
+import { LmsAuthError } from "./auth-error.js"
+import { createBlackboardClient } from "./blackboard/index.js"
+import { createBrightspaceClient } from "./brightspace/index.js"
 import { createCanvasClient } from "./canvas/index.js"
+import type { LmsClientOptions } from "./client-options.js"
 import { createMoodleClient } from "./moodle/index.js"
 
 export const packageId = "@repo-edu/integrations-lms"
 export const workspaceDependencies = [contractPackageId] as const
 
+export {
+  isLmsAuthError,
+  LmsAuthError,
+  lmsAuthErrorKindForStatus,
+} from "./auth-error.js"
+export { createBlackboardClient } from "./blackboard/index.js"
+export { createBrightspaceClient } from "./brightspace/index.js"
 export { createCanvasClient } from "./canvas/index.js"
+export {
+  defaultMembershipConcurrency,
+  type LmsClientOptions,
+} from "./client-options.js"
 export { createMoodleClient } from "./moodle/index.js"
+export {
+  defaultLmsRetryConfig,
+  type LmsJitterStrategy,
+  LmsRateLimitedError,
+  type LmsRateLimitStrategy,
+  type LmsRetryConfig,
+} from "./rate-limit.js"
+export type { LmsRequestLogEvent, LmsRequestLogger } from "./request-log.js"
+export {
+  defaultLmsRequestTimeoutMs,
+  isLmsRequestTimeoutError,
+  LmsRequestTimeoutError,
+} from "./timeout.js"
 
 export function createLmsClient(
   provider: LmsProviderKind,
   http: HttpPort,
+  options?: LmsClientOptions,
 ): LmsClient {
   switch (provider) {
     case "canvas":
-      return createCanvasClient(http)
+      return createCanvasClient(http, options)
     case "moodle":
-      return createMoodleClient(http)
+      return createMoodleClient(http, options)
+    case "blackboard":
+      return createBlackboardClient(http, options)
+    case "brightspace":
+      return createBrightspaceClient(http, options)
   }
 }
 
-export function createLmsProviderDispatch(http: HttpPort): LmsClient {
+export function createLmsProviderDispatch(
+  http: HttpPort,
+  options?: LmsClientOptions,
+): LmsClient {
   const clients = new Map<LmsProviderKind, LmsClient>()
 
   const resolveClient = (provider: LmsProviderKind): LmsClient => {
@@ -32,17 +71,26 @@ export function createLmsProviderDispatch(http: HttpPort): LmsClient {
       return existing
     }
 
-    const next = createLmsClient(provider, http)
+    const next = createLmsClient(provider, http, options)
     clients.set(provider, next)
     return next
   }
 
   return {
     verifyConnection(draft, signal) {
+      if (isTokenExpired(draft.tokenExpiresAt)) {
+        return Promise.reject(
+          new LmsAuthError(
+            "expired",
+            draft.provider,
+            `The ${draft.provider} access token expired at ${draft.tokenExpiresAt}. Reconnect to issue a new token.`,
+          ),
+        )
+      }
       return resolveClient(draft.provider).verifyConnection(draft, signal)
     },
-    listCourses(draft, signal) {
-      return resolveClient(draft.provider).listCourses(draft, signal)
+    listCourses(draft, signal, filter) {
+      return resolveClient(draft.provider).listCourses(draft, signal, filter)
     },
     fetchRoster(draft, courseId, signal, onProgress) {
       return resolveClient(draft.provider).fetchRoster(
@@ -52,6 +100,14 @@ export function createLmsProviderDispatch(http: HttpPort): LmsClient {
         onProgress,
       )
     },
+    fetchRosterPage(draft, courseId, request, signal) {
+      return resolveClient(draft.provider).fetchRosterPage(
+        draft,
+        courseId,
+        request,
+        signal,
+      )
+    },
     listGroupSets(draft, courseId, signal) {
       return resolveClient(draft.provider).listGroupSets(
         draft,
@@ -1,21 +1,34 @@
 import { resolveUserAgent } from "@repo-edu/domain/connection"
 import type { HttpPort, HttpResponse } from "@repo-edu/host-runtime-contract"
 import type {
+  LmsAuthErrorKind,
   LmsClient,
   LmsConnectionDraft,
+  LmsConnectionVerification,
+  LmsCourseFilter,
   LmsCourseSummary,
   LmsFetchedGroupSet,
   LmsGroupSetSummary,
+  LmsPage,
+  LmsPageRequest,
+  LmsTokenUser,
   RemoteLmsGroup,
   RemoteLmsMember,
 } from "@repo-edu/integrations-lms-contract"
+import { LmsAuthError, lmsAuthErrorKindForStatus } from "../auth-error.js"
+import type { LmsClientOptions } from "../client-options.js"
+import { isEmptyLmsCourseFilter } from "../course-filter.js"
+import { sliceIntoPage } from "../pagination.js"
+import { createRateLimitedHttpPort } from "../rate-limit.js"
+import { createTimeoutHttpPort } from "../timeout.js"
 
 type MoodleFunction =
   | "core_webservice_get_site_info"
   | "core_course_get_courses"
   | "core_enrol_get_enrolled_users"
   | "core_group_get_course_groupings"
-  | "core_group_get_course_groups"
+  | "core_group_get_groupings"
+  | "core_group_get_group_members"
 
 function resolveEndpoint(draft: LmsConnectionDraft): string {
   const base = draft.baseUrl.replace(/\/+$/, "")
@@ -59,6 +72,42 @@ function isMoodleException(data: unknown): boolean {
   return typeof (data as { exception?: unknown }).exception === "string"
 }
 
+// Permission failures arrive as HTTP 200 with an exception body, e.g.
+// `{ exception: "required_capability_exception", errorcode: "nopermissions" }`.
+function describeMoodleException(data: unknown): string {
+  if (!isMoodleException(data)) {
+    return ""
+  }
+
+  const record = data as { errorcode?: unknown; message?: unknown }
+  const code =
+    typeof record.errorcode === "string" ? ` (${record.errorcode})` : ""
+  const message =
+    typeof record.message === "string" ? `: ${record.message}` : "."
+  return `${code}${message}`
+}
+
+const MOODLE_AUTH_ERROR_CODES: Record<string, LmsAuthErrorKind> = {
+  invalidtoken: "unauthorized",
+  accessexception: "forbidden",
+  nopermissions: "forbidden",
+}
+
+function resolveMoodleAuthErrorKind(
+  status: number,
+  data: unknown,
+): LmsAuthErrorKind | null {
+  const fromStatus = lmsAuthErrorKindForStatus(status)
+  if (fromStatus !== null || !isMoodleException(data)) {
+    return fromStatus
+  }
+
+  const errorcode = (data as { errorcode?: unknown }).errorcode
+  return typeof errorcode === "string"
+    ? (MOODLE_AUTH_ERROR_CODES[errorcode] ?? null)
+    : null
+}
+
 async function moodleRequest(
   http: HttpPort,
   draft: LmsConnectionDraft,
@@ -82,7 +131,12 @@ async function moodleRequest(
     response.status >= 300 ||
     isMoodleException(data)
   ) {
-    throw new Error(`Moodle request failed for ${fn}.`)
+    const detail = describeMoodleException(data)
+    const message = `Moodle request failed for ${fn}${detail || "."}`
+    const authKind = resolveMoodleAuthErrorKind(response.status, data)
+    throw authKind === null
+      ? new Error(message)
+      : new LmsAuthError(authKind, "moodle", message)
   }
 
   return data
@@ -127,6 +181,16 @@ function moodleStaffEnrollmentType(user: unknown): string | null {
   return null
 }
 
+// Site info identifies the token owner but never includes their email.
+function toTokenUser(siteInfo: unknown): LmsTokenUser {
+  const record = (siteInfo ?? {}) as { fullname?: unknown }
+
+  return {
+    name: typeof record.fullname === "string" ? record.fullname : null,
+    email: null,
+  }
+}
+
 function toRemoteMember(user: unknown): RemoteLmsMember {
   const record = (user ?? {}) as {
     id?: unknown
@@ -162,84 +226,87 @@ function toRemoteMember(user: unknown): RemoteLmsMember {
   }
 }
 
-function toGroupSetSummary(grouping: unknown): LmsGroupSetSummary {
+function toGroupingIdParams(groupingIds: readonly string[]) {
+  return Object.fromEntries(
+    groupingIds.map((id, index) => [`groupingids[${index}]`, id]),
+  )
+}
+
+function toGroupSetSummary(
+  grouping: unknown,
+  groupCounts: ReadonlyMap<string, number>,
+): LmsGroupSetSummary {
   const record = (grouping ?? {}) as {
     id?: unknown
     name?: unknown
-    groupcount?: unknown
   }
+  const id = String(record.id ?? "")
 
   return {
-    id: String(record.id ?? ""),
+    id,
     name: typeof record.name === "string" ? record.name : "Untitled Group Set",
-    groupCount: typeof record.groupcount === "number" ? record.groupcount : 0,
+    groupCount: groupCounts.get(id) ?? 0,
   }
 }
 
-function isGroupInGrouping(group: unknown, groupSetId: string): boolean {
-  const record = (group ?? {}) as {
-    groupingid?: unknown
-    groupingids?: unknown
-  }
-
-  if (String(record.groupingid ?? "") === groupSetId) {
-    return true
-  }
-
-  if (Array.isArray(record.groupingids)) {
-    return record.groupingids.some((value) => String(value) === groupSetId)
-  }
-
-  return false
+function toGroupingGroups(grouping: unknown): unknown[] {
+  const groups = (grouping as { groups?: unknown } | undefined)?.groups
+  return Array.isArray(groups) ? groups : []
 }
 
-function toGroupMemberIds(members: unknown): string[] {
-  if (!Array.isArray(members)) {
-    return []
+async function fetchGroupMemberIds(
+  http: HttpPort,
+  draft: LmsConnectionDraft,
+  groupIds: readonly string[],
+  signal?: AbortSignal,
+): Promise<Map<string, string[]>> {
+  if (groupIds.length === 0) {
+    return new Map()
   }
 
-  return members.flatMap((member) => {
-    const record = member as { userid?: unknown; id?: unknown }
-    const studentId = record.userid ?? record.id
-    if (studentId === undefined || studentId === null) {
-      return []
-    }
-
-    return [String(studentId)]
-  })
+  const data = await moodleRequest(
+    http,
+    draft,
+    "core_group_get_group_members",
+    Object.fromEntries(groupIds.map((id, index) => [`groupids[${index}]`, id])),
+    signal,
+  )
+
+  const entries = Array.isArray(data) ? data : []
+  return new Map(
+    entries.map((entry) => {
+      const record = (entry ?? {}) as { groupid?: unknown; userids?: unknown }
+      const userIds = Array.isArray(record.userids)
+        ? record.userids.map((userId) => String(userId))
+        : []
+      return [String(record.groupid ?? ""), userIds]
+    }),
+  )
 }
 
-function toGroup(group: unknown): RemoteLmsGroup {
-  const record = (group ?? {}) as {
-    id?: unknown
-    name?: unknown
-    members?: unknown
-  }
-
-  const lmsGroupId = String(record.id ?? "")
+export function createMoodleClient(
+  transport: HttpPort,
+  options?: LmsClientOptions,
+): LmsClient {
+  const http = createRateLimitedHttpPort(
+    createTimeoutHttpPort(transport, options),
+    options,
+  )
 
-  return {
-    id: lmsGroupId,
-    name: typeof record.name === "string" ? record.name : "Untitled Group",
-    memberLmsUserIds: toGroupMemberIds(record.members),
-  }
-}
-
-export function createMoodleClient(http: HttpPort): LmsClient {
-  return {
+  const client: LmsClient = {
     async verifyConnection(
       draft: LmsConnectionDraft,
       signal?: AbortSignal,
-    ): Promise<{ verified: boolean }> {
+    ): Promise<LmsConnectionVerification> {
       try {
-        await moodleRequest(
+        const siteInfo = await moodleRequest(
           http,
           draft,
           "core_webservice_get_site_info",
           {},
           signal,
         )
-        return { verified: true }
+        return { verified: true, user: toTokenUser(siteInfo) }
       } catch {
         return { verified: false }
       }
@@ -248,7 +315,11 @@ export function createMoodleClient(http: HttpPort): LmsClient {
     async listCourses(
       draft: LmsConnectionDraft,
       signal?: AbortSignal,
+      filter?: LmsCourseFilter,
     ): Promise<LmsCourseSummary[]> {
+      if (!isEmptyLmsCourseFilter(filter)) {
+        throw unsupportedMoodleOperation("course filters")
+      }
       const data = await moodleRequest(
         http,
         draft,
@@ -301,6 +372,18 @@ export function createMoodleClient(http: HttpPort): LmsClient {
       return [...studentInputs, ...staffInputs]
     },
 
+    async fetchRosterPage(
+      draft: LmsConnectionDraft,
+      courseId: string,
+      request: LmsPageRequest,
+      signal?: AbortSignal,
+    ): Promise<LmsPage<RemoteLmsMember>> {
+      return sliceIntoPage(
+        await client.fetchRoster(draft, courseId, signal),
+        request,
+      )
+    },
+
     async listGroupSets(
       draft: LmsConnectionDraft,
       courseId: string,
@@ -314,11 +397,31 @@ export function createMoodleClient(http: HttpPort): LmsClient {
         signal,
       )
 
-      if (!Array.isArray(data)) {
+      if (!Array.isArray(data) || data.length === 0) {
         return []
       }
 
-      return data.map(toGroupSetSummary)
+      // Course groupings omit their groups; a second call counts them.
+      const withGroups = await moodleRequest(
+        http,
+        draft,
+        "core_group_get_groupings",
+        {
+          ...toGroupingIdParams(
+            data.map((grouping) => String(grouping?.id ?? "")),
+          ),
+          returngroups: "1",
+        },
+        signal,
+      )
+      const groupCounts = new Map(
+        (Array.isArray(withGroups) ? withGroups : []).map((grouping) => [
+          String(grouping?.id ?? ""),
+          toGroupingGroups(grouping).length,
+        ]),
+      )
+
+      return data.map((grouping) => toGroupSetSummary(grouping, groupCounts))
     },
 
     async fetchGroupSet(
@@ -329,43 +432,63 @@ export function createMoodleClient(http: HttpPort): LmsClient {
       onProgress?: (message: string) => void,
     ): Promise<LmsFetchedGroupSet> {
       onProgress?.("Fetching LMS group set data.")
-      const [groupings, groups] = await Promise.all([
-        moodleRequest(
-          http,
-          draft,
-          "core_group_get_course_groupings",
-          { courseid: courseId },
-          signal,
-        ),
-        moodleRequest(
-          http,
-          draft,
-          "core_group_get_course_groups",
-          { courseid: courseId },
-          signal,
-        ),
-      ])
-
-      const groupingList = Array.isArray(groupings) ? groupings : []
-      const groupList = Array.isArray(groups) ? groups : []
-      const matchedGroups = groupList
-        .filter((group) => isGroupInGrouping(group, groupSetId))
-        .map(toGroup)
-      onProgress?.(`Loaded ${matchedGroups.length} groups from LMS.`)
-      const grouping = groupingList.find(
-        (item) => String((item as { id?: unknown }).id ?? "") === groupSetId,
+      const groupings = await moodleRequest(
+        http,
+        draft,
+        "core_group_get_groupings",
+        { ...toGroupingIdParams([groupSetId]), returngroups: "1" },
+        signal,
+      )
+
+      const grouping = (Array.isArray(groupings) ? groupings : []).find(
+        (item) => {
+          const record = (item ?? {}) as { id?: unknown; courseid?: unknown }
+          return (
+            String(record.id ?? "") === groupSetId &&
+            (record.courseid === undefined ||
+              String(record.courseid) === courseId)
+          )
+        },
       ) as { name?: unknown } | undefined
+      if (grouping === undefined) {
+        throw new Error(
+          `Moodle grouping ${groupSetId} was not found in course ${courseId}.`,
+        )
+      }
+
+      const groups = toGroupingGroups(grouping).map((group) => {
+        const record = (group ?? {}) as { id?: unknown; name?: unknown }
+        return {
+          id: String(record.id ?? ""),
+          name:
+            typeof record.name === "string" ? record.name : "Untitled Group",
+        }
+      })
+      const memberIdsByGroupId = await fetchGroupMemberIds(
+        http,
+        draft,
+        groups.map((group) => group.id),
+        signal,
+      )
+      onProgress?.(`Loaded ${groups.length} groups from LMS.`)
 
       return {
         groupSet: {
           id: groupSetId,
           name:
-            typeof grouping?.name === "string"
+            typeof grouping.name === "string"
               ? grouping.name
               : `Group Set ${groupSetId}`,
         },
-        groups: matchedGroups,
+        groups: groups.map(
+          (group): RemoteLmsGroup => ({
+            ...group,
+            memberLmsUserIds: memberIdsByGroupId.get(group.id) ?? [],
+          }),
+        ),
       }
     },
   }
+
+  return client
 }
@@ -0,0 +1,30 @@
+import type {
+  LmsPage,
+  LmsPageRequest,
+} from "@repo-edu/integrations-lms-contract"
+
+export function normalizePageRequest(request: LmsPageRequest): LmsPageRequest {
+  return {
+    page: Math.max(1, Math.floor(request.page)),
+    perPage: Math.max(1, Math.floor(request.perPage)),
+  }
+}
+
+// For providers that return whole collections in one response.
+export function sliceIntoPage<TItem>(
+  items: readonly TItem[],
+  request: LmsPageRequest,
+): LmsPage<TItem> {
+  const { page, perPage } = normalizePageRequest(request)
+  const start = (page - 1) * perPage
+  return {
+    items: items.slice(start, start + perPage),
+    pagination: {
+      page,
+      perPage,
+      hasNext: start + perPage < items.length,
+      totalCount: items.length,
+      nextCursor: null,
+    },
+  }
+}
@@ -0,0 +1,209 @@
+import { sleepWithSignal } from "@repo-edu/concurrency"
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import type { LmsClientOptions } from "./client-options.js"
+import { redactRequestUrl } from "./request-log.js"
+
+export type LmsRateLimitStrategy = "fixed" | "respect-header" | "exponential"
+
+/**
+ * Randomizes retry delays so concurrent requests throttled together do not
+ * retry together. `full` picks a delay between zero and the strategy delay;
+ * `decorrelated` picks between `baseDelayMs` and three times the previous
+ * delay (the AWS "decorrelated jitter" scheme).
+ */
+export type LmsJitterStrategy = "none" | "full" | "decorrelated"
+
+export type LmsRetryConfig = {
+  maxAttempts: number
+  strategy: LmsRateLimitStrategy
+  baseDelayMs: number
+  maxDelayMs: number
+  jitter: LmsJitterStrategy
+}
+
+export type LmsSleep = (delayMs: number, signal?: AbortSignal) => Promise<void>
+
+export const defaultLmsRetryConfig: LmsRetryConfig = {
+  maxAttempts: 3,
+  strategy: "respect-header",
+  baseDelayMs: 500,
+  maxDelayMs: 30_000,
+  jitter: "full",
+}
+
+export class LmsRateLimitedError extends Error {
+  readonly status: number
+  readonly retryAfterMs: number | null
+
+  constructor(status: number, retryAfterMs: number | null) {
+    super(`LMS request was rate limited with status ${status}.`)
+    this.name = "LmsRateLimitedError"
+    this.status = status
+    this.retryAfterMs = retryAfterMs
+  }
+}
+
+export type RateLimitDetector = (response: HttpResponse) => boolean
+
+export function isTooManyRequestsResponse(response: HttpResponse): boolean {
+  return response.status === 429
+}
+
+// Accepts both forms allowed by RFC 9110: delay-seconds and an HTTP-date.
+export function parseRetryAfterMs(
+  value: string | undefined,
+  now: number = Date.now(),
+): number | null {
+  if (value === undefined) {
+    return null
+  }
+
+  const trimmed = value.trim()
+  if (/^\d+$/.test(trimmed)) {
+    return Number(trimmed) * 1000
+  }
+
+  const date = Date.parse(trimmed)
+  if (Number.isNaN(date)) {
+    return null
+  }
+
+  return Math.max(0, date - now)
+}
+
+export function resolveRateLimitDelayMs(
+  config: LmsRetryConfig,
+  attempt: number,
+  retryAfterMs: number | null,
+): number {
+  const exponential = config.baseDelayMs * 2 ** (attempt - 1)
+
+  switch (config.strategy) {
+    case "fixed":
+      return Math.min(config.baseDelayMs, config.maxDelayMs)
+    case "exponential":
+      return Math.min(exponential, config.maxDelayMs)
+    case "respect-header":
+      return Math.min(retryAfterMs ?? exponential, config.maxDelayMs)
+  }
+}
+
+/**
+ * Applies the configured jitter to a strategy delay. `random` returns values
+ * in `[0, 1)`, like `Math.random`.
+ */
+export function applyRetryJitter(
+  config: LmsRetryConfig,
+  delayMs: number,
+  previousDelayMs: number,
+  random: () => number = Math.random,
+): number {
+  switch (config.jitter) {
+    case "none":
+      return delayMs
+    case "full":
+      return Math.floor(random() * delayMs)
+    case "decorrelated": {
+      const upper = Math.max(config.baseDelayMs, previousDelayMs * 3)
+      const delay =
+        config.baseDelayMs + random() * (upper - config.baseDelayMs)
+      return Math.floor(Math.min(delay, config.maxDelayMs))
+    }
+  }
+}
+
+export function resolveLmsRetryConfig(
+  options: LmsClientOptions | undefined,
+): LmsRetryConfig {
+  return { ...defaultLmsRetryConfig, ...options?.retry }
+}
+
+/**
+ * Wraps an `HttpPort` so rate-limited responses are retried according to the
+ * configured strategy. Once attempts are exhausted the last rate-limited
+ * response surfaces as an `LmsRateLimitedError`. Delays taken from a
+ * Retry-After header are honored as sent; all others get the configured
+ * jitter. Every attempt and retry is reported to `options.onRequestLog` when
+ * set.
+ */
+export function createRateLimitedHttpPort(
+  http: HttpPort,
+  options: LmsClientOptions | undefined,
+  isRateLimited: RateLimitDetector = isTooManyRequestsResponse,
+): HttpPort {
+  const config = resolveLmsRetryConfig(options)
+  const sleep = options?.sleep ?? sleepWithSignal
+  const maxAttempts = Math.max(1, config.maxAttempts)
+  const log = options?.onRequestLog
+  const random = options?.random ?? Math.random
+
+  return {
+    async fetch(request: HttpRequest): Promise<HttpResponse> {
+      const requestId = log ? crypto.randomUUID() : ""
+      const method = request.method ?? "GET"
+      const url = log ? redactRequestUrl(request.url) : ""
+      let previousDelayMs = config.baseDelayMs
+
+      for (let attempt = 1; ; attempt += 1) {
+        const startedAt = Date.now()
+        let response: HttpResponse
+        try {
+          response = await http.fetch(request)
+        } catch (error) {
+          log?.({
+            kind: "failure",
+            requestId,
+            method,
+            url,
+            attempt,
+            message: error instanceof Error ? error.message : String(error),
+            durationMs: Date.now() - startedAt,
+          })
+          throw error
+        }
+        log?.({
+          kind: "response",
+          requestId,
+          method,
+          url,
+          attempt,
+          status: response.status,
+          durationMs: Date.now() - startedAt,
+        })
+        if (!isRateLimited(response)) {
+          return response
+        }
+
+        const retryAfterMs = parseRetryAfterMs(response.headers["retry-after"])
+        if (attempt >= maxAttempts) {
+          throw new LmsRateLimitedError(response.status, retryAfterMs)
+        }
+
+        const strategyDelayMs = resolveRateLimitDelayMs(
+          config,
+          attempt,
+          retryAfterMs,
+        )
+        const delayMs =
+          config.strategy === "respect-header" && retryAfterMs !== null
+            ? strategyDelayMs
+            : applyRetryJitter(config, strategyDelayMs, previousDelayMs, random)
+        previousDelayMs = delayMs
+        log?.({
+          kind: "retry",
+          requestId,
+          method,
+          url,
+          attempt,
+          status: response.status,
+          delayMs,
+        })
+        await sleep(delayMs, request.signal)
+      }
+    },
+  }
+}
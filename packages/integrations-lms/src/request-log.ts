@@ -0,0 +1,53 @@
+// Request log events never carry headers or bodies, so `Authorization`
+// values and form-encoded credentials stay out of logs by construction.
+
+type LmsRequestLogBase = {
+  /** Shared by every attempt of one logical request. */
+  requestId: string
+  method: string
+  /** Request URL with credential-bearing query parameters redacted. */
+  url: string
+  attempt: number
+}
+
+export type LmsRequestLogEvent =
+  | (LmsRequestLogBase & {
+      kind: "response"
+      status: number
+      durationMs: number
+    })
+  | (LmsRequestLogBase & {
+      kind: "failure"
+      message: string
+      durationMs: number
+    })
+  | (LmsRequestLogBase & {
+      kind: "retry"
+      status: number
+      delayMs: number
+    })
+
+export type LmsRequestLogger = (event: LmsRequestLogEvent) => void
+
+const sensitiveQueryParamPattern = /token|key|secret|password/i
+
+export const redactedValue = "REDACTED"
+
+/** Masks token-like query parameters such as Moodle's `wstoken`. */
+export function redactRequestUrl(url: string): string {
+  let parsed: URL
+  try {
+    parsed = new URL(url)
+  } catch {
+    return url
+  }
+
+  let redacted = false
+  for (const name of [...parsed.searchParams.keys()]) {
+    if (sensitiveQueryParamPattern.test(name)) {
+      parsed.searchParams.set(name, redactedValue)
+      redacted = true
+    }
+  }
+  return redacted ? parsed.toString() : url
+}
@@ -0,0 +1,78 @@
+import type {
+  HttpPort,
+  HttpRequest,
+  HttpResponse,
+} from "@repo-edu/host-runtime-contract"
+import type { LmsClientOptions } from "./client-options.js"
+import { redactRequestUrl } from "./request-log.js"
+
+export const defaultLmsRequestTimeoutMs = 30_000
+
+export class LmsRequestTimeoutError extends Error {
+  /** Request URL with credential-bearing query parameters redacted. */
+  readonly url: string
+  readonly elapsedMs: number
+
+  constructor(url: string, elapsedMs: number) {
+    super(`LMS request to ${url} timed out after ${elapsedMs} ms.`)
+    this.name = "LmsRequestTimeoutError"
+    this.url = url
+    this.elapsedMs = elapsedMs
+  }
+}
+
+export function isLmsRequestTimeoutError(
+  error: unknown,
+): error is LmsRequestTimeoutError {
+  return error instanceof LmsRequestTimeoutError
+}
+
+export function resolveLmsRequestTimeoutMs(
+  options: LmsClientOptions | undefined,
+): number {
+  return Math.max(1, options?.timeoutMs ?? defaultLmsRequestTimeoutMs)
+}
+
+/**
+ * Wraps an `HttpPort` so each request fails with `LmsRequestTimeoutError`
+ * once `options.timeoutMs` elapses. The request signal is aborted on timeout,
+ * and the wrapper rejects even when the port ignores the signal. Aborts from
+ * the caller's own signal pass through unchanged.
+ */
+export function createTimeoutHttpPort(
+  http: HttpPort,
+  options: LmsClientOptions | undefined,
+): HttpPort {
+  const timeoutMs = resolveLmsRequestTimeoutMs(options)
+
+  return {
+    async fetch(request: HttpRequest): Promise<HttpResponse> {
+      const controller = new AbortController()
+      const signal = request.signal
+        ? AbortSignal.any([request.signal, controller.signal])
+        : controller.signal
+      const startedAt = Date.now()
+      let timer: ReturnType<typeof setTimeout> | undefined
+
+      const timedOut = new Promise<never>((_, reject) => {
+        timer = setTimeout(() => {
+          const error = new LmsRequestTimeoutError(
+            redactRequestUrl(request.url),
+            Date.now() - startedAt,
+          )
+          reject(error)
+          controller.abort(error)
+        }, timeoutMs)
+      })
+
+      try {
+        return await Promise.race([
+          http.fetch({ ...request, signal }),
+          timedOut,
+        ])
+      } finally {
+        clearTimeout(timer)
+      }
+    },
+  }
+}
@@ -1,30 +1,47 @@
 import assert from "node:assert/strict"
 import { describe, it } from "node:test"
-import type { compileGroupNamePattern } from "@repo-edu/domain/pattern-matching"
+import type { compileGroupSelection } from "@repo-edu/domain/group-selection"
+import type { PersistedGroupSelection } from "@repo-edu/domain/settings"
 import type { ValidationResult } from "@repo-edu/domain/types"
 import {
+  groupSelectionFromInput,
   matchGroupIndexes,
   scheduleGroupIndexMatch,
 } from "../components/dialogs/NewLocalGroupSetDialog.js"
 
+function patternSelection(
+  patterns: string[],
+  options: { matchCase?: boolean; useRegex?: boolean } = {},
+): PersistedGroupSelection {
+  return {
+    mode: { kind: "multi-pattern", patterns },
+    matchCase: options.matchCase ?? true,
+    useRegex: options.useRegex ?? false,
+  }
+}
+
 describe("new local group-set pattern selection", () => {
   it("exposes and selects every source group for blank input without compiling", () => {
     let compilationCount = 0
-    const compile: typeof compileGroupNamePattern = () => {
+    const compile: typeof compileGroupSelection = () => {
       compilationCount += 1
       return { ok: true, value: () => false }
     }
     const groups = [{ name: "Alpha" }, { name: "Beta" }, { name: "Gamma" }]
 
-    const result = matchGroupIndexes("", groups, compile)
+    const result = matchGroupIndexes(
+      groupSelectionFromInput("", true, false),
+      groups,
+      compile,
+    )
 
     assert.deepEqual(result, { ok: true, value: [0, 1, 2] })
     assert.equal(compilationCount, 0)
   })
 
-  it("compiles a nonblank pattern once and reuses its predicate", () => {
+  it("compiles a pattern list once and reuses its predicate", () => {
     let compilationCount = 0
-    const compile: typeof compileGroupNamePattern = () => {
+    const compile: typeof compileGroupSelection = () => {
       compilationCount += 1
       return { ok: true, value: (value) => value.startsWith("Team-") }
     }
@@ -34,18 +51,90 @@ describe("new local group-set pattern selection", () => {
       { name: "Team-Two" },
     ]
 
-    const result = matchGroupIndexes("Team-*", groups, compile)
+    const result = matchGroupIndexes(
+      patternSelection(["Team-*", "Lab-*"]),
+      groups,
+      compile,
+    )
 
     assert.deepEqual(result, { ok: true, value: [0, 2] })
     assert.equal(compilationCount, 1)
   })
 
+  it("reads one pattern per line and keeps commas literal", () => {
+    assert.deepEqual(
+      groupSelectionFromInput(" Lab, Section *\n\nExam \n", false, true),
+      patternSelection(["Lab, Section *", "Exam"], {
+        matchCase: false,
+        useRegex: true,
+      }),
+    )
+  })
+
+  it("matches any listed pattern", () => {
+    const groups = [
+      { name: "Lab, Section 1" },
+      { name: "Staff" },
+      { name: "Exam" },
+      { name: "Lab" },
+    ]
+
+    assert.deepEqual(
+      matchGroupIndexes(patternSelection(["Lab, Section *", "Exam"]), groups),
+      { ok: true, value: [0, 2] },
+    )
+  })
+
+  it("matches capitalized names case-insensitively", () => {
+    const groups = [{ name: "Team A" }, { name: "Staff" }, { name: "team b" }]
+
+    assert.deepEqual(
+      matchGroupIndexes(
+        patternSelection(["team*"], { matchCase: false }),
+        groups,
+      ),
+      { ok: true, value: [0, 2] },
+    )
+    assert.deepEqual(matchGroupIndexes(patternSelection(["team*"]), groups), {
+      ok: true,
+      value: [2],
+    })
+  })
+
+  it("applies the scheduled selection once the delay elapses", (context) => {
+    context.mock.timers.enable({ apis: ["setTimeout"] })
+    const results: ValidationResult<number[]>[] = []
+
+    scheduleGroupIndexMatch(
+      patternSelection(["team*"], { matchCase: false }),
+      [{ name: "Team-One" }, { name: "Staff" }],
+      (result) => results.push(result),
+    )
+    context.mock.timers.tick(400)
+
+    assert.deepEqual(results, [{ ok: true, value: [0] }])
+  })
+
+  it("schedules regex selections", (context) => {
+    context.mock.timers.enable({ apis: ["setTimeout"] })
+    const results: ValidationResult<number[]>[] = []
+
+    scheduleGroupIndexMatch(
+      patternSelection(["^team-\\d"], { matchCase: false, useRegex: true }),
+      [{ name: "Team-1" }, { name: "Team-A" }, { name: "Old Team-2" }],
+      (result) => results.push(result),
+    )
+    context.mock.timers.tick(400)
+
+    assert.deepEqual(results, [{ ok: true, value: [0] }])
+  })
+
   it("cancels a pending match before a reset can be overwritten", (context) => {
     context.mock.timers.enable({ apis: ["setTimeout"] })
     const results: ValidationResult<number[]>[] = []
 
     const cancel = scheduleGroupIndexMatch(
-      "Team-*",
+      patternSelection(["Team-*"]),
       [{ name: "Team-One" }, { name: "Staff" }],
       (result) => results.push(result),
     )
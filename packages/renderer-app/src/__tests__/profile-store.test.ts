@@ -452,6 +452,131 @@ describe("course store", () => {
     assert.equal(renamedGroup?.name, "s.o.s.van.den.berg")
   })
 
+  it("copies an assignment with a fresh id and no recorded repositories", async () => {
+    const course = makeProfile()
+    course.roster.assignments = [
+      {
+        id: "a_0001",
+        name: "Lab 1",
+        groupSetId: "gs_0001",
+        templateCommitSha: "abc123",
+        repositories: { g_0001: "lab-1-team-a" },
+      },
+    ]
+    course.idSequences.nextAssignmentSeq = 2
+    const client = createWorkflowClient({
+      "course.load": async () => course,
+      "course.save": async (current) => saveStamp(current),
+    })
+    setWorkflowClient(client as unknown as WorkflowClient)
+    useCourseStore.getState().hydrate(course)
+
+    const { copyAssignment } = useCourseStore.getState()
+    const copyId = copyAssignment("a_0001", "Lab 1 (copy)")
+
+    const state = useCourseStore.getState()
+    const copy = state.course?.roster.assignments.find((a) => a.id === copyId)
+    assert.ok(copy)
+    assert.notEqual(copy.id, "a_0001")
+    assert.equal(copy.name, "Lab 1 (copy)")
+    assert.equal(copy.groupSetId, "gs_0001")
+    assert.equal(copy.templateCommitSha, null)
+    assert.deepEqual(copy.repositories, {})
+    assert.deepEqual(state.course?.roster.assignments[0]?.repositories, {
+      g_0001: "lab-1-team-a",
+    })
+    assert.equal(state.history.length, 1)
+    assert.equal(copyAssignment("missing", "Nope"), null)
+  })
+
+  it("deactivates or purges removed members", async () => {
+    const course = makeProfile()
+    course.roster.groups = [
+      {
+        id: "g_0001",
+        name: "Team A",
+        memberIds: ["s-1"],
+        origin: "local",
+        lmsGroupId: null,
+      },
+    ]
+    const client = createWorkflowClient({
+      "course.load": async () => course,
+      "course.save": async (current) => saveStamp(current),
+    })
+    setWorkflowClient(client as unknown as WorkflowClient)
+    useCourseStore.getState().hydrate(course)
+
+    useCourseStore.getState().removeMember("s-1")
+    let roster = useCourseStore.getState().course?.roster
+    assert.equal(roster?.students[0]?.status, "dropped")
+    assert.deepEqual(roster?.groups[0]?.memberIds, ["s-1"])
+
+    useCourseStore.getState().deleteMemberPermanently("s-1")
+    roster = useCourseStore.getState().course?.roster
+    assert.equal(roster?.students.length, 0)
+    assert.deepEqual(roster?.groups[0]?.memberIds, [])
+
+    useCourseStore.getState().deleteMemberPermanently("missing")
+    assert.equal(useCourseStore.getState().history.length, 2)
+  })
+
+  it("splits a group in half and merges it back", async () => {
+    const course = makeProfile()
+    course.roster.students.push(
+      makeStudent("s-2", "Grace Hopper"),
+      makeStudent("s-3", "Alan Turing"),
+    )
+    course.roster.groups = [
+      {
+        id: "g_0001",
+        name: "Team A",
+        memberIds: ["s-1", "s-2", "s-3"],
+        origin: "local",
+        lmsGroupId: null,
+      },
+    ]
+    course.roster.groupSets = [
+      {
+        id: "gs_0001",
+        name: "Teams",
+        nameMode: "named",
+        groupIds: ["g_0001"],
+        connection: null,
+        repoNameTemplate: null,
+        columnVisibility: {},
+        columnSizing: {},
+      },
+    ]
+    course.idSequences.nextGroupSeq = 2
+    const client = createWorkflowClient({
+      "course.load": async () => course,
+      "course.save": async (current) => saveStamp(current),
+    })
+    setWorkflowClient(client as unknown as WorkflowClient)
+    useCourseStore.getState().hydrate(course)
+
+    const createdId = useCourseStore.getState().splitGroup("gs_0001", "g_0001")
+    let state = useCourseStore.getState()
+    assert.equal(createdId, "g_0002")
+    assert.equal(state.course?.idSequences.nextGroupSeq, 3)
+    assert.deepEqual(
+      state.course?.roster.groups.map((group) => group.memberIds),
+      [["s-1", "s-2"], ["s-3"]],
+    )
+    const groupSet = state.course?.roster.groupSets[0]
+    assert.deepEqual(
+      groupSet?.nameMode === "named" ? groupSet.groupIds : [],
+      ["g_0001", "g_0002"],
+    )
+
+    useCourseStore.getState().mergeGroups("gs_0001", "g_0001", "g_0002")
+    state = useCourseStore.getState()
+    assert.deepEqual(state.course?.roster.groups, course.roster.groups)
+    assert.equal(state.history.length, 2)
+    assert.equal(useCourseStore.getState().splitGroup("gs_0001", "x"), null)
+  })
+
   it("keeps local updates in the in-memory course document", async () => {
     const course = makeProfile()
     const client = createWorkflowClient({
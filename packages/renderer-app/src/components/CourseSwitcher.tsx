@@ -1,3 +1,6 @@
+import type {
+  CourseBundleConflictResolution,
+} from "@repo-edu/application-contract"
 import {
   activeSurfaceEquals,
   type SubmissionFolderRecent,
@@ -29,11 +32,13 @@ import {
 import {
   ChevronDown,
   Copy,
+  Download,
   FolderOpen,
   Home,
   Loader2,
   Pencil,
   Trash2,
+  Upload,
   X,
 } from "@repo-edu/ui/components/icons"
 import {
@@ -103,6 +108,8 @@ export function CourseSwitcher() {
     duplicateCourse,
     renameCourse,
     deleteCourse,
+    exportCourses,
+    importCourses,
   } = useCourses()
   const [open, setOpen] = useState(false)
 
@@ -150,6 +157,11 @@ export function CourseSwitcher() {
     name: string
   }>({ open: false, id: "", name: "" })
 
+  const [importDialog, setImportDialog] = useState<{
+    open: boolean
+    isProcessing: boolean
+  }>({ open: false, isProcessing: false })
+
   const handleCourseSelect = (course: CourseSummary) => {
     const id = course.id
     if (activeSurfaceEquals(activeSurface, { kind: "course", courseId: id })) {
@@ -274,6 +286,24 @@ export function CourseSwitcher() {
     setDeleteDialog({ open: false, id: "", name: "" })
   }
 
+  const handleExportCourses = () => {
+    setOpen(false)
+    void exportCourses(activeCourseId)
+  }
+
+  const handleImportCoursesClick = () => {
+    setOpen(false)
+    setImportDialog({ open: true, isProcessing: false })
+  }
+
+  const handleImportCoursesConfirm = async (
+    conflict: CourseBundleConflictResolution,
+  ) => {
+    setImportDialog({ open: true, isProcessing: true })
+    const imported = await importCourses(conflict)
+    setImportDialog({ open: !imported, isProcessing: false })
+  }
+
   const handleHomeSelect = () => {
     if (isHomeSurface) {
       setOpen(false)
@@ -584,6 +614,42 @@ export function CourseSwitcher() {
               {renderRecentSubmissionRows()}
             </>
           )}
+
+          <DropdownMenuSeparator className="my-0.5" />
+          {hasCourses && (
+            <div
+              role="option"
+              tabIndex={0}
+              aria-selected={false}
+              onClick={handleExportCourses}
+              onKeyDown={(event) =>
+                handleRowKeyDown(event, handleExportCourses)
+              }
+              className={cn(
+                "flex items-center gap-1 rounded-sm px-2 py-1.5 text-xs cursor-pointer",
+                "hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground focus:outline-none",
+              )}
+            >
+              <Download className="size-3" />
+              Export all courses...
+            </div>
+          )}
+          <div
+            role="option"
+            tabIndex={0}
+            aria-selected={false}
+            onClick={handleImportCoursesClick}
+            onKeyDown={(event) =>
+              handleRowKeyDown(event, handleImportCoursesClick)
+            }
+            className={cn(
+              "flex items-center gap-1 rounded-sm px-2 py-1.5 text-xs cursor-pointer",
+              "hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground focus:outline-none",
+            )}
+          >
+            <Upload className="size-3" />
+            Import courses...
+          </div>
         </DropdownMenuContent>
       </DropdownMenu>
 
@@ -703,6 +769,58 @@ export function CourseSwitcher() {
         </DialogContent>
       </Dialog>
 
+      <Dialog
+        open={importDialog.open}
+        onOpenChange={(nextOpen) => {
+          if (!importDialog.isProcessing) {
+            setImportDialog({ open: nextOpen, isProcessing: false })
+          }
+        }}
+      >
+        <DialogContent className="max-w-md">
+          <DialogHeader>
+            <DialogTitle>Import Courses</DialogTitle>
+          </DialogHeader>
+          <p className="text-xs text-muted-foreground">
+            Choose how to handle bundled courses that already exist, then pick
+            the bundle file.
+          </p>
+          <DialogFooter>
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={importDialog.isProcessing}
+              onClick={() => void handleImportCoursesConfirm("skip")}
+            >
+              Skip existing
+            </Button>
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={importDialog.isProcessing}
+              onClick={() => void handleImportCoursesConfirm("overwrite")}
+            >
+              Overwrite
+            </Button>
+            <Button
+              variant="outline"
+              size="sm"
+              disabled={importDialog.isProcessing}
+              onClick={() => void handleImportCoursesConfirm("merge")}
+            >
+              Merge rosters
+            </Button>
+            <Button
+              size="sm"
+              disabled={importDialog.isProcessing}
+              onClick={() => void handleImportCoursesConfirm("rename")}
+            >
+              Keep both
+            </Button>
+          </DialogFooter>
+        </DialogContent>
+      </Dialog>
+
       <AlertDialog
         open={deleteDialog.open}
         onOpenChange={(nextOpen) =>
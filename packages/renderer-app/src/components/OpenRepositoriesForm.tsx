@@ -326,7 +326,7 @@ export function OpenRepositoriesForm() {
         description={
           <>
             Pulls roster, groups and repos from your Learning Management System
-            (LMS), such as Canvas or Moodle. Use this for any course (current or
+            (LMS), such as Canvas, Moodle or Blackboard. Use this for any course (current or
             past term) where the LMS holds the authoritative roster.
           </>
         }
@@ -355,8 +355,8 @@ export function OpenRepositoriesForm() {
             <AlertDescription>
               <Text>
                 No Learning Management System connections configured. Add a
-                Canvas or Moodle connection in Settings to import a course and
-                roster.
+                Canvas, Moodle, Blackboard or Brightspace connection in Settings
+                to import a course and roster.
               </Text>
               <div className="mt-2">
                 <button
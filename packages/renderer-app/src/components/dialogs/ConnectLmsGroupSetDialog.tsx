@@ -35,6 +35,8 @@ function connectedExternalId(
   if (!connection) return null
   if (connection.kind === "canvas") return connection.groupSetId
   if (connection.kind === "moodle") return connection.groupingId
+  if (connection.kind === "blackboard") return connection.groupSetId
+  if (connection.kind === "brightspace") return connection.groupSetId
   return null
 }
 
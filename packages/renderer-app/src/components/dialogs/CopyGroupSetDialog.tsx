@@ -17,6 +17,7 @@ import {
   useCourseStore,
 } from "../../stores/course-store.js"
 import { useUiStore } from "../../stores/ui-store.js"
+import { isLmsGroupSetConnection } from "../../utils/lms-provider.js"
 
 export function CopyGroupSetDialog() {
   const sourceId = useUiStore((state) => state.copyGroupSetSourceId)
@@ -40,8 +41,7 @@ export function CopyGroupSetDialog() {
   }
 
   const isLmsOrSystem =
-    groupSet?.connection?.kind === "canvas" ||
-    groupSet?.connection?.kind === "moodle" ||
+    isLmsGroupSetConnection(groupSet?.connection) ||
     groupSet?.connection?.kind === "system"
 
   const trimmedName = name.trim()
@@ -42,7 +42,7 @@ export function ImportStudentsFromFileDialog() {
       const host = getRendererHost()
       const ref = await host.pickUserFile({
         title: "Select file to import",
-        acceptFormats: ["csv", "xlsx"],
+        acceptFormats: ["csv", "xlsx", "yaml"],
       })
       if (ref) {
         setFileRef(ref)
@@ -104,7 +104,7 @@ export function ImportStudentsFromFileDialog() {
 
         <div className="flex flex-col gap-4 py-4">
           <p className="text-sm text-muted-foreground">
-            Import students from CSV or Excel file.
+            Import students from CSV, Excel or YAML file.
           </p>
 
           <div className="text-sm">
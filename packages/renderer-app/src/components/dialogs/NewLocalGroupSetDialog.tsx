@@ -1,4 +1,5 @@
-import { compileGroupNamePattern } from "@repo-edu/domain/pattern-matching"
+import { compileGroupSelection } from "@repo-edu/domain/group-selection"
+import type { PersistedGroupSelection } from "@repo-edu/domain/settings"
 import type { ValidationResult } from "@repo-edu/domain/types"
 import {
   Button,
@@ -10,6 +11,7 @@ import {
   DialogTitle,
   FormField,
   Input,
+  Label,
   Select,
   SelectContent,
   SelectGroup,
@@ -19,6 +21,7 @@ import {
   SelectTrigger,
   SelectValue,
   Text,
+  Textarea,
 } from "@repo-edu/ui"
 import { cn } from "@repo-edu/ui/lib/utils"
 import { useCallback, useEffect, useMemo, useState } from "react"
@@ -31,20 +34,50 @@ import {
   selectSystemGroupSet,
   useCourseStore,
 } from "../../stores/course-store.js"
+import { useAppSettingsStore } from "../../stores/app-settings-store.js"
 import { useUiStore } from "../../stores/ui-store.js"
 
-type GroupNameCompiler = typeof compileGroupNamePattern
+type GroupSelectionCompiler = typeof compileGroupSelection
+
+/**
+ * One pattern per line. Lines are trimmed and blank lines dropped; commas are
+ * part of the pattern, since group names may contain them.
+ */
+export function groupSelectionFromInput(
+  patternText: string,
+  matchCase: boolean,
+  useRegex: boolean,
+): PersistedGroupSelection {
+  const patterns = patternText
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0)
+  return {
+    mode:
+      patterns.length === 0
+        ? { kind: "all" }
+        : { kind: "multi-pattern", patterns },
+    matchCase,
+    useRegex,
+  }
+}
+
+function groupSelectionInputText(selection: PersistedGroupSelection): string {
+  return selection.mode.kind === "all"
+    ? ""
+    : selection.mode.patterns.join("\n")
+}
 
 export function matchGroupIndexes(
-  pattern: string,
+  selection: PersistedGroupSelection,
   groups: readonly { name: string }[],
-  compile: GroupNameCompiler = compileGroupNamePattern,
+  compile: GroupSelectionCompiler = compileGroupSelection,
 ): ValidationResult<number[]> {
-  if (pattern === "") {
+  if (selection.mode.kind === "all") {
     return { ok: true, value: groups.map((_, index) => index) }
   }
 
-  const compilation = compile(pattern)
+  const compilation = compile(selection)
   if (!compilation.ok) return compilation
 
   const matchedIndexes: number[] = []
@@ -57,12 +90,13 @@ export function matchGroupIndexes(
 }
 
 export function scheduleGroupIndexMatch(
-  pattern: string,
+  selection: PersistedGroupSelection,
   groups: readonly { name: string }[],
   onResult: (result: ValidationResult<number[]>) => void,
+  compile: GroupSelectionCompiler = compileGroupSelection,
 ): () => void {
   const timeout = setTimeout(() => {
-    onResult(matchGroupIndexes(pattern, groups))
+    onResult(matchGroupIndexes(selection, groups, compile))
   }, 400)
 
   return () => clearTimeout(timeout)
@@ -72,7 +106,9 @@ export function NewLocalGroupSetDialog() {
   const controller = useSessionController()
   const [name, setName] = useState("")
   const [sourceGroupSetId, setSourceGroupSetId] = useState<string | null>(null)
-  const [pattern, setPattern] = useState("")
+  const [patternText, setPatternText] = useState("")
+  const [matchCase, setMatchCase] = useState(true)
+  const [useRegex, setUseRegex] = useState(false)
   const [patternError, setPatternError] = useState<string | null>(null)
   const [matchedIndexes, setMatchedIndexes] = useState<number[] | null>(null)
   const [checkedGroupIds, setCheckedGroupIds] = useState<Set<string>>(new Set())
@@ -81,6 +117,9 @@ export function NewLocalGroupSetDialog() {
   const open = useUiStore((state) => state.newLocalGroupSetDialogOpen)
   const setOpen = useUiStore((state) => state.setNewLocalGroupSetDialogOpen)
   const setSidebarSelection = useUiStore((state) => state.setSidebarSelection)
+  const setGroupSelection = useAppSettingsStore(
+    (state) => state.setGroupSelection,
+  )
   const connectedSets = useCourseStore(selectConnectedGroupSets)
   const localSets = useCourseStore(selectLocalGroupSets)
   const individualStudentsSet = useCourseStore(
@@ -129,12 +168,18 @@ export function NewLocalGroupSetDialog() {
     return sets
   }, [individualStudentsSet, staffSet])
 
+  const selection = useMemo(
+    () => groupSelectionFromInput(patternText, matchCase, useRegex),
+    [patternText, matchCase, useRegex],
+  )
+  const filtered = selection.mode.kind !== "all"
+
   const visibleIndexes = useMemo(() => {
-    if (!pattern || matchedIndexes === null) {
+    if (!filtered || matchedIndexes === null) {
       return sourceGroups.map((_, i) => i)
     }
     return matchedIndexes
-  }, [pattern, matchedIndexes, sourceGroups])
+  }, [filtered, matchedIndexes, sourceGroups])
 
   const visibleGroups = useMemo(
     () => visibleIndexes.map((i) => sourceGroups[i]),
@@ -156,35 +201,34 @@ export function NewLocalGroupSetDialog() {
 
   useEffect(() => {
     if (!sourceGroupSetId) return
-    if (!pattern) {
+    if (selection.mode.kind === "all") {
       setPatternError(null)
       setMatchedIndexes(null)
       setCheckedGroupIds(new Set(sourceGroups.map((group) => group.id)))
       return
     }
 
-    return scheduleGroupIndexMatch(pattern, sourceGroups, (validation) => {
-      if (!validation.ok) {
-        setPatternError(validation.issues[0]?.message ?? "Invalid pattern")
-        setMatchedIndexes(null)
-        return
-      }
-
-      setPatternError(null)
-      setMatchedIndexes(validation.value)
-      setCheckedGroupIds(
-        new Set(validation.value.map((index) => sourceGroups[index].id)),
-      )
-    })
-  }, [pattern, sourceGroupSetId, sourceGroups])
-
-  const handlePatternChange = (value: string) => {
-    setPattern(value)
-  }
+    return scheduleGroupIndexMatch(
+      selection,
+      sourceGroups,
+      (validation) => {
+        if (!validation.ok) {
+          setPatternError(validation.issues[0]?.message ?? "Invalid pattern")
+          setMatchedIndexes(null)
+          return
+        }
+
+        setPatternError(null)
+        setMatchedIndexes(validation.value)
+        setCheckedGroupIds(
+          new Set(validation.value.map((index) => sourceGroups[index].id)),
+        )
+      },
+    )
+  }, [selection, sourceGroupSetId, sourceGroups])
 
   const handleSourceGroupSetChange = (value: string) => {
     setSourceGroupSetId(value)
-    setPattern("")
   }
 
   const handleToggleGroup = (groupId: string) => {
@@ -225,6 +269,7 @@ export function NewLocalGroupSetDialog() {
         .map((g) => g.id)
 
       const id = controller.createLocalGroupSet(courseId, trimmedName, groupIds)
+      setGroupSelection(selection)
       if (id) {
         setSidebarSelection({ kind: "group-set", id })
       }
@@ -235,9 +280,12 @@ export function NewLocalGroupSetDialog() {
   }
 
   const resetDialogState = useCallback(() => {
+    const persisted = useAppSettingsStore.getState().settings.groupSelection
     setName("")
     setSourceGroupSetId(null)
-    setPattern("")
+    setPatternText(groupSelectionInputText(persisted))
+    setMatchCase(persisted.matchCase)
+    setUseRegex(persisted.useRegex)
     setPatternError(null)
     setMatchedIndexes(null)
     setCheckedGroupIds(new Set())
@@ -400,16 +448,43 @@ export function NewLocalGroupSetDialog() {
 
           {sourceGroupSetId && (
             <div className="flex flex-col gap-2 min-h-0 flex-1">
-              <div className="flex items-center gap-2">
-                <Input
-                  value={pattern}
-                  onChange={(e) => handlePatternChange(e.target.value)}
-                  placeholder="Filter by pattern, e.g., 1D* or Team-*"
+              <div className="flex items-start gap-2">
+                <Textarea
+                  size="xs"
+                  rows={2}
+                  value={patternText}
+                  onChange={(e) => setPatternText(e.target.value)}
+                  aria-label="Group name filters, one per line"
+                  placeholder={
+                    useRegex
+                      ? "One regex per line, e.g., ^Team-\\d+$"
+                      : "One pattern per line, e.g., 1D* or Team-*"
+                  }
                   className={cn(
-                    "h-7 text-sm",
+                    "min-h-7 resize-y",
                     patternError && "border-destructive",
                   )}
                 />
+                <div className="flex items-center gap-1.5 shrink-0">
+                  <Checkbox
+                    id="group-pattern-match-case"
+                    checked={matchCase}
+                    onCheckedChange={(next) => setMatchCase(next === true)}
+                  />
+                  <Label htmlFor="group-pattern-match-case" className="text-xs">
+                    Match case
+                  </Label>
+                </div>
+                <div className="flex items-center gap-1.5 shrink-0">
+                  <Checkbox
+                    id="group-pattern-regex"
+                    checked={useRegex}
+                    onCheckedChange={(next) => setUseRegex(next === true)}
+                  />
+                  <Label htmlFor="group-pattern-regex" className="text-xs">
+                    Regex
+                  </Label>
+                </div>
                 {patternError && (
                   <p className="text-[11px] text-destructive shrink-0">
                     {patternError}
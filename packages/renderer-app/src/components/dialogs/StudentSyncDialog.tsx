@@ -1,9 +1,11 @@
 import {
   courseSupportsLms,
+  type LmsImportMatchPriority,
   type RosterImportFromLmsResult,
 } from "@repo-edu/domain/types"
 import {
   Button,
+  Checkbox,
   Dialog,
   DialogContent,
   DialogFooter,
@@ -37,8 +39,17 @@ import { lmsConnectionDisplayName } from "../settings/ConnectionsPane.shared.js"
 type RosterSyncPreview = {
   courseId: string
   result: RosterImportFromLmsResult
+  skippedPages: string[]
 }
 
+const missingEmailRatioOptions = [
+  { value: "none", label: "No limit" },
+  { value: "0", label: "0%" },
+  { value: "0.1", label: "10%" },
+  { value: "0.25", label: "25%" },
+  { value: "0.5", label: "50%" },
+] as const
+
 export function StudentSyncDialog() {
   const open = useUiStore((state) => state.rosterSyncDialogOpen)
   const setOpen = useUiStore((state) => state.setRosterSyncDialogOpen)
@@ -60,12 +71,18 @@ export function StudentSyncDialog() {
   const [preview, setPreview] = useState<RosterSyncPreview | null>(null)
   const [error, setError] = useState<string | null>(null)
   const [progressMessage, setProgressMessage] = useState<string | null>(null)
+  const [matchPriority, setMatchPriority] =
+    useState<LmsImportMatchPriority>("lmsUserId")
+  const [continueOnPageError, setContinueOnPageError] = useState(false)
+  const [maxMissingEmailRatio, setMaxMissingEmailRatio] = useState("none")
   const autoPreviewedCourseIdRef = useRef<string | null>(null)
   const previewRequestIdRef = useRef(0)
   const visiblePreview =
     preview !== null && preview.courseId === loadedCourse?.id
       ? preview.result
       : null
+  const skippedPageWarnings =
+    visiblePreview === null ? [] : (preview?.skippedPages ?? [])
 
   const resetState = useCallback(() => {
     previewRequestIdRef.current += 1
@@ -119,6 +136,7 @@ export function StudentSyncDialog() {
     setError(null)
     setPreview(null)
     setProgressMessage("Connecting to LMS...")
+    const skippedPages: string[] = []
 
     try {
       const client = getWorkflowClient()
@@ -128,16 +146,27 @@ export function StudentSyncDialog() {
           course: loadedCourse,
           credentials,
           lmsCourseId,
+          matchPriority,
+          continueOnPageError,
+          maxMissingEmailRatio:
+            maxMissingEmailRatio === "none"
+              ? undefined
+              : Number(maxMissingEmailRatio),
         },
         {
           onProgress: (p) => {
             if (previewRequestIdRef.current !== requestId) return
             setProgressMessage(p.label)
           },
+          onOutput: (output) => {
+            if (output.code === "lms_roster_page_failed") {
+              skippedPages.push(output.message)
+            }
+          },
         },
       )
       if (previewRequestIdRef.current !== requestId) return
-      setPreview({ courseId: previewCourseId, result })
+      setPreview({ courseId: previewCourseId, result, skippedPages })
       setProgressMessage(null)
     } catch (previewError) {
       if (previewRequestIdRef.current !== requestId) return
@@ -157,6 +186,9 @@ export function StudentSyncDialog() {
     courseLoadStatus.state,
     lmsConnectionId,
     lmsCourseId,
+    matchPriority,
+    continueOnPageError,
+    maxMissingEmailRatio,
   ])
 
   useEffect(() => {
@@ -242,6 +274,82 @@ export function StudentSyncDialog() {
             </div>
           )}
 
+          {loadedCourse && (
+            <div className="flex items-center gap-2 text-sm">
+              <Label htmlFor="student-sync-match-priority">
+                Match members by
+              </Label>
+              <Select
+                value={matchPriority}
+                onValueChange={(value) => {
+                  setMatchPriority(value as LmsImportMatchPriority)
+                  autoPreviewedCourseIdRef.current = null
+                  resetState()
+                }}
+              >
+                <SelectTrigger
+                  id="student-sync-match-priority"
+                  className="w-auto"
+                >
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="lmsUserId">LMS user ID first</SelectItem>
+                  <SelectItem value="studentNumber">
+                    Student number first
+                  </SelectItem>
+                </SelectContent>
+              </Select>
+            </div>
+          )}
+
+          {loadedCourse && (
+            <div className="flex items-center gap-2 text-sm">
+              <Label htmlFor="student-sync-missing-email-ratio">
+                Reject if students without email exceed
+              </Label>
+              <Select
+                value={maxMissingEmailRatio}
+                onValueChange={(value) => {
+                  setMaxMissingEmailRatio(value)
+                  autoPreviewedCourseIdRef.current = null
+                  resetState()
+                }}
+              >
+                <SelectTrigger
+                  id="student-sync-missing-email-ratio"
+                  className="w-auto"
+                >
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {missingEmailRatioOptions.map((option) => (
+                    <SelectItem key={option.value} value={option.value}>
+                      {option.label}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+          )}
+
+          {loadedCourse && (
+            <div className="flex items-center gap-2 text-sm">
+              <Checkbox
+                id="student-sync-skip-failed-pages"
+                checked={continueOnPageError}
+                onCheckedChange={(next) => {
+                  setContinueOnPageError(next === true)
+                  autoPreviewedCourseIdRef.current = null
+                  resetState()
+                }}
+              />
+              <Label htmlFor="student-sync-skip-failed-pages">
+                Skip roster pages the LMS fails to return
+              </Label>
+            </div>
+          )}
+
           {loadingPreview && (
             <div className="inline-flex items-center gap-2 text-sm text-muted-foreground">
               <Loader2 className="size-4 animate-spin" />
@@ -274,6 +382,19 @@ export function StudentSyncDialog() {
                 {visiblePreview.summary.membersUpdated} to update,{" "}
                 {visiblePreview.summary.membersUnchanged} unchanged
               </p>
+              {skippedPageWarnings.length > 0 && (
+                <div className="space-y-1 text-xs text-amber-700 dark:text-amber-300">
+                  <p>
+                    Some roster pages were skipped. Members missing from the
+                    import are kept instead of being marked dropped.
+                  </p>
+                  <ul className="list-disc pl-4">
+                    {skippedPageWarnings.map((warning) => (
+                      <li key={warning}>{warning}</li>
+                    ))}
+                  </ul>
+                </div>
+              )}
               {visiblePreview.totalConflicts > 0 && (
                 <div className="space-y-1">
                   <p className="text-xs text-amber-700 dark:text-amber-300">
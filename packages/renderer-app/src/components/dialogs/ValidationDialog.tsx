@@ -172,6 +172,12 @@ function formatIssue(issue: RosterValidationIssue): string {
       return `Duplicate repo name: ${context ?? "unknown"} (${count} groups)`
     case "student_in_multiple_groups_in_assignment":
       return `${count} student${count === 1 ? "" : "s"} in multiple groups`
+    case "assignment_deadline_passed":
+      return `Assignment deadline passed: ${context ?? "unknown"}`
+    case "stale_lms_group_set":
+      return `LMS group set not synced recently: ${context ?? "unknown"}`
+    case "missing_required_file":
+      return `Template is missing required file: ${context ?? "unknown"}`
     case "orphan_group_member":
       return `${count} group member reference${count === 1 ? "" : "s"} unknown student${count === 1 ? "" : "s"}`
     case "missing_git_username":
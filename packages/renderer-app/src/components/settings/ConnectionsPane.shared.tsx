@@ -33,6 +33,8 @@ export type LlmDraft = {
 export const LMS_PROVIDER_LABEL: Record<LmsProviderKind, string> = {
   canvas: "Canvas",
   moodle: "Moodle",
+  blackboard: "Blackboard Learn",
+  brightspace: "Brightspace",
 }
 
 export function lmsConnectionDisplayName(
@@ -186,13 +186,19 @@ export function LmsConnectionsPane() {
     }
 
     const id = editorOriginalId ?? crypto.randomUUID()
+    const token = draft.token.trim()
+    const original = lmsConnections.find((c) => c.id === editorOriginalId)
     const nextConnection: PersistedLmsConnection = {
       id,
       name: draft.name.trim(),
       provider: draft.provider,
       baseUrl: normalizedBaseUrl,
-      token: draft.token.trim(),
+      token,
       userAgent: normalizeUserAgent(draft.userAgent),
+      // A replaced token no longer carries the previous token's expiry.
+      ...(original?.tokenExpiresAt && original.token === token
+        ? { tokenExpiresAt: original.tokenExpiresAt }
+        : {}),
     }
 
     if (editorOriginalId === null) {
@@ -229,6 +235,7 @@ export function LmsConnectionsPane() {
         baseUrl: normalizedBaseUrl,
         token: connection.token,
         userAgent: connection.userAgent,
+        tokenExpiresAt: connection.tokenExpiresAt,
       })
       setLmsConnectionStatus(
         connection.id,
@@ -277,6 +284,8 @@ export function LmsConnectionsPane() {
             <SelectContent>
               <SelectItem value="canvas">Canvas</SelectItem>
               <SelectItem value="moodle">Moodle</SelectItem>
+              <SelectItem value="blackboard">Blackboard Learn</SelectItem>
+              <SelectItem value="brightspace">Brightspace</SelectItem>
             </SelectContent>
           </Select>
         </FormField>
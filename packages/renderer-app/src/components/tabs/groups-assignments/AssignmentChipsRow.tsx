@@ -9,6 +9,7 @@ import {
   Input,
 } from "@repo-edu/ui"
 import {
+  Copy,
   EllipsisVertical,
   FileText,
   Pencil,
@@ -23,6 +24,7 @@ type AssignmentChipsRowProps = {
   onSelect: (id: string) => void
   onAdd: () => void
   onEdit: (id: string, name: string) => void
+  onCopy: (id: string) => void
   onDelete: (id: string) => void
   showSelection: boolean
   disabled: boolean
@@ -34,6 +36,7 @@ export function AssignmentChipsRow({
   onSelect,
   onAdd,
   onEdit,
+  onCopy,
   onDelete,
   showSelection,
   disabled,
@@ -51,6 +54,7 @@ export function AssignmentChipsRow({
           selectable={showSelection}
           onSelect={() => onSelect(a.id)}
           onEdit={(name) => onEdit(a.id, name)}
+          onCopy={() => onCopy(a.id)}
           onDelete={() => onDelete(a.id)}
           disabled={disabled}
         />
@@ -75,6 +79,7 @@ function AssignmentChip({
   selectable,
   onSelect,
   onEdit,
+  onCopy,
   onDelete,
   disabled,
 }: {
@@ -83,6 +88,7 @@ function AssignmentChip({
   selectable: boolean
   onSelect: () => void
   onEdit: (name: string) => void
+  onCopy: () => void
   onDelete: () => void
   disabled: boolean
 }) {
@@ -168,6 +174,14 @@ function AssignmentChip({
             <Pencil className="size-3 mr-1.5" />
             Rename
           </DropdownMenuItem>
+          <DropdownMenuItem
+            className="text-xs"
+            disabled={disabled}
+            onSelect={onCopy}
+          >
+            <Copy className="size-3 mr-1.5" />
+            Duplicate
+          </DropdownMenuItem>
           <DropdownMenuItem
             className="text-xs text-destructive"
             disabled={disabled}
@@ -9,6 +9,10 @@ export function connectionLabel(connection: GroupSetConnection | null): string {
       return "canvas"
     case "moodle":
       return "moodle"
+    case "blackboard":
+      return "blackboard"
+    case "brightspace":
+      return "brightspace"
     case "import":
       return "import"
   }
@@ -6,18 +6,24 @@ import {
   DropdownMenuContent,
   DropdownMenuItem,
   DropdownMenuSeparator,
+  DropdownMenuSub,
+  DropdownMenuSubContent,
+  DropdownMenuSubTrigger,
   DropdownMenuTrigger,
   Input,
 } from "@repo-edu/ui"
 import {
   EllipsisVertical,
+  Merge,
   Pencil,
+  Scissors,
   Trash2,
   X,
 } from "@repo-edu/ui/components/icons"
 import { useCallback, useEffect, useMemo, useRef, useState } from "react"
 import { useSessionController } from "../../../session/session-controller-context.js"
 import {
+  selectEditableGroupTargets,
   selectOtherGroupSetNames,
   useCourseStore,
 } from "../../../stores/course-store.js"
@@ -48,6 +54,15 @@ export function GroupNameCell({
   const otherNames = useCourseStore(otherSetNames)
   const courseId = useCourseStore((state) => state.course?.id ?? null)
   const isShared = otherNames.length > 0
+  const editableTargets = useCourseStore(selectEditableGroupTargets)
+  const mergeTargets = useMemo(
+    () =>
+      editableTargets
+        .find((target) => target.groupSetId === groupSetId)
+        ?.groups.filter((candidate) => candidate.id !== group.id) ?? [],
+    [editableTargets, groupSetId, group.id],
+  )
+  const canSplit = isSetEditable && group.memberIds.length > 1
 
   const controller = useSessionController()
 
@@ -145,6 +160,46 @@ export function GroupNameCell({
                 <Pencil className="size-3.5 mr-2" />
                 Rename
               </DropdownMenuItem>
+              {canSplit && (
+                <DropdownMenuItem
+                  disabled={disabled}
+                  onClick={() => {
+                    if (courseId !== null) {
+                      controller.splitGroup(courseId, groupSetId, group.id)
+                    }
+                  }}
+                >
+                  <Scissors className="size-3.5 mr-2" />
+                  Split in half
+                </DropdownMenuItem>
+              )}
+              {isSetEditable && mergeTargets.length > 0 && (
+                <DropdownMenuSub>
+                  <DropdownMenuSubTrigger disabled={disabled}>
+                    <Merge className="size-3.5 mr-2" />
+                    Merge with
+                  </DropdownMenuSubTrigger>
+                  <DropdownMenuSubContent>
+                    {mergeTargets.map((target) => (
+                      <DropdownMenuItem
+                        key={target.id}
+                        onSelect={() => {
+                          if (courseId !== null) {
+                            controller.mergeGroups(
+                              courseId,
+                              groupSetId,
+                              group.id,
+                              target.id,
+                            )
+                          }
+                        }}
+                      >
+                        {target.name}
+                      </DropdownMenuItem>
+                    ))}
+                  </DropdownMenuSubContent>
+                </DropdownMenuSub>
+              )}
               {isShared && (
                 <DropdownMenuItem
                   disabled={disabled}
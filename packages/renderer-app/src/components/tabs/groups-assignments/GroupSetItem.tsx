@@ -70,6 +70,8 @@ function connectionTimestamp(
   switch (connection.kind) {
     case "canvas":
     case "moodle":
+    case "blackboard":
+    case "brightspace":
       return {
         relative: `synced ${formatRelativeTime(connection.lastUpdated)}`,
         exact: formatExactTimestamp(connection.lastUpdated),
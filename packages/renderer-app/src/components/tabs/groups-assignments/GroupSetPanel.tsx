@@ -15,6 +15,7 @@ import {
   useCourseStore,
 } from "../../../stores/course-store.js"
 import { useUiStore } from "../../../stores/ui-store.js"
+import { isLmsGroupSetConnection } from "../../../utils/lms-provider.js"
 import { AssignmentChipsRow } from "./AssignmentChipsRow.js"
 import { GroupsTable } from "./GroupSetGroupsTable/index.js"
 import { RepoNameTemplateBuilder } from "./RepoNameTemplateBuilder.js"
@@ -25,7 +26,7 @@ type GroupSetPanelProps = {
 
 function getConnectionKind(
   connection: GroupSetConnection | null,
-): "local" | "system" | "canvas" | "moodle" | "import" {
+): "local" | GroupSetConnection["kind"] {
   if (!connection) return "local"
   return connection.kind
 }
@@ -104,7 +105,8 @@ export function GroupSetPanel({ groupSetId }: GroupSetPanelProps) {
   const connection = groupSet.connection
   const kind = getConnectionKind(connection)
   const isOperationActive = groupSetOperation !== null
-  const isReadOnly = kind === "system" || kind === "canvas" || kind === "moodle"
+  const isLmsConnected = isLmsGroupSetConnection(connection)
+  const isReadOnly = kind === "system" || isLmsConnected
   const isSetEditable = !isReadOnly && groupSet.nameMode === "named"
 
   const template =
@@ -139,7 +141,7 @@ export function GroupSetPanel({ groupSetId }: GroupSetPanelProps) {
         hiddenSegments={
           groupSet.nameMode === "unnamed"
             ? ["group", "surnames"]
-            : kind === "canvas" || kind === "moodle"
+            : isLmsConnected
               ? ["members"]
               : undefined
         }
@@ -158,6 +160,11 @@ export function GroupSetPanel({ groupSetId }: GroupSetPanelProps) {
           if (courseId !== null)
             controller.updateAssignment(courseId, id, { name })
         }}
+        onCopy={(id) => {
+          const source = assignments.find((a) => a.id === id)
+          if (courseId !== null && source)
+            controller.copyAssignment(courseId, id, `${source.name} (copy)`)
+        }}
         onDelete={(id) => {
           if (courseId !== null) controller.deleteAssignment(courseId, id)
         }}
@@ -18,6 +18,12 @@ export function RosterSourceBadge({ roster }: { roster: Roster | null }) {
     case "moodle":
       sourceLabel = "LMS (Moodle)"
       break
+    case "blackboard":
+      sourceLabel = "LMS (Blackboard)"
+      break
+    case "brightspace":
+      sourceLabel = "LMS (Brightspace)"
+      break
     case "import":
       sourceLabel = connection.sourceFilename
       break
@@ -1,3 +1,6 @@
+import type {
+  CourseBundleConflictResolution,
+} from "@repo-edu/application-contract"
 import {
   activeCourseIdFromSurface,
   type PersistedActiveSurface,
@@ -8,6 +11,7 @@ import type {
   PersistedCourse,
 } from "@repo-edu/domain/types"
 import { useCallback } from "react"
+import { useRendererHost } from "../contexts/renderer-host.js"
 import { useWorkflowClient } from "../contexts/workflow-client.js"
 import { useSessionController } from "../session/session-controller-context.js"
 import { useToastStore } from "../stores/toast-store.js"
@@ -61,6 +65,7 @@ export function useCourses() {
   const courseList = useUiStore((s) => s.courseList)
   const loading = useUiStore((s) => s.courseListLoading)
   const client = useWorkflowClient()
+  const rendererHost = useRendererHost()
   const controller = useSessionController()
 
   // Prune and redirect are owned by the AppShell effect that observes
@@ -152,6 +157,73 @@ export function useCourses() {
     [controller, refresh],
   )
 
+  const exportCourses = useCallback(
+    async (activeCourseId: string | null): Promise<void> => {
+      const addToast = useToastStore.getState().addToast
+      const target = await rendererHost.pickSaveTarget({
+        title: "Export courses",
+        suggestedName: `courses-${formatDateStamp()}.json`,
+        defaultFormat: "json",
+      })
+      if (!target) return
+      try {
+        const result = await client.run("course.exportBundle", {
+          target,
+          activeCourseId,
+        })
+        const count = result.manifest.courseCount
+        addToast(`Exported ${count} course${count === 1 ? "" : "s"}.`, {
+          tone: "success",
+        })
+      } catch (error) {
+        const message = getErrorMessage(error)
+        addToast(`Failed to export courses: ${message}`, { tone: "error" })
+      }
+    },
+    [client, rendererHost],
+  )
+
+  const importCourses = useCallback(
+    async (conflict: CourseBundleConflictResolution): Promise<boolean> => {
+      const addToast = useToastStore.getState().addToast
+      const file = await rendererHost.pickUserFile({
+        title: "Import courses",
+        acceptFormats: ["json"],
+      })
+      if (!file) return false
+      try {
+        const warnings: string[] = []
+        const result = await client.run(
+          "course.importBundle",
+          { file, conflict },
+          {
+            onOutput: (output) => {
+              if (output.channel === "warn") warnings.push(output.message)
+            },
+          },
+        )
+        await refresh()
+        const imported = result.importedCourseIds.length
+        const skipped = result.skippedCourseIds.length
+        addToast(
+          `Imported ${imported} course${imported === 1 ? "" : "s"}${
+            skipped > 0 ? `, ${skipped} skipped` : ""
+          }.`,
+          { tone: "success" },
+        )
+        for (const warning of warnings) {
+          addToast(warning, { tone: "warning" })
+        }
+        return true
+      } catch (error) {
+        const message = getErrorMessage(error)
+        addToast(`Failed to import courses: ${message}`, { tone: "error" })
+        return false
+      }
+    },
+    [client, rendererHost, refresh],
+  )
+
   return {
     courses: courseList,
     loading,
@@ -161,5 +233,15 @@ export function useCourses() {
     duplicateCourse,
     renameCourse,
     deleteCourse,
+    exportCourses,
+    importCourses,
   }
 }
+
+function formatDateStamp(): string {
+  const date = new Date()
+  const year = date.getFullYear()
+  const month = String(date.getMonth() + 1).padStart(2, "0")
+  const day = String(date.getDate()).padStart(2, "0")
+  return `${year}${month}${day}`
+}
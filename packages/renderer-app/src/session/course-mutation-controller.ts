@@ -21,11 +21,14 @@ export type CourseMutationActions = Pick<
   | "addAssignment"
   | "updateAssignment"
   | "deleteAssignment"
+  | "copyAssignment"
   | "createGroup"
   | "updateGroup"
   | "deleteGroup"
   | "moveMemberToGroup"
   | "copyMemberToGroup"
+  | "splitGroup"
+  | "mergeGroups"
   | "createLocalGroupSet"
   | "copyGroupSet"
   | "renameGroupSet"
@@ -103,6 +106,14 @@ export abstract class CourseMutationController {
     this.runCourseAction(courseId, "deleteAssignment", id)
   }
 
+  copyAssignment(
+    courseId: string,
+    assignmentId: string,
+    name: string,
+  ): string | null {
+    return this.runCourseAction(courseId, "copyAssignment", assignmentId, name)
+  }
+
   createGroup(
     courseId: string,
     groupSetId: string,
@@ -153,6 +164,29 @@ export abstract class CourseMutationController {
     this.runCourseAction(courseId, "copyMemberToGroup", memberId, targetGroupId)
   }
 
+  splitGroup(
+    courseId: string,
+    groupSetId: string,
+    groupId: string,
+  ): string | null {
+    return this.runCourseAction(courseId, "splitGroup", groupSetId, groupId)
+  }
+
+  mergeGroups(
+    courseId: string,
+    groupSetId: string,
+    groupAId: string,
+    groupBId: string,
+  ): void {
+    this.runCourseAction(
+      courseId,
+      "mergeGroups",
+      groupSetId,
+      groupAId,
+      groupBId,
+    )
+  }
+
   createLocalGroupSet(
     courseId: string,
     name: string,
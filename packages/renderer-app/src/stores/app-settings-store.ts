@@ -9,6 +9,7 @@ import {
   type PersistedAnalysisConcurrency,
   type PersistedAnalysisSidebarSettings,
   type PersistedAppPreferences,
+  type PersistedGroupSelection,
   pruneSubmissionStateForRecents,
   type SubmissionFolderRecent,
   type SubmissionSurfaceState,
@@ -67,6 +68,7 @@ type AppSettingsActions = {
   setAnalysisSidebar: (sidebar: PersistedAnalysisSidebarSettings | null) => void
 
   setAnalysisConcurrency: (concurrency: PersistedAnalysisConcurrency) => void
+  setGroupSelection: (selection: PersistedGroupSelection) => void
 
   reset: () => void
 }
@@ -410,6 +412,11 @@ export const useAppSettingsStore = create<
         settings: { ...state.settings, analysisConcurrency: concurrency },
       })),
 
+    setGroupSelection: (selection) =>
+      set((state) => ({
+        settings: { ...state.settings, groupSelection: selection },
+      })),
+
     reset: () => set(initialState),
   }
 })
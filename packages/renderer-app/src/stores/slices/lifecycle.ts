@@ -82,12 +82,14 @@ export function createLifecycleSlice(
       })
 
       try {
+        const now = new Date()
         const assignmentResults: Record<string, RosterValidationResult> = {}
         for (const assignment of roster.assignments) {
           assignmentResults[assignment.id] = validateAssignment(
             roster,
             assignment.id,
             identityMode,
+            { now },
           )
         }
 
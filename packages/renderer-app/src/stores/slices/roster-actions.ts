@@ -1,3 +1,4 @@
+import { mergeGroups, splitGroup } from "@repo-edu/domain/group-set"
 import {
   allocateAssignmentId,
   allocateGroupId,
@@ -5,6 +6,10 @@ import {
   allocateGroupSetId,
   allocateMemberId,
 } from "@repo-edu/domain/id-allocator"
+import {
+  type RosterMemberRemovalMode,
+  removeRosterMember,
+} from "@repo-edu/domain/roster"
 import {
   courseHasGroups,
   courseHasRoster,
@@ -32,6 +37,18 @@ function hasRosterSurfaceState(roster: Roster): boolean {
   )
 }
 
+function applyMemberRemoval(
+  roster: Roster,
+  id: string,
+  mode: RosterMemberRemovalMode,
+): void {
+  const { roster: next, removal } = removeRosterMember(roster, id, mode)
+  if (!removal.found) return
+  roster.students = next.students
+  roster.staff = next.staff
+  roster.groups = next.groups
+}
+
 export function createRosterActionsSlice(
   set: StoreSet,
   get: StoreGet,
@@ -47,12 +64,15 @@ export function createRosterActionsSlice(
   | "addAssignment"
   | "updateAssignment"
   | "deleteAssignment"
+  | "copyAssignment"
   | "setAssignmentSelection"
   | "createGroup"
   | "updateGroup"
   | "deleteGroup"
   | "moveMemberToGroup"
   | "copyMemberToGroup"
+  | "splitGroup"
+  | "mergeGroups"
   | "createLocalGroupSet"
   | "copyGroupSet"
   | "renameGroupSet"
@@ -101,15 +121,7 @@ export function createRosterActionsSlice(
       const state = get()
       if (!state.course || !courseHasRoster(state.course)) return
       internals.mutateRoster("Remove member", (roster) => {
-        const student = roster.students.find((m) => m.id === id)
-        if (student) {
-          student.status = "dropped"
-          return
-        }
-        const staff = roster.staff.find((m) => m.id === id)
-        if (staff) {
-          staff.status = "dropped"
-        }
+        applyMemberRemoval(roster, id, "deactivate")
       })
     },
 
@@ -117,11 +129,7 @@ export function createRosterActionsSlice(
       const state = get()
       if (!state.course || !courseHasRoster(state.course)) return
       internals.mutateRoster("Delete member permanently", (roster) => {
-        roster.students = roster.students.filter((m) => m.id !== id)
-        roster.staff = roster.staff.filter((m) => m.id !== id)
-        for (const group of roster.groups) {
-          group.memberIds = group.memberIds.filter((mid) => mid !== id)
-        }
+        applyMemberRemoval(roster, id, "purge")
       })
     },
 
@@ -207,6 +215,38 @@ export function createRosterActionsSlice(
       })
     },
 
+    copyAssignment: (assignmentId, name) => {
+      const state = get()
+      if (!state.course) return null
+      if (!courseHasGroups(state.course)) return null
+      const source = state.course.roster.assignments.find(
+        (a) => a.id === assignmentId,
+      )
+      if (!source) return null
+
+      const alloc = allocateAssignmentId(state.course.idSequences)
+      set((draft) => {
+        if (!draft.course) return
+        draft.course.idSequences = alloc.sequences
+      })
+
+      // Recorded repository names and the template commit describe
+      // repositories created for the source, so the copy starts empty.
+      internals.mutateRoster(
+        `Copy assignment "${source.name}"`,
+        (roster) => {
+          roster.assignments.push({
+            ...source,
+            id: alloc.id,
+            name,
+            templateCommitSha: null,
+            repositories: {},
+          })
+        },
+      )
+      return alloc.id
+    },
+
     setAssignmentSelection: (id) => {
       set((draft) => {
         draft.assignmentSelection = id
@@ -299,6 +339,52 @@ export function createRosterActionsSlice(
       })
     },
 
+    splitGroup: (groupSetId, groupId) => {
+      const state = get()
+      if (!state.course || !courseHasGroups(state.course)) return null
+      const group = state.course.roster.groups.find((g) => g.id === groupId)
+      if (!group) return null
+      const result = splitGroup(
+        state.course.roster,
+        state.course.idSequences,
+        groupSetId,
+        groupId,
+        Math.ceil(group.memberIds.length / 2),
+      )
+      if (!result.ok) return null
+      const { roster: next, idSequences, created } = result.value
+      set((draft) => {
+        if (!draft.course) return
+        draft.course.idSequences = idSequences
+      })
+
+      internals.mutateRoster(`Split group "${group.name}"`, (roster) => {
+        roster.groups = next.groups
+        roster.groupSets = next.groupSets
+      })
+      return created.id
+    },
+
+    mergeGroups: (groupSetId, groupAId, groupBId) => {
+      const state = get()
+      if (!state.course || !courseHasGroups(state.course)) return
+      const groupA = state.course.roster.groups.find((g) => g.id === groupAId)
+      if (!groupA) return
+      const result = mergeGroups(
+        state.course.roster,
+        groupSetId,
+        groupAId,
+        groupBId,
+        groupA.name,
+      )
+      if (!result.ok) return
+      const next = result.value.roster
+      internals.mutateRoster(`Merge into group "${groupA.name}"`, (roster) => {
+        roster.groups = next.groups
+        roster.groupSets = next.groupSets
+      })
+    },
+
     // ------------------------------------------------------------------
     // Group set CRUD
     // ------------------------------------------------------------------
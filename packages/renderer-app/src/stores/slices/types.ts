@@ -56,6 +56,7 @@ export type CourseActions = {
   addAssignment: (assignment: Omit<Assignment, "id">) => void
   updateAssignment: (id: string, updates: Partial<Assignment>) => void
   deleteAssignment: (id: string) => void
+  copyAssignment: (assignmentId: string, name: string) => string | null
   setAssignmentSelection: (id: string | null) => void
 
   // Group CRUD
@@ -72,6 +73,8 @@ export type CourseActions = {
     targetGroupId: string,
   ) => void
   copyMemberToGroup: (memberId: string, targetGroupId: string) => void
+  splitGroup: (groupSetId: string, groupId: string) => string | null
+  mergeGroups: (groupSetId: string, groupAId: string, groupBId: string) => void
 
   // Group set CRUD
   createLocalGroupSet: (name: string, groupIds?: string[]) => string | null
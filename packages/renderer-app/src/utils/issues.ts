@@ -188,6 +188,12 @@ function validationKindLabel(kind: RosterValidationKind): string {
       return "Duplicate repo names"
     case "student_in_multiple_groups_in_assignment":
       return "Students in multiple groups"
+    case "assignment_deadline_passed":
+      return "Deadline passed"
+    case "stale_lms_group_set":
+      return "Stale LMS group set"
+    case "missing_required_file":
+      return "Missing template files"
     case "orphan_group_member":
       return "Unknown students"
     case "missing_git_username":
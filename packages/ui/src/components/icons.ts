@@ -45,6 +45,7 @@ export {
   LockKeyholeOpen,
   LockOpen,
   Menu,
+  Merge,
   MessageSquare,
   Minus,
   Monitor,
@@ -55,6 +56,7 @@ export {
   Redo2,
   RefreshCw,
   RotateCcw,
+  Scissors,
   Search,
   Settings,
   Square,